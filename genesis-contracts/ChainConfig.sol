@@ -15,6 +15,8 @@ contract ChainConfig {
     uint256 public calldataGasPerByte; // Calldata gas cost per byte (default: 16)
     uint256 public maxTxGas;           // Max gas per transaction (default: 30_000_000)
     bool    public eagerMining;        // Mine on tx arrival vs interval (default: false)
+    uint256 public recentSignerWindow; // Blocks before a signer may seal again (0 = floor(N/2) + 1)
+    uint256 public epoch;              // Blocks per epoch (0 = the node's configured epoch)
 
     // ---- Events ----
     event GovernanceTransferred(address indexed previous, address indexed newGovernance);
@@ -24,6 +26,8 @@ contract ChainConfig {
     event CalldataGasPerByteUpdated(uint256 newCost);
     event MaxTxGasUpdated(uint256 newMaxTxGas);
     event EagerMiningUpdated(bool enabled);
+    event RecentSignerWindowUpdated(uint256 newWindow);
+    event EpochUpdated(uint256 newEpoch);
 
     // ---- Modifiers ----
     modifier onlyGovernance() {
@@ -86,6 +90,20 @@ contract ChainConfig {
         emit EagerMiningUpdated(_enabled);
     }
 
+    /// @notice The node caps the window at the signer count and applies it from
+    ///         the next epoch block.
+    function setRecentSignerWindow(uint256 _window) external onlyGovernance {
+        recentSignerWindow = _window;
+        emit RecentSignerWindowUpdated(_window);
+    }
+
+    /// @notice A new length takes effect at the next epoch boundary under the
+    ///         current one, so nodes agree on which blocks are checkpoints.
+    function setEpoch(uint256 _epoch) external onlyGovernance {
+        epoch = _epoch;
+        emit EpochUpdated(_epoch);
+    }
+
     function transferGovernance(address _newGovernance) external onlyGovernance {
         require(_newGovernance != address(0), "ChainConfig: zero address");
         emit GovernanceTransferred(governance, _newGovernance);
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.28;
+
+/// @title ERC20 - Minimal token deployed for genesis ERC-20 prefunds
+/// @notice Reference source of `src/bytecodes/erc20.bin`. The genesis writes
+///         balances and total supply straight into storage, so there is no
+///         constructor and no mint: the layout below must not change.
+///         `name` and `symbol` are short strings (at most 31 bytes) and are
+///         left empty by the genesis.
+contract ERC20 {
+    // ---- State (OpenZeppelin layout) ----
+    mapping(address => uint256) private _balances;                      // slot 0
+    mapping(address => mapping(address => uint256)) private _allowances; // slot 1
+    uint256 private _totalSupply;                                        // slot 2
+    string private _name;                                                // slot 3
+    string private _symbol;                                              // slot 4
+
+    // ---- Events ----
+    event Transfer(address indexed from, address indexed to, uint256 value);
+    event Approval(address indexed owner, address indexed spender, uint256 value);
+
+    // ---- Views ----
+    function name() external view returns (string memory) {
+        return _name;
+    }
+
+    function symbol() external view returns (string memory) {
+        return _symbol;
+    }
+
+    function decimals() external pure returns (uint8) {
+        return 18;
+    }
+
+    function totalSupply() external view returns (uint256) {
+        return _totalSupply;
+    }
+
+    function balanceOf(address account) external view returns (uint256) {
+        return _balances[account];
+    }
+
+    function allowance(address owner, address spender) external view returns (uint256) {
+        return _allowances[owner][spender];
+    }
+
+    // ---- Transfers ----
+    function transfer(address to, uint256 value) external returns (bool) {
+        _transfer(msg.sender, to, value);
+        return true;
+    }
+
+    function approve(address spender, uint256 value) external returns (bool) {
+        require(spender != address(0));
+        _allowances[msg.sender][spender] = value;
+        emit Approval(msg.sender, spender, value);
+        return true;
+    }
+
+    /// @notice An allowance of `type(uint256).max` is never spent.
+    function transferFrom(address from, address to, uint256 value) external returns (bool) {
+        uint256 allowed = _allowances[from][msg.sender];
+        if (allowed != type(uint256).max) {
+            require(value <= allowed);
+            _allowances[from][msg.sender] = allowed - value;
+        }
+        _transfer(from, to, value);
+        return true;
+    }
+
+    function _transfer(address from, address to, uint256 value) private {
+        require(from != address(0) && to != address(0));
+        uint256 fromBalance = _balances[from];
+        require(value <= fromBalance);
+        unchecked {
+            _balances[from] = fromBalance - value;
+            // Cannot overflow: the sum of all balances is the total supply
+            _balances[to] += value;
+        }
+        emit Transfer(from, to, value);
+    }
+}
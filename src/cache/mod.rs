@@ -12,10 +12,22 @@
 //! ```
 //!
 //! The cache is safe to share across threads via `Arc<Mutex<HotStateCache>>`.
+//!
+//! Entries can be persisted with [`HotStateCache::save_to_disk`] on shutdown and
+//! restored with [`HotStateCache::load_from_disk`] on startup, so the first blocks
+//! after a restart don't pay full MDBX read cost. Stale entries are harmless: the
+//! epoch refresh invalidates `SIGNER_REGISTRY_ADDRESS` before re-reading it.
+//!
+//! An optional TTL ([`HotStateCache::with_ttl`]) bounds staleness for slots that are
+//! not refresh-driven: expired entries are treated as misses and re-read downstream.
 
 use alloy_primitives::{Address, B256, U256};
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
+use std::io;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use crate::onchain::StorageReader;
 
@@ -33,6 +45,8 @@ pub struct CacheConfig {
     pub max_entries: usize,
     /// Automatically invalidate the cache every N block-builds (0 = never auto-invalidate).
     pub invalidate_every_n_blocks: u64,
+    /// Expire entries this long after insertion (`None` = entries never expire).
+    pub ttl: Option<Duration>,
 }
 
 impl Default for CacheConfig {
@@ -40,6 +54,7 @@ impl Default for CacheConfig {
         Self {
             max_entries: 1_024,
             invalidate_every_n_blocks: 0,
+            ttl: None,
         }
     }
 }
@@ -50,6 +65,7 @@ impl CacheConfig {
         Self {
             max_entries: 256,
             invalidate_every_n_blocks: 30_000, // re-seed at every epoch
+            ttl: None,
         }
     }
 
@@ -58,6 +74,7 @@ impl CacheConfig {
         Self {
             max_entries,
             invalidate_every_n_blocks: 0,
+            ttl: None,
         }
     }
 }
@@ -94,26 +111,37 @@ impl CacheStats {
     }
 }
 
+/// On-disk representation of a single cache entry.
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedEntry {
+    address: Address,
+    slot: U256,
+    value: B256,
+}
+
 /// LRU hot state cache mapping `(Address, slot) → B256`.
 ///
 /// Internally uses a `HashMap` for O(1) lookup and a `VecDeque` to track
 /// LRU order (front = least recently used, back = most recently used).
+/// Each value is stored with its insertion time for optional TTL expiry.
 #[derive(Debug)]
 pub struct HotStateCache {
-    map: HashMap<(Address, U256), B256>,
+    map: HashMap<(Address, U256), (B256, Instant)>,
     order: VecDeque<(Address, U256)>,
     max_entries: usize,
+    ttl: Option<Duration>,
     stats: CacheStats,
 }
 
 impl HotStateCache {
-    /// Create a new cache with the given maximum capacity.
+    /// Create a new cache with the given maximum capacity and no TTL.
     pub fn new(max_entries: usize) -> Self {
         assert!(max_entries > 0, "cache capacity must be > 0");
         Self {
             map: HashMap::with_capacity(max_entries),
             order: VecDeque::with_capacity(max_entries),
             max_entries,
+            ttl: None,
             stats: CacheStats {
                 max_entries,
                 ..Default::default()
@@ -121,26 +149,80 @@ impl HotStateCache {
         }
     }
 
+    /// Create a cache from a [`CacheConfig`] (capacity and TTL).
+    pub fn from_config(config: &CacheConfig) -> Self {
+        let cache = Self::new(config.max_entries);
+        match config.ttl {
+            Some(ttl) => cache.with_ttl(ttl),
+            None => cache,
+        }
+    }
+
+    /// Expire entries `ttl` after they were inserted.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Configured entry TTL, if any.
+    pub fn ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+
     /// Look up a slot value. Updates LRU order on hit.
+    ///
+    /// An entry older than the TTL counts as a miss and is dropped.
     pub fn get(&mut self, addr: Address, slot: U256) -> Option<B256> {
+        self.get_at(addr, slot, Instant::now())
+    }
+
+    /// [`get`](Self::get) with an explicit current time.
+    fn get_at(&mut self, addr: Address, slot: U256, now: Instant) -> Option<B256> {
         let key = (addr, slot);
-        if let Some(&value) = self.map.get(&key) {
-            self.stats.hits += 1;
-            // Promote to MRU position
-            if let Some(pos) = self.order.iter().position(|k| *k == key) {
-                self.order.remove(pos);
-                self.order.push_back(key);
+        match self.map.get(&key) {
+            Some(&(value, inserted)) if !self.is_expired(inserted, now) => {
+                self.stats.hits += 1;
+                // Promote to MRU position
+                if let Some(pos) = self.order.iter().position(|k| *k == key) {
+                    self.order.remove(pos);
+                    self.order.push_back(key);
+                }
+                Some(value)
+            }
+            Some(_) => {
+                self.remove(&key);
+                self.stats.misses += 1;
+                None
+            }
+            None => {
+                self.stats.misses += 1;
+                None
             }
-            Some(value)
-        } else {
-            self.stats.misses += 1;
-            None
         }
     }
 
+    fn is_expired(&self, inserted: Instant, now: Instant) -> bool {
+        self.ttl
+            .is_some_and(|ttl| now.saturating_duration_since(inserted) >= ttl)
+    }
+
+    fn remove(&mut self, key: &(Address, U256)) {
+        self.map.remove(key);
+        if let Some(pos) = self.order.iter().position(|k| k == key) {
+            self.order.remove(pos);
+        }
+        self.stats.current_entries = self.map.len();
+    }
+
     /// Insert or update a slot value. Evicts LRU entry if at capacity.
     pub fn insert(&mut self, addr: Address, slot: U256, value: B256) {
+        self.insert_at(addr, slot, value, Instant::now());
+    }
+
+    /// [`insert`](Self::insert) with an explicit insertion time.
+    fn insert_at(&mut self, addr: Address, slot: U256, value: B256, now: Instant) {
         let key = (addr, slot);
+        let value = (value, now);
         if self.map.contains_key(&key) {
             self.map.insert(key, value);
             // Refresh MRU position
@@ -173,10 +255,7 @@ impl HotStateCache {
             .cloned()
             .collect();
         for key in to_remove {
-            self.map.remove(&key);
-            if let Some(pos) = self.order.iter().position(|k| *k == key) {
-                self.order.remove(pos);
-            }
+            self.remove(&key);
         }
         self.stats.current_entries = self.map.len();
     }
@@ -202,6 +281,49 @@ impl HotStateCache {
     pub fn stats(&self) -> CacheStats {
         self.stats.clone()
     }
+
+    /// Write all entries to `path` as JSON, in LRU order (least recent first).
+    ///
+    /// Writes to a temporary file and renames it so a crash never leaves a
+    /// truncated cache file behind.
+    pub fn save_to_disk(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let entries: Vec<PersistedEntry> = self
+            .order
+            .iter()
+            .filter_map(|key| {
+                self.map.get(key).map(|(value, _)| PersistedEntry {
+                    address: key.0,
+                    slot: key.1,
+                    value: *value,
+                })
+            })
+            .collect();
+        let json = serde_json::to_vec(&entries).map_err(io::Error::other)?;
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, json)?;
+        std::fs::rename(&tmp, path)
+    }
+
+    /// Load entries previously written by [`save_to_disk`](Self::save_to_disk).
+    ///
+    /// Entries are inserted in saved LRU order, so if the file holds more entries
+    /// than this cache's capacity, the most recently used ones are kept.
+    /// Returns the number of entries read from the file.
+    pub fn load_from_disk(&mut self, path: impl AsRef<Path>) -> io::Result<usize> {
+        let bytes = std::fs::read(path)?;
+        let entries: Vec<PersistedEntry> =
+            serde_json::from_slice(&bytes).map_err(io::Error::other)?;
+        let count = entries.len();
+        for entry in entries {
+            self.insert(entry.address, entry.slot, entry.value);
+        }
+        Ok(count)
+    }
 }
 
 /// A [`StorageReader`] wrapper that adds a thread-safe LRU cache in front of
@@ -230,7 +352,7 @@ impl<R: StorageReader> CachedStorageReader<R> {
     pub fn new(inner: R, config: CacheConfig) -> Self {
         Self {
             inner,
-            cache: Arc::new(Mutex::new(HotStateCache::new(config.max_entries))),
+            cache: Arc::new(Mutex::new(HotStateCache::from_config(&config))),
         }
     }
 
@@ -488,6 +610,118 @@ mod tests {
         assert_eq!(cache.get(addr(2), slot(0)), Some(val(2)));
     }
 
+    // ── Persistence tests ─────────────────────────────────────────────────────
+
+    fn temp_cache_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "meowchain-cache-{name}-{}/hot_state_cache.json",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn test_cache_save_and_load_roundtrip() {
+        let path = temp_cache_path("roundtrip");
+        let mut cache = HotStateCache::new(10);
+        cache.insert(addr(1), slot(0), val(1));
+        cache.insert(addr(2), slot(5), val(2));
+        cache.save_to_disk(&path).unwrap();
+
+        let mut restored = HotStateCache::new(10);
+        assert_eq!(restored.load_from_disk(&path).unwrap(), 2);
+        assert_eq!(restored.get(addr(1), slot(0)), Some(val(1)));
+        assert_eq!(restored.get(addr(2), slot(5)), Some(val(2)));
+
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_cache_load_keeps_most_recent_when_over_capacity() {
+        let path = temp_cache_path("capacity");
+        let mut cache = HotStateCache::new(10);
+        cache.insert(addr(1), slot(0), val(1));
+        cache.insert(addr(2), slot(0), val(2));
+        cache.insert(addr(3), slot(0), val(3));
+        cache.get(addr(1), slot(0)); // addr(1) becomes MRU
+        cache.save_to_disk(&path).unwrap();
+
+        let mut restored = HotStateCache::new(2);
+        assert_eq!(restored.load_from_disk(&path).unwrap(), 3);
+        assert_eq!(restored.len(), 2);
+        assert_eq!(restored.get(addr(2), slot(0)), None, "LRU entry dropped");
+        assert_eq!(restored.get(addr(3), slot(0)), Some(val(3)));
+        assert_eq!(restored.get(addr(1), slot(0)), Some(val(1)));
+
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_cache_load_missing_file_errors() {
+        let mut cache = HotStateCache::new(4);
+        assert!(cache.load_from_disk(temp_cache_path("missing")).is_err());
+        assert!(cache.is_empty());
+    }
+
+    // ── TTL tests ─────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_ttl_expired_entry_is_miss() {
+        let mut cache = HotStateCache::new(10).with_ttl(Duration::from_secs(10));
+        let t0 = Instant::now();
+        cache.insert_at(addr(1), slot(0), val(1), t0);
+
+        // Within TTL: hit
+        let t1 = t0 + Duration::from_secs(5);
+        assert_eq!(cache.get_at(addr(1), slot(0), t1), Some(val(1)));
+
+        // Clock advanced past TTL: miss, entry dropped
+        let t2 = t0 + Duration::from_secs(11);
+        assert!(cache.get_at(addr(1), slot(0), t2).is_none());
+        assert!(cache.is_empty());
+        assert_eq!(cache.stats().hits, 1);
+        assert_eq!(cache.stats().misses, 1);
+        assert_eq!(cache.stats().current_entries, 0);
+    }
+
+    #[test]
+    fn test_no_ttl_entries_never_expire() {
+        let mut cache = HotStateCache::new(10);
+        assert!(cache.ttl().is_none());
+        let t0 = Instant::now();
+        cache.insert_at(addr(1), slot(0), val(1), t0);
+        let much_later = t0 + Duration::from_secs(365 * 24 * 3600);
+        assert_eq!(cache.get_at(addr(1), slot(0), much_later), Some(val(1)));
+    }
+
+    #[test]
+    fn test_reinsert_refreshes_ttl() {
+        let mut cache = HotStateCache::new(10).with_ttl(Duration::from_secs(10));
+        let t0 = Instant::now();
+        cache.insert_at(addr(1), slot(0), val(1), t0);
+        cache.insert_at(addr(1), slot(0), val(2), t0 + Duration::from_secs(8));
+        let t = t0 + Duration::from_secs(12);
+        assert_eq!(cache.get_at(addr(1), slot(0), t), Some(val(2)));
+    }
+
+    #[test]
+    fn test_cached_reader_rereads_expired_slot() {
+        let storage = MockStorage::new().with_entry(addr(1), slot(0), val(7));
+        let config = CacheConfig {
+            ttl: Some(Duration::from_millis(20)),
+            ..Default::default()
+        };
+        let reader = CachedStorageReader::new(storage, config);
+
+        assert_eq!(reader.read_storage(addr(1), slot(0)), Some(val(7))); // miss
+        assert_eq!(reader.read_storage(addr(1), slot(0)), Some(val(7))); // hit
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(reader.read_storage(addr(1), slot(0)), Some(val(7))); // expired -> miss
+
+        let stats = reader.stats();
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.misses, 2);
+    }
+
     // ── CachedStorageReader tests ─────────────────────────────────────────────
 
     #[test]
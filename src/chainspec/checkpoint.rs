@@ -0,0 +1,126 @@
+//! Live signer checkpoint.
+//!
+//! The live signer list is read from `SignerRegistry` at epoch blocks, so a
+//! restarted node runs on genesis signers until the next epoch. Saving the list
+//! on shutdown ([`SignerCheckpoint::save_to_disk`]) and restoring it on startup
+//! closes that gap.
+//!
+//! A checkpoint can disagree with genesis. [`SignerPrecedence`] decides which
+//! wins: by default the checkpoint for a node past genesis, and genesis for a
+//! node with no blocks — there the checkpoint is left over from a wiped
+//! datadir and must not misconfigure the fresh chain.
+
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Signer list saved from a running node.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignerCheckpoint {
+    /// Chain head when the checkpoint was written.
+    pub block_number: u64,
+    /// Live signers at that block.
+    pub signers: Vec<Address>,
+}
+
+impl SignerCheckpoint {
+    /// Write the checkpoint as JSON to `path` (via a temp file + rename).
+    pub fn save_to_disk(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, serde_json::to_vec_pretty(self)?)?;
+        std::fs::rename(&tmp, path)
+    }
+
+    /// Read a checkpoint written by [`Self::save_to_disk`].
+    pub fn load_from_disk(path: impl AsRef<Path>) -> io::Result<Self> {
+        let data = std::fs::read(path)?;
+        serde_json::from_slice(&data).map_err(io::Error::other)
+    }
+}
+
+/// Which signer list wins when a checkpoint disagrees with genesis.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SignerPrecedence {
+    /// Genesis at block 0, the checkpoint past genesis.
+    #[default]
+    Auto,
+    /// Always use genesis signers (ignore the checkpoint).
+    Genesis,
+    /// Always use the checkpoint.
+    Checkpoint,
+}
+
+/// Where the startup signer list came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignerSource {
+    /// `PoaConfig.signers` from genesis.
+    Genesis,
+    /// The restored [`SignerCheckpoint`].
+    Checkpoint,
+}
+
+impl SignerPrecedence {
+    /// The winning source for a node whose chain head is `head_block`.
+    pub fn resolve(self, head_block: u64) -> SignerSource {
+        match self {
+            Self::Auto if head_block == 0 => SignerSource::Genesis,
+            Self::Auto | Self::Checkpoint => SignerSource::Checkpoint,
+            Self::Genesis => SignerSource::Genesis,
+        }
+    }
+}
+
+impl FromStr for SignerPrecedence {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "genesis" => Ok(Self::Genesis),
+            "checkpoint" => Ok(Self::Checkpoint),
+            other => Err(format!(
+                "unknown signer precedence '{other}' (expected auto, genesis or checkpoint)"
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_precedence_resolution() {
+        assert_eq!(SignerPrecedence::Auto.resolve(0), SignerSource::Genesis);
+        assert_eq!(SignerPrecedence::Auto.resolve(1), SignerSource::Checkpoint);
+        assert_eq!(
+            SignerPrecedence::Genesis.resolve(500),
+            SignerSource::Genesis
+        );
+        assert_eq!(
+            SignerPrecedence::Checkpoint.resolve(0),
+            SignerSource::Checkpoint
+        );
+        assert_eq!("genesis".parse(), Ok(SignerPrecedence::Genesis));
+        assert!("latest".parse::<SignerPrecedence>().is_err());
+    }
+
+    #[test]
+    fn test_checkpoint_disk_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "meowchain-signer-checkpoint-{}.json",
+            std::process::id()
+        ));
+        let checkpoint = SignerCheckpoint {
+            block_number: 90_000,
+            signers: vec![Address::with_last_byte(1), Address::with_last_byte(2)],
+        };
+        checkpoint.save_to_disk(&path).unwrap();
+        assert_eq!(SignerCheckpoint::load_from_disk(&path).unwrap(), checkpoint);
+        let _ = std::fs::remove_file(&path);
+    }
+}
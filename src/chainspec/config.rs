@@ -1,6 +1,43 @@
+use crate::genesis::{signers_from_extra_data, GenesisError};
+use alloy_genesis::Genesis;
 use alloy_primitives::Address;
+use reth_chainspec::BaseFeeParams;
 use serde::{Deserialize, Serialize};
 
+/// Key of [`PoaBaseFeeParams`] among the genesis `config` fields.
+pub const BASE_FEE_PARAMS_KEY: &str = "baseFeeParams";
+
+/// EIP-1559 base fee parameters, set chain-wide in the genesis `config.baseFeeParams`.
+///
+/// Every node must use the same values, since they decide each block's base fee.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoaBaseFeeParams {
+    /// The base fee moves by at most `1/max_change_denominator` per block
+    pub max_change_denominator: u64,
+    /// The gas target is `gas_limit / elasticity_multiplier`
+    pub elasticity_multiplier: u64,
+}
+
+impl Default for PoaBaseFeeParams {
+    fn default() -> Self {
+        let ethereum = BaseFeeParams::ethereum();
+        Self {
+            max_change_denominator: ethereum.max_change_denominator as u64,
+            elasticity_multiplier: ethereum.elasticity_multiplier as u64,
+        }
+    }
+}
+
+impl From<PoaBaseFeeParams> for BaseFeeParams {
+    fn from(params: PoaBaseFeeParams) -> Self {
+        Self::new(
+            params.max_change_denominator.into(),
+            params.elasticity_multiplier.into(),
+        )
+    }
+}
+
 /// POA-specific configuration that extends the standard chain config
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -11,6 +48,12 @@ pub struct PoaConfig {
     pub epoch: u64,
     /// List of authorized signer addresses
     pub signers: Vec<Address>,
+    /// Include transactions to governance contracts ahead of ordinary ones at equal fee
+    #[serde(default)]
+    pub prioritize_governance_txs: bool,
+    /// EIP-1559 base fee parameters (Ethereum's by default)
+    #[serde(default)]
+    pub base_fee_params: PoaBaseFeeParams,
 }
 
 impl Default for PoaConfig {
@@ -19,6 +62,41 @@ impl Default for PoaConfig {
             period: 12, // 12 second block time like mainnet
             epoch: 30000,
             signers: vec![],
+            prioritize_governance_txs: false,
+            base_fee_params: PoaBaseFeeParams::default(),
+        }
+    }
+}
+
+impl PoaConfig {
+    /// Derive the POA config from a genesis: signers from its extraData, period and
+    /// epoch from its `clique` config, base fee parameters from its
+    /// `config.baseFeeParams` (defaults where absent).
+    pub fn from_genesis(genesis: &Genesis) -> Result<Self, GenesisError> {
+        let defaults = Self::default();
+        let clique = genesis.config.clique.as_ref();
+        let base_fee_params = match genesis
+            .config
+            .extra_fields
+            .get_deserialized::<PoaBaseFeeParams>(BASE_FEE_PARAMS_KEY)
+        {
+            Some(params) => {
+                params.map_err(|e| GenesisError::InvalidBaseFeeParams(e.to_string()))?
+            }
+            None => defaults.base_fee_params,
+        };
+        if base_fee_params.max_change_denominator == 0 || base_fee_params.elasticity_multiplier == 0
+        {
+            return Err(GenesisError::InvalidBaseFeeParams(
+                "maxChangeDenominator and elasticityMultiplier must be non-zero".to_string(),
+            ));
         }
+        Ok(Self {
+            period: clique.and_then(|c| c.period).unwrap_or(defaults.period),
+            epoch: clique.and_then(|c| c.epoch).unwrap_or(defaults.epoch),
+            signers: signers_from_extra_data(&genesis.extra_data)?,
+            base_fee_params,
+            ..defaults
+        })
     }
 }
@@ -0,0 +1,129 @@
+//! Epoch length schedule, following `ChainConfig`'s epoch slot.
+//!
+//! Switching the epoch length at an arbitrary block would leave nodes disagreeing
+//! on which blocks are checkpoints, so a new length read at an epoch block is only
+//! scheduled: it takes effect at the next boundary under the old length, and epochs
+//! of the new length are counted from that block.
+
+/// Which blocks are epoch (checkpoint) blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EpochSchedule {
+    /// First block of the current length's epochs (0 for the genesis length).
+    anchor: u64,
+    /// Current epoch length.
+    length: u64,
+    /// Scheduled change: (first block, new length).
+    pending: Option<(u64, u64)>,
+}
+
+impl EpochSchedule {
+    /// Epochs of `length` blocks from genesis.
+    pub fn new(length: u64) -> Self {
+        Self {
+            anchor: 0,
+            length: length.max(1),
+            pending: None,
+        }
+    }
+
+    /// Epoch length currently in force (not counting a scheduled change).
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+
+    /// Epoch length in force at `block_number`.
+    pub fn length_at(&self, block_number: u64) -> u64 {
+        self.current_at(block_number).1
+    }
+
+    /// First block of the epochs of the length in force at `block_number`.
+    pub fn anchor_at(&self, block_number: u64) -> u64 {
+        self.current_at(block_number).0
+    }
+
+    /// The scheduled change as (first block, new length), if any.
+    pub fn pending(&self) -> Option<(u64, u64)> {
+        self.pending
+    }
+
+    /// Whether `block_number` is an epoch block.
+    ///
+    /// Blocks before the current length took effect are never epoch blocks under
+    /// it; the schedule only describes the chain from there on.
+    pub fn is_epoch_block(&self, block_number: u64) -> bool {
+        let (anchor, length) = self.current_at(block_number);
+        block_number >= anchor && (block_number - anchor).is_multiple_of(length)
+    }
+
+    /// Apply the epoch length `length` read from the state at epoch block
+    /// `block_number`.
+    ///
+    /// A change that `block_number` has reached is put in force first. A different
+    /// `length` is then scheduled for the next boundary under the current length,
+    /// replacing any change still pending. Returns the block a newly scheduled
+    /// change takes effect at.
+    pub fn update(&mut self, block_number: u64, length: u64) -> Option<u64> {
+        if let Some((at, new_length)) = self.pending {
+            if block_number >= at {
+                self.anchor = at;
+                self.length = new_length;
+                self.pending = None;
+            }
+        }
+
+        let length = length.max(1);
+        if length == self.length {
+            self.pending = None;
+            return None;
+        }
+        if self.pending.is_some_and(|(_, pending)| pending == length) {
+            return None;
+        }
+        let elapsed = block_number.saturating_sub(self.anchor) / self.length + 1;
+        let at = self.anchor + elapsed * self.length;
+        self.pending = Some((at, length));
+        Some(at)
+    }
+
+    fn current_at(&self, block_number: u64) -> (u64, u64) {
+        match self.pending {
+            Some((at, length)) if block_number >= at => (at, length),
+            _ => (self.anchor, self.length),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_change_waits_for_next_boundary_under_old_length() {
+        let mut schedule = EpochSchedule::new(10);
+        assert!(schedule.is_epoch_block(20));
+        assert!(!schedule.is_epoch_block(25));
+
+        // Read 15 at block 20: blocks 21..=29 still use 10, the switch is at 30
+        assert_eq!(schedule.update(20, 15), Some(30));
+        assert_eq!(schedule.length_at(29), 10);
+        assert!(schedule.is_epoch_block(30));
+        assert_eq!(schedule.length_at(30), 15);
+        assert_eq!(schedule.anchor_at(29), 0);
+        assert_eq!(schedule.anchor_at(30), 30);
+        // New epochs count from 30, not from genesis
+        assert!(!schedule.is_epoch_block(40));
+        assert!(schedule.is_epoch_block(45));
+        assert!(schedule.is_epoch_block(60));
+
+        // Re-reading the same value doesn't move the switch
+        assert_eq!(schedule.update(30, 15), None);
+        assert_eq!(schedule.pending(), None);
+        assert!(schedule.is_epoch_block(45));
+
+        // Reverting before a change takes effect cancels it
+        assert_eq!(schedule.update(45, 20), Some(60));
+        assert_eq!(schedule.update(45, 15), None);
+        assert_eq!(schedule.pending(), None);
+        assert!(schedule.is_epoch_block(75));
+    }
+}
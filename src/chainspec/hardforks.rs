@@ -1,6 +1,7 @@
 use alloy_primitives::U256;
 use reth_chainspec::{ChainHardforks, ForkCondition, Hardfork};
 use reth_ethereum_forks::EthereumHardfork;
+use std::collections::BTreeMap;
 
 /// Creates hardforks configuration that matches Ethereum mainnet.
 /// This ensures full smart contract compatibility.
@@ -52,3 +53,17 @@ pub fn mainnet_compatible_hardforks() -> ChainHardforks {
         ),
     ])
 }
+
+/// Mainnet-compatible hardforks with some activations moved, e.g. Prague at a
+/// future timestamp to exercise a chain crossing the fork.
+///
+/// Forks without an override stay active at genesis.
+pub fn hardforks_with_overrides(
+    overrides: &BTreeMap<EthereumHardfork, ForkCondition>,
+) -> ChainHardforks {
+    let mut hardforks = mainnet_compatible_hardforks();
+    for (fork, condition) in overrides {
+        hardforks.insert(*fork, *condition);
+    }
+    hardforks
+}
@@ -3,10 +3,14 @@
 //! This module defines the chain specification for a POA network that maintains
 //! full compatibility with Ethereum mainnet's EVM and hardforks.
 
+pub mod checkpoint;
 pub mod config;
+pub mod epoch;
 pub mod hardforks;
 
-pub use config::PoaConfig;
+pub use checkpoint::{SignerCheckpoint, SignerPrecedence, SignerSource};
+pub use config::{PoaBaseFeeParams, PoaConfig, BASE_FEE_PARAMS_KEY};
+pub use epoch::EpochSchedule;
 
 use alloy_consensus::Header;
 use alloy_eips::eip7840::BlobParams;
@@ -19,6 +23,7 @@ use reth_chainspec::{
 use reth_ethereum_forks::EthereumHardfork;
 use reth_network_peers::NodeRecord;
 use reth_primitives_traits::SealedHeader;
+use std::collections::BTreeMap;
 use std::sync::{Arc, RwLock};
 
 /// Custom POA chain specification
@@ -32,6 +37,13 @@ pub struct PoaChainSpec {
     /// None = not yet synced from chain (falls back to poa_config.signers).
     /// Arc<RwLock<...>> so Clone shares the same live cache across consensus + payload.
     live_signers: Arc<RwLock<Option<Vec<Address>>>>,
+    /// Live recent-signer window from `ChainConfig`, updated at epoch blocks alongside
+    /// `live_signers`. None = not yet synced (consensus falls back to `floor(N/2) + 1`).
+    live_recent_signer_window: Arc<RwLock<Option<u64>>>,
+    /// Epoch blocks, starting from `poa_config.epoch` and following `ChainConfig`'s
+    /// epoch slot as read at epoch blocks. Not persisted: after a restart it starts
+    /// over from the configured length.
+    epoch_schedule: Arc<RwLock<EpochSchedule>>,
     /// Static bootnodes for P2P peer discovery.
     boot_nodes: Vec<NodeRecord>,
 }
@@ -39,10 +51,23 @@ pub struct PoaChainSpec {
 impl PoaChainSpec {
     /// Creates a new POA chain spec from genesis and POA config
     pub fn new(genesis: Genesis, poa_config: PoaConfig) -> Self {
+        Self::new_with_hardforks(genesis, poa_config, &BTreeMap::new())
+    }
+
+    /// Creates a new POA chain spec with some hardforks activated later than genesis.
+    ///
+    /// Hardforks missing from `overrides` keep the default all-at-genesis activation.
+    pub fn new_with_hardforks(
+        genesis: Genesis,
+        poa_config: PoaConfig,
+        overrides: &BTreeMap<EthereumHardfork, ForkCondition>,
+    ) -> Self {
         // Build hardforks - enable all Ethereum hardforks for mainnet compatibility
-        let hardforks = hardforks::mainnet_compatible_hardforks();
+        let hardforks = hardforks::hardforks_with_overrides(overrides);
 
-        let genesis_header = reth_chainspec::make_genesis_header(&genesis, &hardforks);
+        let mut genesis_header = reth_chainspec::make_genesis_header(&genesis, &hardforks);
+        // A chain forked from existing state starts at that state's height
+        genesis_header.number = genesis.number.unwrap_or_default();
 
         let inner = ChainSpec {
             chain: Chain::from_id(genesis.config.chain_id),
@@ -52,15 +77,17 @@ impl PoaChainSpec {
             paris_block_and_final_difficulty: Some((0, U256::ZERO)),
             hardforks,
             deposit_contract: None,
-            base_fee_params: BaseFeeParamsKind::Constant(BaseFeeParams::ethereum()),
+            base_fee_params: BaseFeeParamsKind::Constant(poa_config.base_fee_params.into()),
             prune_delete_limit: 10000,
             blob_params: Default::default(),
         };
 
         Self {
             inner: Arc::new(inner),
+            epoch_schedule: Arc::new(RwLock::new(EpochSchedule::new(poa_config.epoch))),
             poa_config,
             live_signers: Arc::new(RwLock::new(None)),
+            live_recent_signer_window: Arc::new(RwLock::new(None)),
             boot_nodes: Vec::new(),
         }
     }
@@ -72,6 +99,8 @@ impl PoaChainSpec {
             period: 1, // 1-second blocks for dev (Phase 2)
             epoch: 30000,
             signers: crate::genesis::dev_signers(),
+            prioritize_governance_txs: false,
+            base_fee_params: PoaBaseFeeParams::default(),
         };
         Self::new(genesis, poa_config)
     }
@@ -114,6 +143,40 @@ impl PoaChainSpec {
         }
     }
 
+    /// The recent-signer window last read from `ChainConfig`, if synced.
+    pub fn live_recent_signer_window(&self) -> Option<u64> {
+        self.live_recent_signer_window.read().ok().and_then(|g| *g)
+    }
+
+    /// Update the recent-signer window from the on-chain `ChainConfig` contract.
+    ///
+    /// Called by `PoaPayloadBuilder` at epoch blocks, right after `update_live_signers`.
+    pub fn update_recent_signer_window(&self, window: u64) {
+        if let Ok(mut guard) = self.live_recent_signer_window.write() {
+            *guard = Some(window);
+        }
+    }
+
+    /// Seed the live signer list from a saved checkpoint, subject to `precedence`.
+    ///
+    /// `head_block` is the node's chain head at startup. Returns the source that won;
+    /// an empty checkpoint always loses to genesis.
+    pub fn apply_signer_checkpoint(
+        &self,
+        checkpoint: &SignerCheckpoint,
+        head_block: u64,
+        precedence: SignerPrecedence,
+    ) -> SignerSource {
+        if checkpoint.signers.is_empty() {
+            return SignerSource::Genesis;
+        }
+        let source = precedence.resolve(head_block);
+        if source == SignerSource::Checkpoint {
+            self.update_live_signers(checkpoint.signers.clone());
+        }
+        source
+    }
+
     /// Whether the live signer cache has been populated from on-chain data.
     pub fn has_live_signers(&self) -> bool {
         self.live_signers
@@ -128,11 +191,53 @@ impl PoaChainSpec {
         self.poa_config.period
     }
 
-    /// Returns the epoch length
+    /// Returns the configured (genesis/CLI) epoch length
     pub fn epoch(&self) -> u64 {
         self.poa_config.epoch
     }
 
+    /// Returns the epoch length currently in force: the on-chain `ChainConfig` value
+    /// once a change has taken effect, else [`Self::epoch`].
+    pub fn effective_epoch(&self) -> u64 {
+        self.epoch_schedule
+            .read()
+            .map(|schedule| schedule.length())
+            .unwrap_or(self.poa_config.epoch)
+    }
+
+    /// Whether `block_number` is an epoch (checkpoint) block under the live schedule.
+    pub fn is_epoch_block(&self, block_number: u64) -> bool {
+        match self.epoch_schedule.read() {
+            Ok(schedule) => schedule.is_epoch_block(block_number),
+            Err(_) => block_number.is_multiple_of(self.poa_config.epoch),
+        }
+    }
+
+    /// Epochs in force at `block_number`, as (first block they count from, length).
+    pub fn epoch_at(&self, block_number: u64) -> (u64, u64) {
+        match self.epoch_schedule.read() {
+            Ok(schedule) => (
+                schedule.anchor_at(block_number),
+                schedule.length_at(block_number),
+            ),
+            Err(_) => (0, self.poa_config.epoch),
+        }
+    }
+
+    /// Apply the epoch length read from `ChainConfig` at epoch block `block_number`
+    /// (`None` = slot unset, so the configured length applies).
+    ///
+    /// Called by `PoaPayloadBuilder` at epoch blocks alongside `update_live_signers`.
+    /// A new length takes effect at the next epoch boundary under the old one; the
+    /// return value is that block when a change was just scheduled.
+    pub fn update_epoch(&self, block_number: u64, epoch: Option<u64>) -> Option<u64> {
+        let epoch = epoch.unwrap_or(self.poa_config.epoch);
+        self.epoch_schedule
+            .write()
+            .ok()
+            .and_then(|mut schedule| schedule.update(block_number, epoch))
+    }
+
     /// Set static bootnodes for P2P peer discovery.
     pub fn with_bootnodes(mut self, bootnodes: Vec<NodeRecord>) -> Self {
         self.boot_nodes = bootnodes;
@@ -251,6 +356,67 @@ mod tests {
         assert_eq!(chain.block_period(), 1); // Phase 2: 1s blocks
     }
 
+    #[test]
+    fn test_poa_config_from_genesis() {
+        let config = crate::genesis::GenesisConfig::dev()
+            .with_signers(vec![Address::with_last_byte(1), Address::with_last_byte(2)])
+            .with_block_period(3);
+        let genesis = crate::genesis::create_genesis(config);
+
+        let poa = PoaConfig::from_genesis(&genesis).unwrap();
+        assert_eq!(
+            poa.signers,
+            vec![Address::with_last_byte(1), Address::with_last_byte(2)]
+        );
+        assert_eq!(poa.period, 3);
+        assert_eq!(poa.epoch, 30000);
+
+        let mut bad = genesis;
+        bad.extra_data = vec![0u8; 40].into();
+        assert!(PoaConfig::from_genesis(&bad).is_err());
+    }
+
+    #[test]
+    fn test_genesis_number_and_timestamp() {
+        let config = crate::genesis::GenesisConfig::dev()
+            .with_genesis_number(1_000)
+            .with_genesis_timestamp(1_700_000_000);
+        let genesis = crate::genesis::create_genesis(config);
+        let poa = PoaConfig::from_genesis(&genesis).unwrap();
+        let chain = PoaChainSpec::new(genesis, poa);
+        assert_eq!(chain.genesis_header().number, 1_000);
+        assert_eq!(chain.genesis_header().timestamp, 1_700_000_000);
+
+        // The default genesis stays at block 0, time 0
+        let dev = PoaChainSpec::dev_chain();
+        assert_eq!(dev.genesis_header().number, 0);
+        assert_eq!(dev.genesis().number, None);
+        assert_eq!(dev.genesis_header().timestamp, 0);
+    }
+
+    #[test]
+    fn test_prague_inactive_before_configured_timestamp() {
+        let overrides =
+            BTreeMap::from([(EthereumHardfork::Prague, ForkCondition::Timestamp(1_000))]);
+        let chain = PoaChainSpec::new_with_hardforks(
+            crate::genesis::create_dev_genesis(),
+            PoaConfig::default(),
+            &overrides,
+        );
+
+        assert!(chain.is_cancun_active_at_timestamp(0));
+        assert!(!chain.is_prague_active_at_timestamp(0));
+        assert!(!chain.is_prague_active_at_timestamp(999));
+        assert!(chain.is_prague_active_at_timestamp(1_000));
+        // Pre-Prague genesis header carries no requests hash
+        assert!(chain.genesis_header().requests_hash.is_none());
+
+        // Default stays all-at-genesis
+        let chain = PoaChainSpec::dev_chain();
+        assert!(chain.is_prague_active_at_timestamp(0));
+        assert!(chain.genesis_header().requests_hash.is_some());
+    }
+
     #[test]
     fn test_dev_chain_id() {
         let chain = PoaChainSpec::dev_chain();
@@ -322,6 +488,7 @@ mod tests {
                     .parse()
                     .unwrap(),
             ],
+            ..PoaConfig::default()
         };
         let chain = PoaChainSpec::new(genesis, poa_config);
 
@@ -367,6 +534,7 @@ mod tests {
             period: 2,
             epoch: 30000,
             signers: vec![], // No signers
+            ..PoaConfig::default()
         };
         let chain = PoaChainSpec::new(genesis, poa_config);
 
@@ -389,6 +557,7 @@ mod tests {
             period: 12,
             epoch: 30000,
             signers: crate::genesis::dev_accounts().into_iter().take(5).collect(),
+            ..PoaConfig::default()
         };
         let chain = PoaChainSpec::new(genesis, poa_config);
 
@@ -457,6 +626,7 @@ mod tests {
             period: 2,
             epoch: 30000,
             signers: crate::genesis::dev_accounts().into_iter().take(5).collect(),
+            ..PoaConfig::default()
         };
         let prod_chain = PoaChainSpec::new(prod_genesis, prod_config);
         assert_eq!(prod_chain.inner().chain.id(), 9323310);
@@ -489,6 +659,7 @@ mod tests {
             period: 2,
             epoch: 30000,
             signers: vec![signer],
+            ..PoaConfig::default()
         };
         let chain = PoaChainSpec::new(genesis, poa_config);
 
@@ -512,6 +683,7 @@ mod tests {
             period: 2,
             epoch: 30000,
             signers: signers.clone(),
+            ..PoaConfig::default()
         };
         let chain = PoaChainSpec::new(genesis, poa_config);
 
@@ -537,6 +709,7 @@ mod tests {
             signers: vec!["0x0000000000000000000000000000000000000001"
                 .parse()
                 .unwrap()],
+            ..PoaConfig::default()
         };
         let chain = PoaChainSpec::new(genesis, poa_config);
 
@@ -616,6 +789,40 @@ mod tests {
         assert_eq!(chain_clone.effective_signers(), new_signers);
     }
 
+    fn conflicting_checkpoint() -> SignerCheckpoint {
+        SignerCheckpoint {
+            block_number: 1200,
+            signers: vec![Address::with_last_byte(0x77)],
+        }
+    }
+
+    #[test]
+    fn test_conflicting_checkpoint_ignored_at_genesis() {
+        let chain = PoaChainSpec::dev_chain();
+        let checkpoint = conflicting_checkpoint();
+
+        let source = chain.apply_signer_checkpoint(&checkpoint, 0, SignerPrecedence::Auto);
+        assert_eq!(source, SignerSource::Genesis);
+        assert!(!chain.has_live_signers());
+        assert_eq!(chain.effective_signers(), chain.signers().to_vec());
+    }
+
+    #[test]
+    fn test_conflicting_checkpoint_wins_past_genesis() {
+        let chain = PoaChainSpec::dev_chain();
+        let checkpoint = conflicting_checkpoint();
+
+        let source = chain.apply_signer_checkpoint(&checkpoint, 1200, SignerPrecedence::Auto);
+        assert_eq!(source, SignerSource::Checkpoint);
+        assert_eq!(chain.effective_signers(), checkpoint.signers);
+
+        // Explicit genesis precedence overrides even past genesis
+        let fresh = PoaChainSpec::dev_chain();
+        let source = fresh.apply_signer_checkpoint(&checkpoint, 1200, SignerPrecedence::Genesis);
+        assert_eq!(source, SignerSource::Genesis);
+        assert_eq!(fresh.effective_signers(), fresh.signers().to_vec());
+    }
+
     #[test]
     fn test_base_fee_params_delegation() {
         let chain = PoaChainSpec::dev_chain();
@@ -624,6 +831,36 @@ mod tests {
         assert_eq!(params, inner_params);
     }
 
+    #[test]
+    fn test_custom_base_fee_params() {
+        let mut genesis = crate::genesis::create_dev_genesis();
+        // Without the field, Ethereum's parameters apply
+        let poa = PoaConfig::from_genesis(&genesis).unwrap();
+        assert_eq!(
+            PoaChainSpec::new(genesis.clone(), poa).base_fee_params_at_timestamp(0),
+            BaseFeeParams::ethereum()
+        );
+
+        genesis.config.extra_fields.insert(
+            BASE_FEE_PARAMS_KEY.to_string(),
+            serde_json::json!({ "maxChangeDenominator": 50, "elasticityMultiplier": 4 }),
+        );
+        let poa = PoaConfig::from_genesis(&genesis).unwrap();
+        let chain = PoaChainSpec::new(genesis.clone(), poa);
+        let params = BaseFeeParams::new(50, 4);
+        assert_eq!(chain.base_fee_params_at_timestamp(0), params);
+        assert_eq!(chain.base_fee_params_at_timestamp(u64::MAX), params);
+
+        genesis.config.extra_fields.insert(
+            BASE_FEE_PARAMS_KEY.to_string(),
+            serde_json::json!({ "maxChangeDenominator": 0, "elasticityMultiplier": 4 }),
+        );
+        assert!(matches!(
+            PoaConfig::from_genesis(&genesis),
+            Err(crate::genesis::GenesisError::InvalidBaseFeeParams(_))
+        ));
+    }
+
     #[test]
     fn test_bootnodes_returns_none() {
         let chain = PoaChainSpec::dev_chain();
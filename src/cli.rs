@@ -1,5 +1,16 @@
-use clap::Parser;
+use crate::chainspec::SignerPrecedence;
+use crate::constants::PRUNE_KEEP_BLOCKS;
+use crate::keystore::KeystoreFormat;
+use crate::node::PoolLimits;
+use crate::payload::{FallbackPolicy, TransactionOrdering};
+use crate::signer::remote::DEFAULT_REMOTE_SIGNER_TIMEOUT;
+use alloy_primitives::Address;
+use clap::{Args, Parser, Subcommand};
+use reth_dns_discovery::tree::LinkEntry;
+use reth_ethereum::node::core::args::PruningArgs;
+use reth_rpc_server_types::constants::DEFAULT_IPC_ENDPOINT;
 use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// CLI arguments for the POA node
 #[derive(Parser, Debug)]
@@ -35,17 +46,74 @@ pub struct Cli {
 
     /// Signer private key (hex, without 0x prefix).
     /// Can also be set via SIGNER_KEY environment variable.
-    #[arg(long, env = "SIGNER_KEY")]
+    #[arg(long, env = "SIGNER_KEY", conflicts_with = "keystore_dir")]
     pub signer_key: Option<String>,
 
+    /// Unix time (seconds) after which the --signer-key key is no longer used to
+    /// seal blocks, so a rotated-out key can't keep signing past its validity.
+    #[arg(long, requires = "signer_key")]
+    pub signer_key_expires_at: Option<u64>,
+
+    /// Keystore directory. The node loads every authorized signer key from the
+    /// keystores in it (alternative to --signer-key for multi-key validators,
+    /// needs --keystore-password-file); the `keystore` command manages it.
+    #[arg(long, global = true)]
+    pub keystore_dir: Option<PathBuf>,
+
+    /// File containing the password for the keystores in --keystore-dir.
+    /// A trailing newline is ignored.
+    #[arg(long, requires = "keystore_dir")]
+    pub keystore_password_file: Option<PathBuf>,
+
+    /// Sign blocks through an external Clef-style signer at this HTTP URL instead
+    /// of a local key. Requires --remote-signer-address.
+    #[arg(
+        long,
+        conflicts_with_all = ["signer_key", "keystore_dir"],
+        requires = "remote_signer_address"
+    )]
+    pub remote_signer_url: Option<String>,
+
+    /// Signer address(es) the remote signer holds keys for (comma-separated).
+    #[arg(long, value_delimiter = ',', requires = "remote_signer_url")]
+    pub remote_signer_address: Vec<Address>,
+
+    /// Timeout for each remote signing request, in milliseconds.
+    #[arg(long, default_value_t = DEFAULT_REMOTE_SIGNER_TIMEOUT.as_millis() as u64)]
+    pub remote_signer_timeout_ms: u64,
+
+    /// Short identifier (e.g. node nickname, max 32 bytes) embedded in the vanity
+    /// region of blocks sealed by the --signer-key signer. Lets operators tell which
+    /// physical node produced a block when several nodes hold the same key.
+    #[arg(long)]
+    pub signer_vanity: Option<String>,
+
+    /// Text (e.g. a client version) written into the vanity region of every block
+    /// this node seals, truncated or zero-padded to 32 bytes. --signer-vanity takes
+    /// precedence for its signer.
+    #[arg(long)]
+    pub block_vanity: Option<String>,
+
     /// Use production genesis configuration (chain ID 9323310)
     #[arg(long)]
     pub production: bool,
 
+    /// Launch from this genesis JSON instead of the built-in dev/production genesis.
+    /// Signers are read from its extraData and the block period from its `clique`
+    /// config, so every node sharing the file agrees on them.
+    #[arg(long, conflicts_with = "gas_limit")]
+    pub genesis: Option<PathBuf>,
+
     /// Disable dev mode (no auto-mining)
     #[arg(long)]
     pub no_dev: bool,
 
+    /// Comma-separated indices of the dev keys this dev node holds (0-9). Give each
+    /// node of a local multi-authority net its own subset, e.g. `0` on one node and
+    /// `1,2` on another. Defaults to the three genesis signers.
+    #[arg(long, value_delimiter = ',', default_value = "0,1,2")]
+    pub dev_signer_indices: Vec<usize>,
+
     /// Override block gas limit (e.g., 100000000 for 100M, 1000000000 for 1B)
     #[arg(long)]
     pub gas_limit: Option<u64>,
@@ -61,6 +129,43 @@ pub struct Cli {
     #[arg(long)]
     pub mining: bool,
 
+    /// Maximum number of transactions per block. Pending transactions beyond the
+    /// cap wait for the next block. With dev mining this is also the pending-tx
+    /// count that triggers a block.
+    #[arg(long)]
+    pub block_max_txs: Option<usize>,
+
+    /// Reject transactions whose effective gas price (at the pending base fee) is
+    /// below this many wei. Submissions fail with `transaction underpriced`. 0 = no floor.
+    #[arg(long, default_value = "0")]
+    pub min_gas_price: u128,
+
+    /// Maximum transactions in the pool's pending sub-pool (default: Reth's
+    /// --txpool.pending-max-count).
+    #[arg(long)]
+    pub txpool_max_pending: Option<usize>,
+
+    /// Maximum transactions in the pool's queued sub-pool (default: Reth's
+    /// --txpool.queued-max-count).
+    #[arg(long)]
+    pub txpool_max_queued: Option<usize>,
+
+    /// Maximum transactions a single sender may have in the pool; further ones are
+    /// rejected (default: Reth's --txpool.max-account-slots).
+    #[arg(long)]
+    pub txpool_max_per_account: Option<usize>,
+
+    /// Don't seal blocks without transactions. A keep-alive empty block is still
+    /// sealed once the chain has been idle for --max-idle seconds.
+    /// Has no effect with --eager-mining, which only mines when transactions arrive.
+    #[arg(long)]
+    pub no_empty_blocks: bool,
+
+    /// Seconds without a block after which --no-empty-blocks seals an empty
+    /// keep-alive block so block timestamps keep advancing.
+    #[arg(long, default_value = "60")]
+    pub max_idle: u64,
+
     /// P2P listener port for peer-to-peer connections.
     #[arg(long, default_value = "30303")]
     pub port: u16,
@@ -70,6 +175,11 @@ pub struct Cli {
     #[arg(long, value_delimiter = ',')]
     pub bootnodes: Option<Vec<String>>,
 
+    /// EIP-1459 ENR tree(s) to discover peers from over DNS, comma-separated.
+    /// Example: enrtree://<base32 public key>@nodes.example.org
+    #[arg(long, value_delimiter = ',')]
+    pub discovery_dns: Vec<LinkEntry>,
+
     /// Disable P2P peer discovery (useful for single-node testing).
     #[arg(long)]
     pub disable_discovery: bool,
@@ -91,6 +201,8 @@ pub struct Cli {
     /// Increase to allow larger contracts (e.g. 524288 = 512KB).
     /// Override is applied to the EVM via revm's `limit_contract_code_size`.
     /// Set to 0 to use the Ethereum default (24,576 bytes).
+    /// A non-zero `ChainConfig.maxContractSize` on chain takes precedence, so
+    /// governance can change the limit without a restart.
     #[arg(long, default_value = "0")]
     pub max_contract_size: usize,
 
@@ -114,6 +226,7 @@ pub struct Cli {
     /// A POA chain can reduce this to increase calldata-heavy throughput.
     /// Default is 4 (same cost as zero bytes), effectively making calldata cheap.
     /// Set to 16 to disable the discount and match Ethereum mainnet behaviour.
+    /// The governed `ChainConfig.calldataGasPerByte` takes precedence once set.
     #[arg(long, default_value = "4", value_parser = clap::value_parser!(u64).range(1..=16))]
     pub calldata_gas: u64,
 
@@ -129,6 +242,11 @@ pub struct Cli {
     #[arg(long, default_value = "9001")]
     pub metrics_port: u16,
 
+    /// Port for plain HTTP health probes: `GET /health` (liveness) and `GET /ready`
+    /// (503 unless a block arrived within 3× the block interval). Disabled by default.
+    #[arg(long)]
+    pub health_port: Option<u16>,
+
     /// Comma-separated list of allowed CORS origins for the HTTP RPC server.
     ///
     /// Use "*" to allow all origins. Default: none (no CORS headers).
@@ -150,6 +268,18 @@ pub struct Cli {
     #[arg(long, default_value = "eth,net,web3")]
     pub ws_api: String,
 
+    /// Path of the JSON-RPC unix domain socket (named pipe on Windows), e.g. for
+    /// `geth attach ipc:<path>` or `cast --rpc-url <path>`.
+    ///
+    /// IPC serves all of Reth's modules plus `meow`, `clique` and `admin`.
+    /// Defaults to Reth's endpoint.
+    #[arg(long, default_value = DEFAULT_IPC_ENDPOINT)]
+    pub ipc_path: PathBuf,
+
+    /// Don't serve JSON-RPC over IPC.
+    #[arg(long)]
+    pub ipcdisable: bool,
+
     /// Enable structured JSON logging instead of human-readable output.
     ///
     /// Useful for log aggregation systems (ELK, Loki, Datadog, etc.).
@@ -186,10 +316,29 @@ pub struct Cli {
     #[arg(long)]
     pub archive: bool,
 
+    /// Prune receipts older than the last 10,064 blocks.
+    ///
+    /// Any --prune-* flag replaces the default full-node pruning: only the selected
+    /// segments are pruned and everything else is kept.
+    #[arg(long, conflicts_with = "archive")]
+    pub prune_receipts: bool,
+
+    /// Prune account and storage history before this block number.
+    #[arg(
+        long,
+        value_name = "BLOCK",
+        conflicts_with_all = ["archive", "prune_account_history"]
+    )]
+    pub prune_history_before: Option<u64>,
+
+    /// Prune account history older than the last 10,064 blocks.
+    #[arg(long, conflicts_with = "archive")]
+    pub prune_account_history: bool,
+
     /// Gas price oracle: number of recent blocks to sample for gas estimation.
     ///
     /// Higher values give smoother estimates but increase computation.
-    /// Used by eth_gasPrice and eth_feeHistory.
+    /// Used by eth_gasPrice and eth_feeHistory, and as the meow_getFeeHistory default.
     #[arg(long, default_value = "20")]
     pub gpo_blocks: u32,
 
@@ -200,4 +349,347 @@ pub struct Cli {
     /// Range: 0-100.
     #[arg(long, default_value = "60")]
     pub gpo_percentile: u32,
+
+    /// Persist the hot state cache to this file on graceful shutdown and warm
+    /// the cache from it on startup (disabled if unset).
+    #[arg(long)]
+    pub cache_file: Option<PathBuf>,
+
+    /// Save the live signer list to this file on graceful shutdown and restore
+    /// it on startup (disabled if unset).
+    #[arg(long)]
+    pub signer_checkpoint: Option<PathBuf>,
+
+    /// Which signer list wins when --signer-checkpoint disagrees with genesis:
+    /// `auto` (genesis on a fresh node, checkpoint past genesis), `genesis`, or `checkpoint`.
+    #[arg(long, default_value = "auto")]
+    pub signer_precedence: SignerPrecedence,
+
+    /// Base out-of-turn wiggle delay in milliseconds (Clique's `wiggleTime`).
+    /// Out-of-turn signers wait up to `(N/2 + 1) * wiggle` before sealing so the
+    /// in-turn signer usually wins. 0 = seal immediately.
+    #[arg(long, default_value = "500")]
+    pub wiggle_ms: u64,
+
+    /// Which signers may seal when the in-turn signer misses its slot: `any`
+    /// (every other eligible signer, separated only by the wiggle delay) or `next`
+    /// (only the next eligible signer in the rotation, so at most one out-of-turn
+    /// block per slot).
+    #[arg(long, default_value = "any")]
+    pub fallback_policy: FallbackPolicy,
+
+    /// Enter a degraded safe mode while fewer distinct signers sealed the last epoch
+    /// than the SignerRegistry threshold: only in-turn blocks are sealed until enough
+    /// signers are back online. Reported by meow_status.
+    #[arg(long)]
+    pub safe_mode: bool,
+
+    /// Log an error when a reorg reverts more than this many blocks, a sign the
+    /// node was partitioned onto a long fork. 0 = disabled.
+    #[arg(long, default_value = "0")]
+    pub max_reorg_depth: u64,
+
+    /// Stop sealing blocks after a reorg deeper than --max-reorg-depth, until the
+    /// node is restarted. No effect without --max-reorg-depth.
+    #[arg(long)]
+    pub halt_on_deep_reorg: bool,
+
+    /// Order in which sealed blocks include pending transactions: `priority`
+    /// (highest tip first), `fifo` (by arrival) or `sender` (grouped by sender).
+    /// The last two are for reproducing ordering-dependent bugs.
+    #[arg(long, default_value = "priority")]
+    pub tx_ordering: TransactionOrdering,
+
+    /// Append each block's state diff to this log file (disabled if unset).
+    #[arg(long)]
+    pub statediff_log: Option<PathBuf>,
+
+    /// How often buffered state diffs are written and fsynced to --statediff-log,
+    /// in milliseconds. Bounds data loss on crash to this interval; diffs are
+    /// always flushed on graceful shutdown. 0 = flush after every block.
+    #[arg(long, default_value = "1000")]
+    pub statediff_flush_ms: u64,
+
+    /// Write each block's state diff as `<block_number>.json` into this directory
+    /// (disabled if unset). Intended as the feed for an external replica.
+    #[arg(long)]
+    pub statediff_dir: Option<PathBuf>,
+
+    /// Maximum age in seconds of a new head (by header timestamp) for per-block
+    /// logging. Older heads (e.g. a catch-up burst after downtime) are folded into
+    /// periodic summary lines instead. 0 = log every block.
+    #[arg(long, default_value = "60")]
+    pub monitor_max_age: u64,
+
+    /// Number of recent blocks whose signer is kept in memory for `clique_status`
+    /// and `meow_getSignerStatus`, so they don't recover signers from headers.
+    #[arg(long, default_value = "10000")]
+    pub signer_index_size: usize,
+
+    /// Verify governance contract reads (ChainConfig, SignerRegistry) against the
+    /// state root with Merkle storage proofs. Detects database corruption or a
+    /// compromised provider; expensive, intended for high-assurance deployments.
+    #[arg(long)]
+    pub verify_storage_reads: bool,
+
+    /// Refuse to start when --gas-limit or --block-time disagree with the on-chain
+    /// ChainConfig values (by default mismatches are only reported).
+    #[arg(long)]
+    pub strict_param_check: bool,
+
+    /// Include transactions to governance contracts (ChainConfig, SignerRegistry,
+    /// Timelock, governance Safe) ahead of ordinary transactions paying the same fee.
+    #[arg(long)]
+    pub prioritize_governance_txs: bool,
+
+    /// Warn when fewer than this many distinct signers sealed the last
+    /// `signers.len()` blocks (reported by `meow_getDecentralizationHealth`). 0 = disabled.
+    #[arg(long, default_value = "0")]
+    pub min_distinct_signers: usize,
+
+    /// In fork choice, prefer a chain meeting --min-distinct-signers over one that
+    /// doesn't, before comparing weights (used by `meow_simulateReorg`). No effect
+    /// without a threshold.
+    #[arg(long)]
+    pub strict_diversity: bool,
+
+    /// Fork-choice weight of each in-turn block on top of its length, so a chain
+    /// weighs `length + bonus * in_turn_blocks` (used by `meow_simulateReorg`).
+    /// Defaults to in-turn count deciding and length only breaking ties.
+    #[arg(long)]
+    pub in_turn_bonus: Option<u64>,
+
+    /// Run a management command instead of starting the node.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+impl Cli {
+    /// Whether any --prune-* flag was given.
+    pub fn custom_pruning(&self) -> bool {
+        self.prune_receipts || self.prune_history_before.is_some() || self.prune_account_history
+    }
+
+    /// Transaction pool limits from the --txpool-max-* flags.
+    pub fn pool_limits(&self) -> PoolLimits {
+        PoolLimits {
+            max_pending: self.txpool_max_pending,
+            max_queued: self.txpool_max_queued,
+            max_per_account: self.txpool_max_per_account,
+        }
+    }
+
+    /// Time left at `now` until the --signer-key key expires (zero once past it),
+    /// or `None` if it doesn't expire.
+    pub fn signer_key_expires_in(&self, now: SystemTime) -> Option<Duration> {
+        let expires_at = UNIX_EPOCH + Duration::from_secs(self.signer_key_expires_at?);
+        Some(expires_at.duration_since(now).unwrap_or_default())
+    }
+
+    /// IPC socket path to serve JSON-RPC on, or `None` with --ipcdisable.
+    pub fn ipc_endpoint(&self) -> Option<String> {
+        (!self.ipcdisable).then(|| self.ipc_path.display().to_string())
+    }
+
+    /// Reth pruning arguments for the selected mode: none with --archive, only the
+    /// chosen segments with --prune-* flags, full-node pruning otherwise.
+    pub fn pruning_args(&self) -> PruningArgs {
+        if self.archive {
+            // No pruning flags means no prune config (= archive behaviour)
+            PruningArgs::default()
+        } else if self.custom_pruning() {
+            PruningArgs {
+                receipts_distance: self.prune_receipts.then_some(PRUNE_KEEP_BLOCKS),
+                account_history_distance: self.prune_account_history.then_some(PRUNE_KEEP_BLOCKS),
+                account_history_before: self.prune_history_before,
+                storage_history_before: self.prune_history_before,
+                ..Default::default()
+            }
+        } else {
+            // "full" mode prunes old state to save disk space
+            PruningArgs {
+                full: true,
+                ..Default::default()
+            }
+        }
+    }
+}
+
+/// Management commands that run and exit without starting the node.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Manage the keystores used with --keystore-dir.
+    Keystore(KeystoreArgs),
+    /// Generate a genesis file for a new network.
+    Genesis(GenesisArgs),
+}
+
+/// Arguments for the `keystore` command, which operates on `--keystore-dir`.
+#[derive(Args, Debug)]
+pub struct KeystoreArgs {
+    /// Calibrate the PBKDF2 iteration count so new keystores take about this many
+    /// milliseconds to unlock on this machine (default: the standard 262144 iterations).
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    pub target_unlock_ms: Option<u64>,
+
+    /// Format of new keystores: `v3` (geth compatible) or the non-standard `v4`
+    /// (argon2id + AES-256-GCM). Existing keystores of either version stay readable.
+    #[arg(long, default_value = "v3")]
+    pub format: KeystoreFormat,
+
+    #[command(subcommand)]
+    pub action: KeystoreCommand,
+}
+
+/// Arguments for the `genesis` command.
+#[derive(Args, Debug)]
+pub struct GenesisArgs {
+    /// Chain ID for the new network
+    #[arg(long, default_value = "9323310")]
+    pub chain_id: u64,
+
+    /// Initial POA signers (comma-separated, at least one)
+    #[arg(long, value_delimiter = ',', required = true)]
+    pub signers: Vec<Address>,
+
+    /// Genesis block gas limit
+    #[arg(long, default_value = "30000000")]
+    pub gas_limit: u64,
+
+    /// Block period in seconds
+    #[arg(long, default_value = "12")]
+    pub block_period: u64,
+
+    /// Vanity text for the genesis extra data (up to 32 bytes)
+    #[arg(long)]
+    pub vanity: Option<String>,
+
+    /// Where to write the genesis JSON
+    #[arg(long, default_value = "genesis.json")]
+    pub output: PathBuf,
+}
+
+/// Keystore operations. Passwords are always read from the terminal without echo.
+#[derive(Subcommand, Debug)]
+pub enum KeystoreCommand {
+    /// Create an account with a random key (password is asked twice).
+    New,
+    /// Encrypt an existing private key (hex, with or without 0x prefix).
+    Import { key: String },
+    /// List the addresses of all keystores in the directory.
+    List,
+    /// Delete the keystore for an address.
+    Delete { address: Address },
+    /// Decrypt the keystore for an address to check its password.
+    Inspect {
+        address: Address,
+        /// Also print the decrypted private key.
+        #[arg(long)]
+        show_private_key: bool,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Cli, clap::Error> {
+        Cli::try_parse_from(std::iter::once("meowchain").chain(args.iter().copied()))
+    }
+
+    #[test]
+    fn test_pruning_modes() {
+        let full = parse(&[]).unwrap().pruning_args();
+        assert!(full.full);
+
+        let archive = parse(&["--archive"]).unwrap().pruning_args();
+        assert!(!archive.full);
+        assert_eq!(archive.receipts_distance, None);
+
+        // Keep receipts, prune old history
+        let custom = parse(&["--prune-history-before", "500000"])
+            .unwrap()
+            .pruning_args();
+        assert!(!custom.full);
+        assert_eq!(custom.receipts_distance, None);
+        assert_eq!(custom.account_history_before, Some(500_000));
+        assert_eq!(custom.storage_history_before, Some(500_000));
+
+        let custom = parse(&["--prune-receipts", "--prune-account-history"])
+            .unwrap()
+            .pruning_args();
+        assert_eq!(custom.receipts_distance, Some(PRUNE_KEEP_BLOCKS));
+        assert_eq!(custom.account_history_distance, Some(PRUNE_KEEP_BLOCKS));
+    }
+
+    #[test]
+    fn test_signer_key_expiry() {
+        let key = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        let now = UNIX_EPOCH + Duration::from_secs(1_000);
+        assert_eq!(
+            parse(&["--signer-key", key])
+                .unwrap()
+                .signer_key_expires_in(now),
+            None
+        );
+        let cli = parse(&["--signer-key", key, "--signer-key-expires-at", "1600"]).unwrap();
+        assert_eq!(
+            cli.signer_key_expires_in(now),
+            Some(Duration::from_secs(600))
+        );
+        // Already past: expires immediately
+        let cli = parse(&["--signer-key", key, "--signer-key-expires-at", "900"]).unwrap();
+        assert_eq!(cli.signer_key_expires_in(now), Some(Duration::ZERO));
+        // Only meaningful with --signer-key
+        assert!(parse(&["--signer-key-expires-at", "1600"]).is_err());
+    }
+
+    #[test]
+    fn test_ipc_endpoint() {
+        // Reth's default socket unless moved or disabled
+        assert_eq!(
+            parse(&[]).unwrap().ipc_endpoint().as_deref(),
+            Some(DEFAULT_IPC_ENDPOINT)
+        );
+        assert_eq!(
+            parse(&["--ipc-path", "/run/meowchain.ipc"])
+                .unwrap()
+                .ipc_endpoint()
+                .as_deref(),
+            Some("/run/meowchain.ipc")
+        );
+        assert_eq!(parse(&["--ipcdisable"]).unwrap().ipc_endpoint(), None);
+    }
+
+    #[test]
+    fn test_discovery_dns_links() {
+        let link = "enrtree://AKA3AM6LPBYEUDMVNU3BSVQJ5AD45Y7YPOHJLEF6W26QOE4VTUDPE@all.mainnet.ethdisco.net";
+        let cli = parse(&["--discovery-dns", link]).unwrap();
+        assert_eq!(cli.discovery_dns.len(), 1);
+        assert_eq!(cli.discovery_dns[0].domain, "all.mainnet.ethdisco.net");
+        assert!(parse(&[]).unwrap().discovery_dns.is_empty());
+
+        assert!(parse(&["--discovery-dns", "enode://abc@127.0.0.1:30303"]).is_err());
+        assert!(parse(&["--discovery-dns", "enrtree://not-a-key@example.org"]).is_err());
+    }
+
+    #[test]
+    fn test_keystore_dir_before_or_after_keystore_command() {
+        for args in [
+            ["--keystore-dir", "keys", "keystore", "list"],
+            ["keystore", "--keystore-dir", "keys", "list"],
+        ] {
+            let cli = parse(&args).unwrap();
+            assert_eq!(cli.keystore_dir, Some(PathBuf::from("keys")));
+            assert!(matches!(cli.command, Some(Command::Keystore(_))));
+        }
+        assert!(parse(&["--keystore-dir", "keys", "--signer-key", "00"]).is_err());
+    }
+
+    #[test]
+    fn test_conflicting_pruning_flags_rejected() {
+        assert!(parse(&["--archive", "--prune-receipts"]).is_err());
+        assert!(parse(&["--archive", "--prune-history-before", "1"]).is_err());
+        assert!(parse(&["--prune-account-history", "--prune-history-before", "1"]).is_err());
+    }
 }
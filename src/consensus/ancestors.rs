@@ -0,0 +1,27 @@
+//! Ancestor headers for the recent-signer rule.
+//!
+//! `HeaderValidator::validate_header_against_parent` only receives the parent, but
+//! the Clique "no monopoly" rule needs the signers of the whole recent-signer
+//! window. Consensus walks back from the parent through [`AncestorSource`], which
+//! the node backs with its provider. Walking by parent hash keeps side-chain blocks
+//! on their own branch.
+
+use alloy_consensus::Header;
+use alloy_primitives::B256;
+use reth_storage_api::HeaderProvider;
+use std::fmt::Debug;
+
+/// Looks up headers by hash.
+pub trait AncestorSource: Debug + Send + Sync {
+    /// The header with hash `hash`, or `None` if unknown.
+    fn header_by_hash(&self, hash: B256) -> Option<Header>;
+}
+
+impl<P> AncestorSource for P
+where
+    P: HeaderProvider<Header = Header> + Debug + Send + Sync,
+{
+    fn header_by_hash(&self, hash: B256) -> Option<Header> {
+        self.header_by_hash_or_number(hash.into()).ok().flatten()
+    }
+}
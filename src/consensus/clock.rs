@@ -0,0 +1,36 @@
+//! Wall-clock source for consensus checks.
+//!
+//! Only the future-timestamp bound needs the current time. It goes through
+//! [`Clock`] so tests can pin "now" with [`FixedClock`].
+
+use std::fmt::Debug;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Source of the current Unix time in seconds.
+pub trait Clock: Debug + Send + Sync {
+    /// Seconds since the Unix epoch.
+    fn now_secs(&self) -> u64;
+}
+
+/// The system clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_secs(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default()
+    }
+}
+
+/// A clock stuck at a fixed time.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub u64);
+
+impl Clock for FixedClock {
+    fn now_secs(&self) -> u64 {
+        self.0
+    }
+}
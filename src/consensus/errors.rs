@@ -36,10 +36,12 @@ pub enum PoaConsensusError {
     },
 
     /// Block timestamp is too far in the future
-    #[error("Block timestamp {timestamp} is too far in the future")]
+    #[error("Block timestamp {timestamp} is too far in the future (latest allowed {max_allowed})")]
     TimestampTooFarInFuture {
         /// Block timestamp
         timestamp: u64,
+        /// Local time plus the allowed drift
+        max_allowed: u64,
     },
 
     /// Block was signed by wrong signer (not in-turn)
@@ -58,6 +60,22 @@ pub enum PoaConsensusError {
     /// Signer list in epoch block is invalid
     #[error("Invalid signer list in epoch block")]
     InvalidSignerList,
+
+    /// Signer sealed one of the recent blocks and must wait for others (Clique rule)
+    #[error("Signer {signer} recently signed block {last_signed} and must wait for others")]
+    RecentlySigned {
+        /// The signer that signed too recently
+        signer: Address,
+        /// Block number the signer last sealed
+        last_signed: u64,
+    },
+
+    /// Header reports gas used although the block body has no transactions
+    #[error("Header gas_used is {gas_used} but the block has no transactions")]
+    GasUsedWithoutTransactions {
+        /// Gas used reported by the header
+        gas_used: u64,
+    },
 }
 
 impl From<PoaConsensusError> for ConsensusError {
@@ -6,19 +6,31 @@
 //! - Timing constraints are respected
 //! - The signer rotation follows the expected pattern
 
+pub mod ancestors;
+pub mod clock;
 pub mod errors;
+pub mod signer_cache;
 
-pub use crate::constants::{ADDRESS_LENGTH, EXTRA_SEAL_LENGTH, EXTRA_VANITY_LENGTH};
+pub use crate::constants::{
+    ADDRESS_LENGTH, EXTRA_SEAL_LENGTH, EXTRA_VANITY_LENGTH, NONCE_AUTH_VOTE, NONCE_DROP_VOTE,
+};
+pub use ancestors::AncestorSource;
+pub use clock::{Clock, FixedClock, SystemClock};
 pub use errors::PoaConsensusError;
+pub use signer_cache::{SignerCache, DEFAULT_SIGNER_CACHE_CAPACITY};
 
 use crate::chainspec::PoaChainSpec;
 use alloy_consensus::{BlockHeader, Header};
+use alloy_eips::eip4844::DATA_GAS_PER_BLOB;
 use alloy_primitives::{keccak256, Address, Signature, B256, U256};
+use reth_chainspec::EthChainSpec;
 use reth_consensus::{Consensus, ConsensusError, FullConsensus, HeaderValidator, ReceiptRootBloom};
 use reth_execution_types::BlockExecutionResult;
 use reth_primitives_traits::{
-    Block, GotExpected, NodePrimitives, RecoveredBlock, SealedBlock, SealedHeader,
+    Block, BlockBody as _, GotExpected, NodePrimitives, RecoveredBlock, SealedBlock, SealedHeader,
 };
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::sync::Arc;
 
 /// POA Consensus implementation
@@ -28,6 +40,82 @@ pub struct PoaConsensus {
     chain_spec: Arc<PoaChainSpec>,
     /// Whether the node is in dev mode (relaxed validation - no signature checks)
     dev_mode: bool,
+    /// Minimum distinct signers expected in the last `signers.len()` blocks (0 = disabled)
+    min_distinct_signers: usize,
+    /// Let signer diversity take precedence in fork choice
+    strict_diversity: bool,
+    /// Fork-choice weight of each in-turn block, on top of 1 per block
+    in_turn_bonus: u64,
+    /// Recovered signers by seal, shared between clones
+    signer_cache: Arc<SignerCache>,
+    /// How far past local time a header timestamp may be, in seconds
+    max_future_drift: u64,
+    /// Source of local time for the future-timestamp check
+    clock: Arc<dyn Clock>,
+    /// Headers behind the parent for the recent-signer rule (parent only while unset)
+    ancestors: Option<Arc<dyn AncestorSource>>,
+}
+
+/// Default tolerance for header timestamps ahead of local time (seconds).
+pub const DEFAULT_MAX_FUTURE_DRIFT_SECS: u64 = 15;
+
+/// Default in-turn bonus: large enough that in-turn count decides fork choice and
+/// length only breaks ties.
+pub const DEFAULT_IN_TURN_BONUS: u64 = u64::MAX;
+
+/// Signer diversity over the most recent `signers.len()` blocks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiversityHealth {
+    /// Number of blocks inspected.
+    pub window: usize,
+    /// Distinct signers that sealed a block in the window.
+    pub distinct_signers: usize,
+    /// Size of the authorized signer set.
+    pub authorized_signers: usize,
+    /// Configured threshold (0 = rule disabled).
+    pub min_distinct_signers: usize,
+    /// `false` when `distinct_signers` is below the threshold.
+    pub healthy: bool,
+}
+
+/// Seal validity breakdown over a range of headers.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureStats {
+    /// Number of headers inspected.
+    pub blocks: u64,
+    /// Headers whose seal recovered to a signer address.
+    pub recovered: u64,
+    /// Recovered signers that are not in the authorized set (subset of `recovered`).
+    pub unauthorized: u64,
+    /// Headers whose extra_data is too short to hold vanity + seal.
+    pub malformed_extra_data: u64,
+    /// Headers with a seal that could not be parsed or recovered.
+    pub invalid_signatures: u64,
+}
+
+impl DiversityHealth {
+    /// Compute diversity from recent signers (oldest first).
+    ///
+    /// Only the last `authorized_signers` entries are considered. A window shorter than
+    /// the signer set (early chain) is never reported unhealthy.
+    pub fn from_signers(
+        recent: &[Address],
+        authorized_signers: usize,
+        min_distinct_signers: usize,
+    ) -> Self {
+        let window = &recent[recent.len().saturating_sub(authorized_signers)..];
+        let distinct_signers = window.iter().collect::<HashSet<_>>().len();
+        let full_window = window.len() >= authorized_signers;
+        Self {
+            window: window.len(),
+            distinct_signers,
+            authorized_signers,
+            min_distinct_signers,
+            healthy: !full_window || distinct_signers >= min_distinct_signers,
+        }
+    }
 }
 
 impl PoaConsensus {
@@ -36,6 +124,13 @@ impl PoaConsensus {
         Self {
             chain_spec,
             dev_mode: false,
+            min_distinct_signers: 0,
+            strict_diversity: false,
+            in_turn_bonus: DEFAULT_IN_TURN_BONUS,
+            signer_cache: Arc::new(SignerCache::default()),
+            max_future_drift: DEFAULT_MAX_FUTURE_DRIFT_SECS,
+            clock: Arc::new(SystemClock),
+            ancestors: None,
         }
     }
 
@@ -44,6 +139,13 @@ impl PoaConsensus {
         Self {
             chain_spec,
             dev_mode: true,
+            min_distinct_signers: 0,
+            strict_diversity: false,
+            in_turn_bonus: DEFAULT_IN_TURN_BONUS,
+            signer_cache: Arc::new(SignerCache::default()),
+            max_future_drift: DEFAULT_MAX_FUTURE_DRIFT_SECS,
+            clock: Arc::new(SystemClock),
+            ancestors: None,
         }
     }
 
@@ -53,6 +155,63 @@ impl PoaConsensus {
         self
     }
 
+    /// Require at least `min` distinct signers in the last `signers.len()` blocks (0 disables)
+    pub fn with_min_distinct_signers(mut self, min: usize) -> Self {
+        self.min_distinct_signers = min;
+        self
+    }
+
+    /// Prefer the chain that satisfies the diversity rule in [`Self::compare_chains`]
+    pub fn with_strict_diversity(mut self, strict: bool) -> Self {
+        self.strict_diversity = strict;
+        self
+    }
+
+    /// Weigh each in-turn block as `1 + bonus` blocks in [`Self::compare_chains`]
+    pub fn with_in_turn_bonus(mut self, bonus: u64) -> Self {
+        self.in_turn_bonus = bonus;
+        self
+    }
+
+    /// Cache up to `capacity` recovered signers (0 disables the cache)
+    pub fn with_signer_cache_capacity(mut self, capacity: usize) -> Self {
+        self.signer_cache = Arc::new(SignerCache::new(capacity));
+        self
+    }
+
+    /// Reject headers more than `secs` seconds ahead of local time
+    pub fn with_max_future_drift(mut self, secs: u64) -> Self {
+        self.max_future_drift = secs;
+        self
+    }
+
+    /// Use `clock` instead of the system clock (for tests)
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Look up the parent's ancestors in `ancestors` for the recent-signer rule
+    pub fn with_ancestors(mut self, ancestors: Arc<dyn AncestorSource>) -> Self {
+        self.ancestors = Some(ancestors);
+        self
+    }
+
+    /// The recovered-signer cache (for hit/recovery counters)
+    pub fn signer_cache(&self) -> &SignerCache {
+        &self.signer_cache
+    }
+
+    /// Configured fork-choice bonus per in-turn block
+    pub fn in_turn_bonus(&self) -> u64 {
+        self.in_turn_bonus
+    }
+
+    /// Configured minimum number of distinct recent signers (0 = disabled)
+    pub fn min_distinct_signers(&self) -> usize {
+        self.min_distinct_signers
+    }
+
     /// Returns whether this consensus is in dev mode
     pub fn is_dev_mode(&self) -> bool {
         self.dev_mode
@@ -80,17 +239,20 @@ impl PoaConsensus {
         let signature_start = extra_data.len() - EXTRA_SEAL_LENGTH;
         let signature_bytes = &extra_data[signature_start..];
 
-        // Parse signature (r, s, v format)
-        let signature = Signature::try_from(signature_bytes)
-            .map_err(|_| PoaConsensusError::InvalidSignature)?;
-
         // Calculate the seal hash (header hash without the signature)
         let seal_hash = self.seal_hash(header);
 
-        // Recover the signer address
-        signature
-            .recover_address_from_prehash(&seal_hash)
-            .map_err(|_| PoaConsensusError::InvalidSignature)
+        self.signer_cache
+            .get_or_recover(seal_hash, signature_bytes, || {
+                // Parse signature (r, s, v format)
+                let signature = Signature::try_from(signature_bytes)
+                    .map_err(|_| PoaConsensusError::InvalidSignature)?;
+
+                // Recover the signer address
+                signature
+                    .recover_address_from_prehash(&seal_hash)
+                    .map_err(|_| PoaConsensusError::InvalidSignature)
+            })
     }
 
     /// Calculate the hash used for sealing (excludes the signature from extra data)
@@ -117,8 +279,11 @@ impl PoaConsensus {
     }
 
     /// Check if this is an epoch block (where signer list is updated)
+    ///
+    /// Follows the live epoch length from `ChainConfig` once a change has taken
+    /// effect, else the configured epoch.
     pub fn is_epoch_block(&self, block_number: u64) -> bool {
-        block_number.is_multiple_of(self.chain_spec.epoch())
+        self.chain_spec.is_epoch_block(block_number)
     }
 
     /// Validate the difficulty field.
@@ -179,13 +344,144 @@ impl PoaConsensus {
         &self.chain_spec
     }
 
+    // ─── Recent Signers (Clique "no monopoly" rule) ─────────────────────
+    //
+    // A signer may seal at most one block in any window of `ChainConfig`'s
+    // recentSignerWindow consecutive blocks (default `floor(N/2) + 1`), so a single
+    // authority cannot monopolize production.
+    // `HeaderValidator` only sees the parent; header validation walks back to the
+    // rest of the window through the `AncestorSource`, and the payload builder
+    // passes the recent headers explicitly.
+
+    /// Size of the recent-signer window for N effective signers.
+    ///
+    /// Uses the on-chain `ChainConfig` window once synced (capped at N), else `floor(N/2) + 1`.
+    pub fn recent_signer_limit(&self) -> u64 {
+        let signer_count = self.chain_spec.effective_signers().len() as u64;
+        match self.chain_spec.live_recent_signer_window() {
+            Some(window) if window > 0 => window.min(signer_count.max(1)),
+            _ => signer_count / 2 + 1,
+        }
+    }
+
+    /// Returns the block in `recent_headers` that `signer` sealed within the forbidden
+    /// window before `block_number`, if any.
+    ///
+    /// Headers whose signer cannot be recovered (genesis, unsigned dev blocks) are skipped.
+    pub fn last_signed_within_window(
+        &self,
+        signer: &Address,
+        block_number: u64,
+        recent_headers: &[Header],
+    ) -> Option<u64> {
+        let limit = self.recent_signer_limit();
+        recent_headers
+            .iter()
+            .filter(|h| h.number < block_number && block_number - h.number < limit)
+            .filter(|h| self.recover_signer(h).ok().as_ref() == Some(signer))
+            .map(|h| h.number)
+            .max()
+    }
+
+    /// Reject `header` if its signer sealed one of the recent blocks in the window.
+    ///
+    /// `recent_headers` should contain at least the last `recent_signer_limit() - 1`
+    /// ancestors of `header`. Skipped in dev mode (blocks are unsigned).
+    pub fn validate_recent_signers(
+        &self,
+        header: &Header,
+        recent_headers: &[Header],
+    ) -> Result<(), PoaConsensusError> {
+        if self.dev_mode {
+            return Ok(());
+        }
+        let signer = self.recover_signer(header)?;
+        match self.last_signed_within_window(&signer, header.number, recent_headers) {
+            Some(last_signed) => Err(PoaConsensusError::RecentlySigned {
+                signer,
+                last_signed,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// `parent` followed by its ancestors, newest first, as far back as the
+    /// recent-signer window reaches. Stops early at genesis or an unknown header.
+    fn window_ancestors(&self, parent: &Header) -> Vec<Header> {
+        let needed = self.recent_signer_limit().saturating_sub(1).max(1) as usize;
+        let mut headers = vec![parent.clone()];
+        if let Some(source) = &self.ancestors {
+            while headers.len() < needed {
+                let oldest = &headers[headers.len() - 1];
+                if oldest.number <= self.chain_spec.genesis_header().number {
+                    break;
+                }
+                match source.header_by_hash(oldest.parent_hash) {
+                    Some(header) => headers.push(header),
+                    None => break,
+                }
+            }
+        }
+        headers
+    }
+
+    // ─── Signer Diversity ─────────────────────────────────────────────
+    //
+    // The recent-signer rule only stops back-to-back sealing. If most signers
+    // go quiet, the remaining ones can still carry the chain between them.
+    // This optional health rule counts distinct signers over the last
+    // `signers.len()` blocks and flags the chain when it drops below a threshold.
+
+    /// Signer diversity over the tail of `headers` (oldest first).
+    ///
+    /// Headers whose signer cannot be recovered are skipped.
+    pub fn diversity_health(&self, headers: &[Header]) -> DiversityHealth {
+        let signers: Vec<Address> = headers
+            .iter()
+            .filter_map(|h| self.recover_signer(h).ok())
+            .collect();
+        DiversityHealth::from_signers(
+            &signers,
+            self.chain_spec.effective_signers().len(),
+            self.min_distinct_signers,
+        )
+    }
+
+    /// Classify the seal of every header in `headers`.
+    ///
+    /// Surfaces corruption or misconfiguration that would otherwise only show up as
+    /// individual rejected blocks. The genesis header carries no seal and is skipped.
+    pub fn signature_stats(&self, headers: &[Header]) -> SignatureStats {
+        let genesis_number = self.chain_spec.genesis_header().number;
+        let mut stats = SignatureStats::default();
+        for header in headers
+            .iter()
+            .filter(|header| header.number != genesis_number)
+        {
+            stats.blocks += 1;
+            match self.recover_signer(header) {
+                Ok(signer) => {
+                    stats.recovered += 1;
+                    if self.validate_signer(&signer).is_err() {
+                        stats.unauthorized += 1;
+                    }
+                }
+                Err(PoaConsensusError::ExtraDataTooShort { .. }) => stats.malformed_extra_data += 1,
+                Err(_) => stats.invalid_signatures += 1,
+            }
+        }
+        stats
+    }
+
     // ─── Fork Choice Rule ─────────────────────────────────────────────
     //
     // POA uses difficulty=0 for Engine API compatibility, so we can't use
     // cumulative difficulty for fork choice. Instead, we score chains by
     // counting how many blocks were signed by their in-turn signer.
     // In-turn blocks are preferred because they represent orderly round-robin
-    // block production, indicating a healthier chain.
+    // block production, indicating a healthier chain. A chain's weight is
+    // `length + in_turn_bonus * in_turn_count`; the default bonus makes in-turn
+    // count decide and length break ties.
 
     /// Check if a block was signed by the expected in-turn signer.
     ///
@@ -208,20 +504,90 @@ impl PoaConsensus {
             .count() as u64
     }
 
+    /// Fork-choice weight of a chain segment: `length + in_turn_bonus * score`.
+    ///
+    /// Computed in `u128` so even the default bonus can't overflow.
+    pub fn chain_weight(&self, headers: &[Header]) -> u128 {
+        headers.len() as u128
+            + u128::from(self.in_turn_bonus) * u128::from(self.score_chain(headers))
+    }
+
     /// Compare two chain segments for fork choice.
     ///
     /// Returns `std::cmp::Ordering`:
-    /// - `Greater` if chain_a is preferred (more in-turn blocks)
+    /// - `Greater` if chain_a is preferred (higher weight)
     /// - `Less` if chain_b is preferred
-    /// - `Equal` if tied (fall back to longest chain)
+    /// - `Equal` if tied
     ///
-    /// When scores are equal, the longer chain wins.
+    /// With the default bonus the chain with more in-turn blocks wins and the
+    /// longer chain breaks ties; a smaller bonus lets length outweigh in-turn
+    /// blocks. In strict diversity mode a chain that meets the distinct-signer
+    /// threshold beats one that doesn't, before weights are compared.
     pub fn compare_chains(&self, chain_a: &[Header], chain_b: &[Header]) -> std::cmp::Ordering {
-        let score_a = self.score_chain(chain_a);
-        let score_b = self.score_chain(chain_b);
-        score_a
-            .cmp(&score_b)
-            .then_with(|| chain_a.len().cmp(&chain_b.len()))
+        let diversity = if self.strict_diversity && self.min_distinct_signers > 0 {
+            let healthy_a = self.diversity_health(chain_a).healthy;
+            let healthy_b = self.diversity_health(chain_b).healthy;
+            healthy_a.cmp(&healthy_b)
+        } else {
+            std::cmp::Ordering::Equal
+        };
+        diversity.then_with(|| self.chain_weight(chain_a).cmp(&self.chain_weight(chain_b)))
+    }
+
+    /// EIP-4844 blob gas accounting of `header` against `parent`.
+    ///
+    /// Applies once Cancun is active and the header carries blob fields:
+    /// `blob_gas_used` must be a whole number of blobs within the fork's maximum,
+    /// and `excess_blob_gas` must follow from the parent's excess and usage under
+    /// the chain's [`BlobParams`](alloy_eips::eip7840::BlobParams). A parent
+    /// without blob fields (the Cancun fork block) counts as zero excess and usage.
+    fn validate_blob_gas_against_parent(
+        &self,
+        header: &Header,
+        parent: &Header,
+    ) -> Result<(), ConsensusError> {
+        let Some(blob_params) = self.chain_spec.blob_params_at_timestamp(header.timestamp) else {
+            return Ok(());
+        };
+        let Some(excess_blob_gas) = header.excess_blob_gas else {
+            return Ok(());
+        };
+        let blob_gas_used = header
+            .blob_gas_used
+            .ok_or(ConsensusError::BlobGasUsedMissing)?;
+
+        let max_blob_gas_per_block = blob_params.max_blob_gas_per_block();
+        if blob_gas_used > max_blob_gas_per_block {
+            return Err(ConsensusError::BlobGasUsedExceedsMaxBlobGasPerBlock {
+                blob_gas_used,
+                max_blob_gas_per_block,
+            });
+        }
+        if !blob_gas_used.is_multiple_of(DATA_GAS_PER_BLOB) {
+            return Err(ConsensusError::BlobGasUsedNotMultipleOfBlobGasPerBlob {
+                blob_gas_used,
+                blob_gas_per_blob: DATA_GAS_PER_BLOB,
+            });
+        }
+
+        let parent_excess_blob_gas = parent.excess_blob_gas.unwrap_or_default();
+        let parent_blob_gas_used = parent.blob_gas_used.unwrap_or_default();
+        let expected = blob_params.next_block_excess_blob_gas_osaka(
+            parent_excess_blob_gas,
+            parent_blob_gas_used,
+            parent.base_fee_per_gas.unwrap_or_default(),
+        );
+        if excess_blob_gas != expected {
+            return Err(ConsensusError::ExcessBlobGasDiff {
+                diff: GotExpected {
+                    got: excess_blob_gas,
+                    expected,
+                },
+                parent_excess_blob_gas,
+                parent_blob_gas_used,
+            });
+        }
+        Ok(())
     }
 }
 
@@ -232,10 +598,7 @@ impl HeaderValidator<Header> for PoaConsensus {
     fn validate_header(&self, header: &SealedHeader<Header>) -> Result<(), ConsensusError> {
         // 1. Validate nonce (POA uses nonce for voting: 0x0 = neutral, 0xff..ff = add, 0x00 = remove)
         if let Some(nonce) = header.header().nonce() {
-            let zero_nonce = alloy_primitives::B64::ZERO;
-            let vote_add = alloy_primitives::B64::from_slice(&[0xff; 8]);
-
-            if nonce != zero_nonce && nonce != vote_add {
+            if nonce != NONCE_DROP_VOTE && nonce != NONCE_AUTH_VOTE {
                 // Allow any nonce for flexibility in voting
             }
         }
@@ -305,6 +668,16 @@ impl HeaderValidator<Header> for PoaConsensus {
             .into());
         }
 
+        // A far-future timestamp would make honest signers wait out `period` after it
+        let max_allowed = self.clock.now_secs().saturating_add(self.max_future_drift);
+        if header.header().timestamp() > max_allowed {
+            return Err(PoaConsensusError::TimestampTooFarInFuture {
+                timestamp: header.header().timestamp(),
+                max_allowed,
+            }
+            .into());
+        }
+
         // Validate gas limit changes (EIP-1559 compatible)
         let parent_gas_limit = parent.header().gas_limit();
         let current_gas_limit = header.header().gas_limit();
@@ -324,7 +697,12 @@ impl HeaderValidator<Header> for PoaConsensus {
             });
         }
 
-        Ok(())
+        // Clique "no monopoly" rule over the parent and its ancestors
+        self.validate_recent_signers(header.header(), &self.window_ancestors(parent.header()))
+            .map_err(|e| -> ConsensusError { ConsensusError::Custom(Arc::new(e)) })?;
+
+        // Validate blob gas accounting (EIP-4844)
+        self.validate_blob_gas_against_parent(header.header(), parent.header())
     }
 }
 
@@ -334,7 +712,7 @@ where
 {
     fn validate_body_against_header(
         &self,
-        _body: &B::Body,
+        body: &B::Body,
         header: &SealedHeader<B::Header>,
     ) -> Result<(), ConsensusError> {
         // Validate that gas used doesn't exceed gas limit
@@ -344,6 +722,15 @@ where
                 gas_limit: header.header().gas_limit(),
             });
         }
+
+        // An empty body cannot consume gas. System-contract calls (EIP-4788, EIP-2935)
+        // are not counted in gas_used, so the only valid value here is 0.
+        if body.transaction_count() == 0 && header.header().gas_used() != 0 {
+            return Err(PoaConsensusError::GasUsedWithoutTransactions {
+                gas_used: header.header().gas_used(),
+            }
+            .into());
+        }
         Ok(())
     }
 
@@ -395,6 +782,16 @@ where
             });
         }
 
+        // Validate blob gas used matches the header (EIP-4844)
+        if let Some(header_blob_gas_used) = block.header().blob_gas_used() {
+            if result.blob_gas_used != header_blob_gas_used {
+                return Err(ConsensusError::BlobGasUsedDiff(GotExpected {
+                    got: result.blob_gas_used,
+                    expected: header_blob_gas_used,
+                }));
+            }
+        }
+
         // Validate receipt root and logs bloom if pre-computed values are provided
         if let Some((receipt_root, logs_bloom)) = receipt_root_bloom {
             let header_receipt_root = block.header().receipts_root();
@@ -474,6 +871,25 @@ mod tests {
         assert!(!consensus.is_epoch_block(epoch + 1));
     }
 
+    #[test]
+    fn test_epoch_block_detection_follows_onchain_epoch() {
+        let chain = Arc::new(crate::chainspec::PoaChainSpec::dev_chain());
+        let consensus = PoaConsensus::new(chain.clone());
+        let epoch = chain.epoch();
+
+        // Doubled on-chain at the first epoch block: the second boundary still
+        // comes after `epoch` blocks, then every `2 * epoch` from there
+        assert_eq!(chain.update_epoch(epoch, Some(epoch * 2)), Some(epoch * 2));
+        assert!(consensus.is_epoch_block(epoch * 2));
+        assert!(!consensus.is_epoch_block(epoch * 3));
+        assert!(consensus.is_epoch_block(epoch * 4));
+
+        // Unset slot: back to the configured length, again from the next boundary
+        assert_eq!(chain.update_epoch(epoch * 2, None), Some(epoch * 4));
+        assert_eq!(chain.effective_epoch(), epoch * 2);
+        assert!(consensus.is_epoch_block(epoch * 5));
+    }
+
     #[test]
     fn test_validate_signer_authorized() {
         let consensus = production_consensus();
@@ -545,6 +961,30 @@ mod tests {
         assert_eq!(recovered, address);
     }
 
+    #[tokio::test]
+    async fn test_recover_signer_cached_for_repeated_header() {
+        let consensus = production_consensus();
+        let header = build_signed_header(1, 1).await;
+
+        let first = consensus.recover_signer(&header).unwrap();
+        assert_eq!(consensus.signer_cache().recoveries(), 1);
+
+        // is_in_turn recovers the same header again: served from cache
+        assert_eq!(consensus.is_in_turn(&header), Some(true));
+        assert_eq!(consensus.recover_signer(&header).unwrap(), first);
+        assert_eq!(consensus.signer_cache().recoveries(), 1);
+        assert_eq!(consensus.signer_cache().hits(), 2);
+
+        // Clones share the cache
+        consensus.clone().recover_signer(&header).unwrap();
+        assert_eq!(consensus.signer_cache().recoveries(), 1);
+
+        let uncached = production_consensus().with_signer_cache_capacity(0);
+        uncached.recover_signer(&header).unwrap();
+        uncached.recover_signer(&header).unwrap();
+        assert_eq!(uncached.signer_cache().recoveries(), 2);
+    }
+
     #[tokio::test]
     async fn test_validate_header_with_valid_signature() {
         let consensus = production_consensus();
@@ -672,6 +1112,36 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_block_one_timestamp_follows_non_zero_genesis() {
+        const GENESIS_TIME: u64 = 1_700_000_000;
+        let genesis = crate::genesis::create_genesis(
+            crate::genesis::GenesisConfig::dev().with_genesis_timestamp(GENESIS_TIME),
+        );
+        let poa = crate::chainspec::PoaConfig::from_genesis(&genesis).unwrap();
+        let chain = Arc::new(PoaChainSpec::new(genesis, poa));
+        let period = chain.block_period();
+        let parent = SealedHeader::seal_slow(chain.genesis_header().clone());
+        assert_eq!(parent.header().timestamp, GENESIS_TIME);
+
+        let consensus = PoaConsensus::new_dev(chain);
+        let child_at = |timestamp| {
+            SealedHeader::seal_slow(Header {
+                number: 1,
+                gas_limit: parent.header().gas_limit,
+                timestamp,
+                parent_hash: parent.hash(),
+                ..Default::default()
+            })
+        };
+        assert!(consensus
+            .validate_header_against_parent(&child_at(GENESIS_TIME + period - 1), &parent)
+            .is_err());
+        assert!(consensus
+            .validate_header_against_parent(&child_at(GENESIS_TIME + period), &parent)
+            .is_ok());
+    }
+
     #[test]
     fn test_validate_header_against_parent_wrong_number() {
         let consensus = dev_consensus();
@@ -745,6 +1215,47 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_validate_header_against_parent_timestamp_too_far_in_future() {
+        let now = 1_700_000_000;
+        let consensus = dev_consensus().with_clock(Arc::new(FixedClock(now)));
+
+        let parent = Header {
+            number: 0,
+            gas_limit: 30_000_000,
+            timestamp: now - 10,
+            ..Default::default()
+        };
+        let sealed_parent = SealedHeader::seal_slow(parent);
+        let child_at = |timestamp| {
+            SealedHeader::seal_slow(Header {
+                number: 1,
+                gas_limit: 30_000_000,
+                timestamp,
+                parent_hash: sealed_parent.hash(),
+                ..Default::default()
+            })
+        };
+
+        // Exactly at the drift bound is accepted
+        let at_bound = child_at(now + DEFAULT_MAX_FUTURE_DRIFT_SECS);
+        assert!(consensus
+            .validate_header_against_parent(&at_bound, &sealed_parent)
+            .is_ok());
+
+        let too_far = child_at(now + DEFAULT_MAX_FUTURE_DRIFT_SECS + 1);
+        let err = consensus
+            .validate_header_against_parent(&too_far, &sealed_parent)
+            .unwrap_err();
+        assert!(err.to_string().contains("too far in the future"), "{err}");
+
+        // A wider tolerance accepts it
+        let lenient = consensus.with_max_future_drift(60);
+        assert!(lenient
+            .validate_header_against_parent(&too_far, &sealed_parent)
+            .is_ok());
+    }
+
     #[test]
     fn test_validate_header_against_parent_gas_limit_increase_too_large() {
         let consensus = dev_consensus();
@@ -913,6 +1424,62 @@ mod tests {
         assert!(consensus.validate_difficulty(&header, &signers[0]).is_err());
     }
 
+    #[test]
+    fn test_validate_header_against_parent_blob_gas_progression() {
+        let consensus = dev_consensus();
+        // Prague: target 6 blobs, max 9 blobs per block
+        let blob = DATA_GAS_PER_BLOB;
+        let child_of = |parent: &SealedHeader<Header>, blob_gas_used, excess_blob_gas| {
+            SealedHeader::seal_slow(Header {
+                number: parent.number + 1,
+                gas_limit: 30_000_000,
+                timestamp: parent.timestamp + 2,
+                parent_hash: parent.hash(),
+                base_fee_per_gas: Some(7),
+                blob_gas_used: Some(blob_gas_used),
+                excess_blob_gas: Some(excess_blob_gas),
+                ..Default::default()
+            })
+        };
+        let genesis = SealedHeader::seal_slow(Header {
+            gas_limit: 30_000_000,
+            base_fee_per_gas: Some(7),
+            blob_gas_used: Some(0),
+            excess_blob_gas: Some(0),
+            ..Default::default()
+        });
+
+        // A full block (9 blobs) leaves 3 blobs of excess for its child...
+        let full = child_of(&genesis, 9 * blob, 0);
+        assert!(consensus
+            .validate_header_against_parent(&full, &genesis)
+            .is_ok());
+        let after_full = child_of(&full, 0, 3 * blob);
+        assert!(consensus
+            .validate_header_against_parent(&after_full, &full)
+            .is_ok());
+        // ...which an empty block then drains back to zero
+        let drained = child_of(&after_full, 0, 0);
+        assert!(consensus
+            .validate_header_against_parent(&drained, &after_full)
+            .is_ok());
+
+        match consensus.validate_header_against_parent(&child_of(&full, 0, 0), &full) {
+            Err(ConsensusError::ExcessBlobGasDiff { diff, .. }) => {
+                assert_eq!((diff.got, diff.expected), (0, 3 * blob));
+            }
+            other => panic!("Expected ExcessBlobGasDiff, got {other:?}"),
+        }
+        assert!(matches!(
+            consensus.validate_header_against_parent(&child_of(&genesis, 10 * blob, 0), &genesis),
+            Err(ConsensusError::BlobGasUsedExceedsMaxBlobGasPerBlock { .. })
+        ));
+        assert!(matches!(
+            consensus.validate_header_against_parent(&child_of(&genesis, blob + 1, 0), &genesis),
+            Err(ConsensusError::BlobGasUsedNotMultipleOfBlobGasPerBlob { .. })
+        ));
+    }
+
     // =========================================================================
     // FullConsensus trait: validate_block_post_execution tests
     // =========================================================================
@@ -963,6 +1530,37 @@ mod tests {
         assert!(validation.is_ok());
     }
 
+    #[test]
+    fn test_validate_block_post_execution_blob_gas_used_mismatch() {
+        let consensus = dev_consensus();
+        let header = Header {
+            gas_limit: 30_000_000,
+            blob_gas_used: Some(DATA_GAS_PER_BLOB),
+            ..Default::default()
+        };
+        let block = RecoveredBlock::new_sealed(
+            SealedBlock::seal_slow(reth_ethereum::Block {
+                header,
+                body: BlockBody::default(),
+            }),
+            vec![],
+        );
+
+        let validation: Result<(), ConsensusError> =
+            FullConsensus::<reth_ethereum::EthPrimitives>::validate_block_post_execution(
+                &consensus,
+                &block,
+                &make_execution_result(0),
+                None,
+            );
+        match validation {
+            Err(ConsensusError::BlobGasUsedDiff(diff)) => {
+                assert_eq!((diff.got, diff.expected), (0, DATA_GAS_PER_BLOB));
+            }
+            other => panic!("Expected BlobGasUsedDiff, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_validate_block_post_execution_gas_used_mismatch() {
         let consensus = dev_consensus();
@@ -1057,6 +1655,18 @@ mod tests {
         SealedBlock::seal_slow(block)
     }
 
+    /// Body with a single (unsigned-content) legacy transaction.
+    fn body_with_one_tx() -> BlockBody {
+        let tx = alloy_consensus::Signed::new_unhashed(
+            alloy_consensus::TxLegacy::default(),
+            Signature::test_signature(),
+        );
+        BlockBody {
+            transactions: vec![reth_ethereum::TransactionSigned::Legacy(tx)],
+            ..Default::default()
+        }
+    }
+
     #[test]
     fn test_validate_body_against_header_gas_ok() {
         let consensus = dev_consensus();
@@ -1066,7 +1676,7 @@ mod tests {
             ..Default::default()
         };
         let sealed = SealedHeader::seal_slow(header);
-        let body = BlockBody::default();
+        let body = body_with_one_tx();
 
         let result: Result<(), ConsensusError> =
             Consensus::<reth_ethereum::Block>::validate_body_against_header(
@@ -1093,6 +1703,43 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_validate_body_empty_with_gas_used_rejected() {
+        let consensus = production_consensus();
+        let header = Header {
+            gas_used: 21_000,
+            gas_limit: 30_000_000,
+            ..Default::default()
+        };
+        let sealed = SealedHeader::seal_slow(header);
+        let body = BlockBody::default();
+
+        let result: Result<(), ConsensusError> =
+            Consensus::<reth_ethereum::Block>::validate_body_against_header(
+                &consensus, &body, &sealed,
+            );
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains("no transactions"));
+    }
+
+    #[test]
+    fn test_validate_body_empty_with_zero_gas_used_accepted() {
+        let consensus = production_consensus();
+        let header = Header {
+            gas_used: 0,
+            gas_limit: 30_000_000,
+            ..Default::default()
+        };
+        let sealed = SealedHeader::seal_slow(header);
+        let body = BlockBody::default();
+
+        let result: Result<(), ConsensusError> =
+            Consensus::<reth_ethereum::Block>::validate_body_against_header(
+                &consensus, &body, &sealed,
+            );
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn test_validate_block_pre_execution_production_short_extra_data() {
         let consensus = production_consensus();
@@ -1422,12 +2069,72 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_compare_chains_in_turn_bonus() {
+        // Chain A: 3 blocks, all in turn. Chain B: 6 blocks, all out of turn.
+        let mut chain_a = Vec::new();
+        for i in 0u64..3 {
+            chain_a.push(build_signed_header(i, (i as usize) % 3).await);
+        }
+        let mut chain_b = Vec::new();
+        for i in 0u64..6 {
+            chain_b.push(build_signed_header(i, (i as usize + 1) % 3).await);
+        }
+
+        // Default: in-turn count dominates
+        let consensus = production_consensus();
+        assert_eq!(consensus.in_turn_bonus(), DEFAULT_IN_TURN_BONUS);
+        assert_eq!(
+            consensus.compare_chains(&chain_a, &chain_b),
+            std::cmp::Ordering::Greater
+        );
+
+        // Bonus 0: pure longest chain (3 vs 6)
+        let consensus = production_consensus().with_in_turn_bonus(0);
+        assert_eq!(consensus.chain_weight(&chain_a), 3);
+        assert_eq!(
+            consensus.compare_chains(&chain_a, &chain_b),
+            std::cmp::Ordering::Less
+        );
+
+        // Bonus 1: 3 + 3 = 6 vs 6, a tie; bonus 2: 9 vs 6, in-turn wins again
+        let consensus = production_consensus().with_in_turn_bonus(1);
+        assert_eq!(
+            consensus.compare_chains(&chain_a, &chain_b),
+            std::cmp::Ordering::Equal
+        );
+        let consensus = production_consensus().with_in_turn_bonus(2);
+        assert_eq!(
+            consensus.compare_chains(&chain_a, &chain_b),
+            std::cmp::Ordering::Greater
+        );
+    }
+
     #[tokio::test]
     async fn test_score_chain_empty() {
         let consensus = production_consensus();
         assert_eq!(consensus.score_chain(&[]), 0);
     }
 
+    #[tokio::test]
+    async fn test_score_chain_recovers_each_header_once() {
+        let consensus = production_consensus();
+        let mut headers = Vec::new();
+        for i in 1u64..=100 {
+            headers.push(build_signed_header(i, (i as usize) % 3).await);
+        }
+
+        assert_eq!(consensus.score_chain(&headers), 100);
+        assert_eq!(consensus.signer_cache().recoveries(), 100);
+
+        // Re-scoring the same segment (fork choice, compare_chains) hits the cache
+        for _ in 0..5 {
+            assert_eq!(consensus.score_chain(&headers), 100);
+        }
+        assert_eq!(consensus.signer_cache().recoveries(), 100);
+        assert_eq!(consensus.signer_cache().hits(), 500);
+    }
+
     // ─── State Sync / Chain Validation Tests ─────────────────────────────
 
     /// Helper: build a chain segment of N signed blocks with proper parent linkage.
@@ -1784,6 +2491,7 @@ mod tests {
             period: 2,
             epoch: 10, // short epoch for testing
             signers: signer_addrs,
+            ..PoaConfig::default()
         };
         let chain = Arc::new(PoaChainSpec::new(genesis, poa_config));
         PoaConsensus::new(chain)
@@ -1797,6 +2505,156 @@ mod tests {
             .unwrap()
     }
 
+    // ─── Recent Signer Tests ─────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_recent_signer_limit() {
+        // 3 signers → floor(3/2)+1 = 2; 5 signers → 3; 1 signer → 1
+        assert_eq!(production_consensus().recent_signer_limit(), 2);
+        let mut addrs = Vec::new();
+        for i in 0..5 {
+            addrs.push(dev_address(i).await);
+        }
+        assert_eq!(consensus_with_signers(addrs).recent_signer_limit(), 3);
+        let single = consensus_with_signers(vec![dev_address(0).await]);
+        assert_eq!(single.recent_signer_limit(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_recent_signer_limit_uses_onchain_window() {
+        let consensus = production_consensus();
+        consensus.chain_spec().update_recent_signer_window(3);
+        assert_eq!(consensus.recent_signer_limit(), 3);
+        // A window larger than the signer set is capped at N
+        consensus.chain_spec().update_recent_signer_window(10);
+        assert_eq!(consensus.recent_signer_limit(), 3);
+        consensus.chain_spec().update_recent_signer_window(1);
+        assert_eq!(consensus.recent_signer_limit(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_recent_signers_rejects_consecutive_blocks() {
+        // Dev chain: 3 signers (keys 0..3), window = 2 → no two consecutive blocks
+        let consensus = production_consensus();
+        let parent = build_signed_header(4, 0).await;
+        let header = build_signed_header(5, 0).await; // out-of-turn, signed block 4
+
+        let err = consensus
+            .validate_recent_signers(&header, &[parent])
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            PoaConsensusError::RecentlySigned { last_signed: 4, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_recent_signers_allows_outside_window() {
+        let consensus = production_consensus();
+        let older = build_signed_header(3, 0).await;
+        let parent = build_signed_header(4, 1).await;
+        let header = build_signed_header(5, 0).await;
+
+        assert!(consensus
+            .validate_recent_signers(&header, &[older, parent])
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_recent_signers_five_signer_window() {
+        // 5 signers → window 3: signer may not appear in the previous 2 blocks
+        let mut addrs = Vec::new();
+        for i in 0..5 {
+            addrs.push(dev_address(i).await);
+        }
+        let consensus = consensus_with_signers(addrs);
+        let recent = vec![
+            build_signed_header(7, 2).await,
+            build_signed_header(8, 3).await,
+            build_signed_header(9, 4).await,
+        ];
+
+        // Key 3 signed block 8 → block 10 is within the window
+        assert!(consensus
+            .validate_recent_signers(&build_signed_header(10, 3).await, &recent)
+            .is_err());
+        // Key 2 signed block 7 → block 10 is outside the window
+        assert!(consensus
+            .validate_recent_signers(&build_signed_header(10, 2).await, &recent)
+            .is_ok());
+    }
+
+    #[derive(Debug, Default)]
+    struct MockAncestors(std::collections::HashMap<B256, Header>);
+
+    impl AncestorSource for MockAncestors {
+        fn header_by_hash(&self, hash: B256) -> Option<Header> {
+            self.0.get(&hash).cloned()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_import_rejects_recent_signer_behind_parent() {
+        // 5 signers → window 3: key 2 sealed block 2, so it may not seal block 4
+        let mut addrs = Vec::new();
+        for i in 0..5 {
+            addrs.push(dev_address(i).await);
+        }
+        let mut ancestors = MockAncestors::default();
+        let mut parent_hash = B256::ZERO;
+        let mut chain = Vec::new();
+        for (number, key) in [(1u64, 1usize), (2, 2), (3, 3), (4, 2)] {
+            let manager = Arc::new(SignerManager::new());
+            let address = manager
+                .add_signer_from_hex(dev::DEV_PRIVATE_KEYS[key])
+                .await
+                .unwrap();
+            let header = Header {
+                number,
+                parent_hash,
+                gas_limit: 30_000_000,
+                timestamp: 1000 + number * 2,
+                extra_data: vec![0u8; EXTRA_VANITY_LENGTH + EXTRA_SEAL_LENGTH].into(),
+                ..Default::default()
+            };
+            let signed = BlockSealer::new(manager)
+                .seal_header(header, &address)
+                .await
+                .unwrap();
+            let sealed = SealedHeader::seal_slow(signed.clone());
+            parent_hash = sealed.hash();
+            ancestors.0.insert(sealed.hash(), signed);
+            chain.push(sealed);
+        }
+
+        // Seeing only the parent (signed by key 3), block 4 looks fine
+        let consensus = consensus_with_signers(addrs);
+        assert!(
+            HeaderValidator::validate_header_against_parent(&consensus, &chain[3], &chain[2])
+                .is_ok()
+        );
+
+        // With the node's headers, the import is rejected
+        let consensus = consensus.with_ancestors(Arc::new(ancestors));
+        let err = HeaderValidator::validate_header_against_parent(&consensus, &chain[3], &chain[2])
+            .unwrap_err();
+        assert!(err.to_string().contains("recently"), "{err}");
+        assert!(
+            HeaderValidator::validate_header_against_parent(&consensus, &chain[2], &chain[1])
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_recent_signers_skipped_in_dev_mode() {
+        let consensus = dev_consensus();
+        let parent = build_signed_header(4, 0).await;
+        let header = build_signed_header(5, 0).await;
+        assert!(consensus
+            .validate_recent_signers(&header, &[parent])
+            .is_ok());
+    }
+
     #[tokio::test]
     async fn test_multi_node_5_signer_sequential() {
         // 5-signer production-like setup: signers 0-4
@@ -2111,4 +2969,105 @@ mod tests {
             "Fork B (round-robin) should score higher than Fork A (single signer)"
         );
     }
+
+    // ── Signer diversity ──
+
+    #[test]
+    fn test_diversity_window_dominated_by_one_signer() {
+        let a = Address::with_last_byte(1);
+        let b = Address::with_last_byte(2);
+        // Last 3 blocks of a 3-signer set: a, a, a (b only sealed earlier)
+        let recent = [b, a, a, a];
+        let health = DiversityHealth::from_signers(&recent, 3, 2);
+        assert_eq!(health.window, 3);
+        assert_eq!(health.distinct_signers, 1);
+        assert!(!health.healthy);
+
+        let healthy = DiversityHealth::from_signers(&[a, b, a], 3, 2);
+        assert!(healthy.healthy);
+    }
+
+    #[test]
+    fn test_diversity_partial_window_is_healthy() {
+        let a = Address::with_last_byte(1);
+        let health = DiversityHealth::from_signers(&[a], 3, 3);
+        assert_eq!(health.window, 1);
+        assert!(health.healthy, "too few blocks to judge");
+    }
+
+    #[tokio::test]
+    async fn test_diversity_health_from_headers() {
+        let consensus = production_consensus().with_min_distinct_signers(2);
+        let mut headers = Vec::new();
+        for i in 1u64..=3 {
+            headers.push(build_signed_header(i, 0).await);
+        }
+        let health = consensus.diversity_health(&headers);
+        assert_eq!(health.distinct_signers, 1);
+        assert_eq!(health.authorized_signers, 3);
+        assert!(!health.healthy);
+    }
+
+    #[tokio::test]
+    async fn test_strict_diversity_overrides_score() {
+        // Chain A: one signer seals everything, one block happens to be in-turn
+        let mut chain_a = Vec::new();
+        for i in 3u64..6 {
+            chain_a.push(build_signed_header(i, 0).await);
+        }
+        // Chain B: diverse but entirely out-of-turn
+        let mut chain_b = Vec::new();
+        for i in 3u64..6 {
+            chain_b.push(build_signed_header(i, ((i as usize) + 1) % 3).await);
+        }
+
+        let lenient = production_consensus().with_min_distinct_signers(2);
+        assert_eq!(
+            lenient.compare_chains(&chain_a, &chain_b),
+            std::cmp::Ordering::Greater
+        );
+
+        let strict = lenient.with_strict_diversity(true);
+        assert_eq!(
+            strict.compare_chains(&chain_a, &chain_b),
+            std::cmp::Ordering::Less
+        );
+    }
+
+    // ── Signature stats ──
+
+    #[tokio::test]
+    async fn test_signature_stats_breakdown() {
+        let consensus = production_consensus();
+        let mut headers = vec![
+            build_signed_header(1, 1).await, // authorized
+            build_signed_header(2, 2).await, // authorized
+            build_signed_header(3, 5).await, // recovered, not a signer
+        ];
+        // Too short to hold a seal
+        headers.push(Header {
+            number: 4,
+            extra_data: vec![0u8; EXTRA_VANITY_LENGTH].into(),
+            ..Default::default()
+        });
+        // Right length, but the seal is all zeros
+        headers.push(Header {
+            number: 5,
+            extra_data: vec![0u8; EXTRA_VANITY_LENGTH + EXTRA_SEAL_LENGTH].into(),
+            ..Default::default()
+        });
+        // The unsealed genesis header isn't counted
+        headers.insert(0, consensus.chain_spec().genesis_header().clone());
+
+        assert_eq!(
+            consensus.signature_stats(&headers),
+            SignatureStats {
+                blocks: 5,
+                recovered: 3,
+                unauthorized: 1,
+                malformed_extra_data: 1,
+                invalid_signatures: 1,
+            }
+        );
+    }
 }
@@ -0,0 +1,187 @@
+//! Bounded cache of recovered block signers.
+//!
+//! The same header's signer is recovered several times during sync and fork-choice
+//! scoring (`is_in_turn`, `validate_header`, recent-signer checks). ECDSA recovery
+//! dominates that cost, so [`PoaConsensus`](super::PoaConsensus) memoizes it here.
+//!
+//! Entries are keyed by seal hash *and* seal bytes: the seal hash alone doesn't
+//! commit to the signature, and a forged seal over an honest block's contents must
+//! not be served the honest signer. Entries never go stale, so there is no
+//! invalidation — the oldest entry is evicted once the cache is full.
+
+use super::EXTRA_SEAL_LENGTH;
+use alloy_primitives::{Address, B256};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// Default number of recovered signers kept in memory.
+pub const DEFAULT_SIGNER_CACHE_CAPACITY: usize = 4_096;
+
+/// Cache key: seal hash plus the raw seal.
+type SealKey = (B256, [u8; EXTRA_SEAL_LENGTH]);
+
+/// Bounded `(seal hash, seal) → signer` map with FIFO eviction.
+#[derive(Debug)]
+pub struct SignerCache {
+    capacity: usize,
+    entries: Mutex<Entries>,
+    /// ECDSA recoveries performed (cache misses that reached recovery).
+    recoveries: AtomicU64,
+    hits: AtomicU64,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    map: HashMap<SealKey, Address>,
+    order: VecDeque<SealKey>,
+}
+
+impl SignerCache {
+    /// Create a cache holding at most `capacity` signers (0 disables caching).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(Entries::default()),
+            recoveries: AtomicU64::new(0),
+            hits: AtomicU64::new(0),
+        }
+    }
+
+    /// Return the cached signer for this seal, or run `recover` and cache its result.
+    ///
+    /// Failed recoveries are not cached.
+    pub fn get_or_recover<E>(
+        &self,
+        seal_hash: B256,
+        seal: &[u8],
+        recover: impl FnOnce() -> Result<Address, E>,
+    ) -> Result<Address, E> {
+        let key = match <[u8; EXTRA_SEAL_LENGTH]>::try_from(seal) {
+            Ok(seal) if self.capacity > 0 => (seal_hash, seal),
+            _ => {
+                self.recoveries.fetch_add(1, Ordering::Relaxed);
+                return recover();
+            }
+        };
+
+        if let Some(signer) = self.lock().map.get(&key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(*signer);
+        }
+
+        self.recoveries.fetch_add(1, Ordering::Relaxed);
+        let signer = recover()?;
+
+        let mut entries = self.lock();
+        if entries.map.insert(key, signer).is_none() {
+            entries.order.push_back(key);
+            while entries.order.len() > self.capacity {
+                if let Some(oldest) = entries.order.pop_front() {
+                    entries.map.remove(&oldest);
+                }
+            }
+        }
+        Ok(signer)
+    }
+
+    /// Maximum number of cached signers.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of cached signers.
+    pub fn len(&self) -> usize {
+        self.lock().map.len()
+    }
+
+    /// Returns `true` if nothing is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of ECDSA recoveries performed so far.
+    pub fn recoveries(&self) -> u64 {
+        self.recoveries.load(Ordering::Relaxed)
+    }
+
+    /// Number of lookups served from the cache so far.
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Entries> {
+        self.entries.lock().expect("signer cache lock poisoned")
+    }
+}
+
+impl Default for SignerCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_SIGNER_CACHE_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recover_ok(n: u8) -> impl FnOnce() -> Result<Address, ()> {
+        move || Ok(Address::with_last_byte(n))
+    }
+
+    #[test]
+    fn test_evicts_oldest_when_full() {
+        let cache = SignerCache::new(2);
+        let seal = [0u8; EXTRA_SEAL_LENGTH];
+        for n in 1..=3 {
+            cache
+                .get_or_recover(B256::with_last_byte(n), &seal, recover_ok(n))
+                .unwrap();
+        }
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.recoveries(), 3);
+
+        // Entry 1 was evicted, entry 3 is still cached
+        cache
+            .get_or_recover(B256::with_last_byte(3), &seal, recover_ok(3))
+            .unwrap();
+        assert_eq!(cache.hits(), 1);
+        cache
+            .get_or_recover(B256::with_last_byte(1), &seal, recover_ok(1))
+            .unwrap();
+        assert_eq!(cache.recoveries(), 4);
+    }
+
+    #[test]
+    fn test_different_seal_same_hash_is_a_miss() {
+        let cache = SignerCache::new(8);
+        let hash = B256::with_last_byte(1);
+        cache
+            .get_or_recover(hash, &[1u8; EXTRA_SEAL_LENGTH], recover_ok(1))
+            .unwrap();
+        let forged = cache
+            .get_or_recover(hash, &[2u8; EXTRA_SEAL_LENGTH], recover_ok(2))
+            .unwrap();
+        assert_eq!(forged, Address::with_last_byte(2));
+        assert_eq!(cache.recoveries(), 2);
+    }
+
+    #[test]
+    fn test_errors_not_cached_and_zero_capacity_disables() {
+        let cache = SignerCache::new(8);
+        let seal = [0u8; EXTRA_SEAL_LENGTH];
+        assert!(cache
+            .get_or_recover(B256::ZERO, &seal, || Err::<Address, _>(()))
+            .is_err());
+        assert!(cache.is_empty());
+
+        let disabled = SignerCache::new(0);
+        for _ in 0..2 {
+            disabled
+                .get_or_recover(B256::ZERO, &seal, recover_ok(1))
+                .unwrap();
+        }
+        assert_eq!(disabled.recoveries(), 2);
+        assert!(disabled.is_empty());
+    }
+}
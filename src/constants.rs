@@ -1,3 +1,5 @@
+use alloy_primitives::B64;
+
 /// Extra data structure for POA blocks
 /// Format: [vanity (32 bytes)][signers list (N*20 bytes, only in epoch blocks)][signature (65 bytes)]
 pub const EXTRA_VANITY_LENGTH: usize = 32;
@@ -9,3 +11,11 @@ pub const ADDRESS_LENGTH: usize = 20;
 pub const DEFAULT_CHAIN_ID: u64 = 9323310;
 /// Default epoch length (blocks between signer list snapshots)
 pub const DEFAULT_EPOCH: u64 = 30000;
+/// Blocks kept by distance-based pruning (Reth's `MINIMUM_PRUNING_DISTANCE`)
+pub const PRUNE_KEEP_BLOCKS: u64 = 10_064;
+/// Base out-of-turn wiggle delay in milliseconds (Clique's `wiggleTime`)
+pub const DEFAULT_WIGGLE_MS: u64 = 500;
+/// Header nonce for a vote to authorize the candidate in `beneficiary` (Clique convention)
+pub const NONCE_AUTH_VOTE: B64 = B64::new([0xff; 8]);
+/// Header nonce for a vote to deauthorize the candidate in `beneficiary` (Clique convention)
+pub const NONCE_DROP_VOTE: B64 = B64::ZERO;
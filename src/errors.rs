@@ -1,2 +1,3 @@
 pub use crate::consensus::PoaConsensusError;
+pub use crate::genesis::GenesisError;
 pub use crate::signer::SignerError;
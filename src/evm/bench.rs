@@ -17,6 +17,8 @@
 //! | `test_evm_keccak_heavy` | SHA3 / KECCAK256 intensive workload |
 //! | `test_evm_calldata_discount` | Calldata gas reduction (4 vs 16 gas/byte) |
 //! | `test_evm_max_contract_size` | Configurable contract size limit |
+//! | `test_evm_governed_max_contract_size` | `ChainConfig.maxContractSize` enforced at CREATE |
+//! | `test_evm_governed_calldata_gas` | `ChainConfig.calldataGasPerByte` charged per tx |
 //! | `test_parallel_schedule_throughput` | ParallelSchedule batch scheduling perf |
 //! | `test_conflict_detection_performance` | ConflictDetector mixed access patterns |
 //! | `test_evm_vs_gevm_comparison` | Print comparison table |
@@ -24,9 +26,12 @@
 #[cfg(test)]
 mod tests {
     use crate::evm::parallel::{ConflictDetector, ParallelSchedule, TxAccessRecord};
-    use crate::evm::{CalldataDiscountInspector, PoaEvmFactory};
+    use crate::evm::{calldata_discount, PoaEvmFactory};
+    use crate::genesis::CHAIN_CONFIG_ADDRESS;
+    use crate::onchain::chain_config_slots;
     use alloy_evm::revm::bytecode::Bytecode;
     use alloy_evm::revm::context::{BlockEnv, TxEnv};
+    use alloy_evm::revm::context_interface::result::EVMError;
     use alloy_evm::revm::database_interface::DBErrorMarker;
     use alloy_evm::revm::inspector::NoOpInspector;
     use alloy_evm::revm::primitives::hardfork::SpecId;
@@ -62,6 +67,8 @@ mod tests {
         sender_balance: U256,
         /// Address of the sender account.
         sender_addr: Address,
+        /// Storage of the `ChainConfig` governance contract.
+        chain_config: std::collections::HashMap<U256, U256>,
     }
 
     impl BenchDb {
@@ -79,6 +86,11 @@ mod tests {
             self
         }
 
+        fn with_chain_config_slot(mut self, slot: U256, value: U256) -> Self {
+            self.chain_config.insert(slot, value);
+            self
+        }
+
         #[allow(dead_code)]
         fn with_storage(mut self, slots: Vec<(U256, U256)>) -> Self {
             for (k, v) in slots {
@@ -125,8 +137,13 @@ mod tests {
             Ok(self.code.clone().unwrap_or_default())
         }
 
-        fn storage(&mut self, _address: Address, index: U256) -> Result<U256, Self::Error> {
-            Ok(self.storage.get(&index).copied().unwrap_or(U256::ZERO))
+        fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+            let storage = if address == CHAIN_CONFIG_ADDRESS {
+                &self.chain_config
+            } else {
+                &self.storage
+            };
+            Ok(storage.get(&index).copied().unwrap_or(U256::ZERO))
         }
 
         fn block_hash(&mut self, _number: u64) -> Result<B256, Self::Error> {
@@ -728,23 +745,23 @@ mod tests {
 
     #[test]
     fn test_evm_calldata_discount() {
-        // Verify that CalldataDiscountInspector correctly computes the gas discount
+        // Verify that calldata_discount correctly computes the gas discount
         // for different calldata_gas_per_byte settings.
         //
         // Ethereum mainnet: 16 gas per non-zero byte, 4 gas per zero byte.
         // Meowchain default: 4 gas per non-zero byte (discount of 12 per byte).
 
-        let calldata_size = 1_000; // 1 KB of non-zero calldata
-        let non_zero_count: u64 = calldata_size;
+        let calldata_size: u64 = 1_000; // 1 KB of non-zero calldata
+        let non_zero_count = calldata_size;
+
+        let data = vec![0xFFu8; calldata_size as usize];
 
         // -- 16 gas/byte (mainnet, no discount) --
-        let inspector_16 = CalldataDiscountInspector::new(NoOpInspector, 16);
-        let discount_16 = inspector_16.discount_for(non_zero_count);
+        let discount_16 = calldata_discount(&data, 16);
         assert_eq!(discount_16, 0, "no discount at 16 gas/byte");
 
         // -- 4 gas/byte (Meowchain default) --
-        let inspector_4 = CalldataDiscountInspector::new(NoOpInspector, 4);
-        let discount_4 = inspector_4.discount_for(non_zero_count);
+        let discount_4 = calldata_discount(&data, 4);
         assert_eq!(
             discount_4,
             non_zero_count * 12,
@@ -753,8 +770,7 @@ mod tests {
         assert_eq!(discount_4, 12_000);
 
         // -- 1 gas/byte (maximum discount) --
-        let inspector_1 = CalldataDiscountInspector::new(NoOpInspector, 1);
-        let discount_1 = inspector_1.discount_for(non_zero_count);
+        let discount_1 = calldata_discount(&data, 1);
         assert_eq!(
             discount_1,
             non_zero_count * 15,
@@ -763,8 +779,7 @@ mod tests {
         assert_eq!(discount_1, 15_000);
 
         // -- 8 gas/byte (custom middle ground) --
-        let inspector_8 = CalldataDiscountInspector::new(NoOpInspector, 8);
-        let discount_8 = inspector_8.discount_for(non_zero_count);
+        let discount_8 = calldata_discount(&data, 8);
         assert_eq!(discount_8, 8_000);
 
         // -- Verify gas savings as a percentage --
@@ -881,6 +896,84 @@ mod tests {
         println!();
     }
 
+    #[test]
+    fn test_evm_governed_max_contract_size() {
+        use alloy_evm::revm::primitives::eip170::MAX_CODE_SIZE;
+
+        // One byte over EIP-170
+        let initcode = make_large_initcode(MAX_CODE_SIZE + 1);
+        let deploy = |db: BenchDb, factory: &PoaEvmFactory| {
+            factory
+                .create_evm(db, bench_env())
+                .transact(create_tx(initcode.clone(), 30_000_000))
+                .unwrap()
+                .result
+        };
+        let factory = PoaEvmFactory::new(None, 4);
+
+        // Default limit: the deployment halts
+        assert!(!deploy(BenchDb::new(), &factory).is_success());
+        // Genesis value (24576) on chain: still rejected
+        let genesis_db = BenchDb::new()
+            .with_chain_config_slot(chain_config_slots::MAX_CONTRACT_SIZE, U256::from(24_576));
+        assert!(!deploy(genesis_db, &factory).is_success());
+
+        // Governance raised the limit: the same deployment succeeds
+        let raised_db = BenchDb::new()
+            .with_chain_config_slot(chain_config_slots::MAX_CONTRACT_SIZE, U256::from(524_288));
+        assert!(deploy(raised_db.clone(), &factory).is_success());
+        // ...and takes precedence over a lower static override
+        let factory_24k = PoaEvmFactory::new(Some(MAX_CODE_SIZE), 4);
+        assert!(deploy(raised_db, &factory_24k).is_success());
+    }
+
+    #[test]
+    fn test_evm_governed_calldata_gas() {
+        // 1 KB of non-zero calldata to a contract that just STOPs
+        let data = Bytes::from(vec![0xFFu8; 1_000]);
+        let gas_used_with_limit = |db: BenchDb, factory: &PoaEvmFactory, gas_limit: u64| {
+            // Cancun: no EIP-7623 calldata floor
+            let mut env = bench_env();
+            env.cfg_env.spec = SpecId::CANCUN;
+            let code = Bytecode::new_raw(Bytes::from(vec![0x00]));
+            let mut evm = factory.create_evm(db.with_code(code), env);
+            let to = Address::from([0xBBu8; 20]);
+            let result = evm.transact(contract_call_tx(to, data.clone(), gas_limit))?;
+            assert!(result.result.is_success());
+            Ok::<_, EVMError<BenchDbError>>(result.result.gas_used())
+        };
+        let gas_used =
+            |db, factory: &PoaEvmFactory| gas_used_with_limit(db, factory, 100_000).unwrap();
+        let factory = PoaEvmFactory::new(None, 16);
+
+        // Unset: the static 16 gas/byte applies (mainnet pricing)
+        let mainnet = gas_used(BenchDb::new(), &factory);
+        assert_eq!(mainnet, 21_000 + 16 * 1_000);
+
+        // Genesis value on chain: same as mainnet
+        let genesis_db = BenchDb::new()
+            .with_chain_config_slot(chain_config_slots::CALLDATA_GAS_PER_BYTE, U256::from(16));
+        assert_eq!(gas_used(genesis_db, &factory), mainnet);
+
+        // Governance lowered it to 4 gas/byte: 12 gas saved per byte
+        let cheap_db = BenchDb::new()
+            .with_chain_config_slot(chain_config_slots::CALLDATA_GAS_PER_BYTE, U256::from(4));
+        assert_eq!(gas_used(cheap_db.clone(), &factory), mainnet - 12_000);
+
+        // The lower intrinsic gas is also what the gas limit must cover
+        let cheap_limit = 21_000 + 4 * 1_000;
+        assert_eq!(
+            gas_used_with_limit(cheap_db, &factory, cheap_limit).unwrap(),
+            cheap_limit
+        );
+        assert!(gas_used_with_limit(BenchDb::new(), &factory, cheap_limit).is_err());
+
+        // An out-of-range governed value is ignored, not clamped
+        let invalid_db = BenchDb::new()
+            .with_chain_config_slot(chain_config_slots::CALLDATA_GAS_PER_BYTE, U256::from(20));
+        assert_eq!(gas_used(invalid_db, &factory), mainnet);
+    }
+
     // -- 9. Parallel schedule throughput ----------------------------------
 
     #[test]
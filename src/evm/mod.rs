@@ -4,12 +4,22 @@
 //! POA-specific EVM overrides before creating each EVM instance:
 //!
 //! - **Max contract code size** (`limit_contract_code_size`): Lifts EIP-170's 24 KB cap.
-//! - **Calldata gas reduction** (Phase 2.12): [`CalldataDiscountInspector`] implements the
-//!   discount logic via [`Inspector::initialize_interp`] + `Gas::erase_cost`.
-//!   It is a standalone utility that callers wrap explicitly:
-//!   `factory.create_evm_with_inspector(db, env, CalldataDiscountInspector::new(my_insp, 4))`
-//!   The stored `calldata_gas_per_byte` field on `PoaEvmFactory` is available for a future
-//!   custom `BlockExecutorFactory` that pre-processes `TxEnv` gas limits automatically.
+//!   The governed `ChainConfig.maxContractSize` is read from the state each EVM runs
+//!   against, so a governance change applies from the next block on every node, with
+//!   no hardfork; `--max-contract-size` only applies while the slot is unset.
+//! - **Calldata gas reduction** (Phase 2.12): every EVM the factory creates is a
+//!   [`PoaEvm`], whose handler computes intrinsic gas ([`intrinsic_gas`]) with the
+//!   governed `ChainConfig.calldataGasPerByte` (or the static value while unset), so
+//!   `eth_call`/`eth_estimateGas` and block execution charge the same gas.
+//!
+//! - **Timelock pause**: while the Timelock is paused, [`PoaEvm`] rejects any transaction
+//!   that writes to the governance contracts' storage (see [`crate::payload::pause`]).
+//!   Block execution and import use the same EVM, so the freeze is a consensus rule.
+//!
+//! - **Custom precompiles** ([`CustomPrecompiles`]): chain-specific precompiles added at
+//!   reserved addresses. They are installed in every EVM the factory creates, so block
+//!   execution, payload building and RPC `eth_call` always see the same set — a
+//!   precompile missing from one path would make state roots diverge.
 //!
 //! Also exposes [`PoaExecutorBuilder`] and [`parallel`] (Phase 2 item 13 foundation).
 //!
@@ -18,28 +28,41 @@
 //!   PoaNode → PoaExecutorBuilder.build_evm()
 //!              → EthEvmConfig::new_with_evm_factory(chain_spec, PoaEvmFactory)
 //!                 → PoaEvmFactory::create_evm(db, env)
+//!                    → read_governed (ChainConfig maxContractSize / calldataGasPerByte from db)
 //!                    → patch_env (contract size limits, spec overrides)
-//!                    → EthEvmFactory::create_evm(db, patched_env)
+//!                    → EthEvmFactory::create_evm_with_inspector(db, patched_env, inspector)
+//!                    → install custom precompiles
+//!                    → PoaEvm (runs each transaction through PoaHandler)
 //! ```
 
 pub mod parallel;
 
 use alloy_evm::{
-    eth::{EthEvm, EthEvmContext, EthEvmFactory},
-    precompiles::PrecompilesMap,
+    eth::{EthEvmContext, EthEvmFactory},
+    precompiles::{DynPrecompile, PrecompilesMap},
     revm::{
-        context::BlockEnv,
-        context_interface::result::{EVMError, HaltReason},
-        inspector::NoOpInspector,
+        context::{BlockEnv, Context, Evm as RevmEvm},
+        context_interface::{
+            result::{EVMError, HaltReason, InvalidTransaction, ResultAndState},
+            Cfg, ContextSetters, ContextTr, JournalTr,
+        },
+        handler::{instructions::EthInstructions, EthFrame, EvmTr, Handler, SystemCallEvm},
+        inspector::{InspectorHandler, NoOpInspector},
         interpreter::{
-            CallInput, CallInputs, CallOutcome, CreateInputs, CreateOutcome, Interpreter,
+            gas::calculate_initial_tx_gas_for_tx, interpreter::EthInterpreter, InitialAndFloorGas,
         },
         primitives::hardfork::SpecId,
         Inspector,
     },
-    Database, EvmEnv, EvmFactory,
+    Database, Evm, EvmEnv, EvmFactory,
 };
-use alloy_primitives::{Address, Log, U256};
+use alloy_primitives::{Address, Bytes, U256};
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+
+use crate::genesis::{CHAIN_CONFIG_ADDRESS, TIMELOCK_ADDRESS};
+use crate::onchain::{chain_config_slots, timelock_slots};
+use crate::payload::{changes_paused_governance, PAUSED_GOVERNANCE_ERROR};
 
 use alloy_evm::eth::spec::EthExecutorSpec;
 use alloy_evm::revm::context::TxEnv;
@@ -50,117 +73,236 @@ use reth_ethereum::node::EthEvmConfig;
 use reth_ethereum::EthPrimitives;
 use reth_ethereum_forks::Hardforks;
 
-// ─── Calldata gas discount inspector ──────────────────────────────────────────
+// ─── Calldata gas ─────────────────────────────────────────────────────────────
 
-/// Inspector wrapper that grants a calldata gas discount at the start of each
-/// top-level transaction frame.
+/// Calldata gas costs per non-zero byte that a chain may configure.
 ///
-/// Ethereum's intrinsic gas deducts **16 gas per non-zero calldata byte** (EIP-2028)
-/// before execution starts.  A POA chain can effectively reduce this by adding back
-/// the difference via `Gas::erase_cost` inside [`Inspector::initialize_interp`].
+/// `16` is Ethereum mainnet (EIP-2028); lower values discount calldata.
+pub const CALLDATA_GAS_RANGE: RangeInclusive<u64> = 1..=16;
+
+/// Gas saved on `data` when non-zero bytes cost `calldata_gas_per_byte` instead
+/// of 16.
 ///
-/// The discount is applied only **once per EVM instance** (tracked by `discount_applied`).
-/// Because reth creates a fresh `EthEvmFactory` call — and therefore a fresh
-/// `CalldataDiscountInspector` — for each transaction, the flag resets automatically.
+/// Zero bytes cost `calldata_gas_per_byte / 4` instead of 4, keeping Ethereum's
+/// 4:1 ratio. `calldata_gas_per_byte` must be in [`CALLDATA_GAS_RANGE`].
+pub fn calldata_discount(data: &[u8], calldata_gas_per_byte: u64) -> u64 {
+    let non_zero = data.iter().filter(|&&b| b != 0).count() as u64;
+    let zero = data.len() as u64 - non_zero;
+    non_zero.saturating_mul(16 - calldata_gas_per_byte)
+        + zero.saturating_mul(4 - calldata_gas_per_byte / 4)
+}
+
+/// Intrinsic gas of `tx` with calldata priced at `calldata_gas_per_byte`.
 ///
-/// # Parameters
-/// - `calldata_gas_per_byte = 16` (default) → no-op, matches Ethereum mainnet.
-/// - `calldata_gas_per_byte = 4`  → discount `12 × non_zero_bytes` gas, making
-///   non-zero bytes as cheap as zero bytes.
-/// - `calldata_gas_per_byte = 1`  → near-free calldata, maximises throughput.
-#[derive(Debug, Clone)]
-pub struct CalldataDiscountInspector<I> {
-    inner: I,
-    /// Replacement cost per non-zero calldata byte (1–16 gas).
+/// Same as revm's mainnet check otherwise: the gas limit must cover the intrinsic
+/// gas and, from Prague, the EIP-7623 calldata floor (which stays at mainnet
+/// pricing). Contract creations are priced on their initcode.
+pub fn intrinsic_gas(
+    tx: &TxEnv,
+    spec: SpecId,
+    eip7623_disabled: bool,
+    calldata_gas_per_byte: u64,
+) -> Result<InitialAndFloorGas, InvalidTransaction> {
+    let mut gas = calculate_initial_tx_gas_for_tx(tx, spec);
+    gas.initial_gas = gas
+        .initial_gas
+        .saturating_sub(calldata_discount(&tx.data, calldata_gas_per_byte));
+    if gas.initial_gas > tx.gas_limit {
+        return Err(InvalidTransaction::CallGasCostMoreThanGasLimit {
+            initial_gas: gas.initial_gas,
+            gas_limit: tx.gas_limit,
+        });
+    }
+    if spec.is_enabled_in(SpecId::PRAGUE) && !eip7623_disabled && gas.floor_gas > tx.gas_limit {
+        return Err(InvalidTransaction::GasFloorMoreThanGasLimit {
+            gas_floor: gas.floor_gas,
+            gas_limit: tx.gas_limit,
+        });
+    }
+    Ok(gas)
+}
+
+/// The revm EVM behind [`PoaEvm`].
+type PoaRevmEvm<DB, I> = RevmEvm<
+    EthEvmContext<DB>,
+    I,
+    EthInstructions<EthInterpreter, EthEvmContext<DB>>,
+    PrecompilesMap,
+    EthFrame,
+>;
+
+/// Mainnet transaction handler, except that intrinsic gas uses the governed
+/// calldata cost (see [`intrinsic_gas`]).
+struct PoaHandler<DB, I> {
     calldata_gas_per_byte: u64,
-    /// Set to `true` after the discount has been applied for this EVM instance.
-    discount_applied: bool,
+    _evm: PhantomData<fn() -> (DB, I)>,
 }
 
-impl<I> CalldataDiscountInspector<I> {
-    /// Create a new inspector wrapping `inner` with the given calldata gas cost.
-    pub fn new(inner: I, calldata_gas_per_byte: u64) -> Self {
+impl<DB, I> PoaHandler<DB, I> {
+    fn new(calldata_gas_per_byte: u64) -> Self {
         Self {
-            inner,
-            calldata_gas_per_byte: calldata_gas_per_byte.clamp(1, 16),
-            discount_applied: false,
+            calldata_gas_per_byte,
+            _evm: PhantomData,
         }
     }
+}
 
-    /// Returns the discount in gas for a given number of non-zero calldata bytes.
-    pub fn discount_for(&self, non_zero_bytes: u64) -> u64 {
-        non_zero_bytes.saturating_mul(16u64.saturating_sub(self.calldata_gas_per_byte))
-    }
+impl<DB: Database, I> Handler for PoaHandler<DB, I> {
+    type Evm = PoaRevmEvm<DB, I>;
+    type Error = EVMError<DB::Error>;
+    type HaltReason = HaltReason;
 
-    /// Consume the wrapper and return the inner inspector.
-    pub fn into_inner(self) -> I {
-        self.inner
+    fn validate_initial_tx_gas(&self, evm: &Self::Evm) -> Result<InitialAndFloorGas, Self::Error> {
+        let ctx = evm.ctx_ref();
+        Ok(intrinsic_gas(
+            ctx.tx(),
+            ctx.cfg().spec(),
+            ctx.cfg().is_eip7623_disabled(),
+            self.calldata_gas_per_byte,
+        )?)
     }
+}
 
-    /// Borrow the inner inspector.
-    pub fn inner(&self) -> &I {
-        &self.inner
-    }
+impl<DB: Database, I: Inspector<EthEvmContext<DB>>> InspectorHandler for PoaHandler<DB, I> {
+    type IT = EthInterpreter;
+}
+
+// ─── PoaEvm ───────────────────────────────────────────────────────────────────
+
+/// Ethereum EVM that charges the governed calldata gas, and rejects transactions
+/// changing governance state while the Timelock is paused.
+///
+/// Transactions run through [`PoaHandler`], so the cheaper calldata lowers both
+/// the gas a transaction is charged and the gas limit it needs. The caller's
+/// inspector only runs while inspection is enabled.
+pub struct PoaEvm<DB: Database, I> {
+    inner: PoaRevmEvm<DB, I>,
+    /// Whether transactions run with the inspector.
+    inspect: bool,
+    /// Calldata gas cost per non-zero byte, in [`CALLDATA_GAS_RANGE`].
+    calldata_gas_per_byte: u64,
+}
 
-    /// Mutably borrow the inner inspector.
-    pub fn inner_mut(&mut self) -> &mut I {
-        &mut self.inner
+impl<DB: Database, I> PoaEvm<DB, I> {
+    /// The calldata gas cost this EVM charges per non-zero byte.
+    pub fn calldata_gas_per_byte(&self) -> u64 {
+        self.calldata_gas_per_byte
     }
 }
 
-impl<CTX, I: Inspector<CTX>> Inspector<CTX> for CalldataDiscountInspector<I> {
-    fn initialize_interp(&mut self, interp: &mut Interpreter, context: &mut CTX) {
-        // Apply discount once per tx (discount_applied resets when a new EVM is created).
-        if !self.discount_applied && self.calldata_gas_per_byte < 16 {
-            self.discount_applied = true;
-            // interp.input is InputsImpl (EthInterpreter default); .input is CallInput.
-            let non_zero = match &interp.input.input {
-                CallInput::Bytes(bytes) => bytes.iter().filter(|&&b| b != 0).count() as u64,
-                CallInput::SharedBuffer(_) => 0, // shared-memory slice: skip (sub-call context)
-            };
-            let discount = self.discount_for(non_zero);
-            if discount > 0 {
-                interp.gas.erase_cost(discount);
-            }
+impl<DB: Database, I: Inspector<EthEvmContext<DB>>> Evm for PoaEvm<DB, I> {
+    type DB = DB;
+    type Tx = TxEnv;
+    type Error = EVMError<DB::Error>;
+    type HaltReason = HaltReason;
+    type Spec = SpecId;
+    type BlockEnv = BlockEnv;
+    type Precompiles = PrecompilesMap;
+    type Inspector = I;
+
+    fn block(&self) -> &BlockEnv {
+        &self.inner.ctx.block
+    }
+
+    fn chain_id(&self) -> u64 {
+        self.inner.ctx.cfg.chain_id
+    }
+
+    fn transact_raw(&mut self, tx: TxEnv) -> Result<ResultAndState<HaltReason>, Self::Error> {
+        let paused = timelock_paused(self.inner.ctx.db_mut());
+        self.inner.ctx.set_tx(tx);
+        let mut handler = PoaHandler::new(self.calldata_gas_per_byte);
+        let result = if self.inspect {
+            handler.inspect_run(&mut self.inner)
+        } else {
+            handler.run(&mut self.inner)
+        }?;
+        let state = self.inner.ctx.journal_mut().finalize();
+        if paused && changes_paused_governance(&state) {
+            return Err(EVMError::Transaction(InvalidTransaction::Str(
+                PAUSED_GOVERNANCE_ERROR.into(),
+            )));
         }
-        self.inner.initialize_interp(interp, context);
+        Ok(ResultAndState { result, state })
     }
 
-    fn step(&mut self, interp: &mut Interpreter, context: &mut CTX) {
-        self.inner.step(interp, context);
+    fn transact_system_call(
+        &mut self,
+        caller: Address,
+        contract: Address,
+        data: Bytes,
+    ) -> Result<ResultAndState<HaltReason>, Self::Error> {
+        // System calls pay no intrinsic gas, so the mainnet handler applies
+        self.inner.system_call_with_caller(caller, contract, data)
     }
 
-    fn step_end(&mut self, interp: &mut Interpreter, context: &mut CTX) {
-        self.inner.step_end(interp, context);
+    fn finish(self) -> (DB, EvmEnv) {
+        let Context {
+            block: block_env,
+            cfg: cfg_env,
+            journaled_state,
+            ..
+        } = self.inner.ctx;
+        (journaled_state.database, EvmEnv { block_env, cfg_env })
     }
 
-    fn log(&mut self, context: &mut CTX, log: Log) {
-        self.inner.log(context, log);
+    fn set_inspector_enabled(&mut self, enabled: bool) {
+        self.inspect = enabled;
     }
 
-    fn call(&mut self, context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
-        self.inner.call(context, inputs)
+    fn components(&self) -> (&DB, &I, &PrecompilesMap) {
+        (
+            &self.inner.ctx.journaled_state.database,
+            &self.inner.inspector,
+            &self.inner.precompiles,
+        )
     }
 
-    fn call_end(&mut self, context: &mut CTX, inputs: &CallInputs, outcome: &mut CallOutcome) {
-        self.inner.call_end(context, inputs, outcome);
+    fn components_mut(&mut self) -> (&mut DB, &mut I, &mut PrecompilesMap) {
+        (
+            &mut self.inner.ctx.journaled_state.database,
+            &mut self.inner.inspector,
+            &mut self.inner.precompiles,
+        )
     }
+}
+
+// ─── Custom precompiles ───────────────────────────────────────────────────────
 
-    fn create(&mut self, context: &mut CTX, inputs: &mut CreateInputs) -> Option<CreateOutcome> {
-        self.inner.create(context, inputs)
+/// Chain-specific precompiles, installed on top of the Ethereum set.
+///
+/// Registering at an address that already holds a precompile replaces it.
+#[derive(Clone, Default)]
+pub struct CustomPrecompiles(Vec<(Address, DynPrecompile)>);
+
+impl CustomPrecompiles {
+    /// Add `precompile` at `address`, replacing an earlier registration there.
+    pub fn insert(&mut self, address: Address, precompile: impl Into<DynPrecompile>) {
+        self.0.retain(|(existing, _)| *existing != address);
+        self.0.push((address, precompile.into()));
     }
 
-    fn create_end(
-        &mut self,
-        context: &mut CTX,
-        inputs: &CreateInputs,
-        outcome: &mut CreateOutcome,
-    ) {
-        self.inner.create_end(context, inputs, outcome);
+    /// Addresses with a custom precompile, in registration order.
+    pub fn addresses(&self) -> impl Iterator<Item = &Address> {
+        self.0.iter().map(|(address, _)| address)
+    }
+
+    /// Returns `true` if no custom precompile is registered.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
     }
 
-    fn selfdestruct(&mut self, contract: Address, target: Address, value: U256) {
-        self.inner.selfdestruct(contract, target, value);
+    /// Install every custom precompile into an EVM's precompile map.
+    fn install(&self, precompiles: &mut PrecompilesMap) {
+        if !self.is_empty() {
+            precompiles.extend_precompiles(self.0.iter().cloned());
+        }
+    }
+}
+
+impl std::fmt::Debug for CustomPrecompiles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.addresses()).finish()
     }
 }
 
@@ -171,9 +313,13 @@ impl<CTX, I: Inspector<CTX>> Inspector<CTX> for CalldataDiscountInspector<I> {
 /// Wraps [`EthEvmFactory`] and injects two POA-specific `CfgEnv` overrides:
 ///
 /// 1. `limit_contract_code_size` — lifts EIP-170's 24 KB bytecode cap.
-/// 2. Calldata gas discount — wraps every created EVM with
-///    [`CalldataDiscountInspector`] so non-zero calldata bytes cost
-///    `calldata_gas_per_byte` instead of the Ethereum default of 16.
+/// 2. Calldata gas discount — every created EVM is a [`PoaEvm`], whose intrinsic gas
+///    charges `calldata_gas_per_byte` per non-zero calldata byte instead of the
+///    Ethereum default of 16.
+///
+/// Both read the governed `ChainConfig` value from the EVM's database first and fall
+/// back to the static setting while it's unset. A governed calldata cost outside
+/// [`CALLDATA_GAS_RANGE`] is ignored the same way.
 #[derive(Debug, Clone)]
 pub struct PoaEvmFactory {
     inner: EthEvmFactory,
@@ -187,6 +333,8 @@ pub struct PoaEvmFactory {
     /// Ethereum mainnet: 16.  POA default: 4 (same as zero bytes — effectively
     /// free relative to zero bytes, maximises L2-style throughput).
     pub calldata_gas_per_byte: u64,
+    /// Chain-specific precompiles added to every created EVM.
+    pub precompiles: CustomPrecompiles,
 }
 
 impl Default for PoaEvmFactory {
@@ -195,6 +343,7 @@ impl Default for PoaEvmFactory {
             inner: EthEvmFactory::default(),
             max_contract_size: None,
             calldata_gas_per_byte: 4, // POA default: reduce calldata cost
+            precompiles: CustomPrecompiles::default(),
         }
     }
 }
@@ -202,19 +351,78 @@ impl Default for PoaEvmFactory {
 impl PoaEvmFactory {
     /// Create a factory with custom contract size and calldata gas overrides.
     ///
-    /// `calldata_gas_per_byte` is clamped to `[1, 16]`.
     /// Pass `16` to disable the calldata discount (Ethereum mainnet behaviour).
+    ///
+    /// # Panics
+    /// If `calldata_gas_per_byte` is outside [`CALLDATA_GAS_RANGE`].
     pub fn new(max_contract_size: Option<usize>, calldata_gas_per_byte: u64) -> Self {
+        assert!(
+            CALLDATA_GAS_RANGE.contains(&calldata_gas_per_byte),
+            "calldata gas per byte must be within 1-16, got {calldata_gas_per_byte}"
+        );
         Self {
             inner: EthEvmFactory::default(),
             max_contract_size,
-            calldata_gas_per_byte: calldata_gas_per_byte.clamp(1, 16),
+            calldata_gas_per_byte,
+            precompiles: CustomPrecompiles::default(),
         }
     }
 
+    /// Install these custom precompiles in every EVM the factory creates.
+    pub fn with_precompiles(mut self, precompiles: CustomPrecompiles) -> Self {
+        self.precompiles = precompiles;
+        self
+    }
+
     /// Apply POA-specific `CfgEnv` overrides to an [`EvmEnv`] before EVM creation.
-    fn patch_env(&self, mut env: EvmEnv) -> EvmEnv {
-        if let Some(limit) = self.max_contract_size {
+    fn patch_env(&self, env: EvmEnv) -> EvmEnv {
+        Self::limit_code_size(env, self.max_contract_size)
+    }
+
+    /// Like [`patch_env`](Self::patch_env), but the governed `maxContractSize` in `db`
+    /// takes precedence over the static override.
+    fn patch_env_from_state<DB: Database>(&self, db: &mut DB, env: EvmEnv) -> EvmEnv {
+        let governed = read_governed(db, chain_config_slots::MAX_CONTRACT_SIZE);
+        Self::limit_code_size(
+            env,
+            governed
+                .map(|size| size.saturating_to())
+                .or(self.max_contract_size),
+        )
+    }
+
+    /// The governed `calldataGasPerByte` in `db`, or the static setting while it's
+    /// unset or outside [`CALLDATA_GAS_RANGE`].
+    fn calldata_gas_from_state<DB: Database>(&self, db: &mut DB) -> u64 {
+        read_governed(db, chain_config_slots::CALLDATA_GAS_PER_BYTE)
+            .map(|cost| cost.saturating_to::<u64>())
+            .filter(|cost| CALLDATA_GAS_RANGE.contains(cost))
+            .unwrap_or(self.calldata_gas_per_byte)
+    }
+
+    fn build_evm<DB: Database, I: Inspector<EthEvmContext<DB>>>(
+        &self,
+        mut db: DB,
+        input: EvmEnv,
+        inspector: I,
+        inspect: bool,
+    ) -> PoaEvm<DB, I> {
+        let env = self.patch_env_from_state(&mut db, input);
+        let calldata_gas_per_byte = self.calldata_gas_from_state(&mut db);
+        let mut inner = self
+            .inner
+            .create_evm_with_inspector(db, env, inspector)
+            .into_inner();
+        self.precompiles.install(&mut inner.precompiles);
+        PoaEvm {
+            inner,
+            inspect,
+            calldata_gas_per_byte,
+        }
+    }
+
+    fn limit_code_size(mut env: EvmEnv, limit: Option<usize>) -> EvmEnv {
+        if let Some(limit) = limit {
             env.cfg_env.limit_contract_code_size = Some(limit);
             // Also lift the initcode size limit (EIP-3860) proportionally.
             env.cfg_env.limit_contract_initcode_size = Some(limit * 2);
@@ -229,11 +437,7 @@ impl PoaEvmFactory {
 }
 
 impl EvmFactory for PoaEvmFactory {
-    // Use the standard inspector passthrough — the `EvmFactory` trait requires
-    // `Evm::Inspector == I`, so we cannot transparently wrap `I` with
-    // `CalldataDiscountInspector<I>` here.  Use `CalldataDiscountInspector`
-    // explicitly when creating an EVM with inspector if the discount is needed.
-    type Evm<DB: Database, I: Inspector<Self::Context<DB>>> = EthEvm<DB, I, PrecompilesMap>;
+    type Evm<DB: Database, I: Inspector<Self::Context<DB>>> = PoaEvm<DB, I>;
     type Context<DB: Database> = EthEvmContext<DB>;
     type Tx = TxEnv;
     type Error<DBError: core::error::Error + Send + Sync + 'static> = EVMError<DBError>;
@@ -243,7 +447,7 @@ impl EvmFactory for PoaEvmFactory {
     type Precompiles = PrecompilesMap;
 
     fn create_evm<DB: Database>(&self, db: DB, input: EvmEnv) -> Self::Evm<DB, NoOpInspector> {
-        self.inner.create_evm(db, self.patch_env(input))
+        self.build_evm(db, input, NoOpInspector, false)
     }
 
     fn create_evm_with_inspector<DB: Database, I: Inspector<Self::Context<DB>>>(
@@ -252,11 +456,25 @@ impl EvmFactory for PoaEvmFactory {
         input: EvmEnv,
         inspector: I,
     ) -> Self::Evm<DB, I> {
-        self.inner
-            .create_evm_with_inspector(db, self.patch_env(input), inspector)
+        self.build_evm(db, input, inspector, true)
     }
 }
 
+/// A `ChainConfig` slot in `db`, or `None` if unset or unreadable (e.g. a custom
+/// genesis without the governance contracts).
+fn read_governed<DB: Database>(db: &mut DB, slot: U256) -> Option<U256> {
+    db.storage(CHAIN_CONFIG_ADDRESS, slot)
+        .ok()
+        .filter(|value| !value.is_zero())
+}
+
+/// Whether the Timelock in `db` is paused. Unreadable state counts as unpaused, like
+/// a custom genesis without the Timelock.
+fn timelock_paused<DB: Database>(db: &mut DB) -> bool {
+    db.storage(TIMELOCK_ADDRESS, timelock_slots::PAUSED)
+        .is_ok_and(|value| value.byte(timelock_slots::PAUSED_OFFSET) != 0)
+}
+
 // ─── PoaExecutorBuilder ───────────────────────────────────────────────────────
 
 /// Custom executor builder that uses [`PoaEvmFactory`] for EVM creation.
@@ -270,6 +488,8 @@ pub struct PoaExecutorBuilder {
     pub max_contract_size: Option<usize>,
     /// Gas cost per non-zero calldata byte (1–16). `16` = Ethereum mainnet default.
     pub calldata_gas_per_byte: u64,
+    /// Chain-specific precompiles for the EVM factory.
+    pub precompiles: CustomPrecompiles,
 }
 
 impl PoaExecutorBuilder {
@@ -278,8 +498,15 @@ impl PoaExecutorBuilder {
         Self {
             max_contract_size,
             calldata_gas_per_byte,
+            precompiles: CustomPrecompiles::default(),
         }
     }
+
+    /// Pass custom precompiles through to the EVM factory.
+    pub fn with_precompiles(mut self, precompiles: CustomPrecompiles) -> Self {
+        self.precompiles = precompiles;
+        self
+    }
 }
 
 impl<Types, Node> ExecutorBuilder<Node> for PoaExecutorBuilder
@@ -293,9 +520,15 @@ where
     type EVM = EthEvmConfig<Types::ChainSpec, PoaEvmFactory>;
 
     async fn build_evm(self, ctx: &BuilderContext<Node>) -> eyre::Result<Self::EVM> {
+        eyre::ensure!(
+            CALLDATA_GAS_RANGE.contains(&self.calldata_gas_per_byte),
+            "calldata gas per byte must be within 1-16, got {}",
+            self.calldata_gas_per_byte
+        );
         Ok(EthEvmConfig::new_with_evm_factory(
             ctx.chain_spec(),
-            PoaEvmFactory::new(self.max_contract_size, self.calldata_gas_per_byte),
+            PoaEvmFactory::new(self.max_contract_size, self.calldata_gas_per_byte)
+                .with_precompiles(self.precompiles),
         ))
     }
 }
@@ -349,44 +582,63 @@ mod tests {
     // ── calldata gas ───────────────────────────────────────────────────────────
 
     #[test]
-    fn test_calldata_discount_inspector_discount_for_zero_bytes() {
-        let inspector = CalldataDiscountInspector::new(NoOpInspector, 4);
-        // 0 non-zero bytes → 0 discount
-        assert_eq!(inspector.discount_for(0), 0);
+    fn test_calldata_discount_empty_data() {
+        assert_eq!(calldata_discount(&[], 4), 0);
     }
 
     #[test]
-    fn test_calldata_discount_inspector_discount_at_4_gas() {
-        let inspector = CalldataDiscountInspector::new(NoOpInspector, 4);
+    fn test_calldata_discount_at_4_gas() {
         // (16 - 4) * 100 = 1200
-        assert_eq!(inspector.discount_for(100), 1200);
+        assert_eq!(calldata_discount(&[0xff; 100], 4), 1200);
+        // Zero bytes cost 1 instead of 4: (4 - 1) * 100 = 300
+        assert_eq!(calldata_discount(&[0; 100], 4), 300);
     }
 
     #[test]
-    fn test_calldata_discount_inspector_no_discount_at_16_gas() {
-        let inspector = CalldataDiscountInspector::new(NoOpInspector, 16);
-        // (16 - 16) * 100 = 0
-        assert_eq!(inspector.discount_for(100), 0);
+    fn test_calldata_discount_no_discount_at_16_gas() {
+        assert_eq!(calldata_discount(&[0xff; 100], 16), 0);
+        assert_eq!(calldata_discount(&[0; 100], 16), 0);
     }
 
     #[test]
-    fn test_calldata_discount_inspector_discount_at_1_gas() {
-        let inspector = CalldataDiscountInspector::new(NoOpInspector, 1);
+    fn test_calldata_discount_at_1_gas() {
         // (16 - 1) * 50 = 750
-        assert_eq!(inspector.discount_for(50), 750);
+        assert_eq!(calldata_discount(&[0xff; 50], 1), 750);
     }
 
     #[test]
-    fn test_calldata_discount_inspector_clamps_cost_to_1() {
-        // 0 would be invalid (division by zero risk) — clamp to 1
-        let inspector = CalldataDiscountInspector::new(NoOpInspector, 0);
-        assert_eq!(inspector.calldata_gas_per_byte, 1);
+    fn test_intrinsic_gas_uses_calldata_cost() {
+        let tx = TxEnv {
+            data: Bytes::from(vec![0xffu8; 1_000]),
+            gas_limit: 21_000 + 4 * 1_000,
+            ..Default::default()
+        };
+        let gas = intrinsic_gas(&tx, SpecId::CANCUN, false, 4).unwrap();
+        assert_eq!(gas.initial_gas, 21_000 + 4 * 1_000);
+
+        // The same limit doesn't cover mainnet pricing
+        assert!(matches!(
+            intrinsic_gas(&tx, SpecId::CANCUN, false, 16),
+            Err(InvalidTransaction::CallGasCostMoreThanGasLimit { .. })
+        ));
+        // From Prague the EIP-7623 floor (10 gas per token) still applies
+        assert!(matches!(
+            intrinsic_gas(&tx, SpecId::PRAGUE, false, 4),
+            Err(InvalidTransaction::GasFloorMoreThanGasLimit { .. })
+        ));
+        assert!(intrinsic_gas(&tx, SpecId::PRAGUE, true, 4).is_ok());
     }
 
     #[test]
-    fn test_calldata_discount_inspector_clamps_cost_to_16() {
-        let inspector = CalldataDiscountInspector::new(NoOpInspector, 20);
-        assert_eq!(inspector.calldata_gas_per_byte, 16);
+    #[should_panic(expected = "within 1-16")]
+    fn test_poa_evm_factory_rejects_zero_calldata_gas() {
+        PoaEvmFactory::new(None, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "within 1-16")]
+    fn test_poa_evm_factory_rejects_calldata_gas_above_16() {
+        PoaEvmFactory::new(None, 20);
     }
 
     #[test]
@@ -418,6 +670,130 @@ mod tests {
         assert_eq!(builder.calldata_gas_per_byte, 16);
     }
 
+    // ── custom precompiles ─────────────────────────────────────────────────────
+
+    #[test]
+    fn test_custom_precompile_same_result_in_call_and_execution() {
+        use alloy_evm::precompiles::PrecompileInput;
+        use alloy_evm::revm::database::{CacheDB, EmptyDB};
+        use alloy_evm::revm::precompile::{PrecompileOutput, PrecompileResult};
+        use alloy_evm::revm::primitives::TxKind;
+        use alloy_primitives::Bytes;
+        use reth_evm::ConfigureEvm;
+        use std::sync::Arc;
+
+        // Reverses its input for a flat 100 gas
+        let address = Address::with_last_byte(0x42);
+        let mut precompiles = CustomPrecompiles::default();
+        precompiles.insert(address, |input: PrecompileInput<'_>| -> PrecompileResult {
+            let reversed: Vec<u8> = input.data.iter().rev().copied().collect();
+            Ok(PrecompileOutput::new(100, reversed.into()))
+        });
+        let factory = PoaEvmFactory::new(None, 16).with_precompiles(precompiles);
+        let tx = TxEnv {
+            kind: TxKind::Call(address),
+            data: Bytes::from_static(&[1, 2, 3]),
+            gas_limit: 100_000,
+            ..Default::default()
+        };
+
+        // eth_call path: EthEvmConfig::evm_with_env, result discarded
+        let evm_config = EthEvmConfig::new_with_evm_factory(
+            Arc::new(reth_chainspec::ChainSpec::default()),
+            factory.clone(),
+        );
+        let call = evm_config
+            .evm_with_env(CacheDB::new(EmptyDB::default()), make_env())
+            .transact(tx.clone())
+            .unwrap()
+            .result;
+        assert!(call.is_success(), "{call:?}");
+        assert_eq!(call.output().unwrap().as_ref(), &[3, 2, 1]);
+
+        // Execution path: committed transaction, and the inspector (tracing) variant
+        let executed = factory
+            .create_evm(CacheDB::new(EmptyDB::default()), make_env())
+            .transact_commit(tx.clone())
+            .unwrap();
+        assert_eq!(executed, call);
+        let traced = factory
+            .create_evm_with_inspector(CacheDB::new(EmptyDB::default()), make_env(), NoOpInspector)
+            .transact(tx)
+            .unwrap()
+            .result;
+        assert_eq!(traced, call);
+    }
+
+    // ── timelock pause ─────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_paused_timelock_rejects_governance_writes() {
+        use alloy_evm::revm::bytecode::Bytecode;
+        use alloy_evm::revm::database::{CacheDB, EmptyDB};
+        use alloy_evm::revm::primitives::TxKind;
+        use alloy_evm::revm::state::AccountInfo;
+
+        // A ChainConfig stand-in that writes slot 0 (PUSH1 1 PUSH1 0 SSTORE STOP), and
+        // a contract that calls it, as the governance Safe would
+        let store = Bytecode::new_raw(Bytes::from_static(&[0x60, 0x01, 0x60, 0x00, 0x55, 0x00]));
+        let mut forward = vec![
+            0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x73,
+        ];
+        forward.extend_from_slice(CHAIN_CONFIG_ADDRESS.as_slice());
+        forward.extend_from_slice(&[0x5a, 0xf1, 0x00]); // GAS CALL STOP
+        let proxy = Address::with_last_byte(0xbb);
+        let mut db = CacheDB::new(EmptyDB::default());
+        for (address, code) in [
+            (CHAIN_CONFIG_ADDRESS, store),
+            (proxy, Bytecode::new_raw(forward.into())),
+        ] {
+            db.insert_account_info(
+                address,
+                AccountInfo {
+                    code: Some(code),
+                    ..Default::default()
+                },
+            );
+        }
+        let call = |to| TxEnv {
+            kind: TxKind::Call(to),
+            gas_limit: 100_000,
+            ..Default::default()
+        };
+        let factory = PoaEvmFactory::new(None, 16);
+
+        // Unpaused: direct and internal writes go through
+        for to in [CHAIN_CONFIG_ADDRESS, proxy] {
+            let result = factory
+                .create_evm(db.clone(), make_env())
+                .transact(call(to))
+                .unwrap();
+            assert!(result.result.is_success());
+        }
+
+        // Paused (byte 20 of the admin slot): both are invalid, other calls aren't
+        db.insert_account_storage(
+            TIMELOCK_ADDRESS,
+            timelock_slots::PAUSED,
+            U256::from(1) << (8 * timelock_slots::PAUSED_OFFSET),
+        )
+        .unwrap();
+        for to in [CHAIN_CONFIG_ADDRESS, proxy] {
+            let err = factory
+                .create_evm(db.clone(), make_env())
+                .transact(call(to))
+                .unwrap_err();
+            assert!(
+                matches!(err, EVMError::Transaction(InvalidTransaction::Str(_))),
+                "{err:?}"
+            );
+        }
+        assert!(factory
+            .create_evm(db, make_env())
+            .transact(call(TIMELOCK_ADDRESS))
+            .is_ok());
+    }
+
     #[test]
     fn test_patch_env_does_not_change_other_fields() {
         let factory = PoaEvmFactory::new(Some(65_536), 4);
@@ -0,0 +1,117 @@
+//! `meowchain genesis` command handler.
+//!
+//! Builds a [`GenesisConfig`] from CLI flags and writes the resulting genesis
+//! JSON, so a new network can be bootstrapped without writing Rust. The
+//! system, governance and Safe contracts are always included; no accounts are
+//! prefunded.
+
+use eyre::{ensure, Result};
+
+use super::{try_create_genesis, write_genesis_file, GenesisConfig};
+use crate::cli::GenesisArgs;
+use crate::output;
+
+/// Build the genesis configuration described by `args`.
+pub fn genesis_config(args: &GenesisArgs) -> Result<GenesisConfig> {
+    ensure!(!args.signers.is_empty(), "At least one signer is required");
+    ensure!(
+        args.block_period > 0,
+        "Block period must be at least 1 second"
+    );
+
+    let mut vanity = [0u8; 32];
+    if let Some(text) = &args.vanity {
+        ensure!(
+            text.len() <= vanity.len(),
+            "Vanity is {} bytes, the limit is 32",
+            text.len()
+        );
+        vanity[..text.len()].copy_from_slice(text.as_bytes());
+    }
+
+    let mut config = GenesisConfig::default()
+        .with_chain_id(args.chain_id)
+        .with_signers(args.signers.clone())
+        .with_block_period(args.block_period)
+        .with_vanity(vanity);
+    config.gas_limit = args.gas_limit;
+    Ok(config)
+}
+
+/// Generate the genesis and write it to `args.output`.
+pub fn run(args: &GenesisArgs) -> Result<()> {
+    let genesis = try_create_genesis(genesis_config(args)?)?;
+    write_genesis_file(&genesis, &args.output)?;
+    output::print_genesis_written(&args.output, args.chain_id, &args.signers);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Cli, Command};
+    use alloy_primitives::Address;
+    use clap::Parser;
+
+    fn parse(args: &[&str]) -> Result<GenesisArgs, clap::Error> {
+        let cli = Cli::try_parse_from(["meowchain", "genesis"].iter().chain(args))?;
+        match cli.command {
+            Some(Command::Genesis(args)) => Ok(args),
+            other => panic!("expected genesis command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_genesis_command_writes_config() {
+        let output = std::env::temp_dir().join(format!(
+            "meowchain-genesis-test-{}.json",
+            hex::encode(&alloy_primitives::B256::random()[..8])
+        ));
+        let args = parse(&[
+            "--chain-id",
+            "4242",
+            "--signers",
+            "0x0000000000000000000000000000000000000001,0x0000000000000000000000000000000000000002",
+            "--gas-limit",
+            "60000000",
+            "--block-period",
+            "2",
+            "--vanity",
+            "Meowchain",
+            "--output",
+            output.to_str().unwrap(),
+        ])
+        .unwrap();
+        run(&args).unwrap();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&output).unwrap()).unwrap();
+        std::fs::remove_file(&output).unwrap();
+        assert_eq!(json["config"]["chainId"], 4242);
+        assert_eq!(json["gasLimit"], "0x3938700");
+        // vanity + 2 signers + empty seal
+        let extra = json["extraData"].as_str().unwrap();
+        assert_eq!(extra.len(), 2 + 2 * (32 + 2 * 20 + 65));
+        assert!(extra.starts_with(&format!("0x{}", hex::encode("Meowchain"))));
+        assert!(extra.contains(&hex::encode(Address::with_last_byte(2))));
+    }
+
+    #[test]
+    fn test_genesis_command_rejects_bad_input() {
+        assert!(parse(&[]).is_err(), "signers are required");
+        assert!(
+            parse(&["--signers", "0x1234"]).is_err(),
+            "malformed address"
+        );
+
+        let args = parse(&[
+            "--signers",
+            "0x0000000000000000000000000000000000000001",
+            "--vanity",
+            &"x".repeat(33),
+        ])
+        .unwrap();
+        let err = genesis_config(&args).unwrap_err();
+        assert!(err.to_string().contains("limit is 32"), "{err}");
+    }
+}
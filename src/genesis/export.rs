@@ -0,0 +1,112 @@
+//! Streaming genesis writer for state exports.
+//!
+//! [`GenesisWriter`] writes a genesis JSON one alloc entry at a time, so exporting
+//! a large state (`meow_exportState`) never holds more than one account in memory.
+//! The output is a regular genesis file: `--genesis exported.json` launches a fresh
+//! chain from the exported state.
+
+use alloy_genesis::{Genesis, GenesisAccount};
+use alloy_primitives::Address;
+use std::io::{self, Write};
+
+/// Writes a genesis JSON with the header fields of a template and a streamed alloc.
+#[derive(Debug)]
+pub struct GenesisWriter<W: Write> {
+    out: W,
+    accounts: u64,
+    storage_slots: u64,
+}
+
+impl<W: Write> GenesisWriter<W> {
+    /// Write every field of `template` except its alloc, then open the alloc object.
+    pub fn new(mut out: W, template: &Genesis) -> io::Result<Self> {
+        let mut header = serde_json::to_value(template)?;
+        let fields = header
+            .as_object_mut()
+            .ok_or_else(|| io::Error::other("genesis did not serialize to an object"))?;
+        fields.remove("alloc");
+
+        out.write_all(b"{")?;
+        for (key, value) in fields.iter() {
+            serde_json::to_writer(&mut out, key)?;
+            out.write_all(b":")?;
+            serde_json::to_writer(&mut out, value)?;
+            out.write_all(b",")?;
+        }
+        out.write_all(b"\"alloc\":{")?;
+        Ok(Self {
+            out,
+            accounts: 0,
+            storage_slots: 0,
+        })
+    }
+
+    /// Append one alloc entry. Addresses must not repeat.
+    pub fn write_account(&mut self, address: Address, account: &GenesisAccount) -> io::Result<()> {
+        if self.accounts > 0 {
+            self.out.write_all(b",")?;
+        }
+        serde_json::to_writer(&mut self.out, &address)?;
+        self.out.write_all(b":")?;
+        serde_json::to_writer(&mut self.out, account)?;
+        self.accounts += 1;
+        self.storage_slots += account.storage.as_ref().map_or(0, |s| s.len() as u64);
+        Ok(())
+    }
+
+    /// Number of accounts written so far.
+    pub fn accounts(&self) -> u64 {
+        self.accounts
+    }
+
+    /// Number of storage slots written so far.
+    pub fn storage_slots(&self) -> u64 {
+        self.storage_slots
+    }
+
+    /// Close the alloc and the genesis object, flush, and return the writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.out.write_all(b"}}")?;
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genesis::create_dev_genesis;
+
+    #[test]
+    fn test_streamed_dev_genesis_round_trips() {
+        let genesis = create_dev_genesis();
+        let mut writer = GenesisWriter::new(Vec::new(), &genesis).unwrap();
+        for (address, account) in &genesis.alloc {
+            writer.write_account(*address, account).unwrap();
+        }
+        assert_eq!(writer.accounts(), genesis.alloc.len() as u64);
+        assert!(
+            writer.storage_slots() > 0,
+            "governance contracts have storage"
+        );
+
+        let json = writer.finish().unwrap();
+        let parsed: Genesis = serde_json::from_slice(&json).unwrap();
+        assert_eq!(parsed, genesis);
+    }
+
+    #[test]
+    fn test_empty_alloc_is_valid_json() {
+        let genesis = Genesis {
+            alloc: Default::default(),
+            ..create_dev_genesis()
+        };
+        let json = GenesisWriter::new(Vec::new(), &genesis)
+            .unwrap()
+            .finish()
+            .unwrap();
+        let parsed: Genesis = serde_json::from_slice(&json).unwrap();
+        assert!(parsed.alloc.is_empty());
+        assert_eq!(parsed.config.chain_id, genesis.config.chain_id);
+    }
+}
@@ -0,0 +1,211 @@
+//! Import of go-ethereum clique genesis files.
+//!
+//! A network migrating from geth clique keeps its signer keys: [`from_geth_clique`]
+//! reads the signers from the geth genesis `extraData` (the same
+//! `vanity + N*20 signers + 65-byte seal` layout we use), the period and epoch from
+//! `config.clique`, and carries the alloc over. The result is a regular
+//! [`GenesisConfig`], so the new genesis also gets the built-in system, governance
+//! and Safe contracts, with the imported signers registered in `SignerRegistry`.
+
+use alloy_genesis::{Genesis, GenesisAccount};
+use alloy_primitives::U256;
+
+use super::{signers_from_extra_data, try_create_genesis, GenesisConfig, GenesisError};
+use crate::chainspec::{PoaChainSpec, PoaConfig};
+use crate::constants::EXTRA_VANITY_LENGTH;
+
+/// geth's default clique epoch, used when the genesis leaves it at 0.
+pub const GETH_DEFAULT_EPOCH: u64 = 30000;
+
+/// Parse a go-ethereum clique genesis JSON into a [`GenesisConfig`].
+///
+/// Chain ID, gas limit, signers, vanity, period and epoch are taken from the file.
+/// Balance-only alloc entries become prefunded accounts; entries with code, storage
+/// or a nonce are copied as-is.
+pub fn from_geth_clique(json: &str) -> Result<GenesisConfig, GenesisError> {
+    let genesis: Genesis =
+        serde_json::from_str(json).map_err(|e| GenesisError::GethJson(e.to_string()))?;
+
+    let clique = genesis
+        .config
+        .clique
+        .as_ref()
+        .ok_or(GenesisError::MissingCliqueConfig)?;
+    if genesis.difficulty != U256::from(1) {
+        return Err(GenesisError::CliqueDifficulty(genesis.difficulty));
+    }
+    let period = clique.period.unwrap_or_default();
+    if period == 0 {
+        return Err(GenesisError::CliqueZeroPeriod);
+    }
+    let epoch = match clique.epoch.unwrap_or_default() {
+        0 => GETH_DEFAULT_EPOCH,
+        epoch => epoch,
+    };
+
+    let signers = signers_from_extra_data(&genesis.extra_data)?;
+    let mut vanity = [0u8; EXTRA_VANITY_LENGTH];
+    vanity.copy_from_slice(&genesis.extra_data[..EXTRA_VANITY_LENGTH]);
+
+    let mut config = GenesisConfig {
+        chain_id: genesis.config.chain_id,
+        gas_limit: genesis.gas_limit,
+        signers,
+        block_period: period,
+        epoch,
+        vanity,
+        genesis_number: genesis.number.unwrap_or_default(),
+        genesis_timestamp: genesis.timestamp,
+        ..GenesisConfig::default()
+    };
+    for (address, account) in genesis.alloc {
+        if is_balance_only(&account) {
+            config.prefunded_accounts.insert(address, account.balance);
+        } else {
+            config.extra_alloc.insert(address, account);
+        }
+    }
+    Ok(config)
+}
+
+/// Build a chain spec from a go-ethereum clique genesis JSON.
+pub fn geth_clique_chain_spec(json: &str) -> Result<PoaChainSpec, GenesisError> {
+    let config = from_geth_clique(json)?;
+    let poa_config = PoaConfig {
+        period: config.block_period,
+        epoch: config.epoch,
+        signers: config.signers.clone(),
+        ..PoaConfig::default()
+    };
+    Ok(PoaChainSpec::new(try_create_genesis(config)?, poa_config))
+}
+
+fn is_balance_only(account: &GenesisAccount) -> bool {
+    account.code.as_ref().is_none_or(|code| code.is_empty())
+        && account.storage.as_ref().is_none_or(|s| s.is_empty())
+        && account.nonce.unwrap_or_default() == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, Address};
+
+    const SIGNER_A: Address = address!("0x1111111111111111111111111111111111111111");
+    const SIGNER_B: Address = address!("0x2222222222222222222222222222222222222222");
+    const CONTRACT: Address = address!("0x3333333333333333333333333333333333333333");
+    const FUNDED: Address = address!("0x4444444444444444444444444444444444444444");
+
+    /// A genesis as written by geth's `puppeth` for a two-signer clique network.
+    fn geth_genesis(difficulty: &str, clique: &str) -> String {
+        let extra_data = format!(
+            "0x{}{}{}{}",
+            "00".repeat(32),
+            hex::encode(SIGNER_A),
+            hex::encode(SIGNER_B),
+            "00".repeat(65)
+        );
+        format!(
+            r#"{{
+  "config": {{
+    "chainId": 4242,
+    "homesteadBlock": 0,
+    "eip150Block": 0,
+    "eip155Block": 0,
+    "eip158Block": 0,
+    "byzantiumBlock": 0,
+    "constantinopleBlock": 0,
+    "petersburgBlock": 0,
+    "istanbulBlock": 0,
+    "berlinBlock": 0,
+    "londonBlock": 0{clique}
+  }},
+  "nonce": "0x0",
+  "timestamp": "0x5f5e1000",
+  "extraData": "{extra_data}",
+  "gasLimit": "0x1c9c380",
+  "difficulty": "{difficulty}",
+  "mixHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+  "coinbase": "0x0000000000000000000000000000000000000000",
+  "alloc": {{
+    "{FUNDED}": {{ "balance": "0xde0b6b3a7640000" }},
+    "{CONTRACT}": {{
+      "balance": "0x0",
+      "code": "0x6001600055",
+      "storage": {{
+        "0x0000000000000000000000000000000000000000000000000000000000000000": "0x0000000000000000000000000000000000000000000000000000000000000001"
+      }}
+    }}
+  }},
+  "number": "0x0",
+  "gasUsed": "0x0",
+  "parentHash": "0x0000000000000000000000000000000000000000000000000000000000000000"
+}}"#
+        )
+    }
+
+    const CLIQUE: &str = r#",
+    "clique": { "period": 5, "epoch": 30000 }"#;
+
+    #[test]
+    fn test_import_geth_clique_genesis() {
+        let config = from_geth_clique(&geth_genesis("0x1", CLIQUE)).unwrap();
+        assert_eq!(config.chain_id, 4242);
+        assert_eq!(config.gas_limit, 30_000_000);
+        assert_eq!(config.signers, vec![SIGNER_A, SIGNER_B]);
+        assert_eq!(config.block_period, 5);
+        assert_eq!(config.epoch, 30000);
+        assert_eq!(
+            config.prefunded_accounts.get(&FUNDED),
+            Some(&U256::from(10u64).pow(U256::from(18)))
+        );
+        assert!(config.extra_alloc[&CONTRACT].code.is_some());
+
+        let chain = geth_clique_chain_spec(&geth_genesis("0x1", CLIQUE)).unwrap();
+        assert_eq!(chain.signers(), [SIGNER_A, SIGNER_B]);
+        assert_eq!(chain.block_period(), 5);
+        assert_eq!(chain.epoch(), 30000);
+        let genesis = chain.inner().genesis();
+        assert_eq!(
+            signers_from_extra_data(&genesis.extra_data).unwrap(),
+            vec![SIGNER_A, SIGNER_B]
+        );
+        assert_eq!(
+            genesis.alloc[&CONTRACT].storage.as_ref().map(|s| s.len()),
+            Some(1)
+        );
+        assert!(genesis
+            .alloc
+            .contains_key(&crate::genesis::SIGNER_REGISTRY_ADDRESS));
+    }
+
+    #[test]
+    fn test_geth_default_epoch() {
+        let clique = r#",
+    "clique": { "period": 15, "epoch": 0 }"#;
+        let config = from_geth_clique(&geth_genesis("0x1", clique)).unwrap();
+        assert_eq!(config.epoch, GETH_DEFAULT_EPOCH);
+    }
+
+    #[test]
+    fn test_rejects_non_clique_genesis() {
+        assert_eq!(
+            from_geth_clique(&geth_genesis("0x1", "")).unwrap_err(),
+            GenesisError::MissingCliqueConfig
+        );
+        assert_eq!(
+            from_geth_clique(&geth_genesis("0x400000000", CLIQUE)).unwrap_err(),
+            GenesisError::CliqueDifficulty(U256::from(0x400000000u64))
+        );
+        let clique = r#",
+    "clique": { "period": 0, "epoch": 30000 }"#;
+        assert_eq!(
+            from_geth_clique(&geth_genesis("0x1", clique)).unwrap_err(),
+            GenesisError::CliqueZeroPeriod
+        );
+        assert!(matches!(
+            from_geth_clique("{ not json"),
+            Err(GenesisError::GethJson(_))
+        ));
+    }
+}
@@ -20,6 +20,7 @@ pub(crate) fn governance_contract_alloc(
     signers: &[Address],
     gas_limit: u64,
     block_time: u64,
+    epoch: u64,
 ) -> BTreeMap<Address, GenesisAccount> {
     let mut contracts = BTreeMap::new();
 
@@ -32,6 +33,8 @@ pub(crate) fn governance_contract_alloc(
     //   slot 4: calldataGasPerByte
     //   slot 5: maxTxGas
     //   slot 6: eagerMining (bool)
+    //   slot 7: recentSignerWindow
+    //   slot 8: epoch
     {
         let mut storage = BTreeMap::new();
         // slot 0: governance
@@ -65,6 +68,16 @@ pub(crate) fn governance_contract_alloc(
             B256::from(U256::from(gas_limit).to_be_bytes()),
         );
         // slot 6: eagerMining = false (0)
+        // slot 7: recentSignerWindow = floor(N/2) + 1, the Clique default for the genesis signers
+        storage.insert(
+            b256!("0000000000000000000000000000000000000000000000000000000000000007"),
+            B256::from(U256::from(signers.len() / 2 + 1).to_be_bytes()),
+        );
+        // slot 8: epoch = the genesis epoch length
+        storage.insert(
+            b256!("0000000000000000000000000000000000000000000000000000000000000008"),
+            B256::from(U256::from(epoch).to_be_bytes()),
+        );
 
         contracts.insert(
             CHAIN_CONFIG_ADDRESS,
@@ -227,9 +240,8 @@ pub(crate) fn governance_contract_alloc(
     //   slot 0: minDelay (uint256) = 86400 (24 hours)
     //   slot 1: proposer (address) = governance
     //   slot 2: executor (address) = governance
-    //   slot 3: admin (address) = governance
-    //   slot 4: paused (bool) = false
-    //   slot 5: timestamps mapping (mapping, individual slots — empty at genesis)
+    //   slot 3: admin (address) = governance, packed with paused (bool, byte 20) = false
+    //   slot 4: timestamps mapping (mapping, individual slots — empty at genesis)
     {
         let mut storage = BTreeMap::new();
         // slot 0: minDelay = 86400 seconds (24 hours)
@@ -246,12 +258,11 @@ pub(crate) fn governance_contract_alloc(
             b256!("0000000000000000000000000000000000000000000000000000000000000002"),
             B256::from(gov_slot),
         );
-        // slot 3: admin = governance
+        // slot 3: admin = governance, paused = false
         storage.insert(
             b256!("0000000000000000000000000000000000000000000000000000000000000003"),
             B256::from(gov_slot),
         );
-        // slot 4: paused = false (0) — default, no need to set
 
         contracts.insert(
             TIMELOCK_ADDRESS,
@@ -269,3 +280,139 @@ pub(crate) fn governance_contract_alloc(
 
     contracts
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evm::PoaEvmFactory;
+    use crate::onchain::{chain_config_slots, function_selector};
+    use alloy_evm::revm::bytecode::Bytecode;
+    use alloy_evm::revm::context::TxEnv;
+    use alloy_evm::revm::database::{CacheDB, EmptyDB};
+    use alloy_evm::revm::primitives::TxKind;
+    use alloy_evm::revm::state::AccountInfo;
+    use alloy_evm::revm::DatabaseRef;
+    use alloy_evm::{Evm, EvmEnv, EvmFactory};
+
+    /// Load the genesis ChainConfig account into an in-memory database.
+    fn chain_config_db(governance: Address) -> CacheDB<EmptyDB> {
+        let alloc =
+            governance_contract_alloc(governance, &dev_accounts()[..3], 30_000_000, 2, 30_000);
+        let account = &alloc[&CHAIN_CONFIG_ADDRESS];
+        let mut db = CacheDB::new(EmptyDB::default());
+        db.insert_account_info(
+            CHAIN_CONFIG_ADDRESS,
+            AccountInfo {
+                nonce: 1,
+                code: Some(Bytecode::new_raw(account.code.clone().unwrap())),
+                ..Default::default()
+            },
+        );
+        for (slot, value) in account.storage.as_ref().unwrap() {
+            db.insert_account_storage(
+                CHAIN_CONFIG_ADDRESS,
+                U256::from_be_bytes(slot.0),
+                U256::from_be_bytes(value.0),
+            )
+            .unwrap();
+        }
+        db
+    }
+
+    /// Call ChainConfig's `signature` with ABI words `args` from `from`, committing
+    /// the state changes. Returns the output if the call succeeded.
+    fn call(
+        db: &mut CacheDB<EmptyDB>,
+        from: Address,
+        signature: &str,
+        args: &[B256],
+    ) -> Option<Bytes> {
+        let mut data = function_selector(signature).to_vec();
+        for word in args {
+            data.extend_from_slice(word.as_slice());
+        }
+        let nonce = db
+            .basic_ref(from)
+            .unwrap()
+            .map_or(0, |account| account.nonce);
+        let result = PoaEvmFactory::new(None, 16)
+            .create_evm(db, EvmEnv::default())
+            .transact_commit(TxEnv {
+                caller: from,
+                kind: TxKind::Call(CHAIN_CONFIG_ADDRESS),
+                data: data.into(),
+                gas_limit: 1_000_000,
+                nonce,
+                ..Default::default()
+            })
+            .unwrap();
+        result
+            .is_success()
+            .then(|| result.output().cloned().unwrap_or_default())
+    }
+
+    fn word(value: u64) -> B256 {
+        B256::from(U256::from(value).to_be_bytes())
+    }
+
+    #[test]
+    fn test_chain_config_set_recent_signer_window() {
+        let governance = Address::with_last_byte(0x60);
+        let mut db = chain_config_db(governance);
+
+        // Genesis value: floor(3/2) + 1
+        let window = call(&mut db, governance, "recentSignerWindow()", &[]);
+        assert_eq!(window, Some(Bytes::from(word(2).to_vec())));
+
+        // Only governance may change it
+        let outsider = Address::with_last_byte(0x61);
+        assert!(call(
+            &mut db,
+            outsider,
+            "setRecentSignerWindow(uint256)",
+            &[word(1)]
+        )
+        .is_none());
+        assert!(call(
+            &mut db,
+            governance,
+            "setRecentSignerWindow(uint256)",
+            &[word(3)]
+        )
+        .is_some());
+
+        // The setter writes the slot the node reads
+        assert_eq!(
+            db.storage_ref(
+                CHAIN_CONFIG_ADDRESS,
+                chain_config_slots::RECENT_SIGNER_WINDOW
+            )
+            .unwrap(),
+            U256::from(3)
+        );
+        // Existing functions are unaffected
+        let gas_limit = call(&mut db, outsider, "gasLimit()", &[]);
+        assert_eq!(gas_limit, Some(Bytes::from(word(30_000_000).to_vec())));
+    }
+
+    #[test]
+    fn test_chain_config_set_epoch() {
+        let governance = Address::with_last_byte(0x60);
+        let mut db = chain_config_db(governance);
+
+        let epoch = call(&mut db, governance, "epoch()", &[]);
+        assert_eq!(epoch, Some(Bytes::from(word(30_000).to_vec())));
+
+        let outsider = Address::with_last_byte(0x61);
+        assert!(call(&mut db, outsider, "setEpoch(uint256)", &[word(10)]).is_none());
+        assert!(call(&mut db, governance, "setEpoch(uint256)", &[word(60_000)]).is_some());
+        assert_eq!(
+            db.storage_ref(CHAIN_CONFIG_ADDRESS, chain_config_slots::EPOCH)
+                .unwrap(),
+            U256::from(60_000)
+        );
+        // The recent-signer window next to it is untouched
+        let window = call(&mut db, outsider, "recentSignerWindow()", &[]);
+        assert_eq!(window, Some(Bytes::from(word(2).to_vec())));
+    }
+}
@@ -5,8 +5,12 @@
 
 pub mod accounts;
 pub mod addresses;
+pub mod commands;
 mod contracts;
+pub mod export;
+pub mod geth;
 mod governance;
+pub mod tokens;
 
 // Re-export public API
 pub use accounts::{default_prefund_balance, dev_accounts, dev_signers};
@@ -15,10 +19,67 @@ pub use addresses::{
     SAFE_FALLBACK_HANDLER_ADDRESS, SAFE_MULTISEND_ADDRESS, SAFE_PROXY_FACTORY_ADDRESS,
     SAFE_SINGLETON_ADDRESS, SIGNER_REGISTRY_ADDRESS, TIMELOCK_ADDRESS, TREASURY_ADDRESS,
 };
+pub use export::GenesisWriter;
+pub use geth::{from_geth_clique, geth_clique_chain_spec};
+pub use tokens::Erc20Prefund;
 
+use crate::constants::{ADDRESS_LENGTH, EXTRA_SEAL_LENGTH, EXTRA_VANITY_LENGTH};
 use alloy_genesis::{Genesis, GenesisAccount};
 use alloy_primitives::{Address, U256};
 use std::collections::BTreeMap;
+use thiserror::Error;
+
+/// Default cap on genesis alloc entries. The built-in contracts plus prefunded
+/// accounts come to a few dozen; anything near this limit is almost certainly a bug.
+pub const DEFAULT_MAX_ALLOC_ENTRIES: usize = 10_000;
+
+/// Errors raised while assembling a genesis.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum GenesisError {
+    /// The merged alloc has more entries than `GenesisConfig::max_alloc_entries`.
+    #[error("genesis alloc has {entries} entries, exceeding the limit of {max}")]
+    AllocTooLarge {
+        /// Number of entries in the final alloc
+        entries: usize,
+        /// Configured maximum
+        max: usize,
+    },
+    /// The genesis extraData doesn't follow the `vanity + N*20 signers + seal` layout.
+    #[error(
+        "genesis extraData is {len} bytes, expected 32-byte vanity + N*20-byte signers + 65-byte seal"
+    )]
+    InvalidExtraData {
+        /// Length of the extraData found
+        len: usize,
+    },
+    /// An ERC-20 prefund targets an address already used by another genesis account.
+    #[error("ERC-20 token address {0} is already used by another genesis account")]
+    Erc20AddressInUse(Address),
+    /// An ERC-20 token's prefunded balances overflow its total supply.
+    #[error("ERC-20 token {0} prefunds overflow the total supply")]
+    Erc20SupplyOverflow(Address),
+    /// The genesis extraData lists no signers.
+    #[error("genesis extraData lists no signers")]
+    NoSigners,
+    /// An imported genesis account collides with a built-in contract.
+    #[error("genesis account {0} collides with a built-in contract")]
+    AllocConflict(Address),
+    /// A go-ethereum genesis could not be parsed.
+    #[error("invalid geth genesis JSON: {0}")]
+    GethJson(String),
+    /// A go-ethereum genesis has no `clique` section (e.g. an ethash or PoS network).
+    #[error("geth genesis has no `config.clique` section; only clique networks can be imported")]
+    MissingCliqueConfig,
+    /// A clique genesis must have difficulty 1 (geth's `diffNoTurn`).
+    #[error("clique genesis difficulty must be 1, found {0}")]
+    CliqueDifficulty(U256),
+    /// A clique period of 0 (seal on demand) has no equivalent here.
+    #[error("clique period must be at least 1 second")]
+    CliqueZeroPeriod,
+    /// The genesis `config.baseFeeParams` is malformed or has a zero parameter.
+    #[error("invalid genesis config.baseFeeParams: {0}")]
+    InvalidBaseFeeParams(String),
+}
 
 /// Create a development genesis configuration
 pub fn create_dev_genesis() -> Genesis {
@@ -42,6 +103,16 @@ pub struct GenesisConfig {
     pub epoch: u64,
     /// Optional extra vanity data (32 bytes)
     pub vanity: [u8; 32],
+    /// Maximum number of entries in the final genesis alloc
+    pub max_alloc_entries: usize,
+    /// ERC-20 balances to mint, deploying a token contract at each token address
+    pub erc20_prefunds: Vec<Erc20Prefund>,
+    /// Accounts copied into the alloc as-is (e.g. contracts from an imported genesis)
+    pub extra_alloc: BTreeMap<Address, GenesisAccount>,
+    /// Genesis block number (non-zero when forking from existing state at that height)
+    pub genesis_number: u64,
+    /// Genesis block timestamp; block 1 may be sealed `block_period` seconds later
+    pub genesis_timestamp: u64,
 }
 
 impl Default for GenesisConfig {
@@ -54,6 +125,11 @@ impl Default for GenesisConfig {
             block_period: 12,
             epoch: 30000,
             vanity: [0u8; 32],
+            max_alloc_entries: DEFAULT_MAX_ALLOC_ENTRIES,
+            erc20_prefunds: Vec::new(),
+            extra_alloc: BTreeMap::new(),
+            genesis_number: 0,
+            genesis_timestamp: 0,
         }
     }
 }
@@ -78,6 +154,11 @@ impl GenesisConfig {
             block_period: 1, // Phase 2: 1-second blocks
             epoch: 30000,
             vanity: [0u8; 32],
+            max_alloc_entries: DEFAULT_MAX_ALLOC_ENTRIES,
+            erc20_prefunds: Vec::new(),
+            extra_alloc: BTreeMap::new(),
+            genesis_number: 0,
+            genesis_timestamp: 0,
         }
     }
 
@@ -91,6 +172,11 @@ impl GenesisConfig {
             block_period: 12, // Same as Ethereum mainnet
             epoch: 30000,
             vanity: [0u8; 32],
+            max_alloc_entries: DEFAULT_MAX_ALLOC_ENTRIES,
+            erc20_prefunds: Vec::new(),
+            extra_alloc: BTreeMap::new(),
+            genesis_number: 0,
+            genesis_timestamp: 0,
         }
     }
 
@@ -141,6 +227,11 @@ impl GenesisConfig {
             block_period: 2, // Production: 2s (faster than Ethereum's 12s)
             epoch: 30000,
             vanity,
+            max_alloc_entries: DEFAULT_MAX_ALLOC_ENTRIES,
+            erc20_prefunds: Vec::new(),
+            extra_alloc: BTreeMap::new(),
+            genesis_number: 0,
+            genesis_timestamp: 0,
         }
     }
 
@@ -173,10 +264,48 @@ impl GenesisConfig {
         self.vanity = vanity;
         self
     }
+
+    /// Builder method to mint `amount` of an ERC-20 at `token` to `holder`
+    pub fn with_erc20_prefund(mut self, token: Address, holder: Address, amount: U256) -> Self {
+        self.erc20_prefunds.push(Erc20Prefund {
+            token,
+            holder,
+            amount,
+        });
+        self
+    }
+
+    /// Builder method to set the genesis block number
+    pub fn with_genesis_number(mut self, number: u64) -> Self {
+        self.genesis_number = number;
+        self
+    }
+
+    /// Builder method to set the genesis block timestamp
+    pub fn with_genesis_timestamp(mut self, timestamp: u64) -> Self {
+        self.genesis_timestamp = timestamp;
+        self
+    }
+
+    /// Builder method to set the maximum genesis alloc size
+    pub fn with_max_alloc_entries(mut self, max: usize) -> Self {
+        self.max_alloc_entries = max;
+        self
+    }
 }
 
 /// Create a genesis configuration from the config
+///
+/// # Panics
+///
+/// If the alloc exceeds `config.max_alloc_entries`; use [`try_create_genesis`] to
+/// handle that as an error.
 pub fn create_genesis(config: GenesisConfig) -> Genesis {
+    try_create_genesis(config).unwrap_or_else(|e| panic!("invalid genesis: {e}"))
+}
+
+/// Create a genesis configuration from the config, rejecting an oversized alloc
+pub fn try_create_genesis(config: GenesisConfig) -> Result<Genesis, GenesisError> {
     // Build the extra data field for POA:
     // Format: [vanity (32 bytes)][signers (N*20 bytes)][signature (65 bytes, all zeros for genesis)]
     let mut extra_data = Vec::with_capacity(32 + config.signers.len() * 20 + 65);
@@ -224,11 +353,34 @@ pub fn create_genesis(config: GenesisConfig) -> Genesis {
         &config.signers,
         config.gas_limit,
         config.block_period,
+        config.epoch,
     ));
 
     // Add Gnosis Safe contracts for multisig governance
     alloc.extend(contracts::safe_contract_alloc());
 
+    // Add ERC-20 tokens with prefunded balances; they must not replace another account
+    for (token, account) in tokens::erc20_token_alloc(&config.erc20_prefunds)? {
+        if alloc.insert(token, account).is_some() {
+            return Err(GenesisError::Erc20AddressInUse(token));
+        }
+    }
+
+    // Add imported accounts; like tokens, they must not replace a built-in contract
+    for (address, account) in config.extra_alloc {
+        if alloc.insert(address, account).is_some() {
+            return Err(GenesisError::AllocConflict(address));
+        }
+    }
+
+    // Guard against accidental alloc explosions from a buggy merge or injection
+    if alloc.len() > config.max_alloc_entries {
+        return Err(GenesisError::AllocTooLarge {
+            entries: alloc.len(),
+            max: config.max_alloc_entries,
+        });
+    }
+
     // Build the chain config JSON
     let chain_config = serde_json::json!({
         "chainId": config.chain_id,
@@ -254,22 +406,22 @@ pub fn create_genesis(config: GenesisConfig) -> Genesis {
         }
     });
 
-    Genesis {
+    Ok(Genesis {
         config: serde_json::from_value(chain_config).expect("valid chain config"),
         nonce: 0,
-        timestamp: 0,
+        timestamp: config.genesis_timestamp,
         extra_data: extra_data.into(),
         gas_limit: config.gas_limit,
         difficulty: U256::from(1),
         mix_hash: Default::default(),
         coinbase: MINER_PROXY_ADDRESS,
         alloc,
-        number: None,
+        number: (config.genesis_number > 0).then_some(config.genesis_number),
         parent_hash: None,
         base_fee_per_gas: Some(875_000_000), // EIP-1559 initial base fee (0.875 gwei)
         excess_blob_gas: Some(0),
         blob_gas_used: Some(0),
-    }
+    })
 }
 
 /// Helper to serialize genesis to JSON (for use with other tools)
@@ -283,6 +435,36 @@ pub fn write_genesis_file(genesis: &Genesis, path: &std::path::Path) -> std::io:
     std::fs::write(path, json)
 }
 
+/// Helper to read a genesis file from disk
+pub fn read_genesis_file(path: &std::path::Path) -> std::io::Result<Genesis> {
+    let json = std::fs::read_to_string(path)?;
+    serde_json::from_str(&json).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+}
+
+/// Parse the signer list from genesis extraData, the inverse of [`try_create_genesis`]:
+/// `[vanity (32 bytes)][signers (N*20 bytes)][seal (65 bytes)]`
+pub fn signers_from_extra_data(extra_data: &[u8]) -> Result<Vec<Address>, GenesisError> {
+    let invalid = || GenesisError::InvalidExtraData {
+        len: extra_data.len(),
+    };
+    let signers_len = extra_data
+        .len()
+        .checked_sub(EXTRA_VANITY_LENGTH + EXTRA_SEAL_LENGTH)
+        .ok_or_else(invalid)?;
+    if !signers_len.is_multiple_of(ADDRESS_LENGTH) {
+        return Err(invalid());
+    }
+    if signers_len == 0 {
+        return Err(GenesisError::NoSigners);
+    }
+
+    let signers = &extra_data[EXTRA_VANITY_LENGTH..EXTRA_VANITY_LENGTH + signers_len];
+    Ok(signers
+        .chunks_exact(ADDRESS_LENGTH)
+        .map(Address::from_slice)
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -515,6 +697,93 @@ mod tests {
         write_genesis_file(&genesis, &path).unwrap();
     }
 
+    #[test]
+    fn test_erc20_prefund_balances_readable_from_genesis() {
+        use crate::onchain::{mapping_address_bool_slot, GenesisStorageReader, StorageReader};
+
+        let token = Address::with_last_byte(0xe2);
+        let (alice, bob) = (Address::with_last_byte(0xa1), Address::with_last_byte(0xb0));
+        let config = GenesisConfig::dev()
+            .with_erc20_prefund(token, alice, U256::from(1_000u64))
+            .with_erc20_prefund(token, bob, U256::from(250u64))
+            .with_erc20_prefund(token, alice, U256::from(5u64));
+        let genesis = create_genesis(config);
+        assert!(genesis.alloc[&token]
+            .code
+            .as_ref()
+            .is_some_and(|c| !c.is_empty()));
+
+        // balanceOf(holder) = _balances[holder], mapping at slot 0
+        let reader = GenesisStorageReader::from_genesis(&genesis);
+        let balance_of = |holder: Address| {
+            let slot = mapping_address_bool_slot(holder, U256::ZERO);
+            reader.read_storage(token, U256::from_be_bytes(slot.0))
+        };
+        assert_eq!(
+            balance_of(alice),
+            Some(B256::from(U256::from(1_005u64).to_be_bytes()))
+        );
+        assert_eq!(
+            balance_of(bob),
+            Some(B256::from(U256::from(250u64).to_be_bytes()))
+        );
+        assert_eq!(balance_of(Address::with_last_byte(1)), None);
+        // totalSupply at slot 2
+        assert_eq!(
+            reader.read_storage(token, U256::from(2)),
+            Some(B256::from(U256::from(1_255u64).to_be_bytes()))
+        );
+    }
+
+    #[test]
+    fn test_erc20_prefund_rejects_taken_address() {
+        let config = GenesisConfig::dev().with_erc20_prefund(
+            TREASURY_ADDRESS,
+            Address::with_last_byte(1),
+            U256::from(1),
+        );
+        assert_eq!(
+            try_create_genesis(config).unwrap_err(),
+            GenesisError::Erc20AddressInUse(TREASURY_ADDRESS)
+        );
+    }
+
+    #[test]
+    fn test_signers_round_trip_through_genesis_file() {
+        let genesis = create_genesis(GenesisConfig::production());
+        let path = std::env::temp_dir().join(format!(
+            "meowchain-genesis-{}.json",
+            hex::encode(&B256::random()[..8])
+        ));
+        write_genesis_file(&genesis, &path).unwrap();
+        let loaded = read_genesis_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, genesis);
+        assert_eq!(
+            signers_from_extra_data(&loaded.extra_data).unwrap(),
+            GenesisConfig::production().signers
+        );
+    }
+
+    #[test]
+    fn test_signers_from_malformed_extra_data() {
+        // Seal only: too short for the vanity
+        assert_eq!(
+            signers_from_extra_data(&[0u8; 65]),
+            Err(GenesisError::InvalidExtraData { len: 65 })
+        );
+        // Partial signer address
+        assert_eq!(
+            signers_from_extra_data(&[0u8; 32 + 19 + 65]),
+            Err(GenesisError::InvalidExtraData { len: 116 })
+        );
+        assert_eq!(
+            signers_from_extra_data(&[0u8; 32 + 65]),
+            Err(GenesisError::NoSigners)
+        );
+    }
+
     #[test]
     fn test_production_genesis_has_all_contracts() {
         let config = GenesisConfig::production();
@@ -715,10 +984,11 @@ mod tests {
             "ChainConfig must have storage"
         );
         let storage = chain_config.storage.as_ref().unwrap();
-        // Should have slots for governance, gasLimit, blockTime, maxContractSize, calldataGasPerByte, maxTxGas
+        // Should have slots for governance, gasLimit, blockTime, maxContractSize, calldataGasPerByte,
+        // maxTxGas, recentSignerWindow, epoch
         assert!(
-            storage.len() >= 6,
-            "ChainConfig needs at least 6 storage slots"
+            storage.len() >= 8,
+            "ChainConfig needs at least 8 storage slots"
         );
 
         // SignerRegistry contract
@@ -852,6 +1122,22 @@ mod tests {
             "ChainConfig slot 2 should be block time 1s"
         );
 
+        // slot 7: recentSignerWindow = floor(3/2) + 1 for the 3 dev signers
+        let slot7 = b256!("0000000000000000000000000000000000000000000000000000000000000007");
+        assert_eq!(
+            *storage.get(&slot7).unwrap(),
+            B256::from(U256::from(2u64).to_be_bytes()),
+            "ChainConfig slot 7 should be recent signer window 2"
+        );
+
+        // slot 8: epoch = 30000, the dev epoch length
+        let slot8 = b256!("0000000000000000000000000000000000000000000000000000000000000008");
+        assert_eq!(
+            *storage.get(&slot8).unwrap(),
+            B256::from(U256::from(30_000u64).to_be_bytes()),
+            "ChainConfig slot 8 should be epoch 30000"
+        );
+
         // --- SignerRegistry storage verification ---
         let signer_registry = genesis.alloc.get(&SIGNER_REGISTRY_ADDRESS).unwrap();
         let storage = signer_registry.storage.as_ref().unwrap();
@@ -1034,4 +1320,29 @@ mod tests {
             "0x000000000000000000000000000000006f5afe00"
         );
     }
+
+    #[test]
+    fn test_alloc_size_guard() {
+        // Normal genesis is far below the default limit
+        let genesis = try_create_genesis(GenesisConfig::production()).unwrap();
+        assert!(genesis.alloc.len() < DEFAULT_MAX_ALLOC_ENTRIES);
+
+        // An artificially huge alloc is rejected
+        let mut config = GenesisConfig::dev();
+        for i in 0..DEFAULT_MAX_ALLOC_ENTRIES as u64 {
+            config.prefunded_accounts.insert(
+                Address::from_word(B256::from(U256::from(i + 1))),
+                U256::from(1),
+            );
+        }
+        let err = try_create_genesis(config.clone()).unwrap_err();
+        assert!(matches!(
+            err,
+            GenesisError::AllocTooLarge { entries, max }
+                if entries > max && max == DEFAULT_MAX_ALLOC_ENTRIES
+        ));
+
+        // ...unless the limit is raised deliberately
+        assert!(try_create_genesis(config.with_max_alloc_entries(20_000)).is_ok());
+    }
 }
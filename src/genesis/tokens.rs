@@ -0,0 +1,211 @@
+//! ERC-20 token prefunds for genesis.
+//!
+//! Every token named in [`GenesisConfig::erc20_prefunds`](super::GenesisConfig) is
+//! deployed with `erc20.bin`, a minimal hand-assembled ERC-20 (transfer, approve,
+//! transferFrom, allowance, balanceOf, totalSupply, decimals = 18, name, symbol)
+//! implementing `genesis-contracts/ERC20.sol`, with OpenZeppelin's storage layout:
+//!
+//!   - slot 0: `mapping(address => uint256) _balances`
+//!   - slot 1: `mapping(address => mapping(address => uint256)) _allowances`
+//!   - slot 2: `uint256 _totalSupply`
+//!   - slot 3: `string _name` (short-string encoding; empty here)
+//!   - slot 4: `string _symbol` (short-string encoding; empty here)
+//!
+//! Balances are written straight into slot 0's mapping and summed into slot 2.
+
+use alloy_genesis::GenesisAccount;
+use alloy_primitives::{Address, Bytes, Keccak256, B256, U256};
+use std::collections::BTreeMap;
+
+use super::GenesisError;
+
+/// Storage slot of the `_balances` mapping.
+pub const ERC20_BALANCES_SLOT: U256 = U256::ZERO;
+
+/// Storage slot of `_totalSupply`.
+pub const ERC20_TOTAL_SUPPLY_SLOT: U256 = U256::from_limbs([2, 0, 0, 0]);
+
+/// A token balance minted in genesis.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Erc20Prefund {
+    /// Address the token contract is deployed at
+    pub token: Address,
+    /// Account receiving the balance
+    pub holder: Address,
+    /// Amount in the token's smallest unit (18 decimals)
+    pub amount: U256,
+}
+
+/// Storage slot of `_balances[holder]`: `keccak256(abi.encode(holder, 0))`.
+pub fn erc20_balance_slot(holder: Address) -> B256 {
+    let mut key_padded = [0u8; 32];
+    key_padded[12..32].copy_from_slice(holder.as_slice());
+    let mut hasher = Keccak256::new();
+    hasher.update(key_padded);
+    hasher.update(B256::from(ERC20_BALANCES_SLOT.to_be_bytes()).as_slice());
+    hasher.finalize()
+}
+
+/// Returns one ERC-20 contract alloc per token, with its balances and total supply.
+///
+/// Several prefunds for the same holder of a token add up.
+pub(crate) fn erc20_token_alloc(
+    prefunds: &[Erc20Prefund],
+) -> Result<BTreeMap<Address, GenesisAccount>, GenesisError> {
+    let mut balances: BTreeMap<Address, BTreeMap<Address, U256>> = BTreeMap::new();
+    for prefund in prefunds {
+        let balance = balances
+            .entry(prefund.token)
+            .or_default()
+            .entry(prefund.holder)
+            .or_default();
+        *balance = balance
+            .checked_add(prefund.amount)
+            .ok_or(GenesisError::Erc20SupplyOverflow(prefund.token))?;
+    }
+
+    let mut contracts = BTreeMap::new();
+    for (token, holders) in balances {
+        let mut storage = BTreeMap::new();
+        let mut total_supply = U256::ZERO;
+        for (holder, balance) in holders {
+            total_supply = total_supply
+                .checked_add(balance)
+                .ok_or(GenesisError::Erc20SupplyOverflow(token))?;
+            storage.insert(
+                erc20_balance_slot(holder),
+                B256::from(balance.to_be_bytes()),
+            );
+        }
+        storage.insert(
+            B256::from(ERC20_TOTAL_SUPPLY_SLOT.to_be_bytes()),
+            B256::from(total_supply.to_be_bytes()),
+        );
+
+        contracts.insert(
+            token,
+            GenesisAccount {
+                balance: U256::ZERO,
+                nonce: Some(1),
+                code: Some(Bytes::from_static(include_bytes!("../bytecodes/erc20.bin"))),
+                storage: Some(storage),
+                private_key: None,
+            },
+        );
+    }
+    Ok(contracts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evm::PoaEvmFactory;
+    use crate::onchain::function_selector;
+    use alloy_evm::revm::bytecode::Bytecode;
+    use alloy_evm::revm::context::TxEnv;
+    use alloy_evm::revm::database::{CacheDB, EmptyDB};
+    use alloy_evm::revm::primitives::TxKind;
+    use alloy_evm::revm::state::AccountInfo;
+    use alloy_evm::revm::DatabaseRef;
+    use alloy_evm::{Evm, EvmEnv, EvmFactory};
+
+    /// Call `token`'s `signature` with ABI words `args` from `from`, committing the
+    /// state changes. Returns the output if the call succeeded.
+    fn call(
+        db: &mut CacheDB<EmptyDB>,
+        token: Address,
+        from: Address,
+        signature: &str,
+        args: &[B256],
+    ) -> Option<Bytes> {
+        let mut data = function_selector(signature).to_vec();
+        for word in args {
+            data.extend_from_slice(word.as_slice());
+        }
+        let nonce = db
+            .basic_ref(from)
+            .unwrap()
+            .map_or(0, |account| account.nonce);
+        let result = PoaEvmFactory::new(None, 16)
+            .create_evm(db, EvmEnv::default())
+            .transact_commit(TxEnv {
+                caller: from,
+                kind: TxKind::Call(token),
+                data: data.into(),
+                gas_limit: 1_000_000,
+                nonce,
+                ..Default::default()
+            })
+            .unwrap();
+        result
+            .is_success()
+            .then(|| result.output().cloned().unwrap_or_default())
+    }
+
+    #[test]
+    fn test_erc20_bytecode_transfer_then_balance_of() {
+        let token = Address::with_last_byte(0xe2);
+        let (alice, bob) = (Address::with_last_byte(0xa1), Address::with_last_byte(0xb0));
+        let alloc = erc20_token_alloc(&[Erc20Prefund {
+            token,
+            holder: alice,
+            amount: U256::from(1_000u64),
+        }])
+        .unwrap();
+
+        let account = &alloc[&token];
+        let mut db = CacheDB::new(EmptyDB::default());
+        db.insert_account_info(
+            token,
+            AccountInfo {
+                nonce: 1,
+                code: Some(Bytecode::new_raw(account.code.clone().unwrap())),
+                ..Default::default()
+            },
+        );
+        for (slot, value) in account.storage.as_ref().unwrap() {
+            db.insert_account_storage(
+                token,
+                U256::from_be_bytes(slot.0),
+                U256::from_be_bytes(value.0),
+            )
+            .unwrap();
+        }
+
+        let word = |amount: u64| B256::from(U256::from(amount).to_be_bytes());
+        let transfer = call(
+            &mut db,
+            token,
+            alice,
+            "transfer(address,uint256)",
+            &[bob.into_word(), word(300)],
+        );
+        assert_eq!(transfer, Some(Bytes::from(word(1).to_vec())));
+        // More than the remaining balance reverts
+        assert!(call(
+            &mut db,
+            token,
+            alice,
+            "transfer(address,uint256)",
+            &[bob.into_word(), word(701)],
+        )
+        .is_none());
+
+        for (holder, balance) in [(alice, 700), (bob, 300)] {
+            let output = call(
+                &mut db,
+                token,
+                bob,
+                "balanceOf(address)",
+                &[holder.into_word()],
+            );
+            assert_eq!(output, Some(Bytes::from(word(balance).to_vec())));
+            // balanceOf reads the slot the genesis writes prefunds to
+            let slot = erc20_balance_slot(holder);
+            assert_eq!(
+                db.storage_ref(token, U256::from_be_bytes(slot.0)).unwrap(),
+                U256::from(balance)
+            );
+        }
+    }
+}
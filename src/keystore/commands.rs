@@ -0,0 +1,130 @@
+//! `meowchain keystore` command handlers.
+//!
+//! Each operation runs against `--keystore-dir` and exits before the node
+//! starts. Passwords are read from the terminal without echo; `new` asks twice
+//! so a typo can't lock the key away.
+
+use eyre::{ensure, eyre, Result};
+use std::path::Path;
+use std::time::Duration;
+
+use super::KeystoreManager;
+use crate::cli::{KeystoreArgs, KeystoreCommand};
+use crate::output;
+
+/// Run a keystore command on `keystore_dir`, prompting on the terminal for passwords.
+pub fn run(args: &KeystoreArgs, keystore_dir: Option<&Path>) -> Result<()> {
+    let keystore_dir =
+        keystore_dir.ok_or_else(|| eyre!("the keystore command needs --keystore-dir"))?;
+    let manager = match args.target_unlock_ms {
+        Some(ms) => {
+            KeystoreManager::with_target_unlock_time(keystore_dir, Duration::from_millis(ms))
+        }
+        None => KeystoreManager::new(keystore_dir),
+    }
+    .with_format(args.format);
+    execute(&manager, &args.action, |prompt| {
+        Ok(rpassword::prompt_password(prompt)?)
+    })
+}
+
+/// Run `command` on `manager`, reading passwords through `prompt`.
+pub fn execute(
+    manager: &KeystoreManager,
+    command: &KeystoreCommand,
+    mut prompt: impl FnMut(&str) -> Result<String>,
+) -> Result<()> {
+    match command {
+        KeystoreCommand::New => {
+            let password = new_password(&mut prompt)?;
+            let address = manager.create_account(&password)?;
+            output::print_keystore_account("Created", &address);
+        }
+        KeystoreCommand::Import { key } => {
+            let password = new_password(&mut prompt)?;
+            let address = manager.import_key(key, &password)?;
+            output::print_keystore_account("Imported", &address);
+        }
+        KeystoreCommand::List => {
+            output::print_keystore_accounts(&manager.list_accounts()?);
+        }
+        KeystoreCommand::Delete { address } => {
+            manager.delete_account(address)?;
+            output::print_keystore_account("Deleted", address);
+        }
+        KeystoreCommand::Inspect {
+            address,
+            show_private_key,
+        } => {
+            let path = manager.find_keystore_path(address)?;
+            let password = prompt("Password: ")?;
+            let key = manager.decrypt_key(address, &password)?;
+            output::print_keystore_account("Unlocked", address);
+            output::print_info(&format!("Keystore file: {}", path.display()));
+            if *show_private_key {
+                output::print_warning(&format!("Private key: 0x{key}"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Ask for a new password twice and require both entries to match.
+fn new_password(prompt: &mut impl FnMut(&str) -> Result<String>) -> Result<String> {
+    let password = prompt("New password: ")?;
+    ensure!(!password.is_empty(), "Password must not be empty");
+    let confirm = prompt("Repeat password: ")?;
+    ensure!(password == confirm, "Passwords do not match");
+    Ok(password)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keystore::tests::temp_keystore;
+    use alloy_primitives::Address;
+
+    const DEV_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+    /// Prompt that answers with `answers` in order.
+    fn answers(answers: &[&str]) -> impl FnMut(&str) -> Result<String> {
+        let mut answers: Vec<String> = answers.iter().rev().map(|a| a.to_string()).collect();
+        move |_| Ok(answers.pop().expect("unexpected prompt"))
+    }
+
+    #[test]
+    fn test_keystore_command_lifecycle() {
+        let (manager, _dir) = temp_keystore();
+        let dev: Address = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
+            .parse()
+            .unwrap();
+        let import = KeystoreCommand::Import {
+            key: DEV_KEY.to_string(),
+        };
+
+        // Mismatched confirmation writes nothing
+        let err = execute(&manager, &import, answers(&["pw", "typo"])).unwrap_err();
+        assert!(err.to_string().contains("do not match"), "{err}");
+        assert!(manager.list_accounts().unwrap().is_empty());
+
+        execute(&manager, &import, answers(&["pw", "pw"])).unwrap();
+        execute(&manager, &KeystoreCommand::New, answers(&["pw", "pw"])).unwrap();
+        assert_eq!(manager.list_accounts().unwrap().len(), 2);
+
+        let inspect = KeystoreCommand::Inspect {
+            address: dev,
+            show_private_key: false,
+        };
+        assert!(execute(&manager, &inspect, answers(&["wrong"])).is_err());
+        execute(&manager, &inspect, answers(&["pw"])).unwrap();
+
+        execute(
+            &manager,
+            &KeystoreCommand::Delete { address: dev },
+            answers(&[]),
+        )
+        .unwrap();
+        assert!(!manager.has_account(&dev));
+        assert_eq!(manager.list_accounts().unwrap().len(), 1);
+    }
+}
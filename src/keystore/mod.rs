@@ -23,14 +23,30 @@
 //!   }
 //! }
 //! ```
+//!
+//! # Version 4 (non-standard)
+//!
+//! For the chain's own authority keys, [`KeystoreFormat::V4`] trades geth
+//! compatibility for a stronger at-rest format: an argon2id KDF and AES-256-GCM.
+//! The file has the same layout with `"version": 4`; `cipherparams.iv` holds the
+//! 12-byte GCM nonce, `kdfparams` holds `m` (KiB), `t` and `p` instead of `c` and
+//! `prf`, and `mac` is the GCM tag, which also authenticates the address. V3
+//! remains the default and both versions are always readable.
+
+pub mod commands;
 
 use aes::cipher::{KeyIvInit, StreamCipher};
+use aes_gcm::aead::{AeadInPlace, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce, Tag};
 use alloy_primitives::{keccak256, Address};
-use alloy_signer_local::PrivateKeySigner;
+use alloy_signer_local::coins_bip39::English;
+use alloy_signer_local::{MnemonicBuilder, PrivateKeySigner};
 use eyre::{bail, ensure, Context, Result};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 use crate::signer::SignerManager;
 
@@ -40,6 +56,15 @@ type Aes128Ctr = ctr::Ctr64BE<aes::Aes128>;
 /// Default PBKDF2 iteration count (262144 = 2^18, standard for Ethereum keystores)
 pub const DEFAULT_PBKDF2_C: u32 = 262_144;
 
+/// Lowest PBKDF2 iteration count calibration will pick, however fast the machine
+pub const MIN_PBKDF2_C: u32 = 65_536;
+
+/// Iterations per calibration sample; large enough that timer noise is negligible
+const CALIBRATION_SAMPLE_C: u32 = 16_384;
+
+/// Number of calibration samples; the fastest one is used
+const CALIBRATION_RUNS: usize = 3;
+
 /// Fast PBKDF2 iteration count for testing (still cryptographically functional, just faster)
 #[cfg(test)]
 const TEST_PBKDF2_C: u32 = 2;
@@ -47,10 +72,78 @@ const TEST_PBKDF2_C: u32 = 2;
 /// Derived key length in bytes
 const DKLEN: u32 = 32;
 
-/// Ethereum Keystore V3 format (compatible with geth, Reth, etc.)
+/// Default argon2id memory cost in KiB (64 MiB, RFC 9106's second recommended option)
+pub const DEFAULT_ARGON2_M_COST: u32 = 65_536;
+
+/// Default argon2id passes over memory
+pub const DEFAULT_ARGON2_T_COST: u32 = 3;
+
+/// Default argon2id lanes
+pub const DEFAULT_ARGON2_P_COST: u32 = 4;
+
+/// Fast argon2id parameters for testing (minimum memory, one pass)
+#[cfg(test)]
+const TEST_ARGON2: Argon2Params = Argon2Params {
+    m_cost: 8,
+    t_cost: 1,
+    p_cost: 1,
+};
+
+/// BIP-44 path of the first Ethereum account (same default as geth, Foundry, MetaMask)
+pub const DEFAULT_DERIVATION_PATH: &str = "m/44'/60'/0'/0/0";
+
+/// First hardened BIP-32 index; plain path components must be below this
+const BIP32_HARDENED_OFFSET: u32 = 1 << 31;
+
+/// On-disk format for new keystores.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeystoreFormat {
+    /// Standard V3: PBKDF2-HMAC-SHA256 + AES-128-CTR (geth compatible)
+    #[default]
+    V3,
+    /// Non-standard V4: argon2id + AES-256-GCM
+    V4,
+}
+
+impl FromStr for KeystoreFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "v3" => Ok(Self::V3),
+            "v4" => Ok(Self::V4),
+            other => Err(format!(
+                "unknown keystore format '{other}' (expected v3 or v4)"
+            )),
+        }
+    }
+}
+
+/// argon2id cost parameters for V4 keystores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Argon2Params {
+    /// Memory cost in KiB
+    pub m_cost: u32,
+    /// Number of passes
+    pub t_cost: u32,
+    /// Degree of parallelism
+    pub p_cost: u32,
+}
+
+impl Default for Argon2Params {
+    fn default() -> Self {
+        Self {
+            m_cost: DEFAULT_ARGON2_M_COST,
+            t_cost: DEFAULT_ARGON2_T_COST,
+            p_cost: DEFAULT_ARGON2_P_COST,
+        }
+    }
+}
+
+/// Ethereum Keystore V3 format (compatible with geth, Reth, etc.), also used for V4
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeystoreFile {
-    /// Keystore version (always 3)
+    /// Keystore version (3, or 4 for [`KeystoreFormat::V4`])
     pub version: u32,
     /// Account address (hex, without 0x prefix)
     pub address: String,
@@ -63,55 +156,92 @@ pub struct KeystoreFile {
 /// Encrypted key data following the V3 crypto JSON format
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CryptoJson {
-    /// Cipher algorithm (always "aes-128-ctr")
+    /// Cipher algorithm ("aes-128-ctr", or "aes-256-gcm" for V4)
     pub cipher: String,
     /// Hex-encoded encrypted private key
     pub ciphertext: String,
     /// Cipher parameters
     pub cipherparams: CipherParams,
-    /// Key derivation function (always "pbkdf2")
+    /// Key derivation function ("pbkdf2", or "argon2id" for V4)
     pub kdf: String,
     /// KDF parameters
     pub kdfparams: KdfParams,
-    /// Hex-encoded MAC for integrity verification (keccak256)
+    /// Hex-encoded MAC for integrity verification (keccak256, or the GCM tag for V4)
     pub mac: String,
 }
 
-/// AES-128-CTR cipher parameters
+/// Cipher parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CipherParams {
-    /// Hex-encoded 16-byte initialization vector
+    /// Hex-encoded initialization vector (16 bytes for AES-128-CTR, 12 for AES-256-GCM)
     pub iv: String,
 }
 
-/// PBKDF2 key derivation parameters
+/// Key derivation parameters (PBKDF2, or argon2id for V4)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KdfParams {
     /// Derived key length in bytes (always 32)
     pub dklen: u32,
-    /// Iteration count (default 262144)
+    /// PBKDF2 iteration count (default 262144; absent for argon2id)
+    #[serde(default, skip_serializing_if = "is_zero")]
     pub c: u32,
-    /// Pseudo-random function (always "hmac-sha256")
+    /// PBKDF2 pseudo-random function (always "hmac-sha256"; absent for argon2id)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub prf: String,
+    /// argon2id memory cost in KiB
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub m: Option<u32>,
+    /// argon2id passes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub t: Option<u32>,
+    /// argon2id lanes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p: Option<u32>,
     /// Hex-encoded random salt
     pub salt: String,
 }
 
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
+/// Outcome of [`KeystoreManager::load_all_into_signer_manager`].
+#[derive(Debug, Default)]
+pub struct KeystoreLoadReport {
+    /// Keys added to the signer manager
+    pub loaded: Vec<Address>,
+    /// Keystores skipped because the address is not an authorized signer
+    pub skipped: Vec<Address>,
+    /// Keystores that could not be loaded, with the reason
+    pub failed: Vec<(Address, eyre::Report)>,
+    /// Keys dropped from the signer manager (reload only)
+    pub removed: Vec<Address>,
+}
+
 /// Manages encrypted keystores on disk.
 ///
 /// Provides create, import, decrypt, list, and delete operations for
-/// Ethereum V3 keystore files.
+/// Ethereum V3 keystore files (and the non-standard V4, see [`KeystoreFormat`]).
+#[derive(Clone)]
 pub struct KeystoreManager {
     /// Directory where keystore files are stored
     keystore_dir: PathBuf,
     /// PBKDF2 iteration count (configurable for testing)
     pbkdf2_c: u32,
+    /// Format of new keystores
+    format: KeystoreFormat,
+    /// argon2id costs for V4 keystores
+    argon2: Argon2Params,
+    /// Optional secondary directory receiving a copy of every new keystore
+    backup_dir: Option<PathBuf>,
 }
 
 impl std::fmt::Debug for KeystoreManager {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("KeystoreManager")
             .field("keystore_dir", &self.keystore_dir)
+            .field("format", &self.format)
+            .field("backup_dir", &self.backup_dir)
             .finish()
     }
 }
@@ -124,6 +254,9 @@ impl KeystoreManager {
         Self {
             keystore_dir: keystore_dir.as_ref().to_path_buf(),
             pbkdf2_c: DEFAULT_PBKDF2_C,
+            format: KeystoreFormat::default(),
+            argon2: Argon2Params::default(),
+            backup_dir: None,
         }
     }
 
@@ -134,9 +267,60 @@ impl KeystoreManager {
         Self {
             keystore_dir: keystore_dir.as_ref().to_path_buf(),
             pbkdf2_c,
+            format: KeystoreFormat::default(),
+            argon2: Argon2Params::default(),
+            backup_dir: None,
         }
     }
 
+    /// Create a keystore manager whose PBKDF2 iteration count makes a keystore
+    /// take about `target` to unlock on this machine.
+    ///
+    /// The count is calibrated once, here, by timing a few PBKDF2 runs (see
+    /// [`calibrate_pbkdf2_iterations`]), and used for every keystore this manager
+    /// writes. Existing keystores keep the count they were encrypted with.
+    pub fn with_target_unlock_time(keystore_dir: impl AsRef<Path>, target: Duration) -> Self {
+        Self::with_pbkdf2_iterations(keystore_dir, calibrate_pbkdf2_iterations(target))
+    }
+
+    /// PBKDF2 iteration count used for new keystores.
+    pub fn pbkdf2_iterations(&self) -> u32 {
+        self.pbkdf2_c
+    }
+
+    /// Write new keystores (and re-encrypted ones, see [`Self::change_password`])
+    /// in `format`. Keystores of either version are always readable.
+    pub fn with_format(mut self, format: KeystoreFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Use `params` as the argon2id costs of new V4 keystores.
+    pub fn with_argon2_params(mut self, params: Argon2Params) -> Self {
+        self.argon2 = params;
+        self
+    }
+
+    /// Format of new keystores.
+    pub fn format(&self) -> KeystoreFormat {
+        self.format
+    }
+
+    /// Also write every created or imported keystore to `backup_dir`.
+    ///
+    /// Intended for a separate disk or mounted secure volume, so a single-disk
+    /// failure doesn't lose an authority key. The backup is the identical
+    /// encrypted file (same password), written atomically.
+    pub fn with_backup_dir(mut self, backup_dir: impl AsRef<Path>) -> Self {
+        self.backup_dir = Some(backup_dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// The configured backup directory, if any.
+    pub fn backup_dir(&self) -> Option<&Path> {
+        self.backup_dir.as_deref()
+    }
+
     /// Create a new account with a random private key, encrypt and save to disk.
     ///
     /// Returns the address of the newly created account.
@@ -146,7 +330,7 @@ impl KeystoreManager {
         let key_bytes = signer.credential().to_bytes();
         let key_hex = hex::encode(key_bytes);
 
-        let keystore = encrypt_key_with_iterations(&key_hex, password, self.pbkdf2_c)?;
+        let keystore = self.encrypt(&key_hex, password)?;
         self.save_keystore(&address, &keystore)?;
 
         Ok(address)
@@ -167,7 +351,35 @@ impl KeystoreManager {
             .map_err(|_| eyre::eyre!("Invalid private key format"))?;
         let address = signer.address();
 
-        let keystore = encrypt_key_with_iterations(clean_hex, password, self.pbkdf2_c)?;
+        let keystore = self.encrypt(clean_hex, password)?;
+        self.save_keystore(&address, &keystore)?;
+
+        Ok(address)
+    }
+
+    /// Import the key derived from a BIP-39 mnemonic, encrypt and save to disk.
+    ///
+    /// `derivation_path` defaults to [`DEFAULT_DERIVATION_PATH`]. Returns an error
+    /// for an invalid phrase (unknown word, bad checksum) or a malformed path.
+    pub fn import_mnemonic(
+        &self,
+        phrase: &str,
+        derivation_path: Option<&str>,
+        password: &str,
+    ) -> Result<Address> {
+        let path = derivation_path.unwrap_or(DEFAULT_DERIVATION_PATH);
+        validate_derivation_path(path)?;
+
+        let signer = MnemonicBuilder::<English>::default()
+            .phrase(phrase.trim())
+            .derivation_path(path)
+            .map_err(|e| eyre::eyre!("Invalid derivation path '{}': {}", path, e))?
+            .build()
+            .map_err(|e| eyre::eyre!("Invalid mnemonic: {}", e))?;
+        let address = signer.address();
+        let key_hex = hex::encode(signer.credential().to_bytes());
+
+        let keystore = self.encrypt(&key_hex, password)?;
         self.save_keystore(&address, &keystore)?;
 
         Ok(address)
@@ -186,6 +398,35 @@ impl KeystoreManager {
         decrypt_key(&keystore, password)
     }
 
+    /// Re-encrypt a keystore under a new password.
+    ///
+    /// Decrypts with `old_password` first, so a wrong password fails without touching
+    /// the file. The new keystore keeps the address and UUID but gets a fresh salt,
+    /// IV, ciphertext and MAC (using this manager's format and costs, so this also
+    /// migrates a V3 keystore to V4), and atomically replaces the old file and its
+    /// backup copy.
+    pub fn change_password(
+        &self,
+        address: &Address,
+        old_password: &str,
+        new_password: &str,
+    ) -> Result<()> {
+        let path = self.find_keystore_path(address)?;
+        let data = fs::read_to_string(&path)
+            .wrap_err_with(|| format!("Failed to read keystore file: {}", path.display()))?;
+        let old: KeystoreFile =
+            serde_json::from_str(&data).wrap_err("Failed to parse keystore JSON")?;
+        let key_hex = decrypt_key(&old, old_password)?;
+
+        let mut keystore = self.encrypt(&key_hex, new_password)?;
+        keystore.id = old.id;
+        let json =
+            serde_json::to_string_pretty(&keystore).wrap_err("Failed to serialize keystore")?;
+
+        write_atomic(&path, &json)?;
+        self.save_backup(address, &json)
+    }
+
     /// List all keystore files (addresses).
     ///
     /// Reads the keystore directory and returns all valid addresses found.
@@ -246,21 +487,134 @@ impl KeystoreManager {
         Ok(())
     }
 
-    /// Save a keystore file to disk.
-    fn save_keystore(&self, address: &Address, keystore: &KeystoreFile) -> Result<()> {
-        fs::create_dir_all(&self.keystore_dir).wrap_err("Failed to create keystore directory")?;
+    /// Decrypt every keystore in the directory and add it to the signer manager.
+    ///
+    /// With `authorized` set, keystores for other addresses are skipped without
+    /// being decrypted. A keystore that fails to decrypt (e.g. a different password)
+    /// is recorded in the report instead of aborting the remaining loads.
+    pub async fn load_all_into_signer_manager(
+        &self,
+        password: &str,
+        signer_manager: &SignerManager,
+        authorized: Option<&[Address]>,
+    ) -> Result<KeystoreLoadReport> {
+        let mut report = KeystoreLoadReport::default();
+        for address in self.list_accounts()? {
+            if authorized.is_some_and(|signers| !signers.contains(&address)) {
+                report.skipped.push(address);
+                continue;
+            }
+            match self
+                .load_into_signer_manager(&address, password, signer_manager)
+                .await
+            {
+                Ok(()) => report.loaded.push(address),
+                Err(e) => report.failed.push((address, e)),
+            }
+        }
+        Ok(report)
+    }
+
+    /// Re-scan the directory and make the signer manager's local keys match it.
+    ///
+    /// Used to rotate authority keys without a restart. `loaded` lists keys that are
+    /// new to the signer manager and `removed` the local keys whose keystore is gone
+    /// (or no longer authorized). A key whose keystore fails to decrypt is kept, so a
+    /// half-written file can't take a live validator offline. Remote signers are
+    /// never touched.
+    ///
+    /// Key derivation (PBKDF2 or argon2id) is CPU-bound, so the keystores are
+    /// decrypted on the blocking thread pool.
+    pub async fn reload_into_signer_manager(
+        &self,
+        password: &str,
+        signer_manager: &SignerManager,
+        authorized: Option<&[Address]>,
+    ) -> Result<KeystoreLoadReport> {
+        let this = self.clone();
+        let password = password.to_owned();
+        let authorized = authorized.map(<[Address]>::to_vec);
+        let (fresh, retain, mut report) = tokio::task::spawn_blocking(move || {
+            this.decrypt_for_reload(&password, authorized.as_deref())
+        })
+        .await
+        .wrap_err("Keystore decryption task failed")??;
+        let (loaded, removed) = signer_manager.sync_local_signers(fresh, &retain).await;
+        report.loaded = loaded;
+        report.removed = removed;
+        Ok(report)
+    }
 
-        let path = self.keystore_path(address);
+    /// Decrypt the authorized keystores for [`Self::reload_into_signer_manager`].
+    ///
+    /// Returns the decrypted signers, the addresses whose keystore failed to
+    /// decrypt (to be kept), and the report of skipped and failed keystores.
+    fn decrypt_for_reload(
+        &self,
+        password: &str,
+        authorized: Option<&[Address]>,
+    ) -> Result<(Vec<PrivateKeySigner>, Vec<Address>, KeystoreLoadReport)> {
+        let mut report = KeystoreLoadReport::default();
+        let mut fresh = Vec::new();
+        let mut retain = Vec::new();
+        for address in self.list_accounts()? {
+            if authorized.is_some_and(|signers| !signers.contains(&address)) {
+                report.skipped.push(address);
+                continue;
+            }
+            let signer = self.decrypt_key(&address, password).and_then(|key_hex| {
+                key_hex
+                    .parse::<PrivateKeySigner>()
+                    .map_err(|e| eyre::eyre!("Invalid private key: {}", e))
+            });
+            match signer {
+                Ok(signer) => fresh.push(signer),
+                Err(e) => {
+                    retain.push(address);
+                    report.failed.push((address, e));
+                }
+            }
+        }
+        Ok((fresh, retain, report))
+    }
+
+    /// Encrypt a private key in this manager's format.
+    fn encrypt(&self, private_key_hex: &str, password: &str) -> Result<KeystoreFile> {
+        match self.format {
+            KeystoreFormat::V3 => {
+                encrypt_key_with_iterations(private_key_hex, password, self.pbkdf2_c)
+            }
+            KeystoreFormat::V4 => encrypt_key_v4(private_key_hex, password, self.argon2),
+        }
+    }
+
+    /// Save a keystore file to disk (and to the backup directory, if configured).
+    fn save_keystore(&self, address: &Address, keystore: &KeystoreFile) -> Result<()> {
         let json =
             serde_json::to_string_pretty(keystore).wrap_err("Failed to serialize keystore")?;
-        fs::write(&path, json)
-            .wrap_err_with(|| format!("Failed to write keystore: {}", path.display()))?;
+
+        create_private_dir(&self.keystore_dir).wrap_err("Failed to create keystore directory")?;
+        write_atomic(&self.keystore_path(address), &json)?;
+        self.save_backup(address, &json)
+    }
+
+    /// Write serialized keystore JSON to the backup directory, if configured.
+    fn save_backup(&self, address: &Address, json: &str) -> Result<()> {
+        if let Some(backup_dir) = &self.backup_dir {
+            create_private_dir(backup_dir).wrap_err_with(|| {
+                format!(
+                    "Failed to create keystore backup directory: {}",
+                    backup_dir.display()
+                )
+            })?;
+            write_atomic(&backup_dir.join(keystore_filename(address)), json)?;
+        }
 
         Ok(())
     }
 
     /// Find the keystore file path for an address (searches directory for matching address).
-    fn find_keystore_path(&self, address: &Address) -> Result<PathBuf> {
+    pub fn find_keystore_path(&self, address: &Address) -> Result<PathBuf> {
         if !self.keystore_dir.exists() {
             bail!(
                 "Keystore directory does not exist: {}",
@@ -311,10 +665,89 @@ impl KeystoreManager {
     ///
     /// Format: `UTC--{address}.json`
     fn keystore_path(&self, address: &Address) -> PathBuf {
-        let addr_hex = hex::encode(address.as_slice()); // 40 lowercase hex chars
-        let filename = format!("UTC--{}.json", addr_hex);
-        self.keystore_dir.join(filename)
+        self.keystore_dir.join(keystore_filename(address))
+    }
+}
+
+/// Canonical keystore filename for an address: `UTC--{address}.json`.
+fn keystore_filename(address: &Address) -> String {
+    let addr_hex = hex::encode(address.as_slice()); // 40 lowercase hex chars
+    format!("UTC--{}.json", addr_hex)
+}
+
+/// Create `dir` (and parents) and restrict it to its owner (`0700`) on unix, as geth does.
+fn create_private_dir(dir: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(dir)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(dir, fs::Permissions::from_mode(0o700))?;
+    }
+    Ok(())
+}
+
+/// Write `contents` to a file only its owner can read (`0600` on unix).
+///
+/// The mode is set at creation so the key is never briefly world-readable, and
+/// again afterwards in case a stale file with wider permissions was reused.
+fn write_private(path: &Path, contents: &str) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::io::Write;
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(0o600)
+            .open(path)?;
+        file.set_permissions(fs::Permissions::from_mode(0o600))?;
+        file.write_all(contents.as_bytes())
     }
+    #[cfg(not(unix))]
+    {
+        fs::write(path, contents)
+    }
+}
+
+/// Write `contents` to a temporary file next to `path`, then rename it into place.
+///
+/// A crash mid-write never leaves a truncated keystore behind.
+fn write_atomic(path: &Path, contents: &str) -> Result<()> {
+    let tmp = path.with_extension("json.tmp");
+    write_private(&tmp, contents)
+        .wrap_err_with(|| format!("Failed to write keystore: {}", tmp.display()))?;
+    fs::rename(&tmp, path)
+        .wrap_err_with(|| format!("Failed to write keystore: {}", path.display()))?;
+    Ok(())
+}
+
+/// PBKDF2 iteration count that takes about `target` on this machine, at least
+/// [`MIN_PBKDF2_C`].
+///
+/// Times [`CALIBRATION_RUNS`] derivations of [`CALIBRATION_SAMPLE_C`] iterations and
+/// scales the fastest linearly (PBKDF2 cost is linear in the count); the fastest run
+/// is the least disturbed by other load on the machine.
+pub fn calibrate_pbkdf2_iterations(target: Duration) -> u32 {
+    let salt = random_bytes::<32>();
+    let mut derived_key = [0u8; DKLEN as usize];
+    let fastest = (0..CALIBRATION_RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            pbkdf2::pbkdf2_hmac::<sha2::Sha256>(
+                b"calibration",
+                &salt,
+                CALIBRATION_SAMPLE_C,
+                &mut derived_key,
+            );
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+        .max(Duration::from_nanos(1));
+
+    let scaled = target.as_nanos() * u128::from(CALIBRATION_SAMPLE_C) / fastest.as_nanos();
+    u32::try_from(scaled).unwrap_or(u32::MAX).max(MIN_PBKDF2_C)
 }
 
 /// Encrypt a private key hex string with the given password using the default iteration count.
@@ -331,19 +764,7 @@ pub fn encrypt_key_with_iterations(
     password: &str,
     pbkdf2_c: u32,
 ) -> Result<KeystoreFile> {
-    let key_bytes = hex::decode(private_key_hex).wrap_err("Invalid private key hex")?;
-
-    ensure!(
-        key_bytes.len() == 32,
-        "Private key must be 32 bytes, got {}",
-        key_bytes.len()
-    );
-
-    // Derive address from the private key
-    let signer: PrivateKeySigner = private_key_hex
-        .parse()
-        .map_err(|_| eyre::eyre!("Invalid private key"))?;
-    let address = signer.address();
+    let (key_bytes, address) = parse_private_key(private_key_hex)?;
 
     // Generate random salt (32 bytes) and IV (16 bytes)
     let salt = random_bytes::<32>();
@@ -381,6 +802,9 @@ pub fn encrypt_key_with_iterations(
                 dklen: DKLEN,
                 c: pbkdf2_c,
                 prf: "hmac-sha256".to_string(),
+                m: None,
+                t: None,
+                p: None,
                 salt: hex::encode(salt),
             },
             mac: hex::encode(mac),
@@ -389,15 +813,70 @@ pub fn encrypt_key_with_iterations(
     })
 }
 
+/// Encrypt a private key hex string as a non-standard V4 keystore.
+///
+/// Uses argon2id for key derivation and AES-256-GCM for encryption. The GCM tag,
+/// stored as the MAC, covers the ciphertext and the address bytes.
+pub fn encrypt_key_v4(
+    private_key_hex: &str,
+    password: &str,
+    params: Argon2Params,
+) -> Result<KeystoreFile> {
+    let (key_bytes, address) = parse_private_key(private_key_hex)?;
+
+    // Generate random salt (32 bytes) and nonce (12 bytes)
+    let salt = random_bytes::<32>();
+    let nonce = random_bytes::<12>();
+
+    let derived_key = derive_argon2id_key(password, &salt, params)?;
+    let cipher = Aes256Gcm::new(derived_key.as_slice().into());
+    let mut ciphertext = key_bytes;
+    let tag = cipher
+        .encrypt_in_place_detached(
+            Nonce::from_slice(&nonce),
+            address.as_slice(),
+            &mut ciphertext,
+        )
+        .map_err(|_| eyre::eyre!("AES-256-GCM encryption failed"))?;
+
+    Ok(KeystoreFile {
+        version: 4,
+        address: hex::encode(address.as_slice()),
+        crypto: CryptoJson {
+            cipher: "aes-256-gcm".to_string(),
+            ciphertext: hex::encode(&ciphertext),
+            cipherparams: CipherParams {
+                iv: hex::encode(nonce),
+            },
+            kdf: "argon2id".to_string(),
+            kdfparams: KdfParams {
+                dklen: DKLEN,
+                c: 0,
+                prf: String::new(),
+                m: Some(params.m_cost),
+                t: Some(params.t_cost),
+                p: Some(params.p_cost),
+                salt: hex::encode(salt),
+            },
+            mac: hex::encode(tag),
+        },
+        id: uuid::Uuid::new_v4().to_string(),
+    })
+}
+
 /// Decrypt a keystore file with the given password.
 ///
-/// Verifies the MAC before returning the decrypted private key hex.
+/// Verifies the MAC (the GCM tag for V4) before returning the decrypted private key hex.
 pub fn decrypt_key(keystore: &KeystoreFile, password: &str) -> Result<String> {
-    ensure!(
-        keystore.version == 3,
-        "Unsupported keystore version: {}",
-        keystore.version
-    );
+    match keystore.version {
+        3 => decrypt_key_v3(keystore, password),
+        4 => decrypt_key_v4(keystore, password),
+        version => bail!("Unsupported keystore version: {}", version),
+    }
+}
+
+/// Decrypt a V3 (PBKDF2 + AES-128-CTR) keystore.
+fn decrypt_key_v3(keystore: &KeystoreFile, password: &str) -> Result<String> {
     ensure!(
         keystore.crypto.cipher == "aes-128-ctr",
         "Unsupported cipher: {}",
@@ -408,6 +887,10 @@ pub fn decrypt_key(keystore: &KeystoreFile, password: &str) -> Result<String> {
         "Unsupported KDF: {} (only pbkdf2 is supported)",
         keystore.crypto.kdf
     );
+    ensure!(
+        keystore.crypto.kdfparams.c > 0,
+        "Missing PBKDF2 iteration count"
+    );
 
     let salt = hex::decode(&keystore.crypto.kdfparams.salt).wrap_err("Invalid salt hex")?;
     let iv = hex::decode(&keystore.crypto.cipherparams.iv).wrap_err("Invalid IV hex")?;
@@ -450,6 +933,97 @@ pub fn decrypt_key(keystore: &KeystoreFile, password: &str) -> Result<String> {
     Ok(hex::encode(&plaintext))
 }
 
+/// Decrypt a V4 (argon2id + AES-256-GCM) keystore.
+fn decrypt_key_v4(keystore: &KeystoreFile, password: &str) -> Result<String> {
+    ensure!(
+        keystore.crypto.cipher == "aes-256-gcm",
+        "Unsupported cipher for version 4: {}",
+        keystore.crypto.cipher
+    );
+    ensure!(
+        keystore.crypto.kdf == "argon2id",
+        "Unsupported KDF for version 4: {} (only argon2id is supported)",
+        keystore.crypto.kdf
+    );
+
+    let kdfparams = &keystore.crypto.kdfparams;
+    let (Some(m_cost), Some(t_cost), Some(p_cost)) = (kdfparams.m, kdfparams.t, kdfparams.p) else {
+        bail!("Missing argon2id parameters (m, t, p)");
+    };
+    let address = parse_address(&keystore.address)?;
+    let salt = hex::decode(&kdfparams.salt).wrap_err("Invalid salt hex")?;
+    let nonce = hex::decode(&keystore.crypto.cipherparams.iv).wrap_err("Invalid IV hex")?;
+    let ciphertext = hex::decode(&keystore.crypto.ciphertext).wrap_err("Invalid ciphertext hex")?;
+    let tag = hex::decode(&keystore.crypto.mac).wrap_err("Invalid MAC hex")?;
+
+    ensure!(
+        nonce.len() == 12,
+        "IV must be 12 bytes, got {}",
+        nonce.len()
+    );
+    ensure!(tag.len() == 16, "MAC must be 16 bytes, got {}", tag.len());
+    ensure!(
+        ciphertext.len() == 32,
+        "Ciphertext must be 32 bytes, got {}",
+        ciphertext.len()
+    );
+
+    let params = Argon2Params {
+        m_cost,
+        t_cost,
+        p_cost,
+    };
+    let derived_key = derive_argon2id_key(password, &salt, params)?;
+    let cipher = Aes256Gcm::new(derived_key.as_slice().into());
+    let mut plaintext = ciphertext;
+    cipher
+        .decrypt_in_place_detached(
+            Nonce::from_slice(&nonce),
+            address.as_slice(),
+            &mut plaintext,
+            Tag::from_slice(&tag),
+        )
+        .map_err(|_| {
+            eyre::eyre!("MAC verification failed: wrong password or corrupted keystore")
+        })?;
+
+    Ok(hex::encode(&plaintext))
+}
+
+/// Derive a 32-byte key from `password` with argon2id.
+fn derive_argon2id_key(password: &str, salt: &[u8], params: Argon2Params) -> Result<[u8; 32]> {
+    let params = argon2::Params::new(
+        params.m_cost,
+        params.t_cost,
+        params.p_cost,
+        Some(DKLEN as usize),
+    )
+    .map_err(|e| eyre::eyre!("Invalid argon2id parameters: {}", e))?;
+    let argon2 = argon2::Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params);
+    let mut derived_key = [0u8; DKLEN as usize];
+    argon2
+        .hash_password_into(password.as_bytes(), salt, &mut derived_key)
+        .map_err(|e| eyre::eyre!("argon2id key derivation failed: {}", e))?;
+    Ok(derived_key)
+}
+
+/// Decode a 32-byte private key hex string and derive its address.
+fn parse_private_key(private_key_hex: &str) -> Result<(Vec<u8>, Address)> {
+    let key_bytes = hex::decode(private_key_hex).wrap_err("Invalid private key hex")?;
+
+    ensure!(
+        key_bytes.len() == 32,
+        "Private key must be 32 bytes, got {}",
+        key_bytes.len()
+    );
+
+    // Derive address from the private key
+    let signer: PrivateKeySigner = private_key_hex
+        .parse()
+        .map_err(|_| eyre::eyre!("Invalid private key"))?;
+    Ok((key_bytes, signer.address()))
+}
+
 /// Generate N random bytes using alloy_primitives::B256::random() as entropy source.
 fn random_bytes<const N: usize>() -> [u8; N] {
     let mut result = [0u8; N];
@@ -477,13 +1051,40 @@ fn parse_address(addr_str: &str) -> Result<Address> {
         .map_err(|e| eyre::eyre!("Invalid address '{}': {}", addr_str, e))
 }
 
+/// Check a BIP-32 path like `m/44'/60'/0'/0/0`.
+///
+/// Each component is a decimal index below 2^31, optionally hardened with `'` or `h`.
+fn validate_derivation_path(path: &str) -> Result<()> {
+    let components = path
+        .strip_prefix("m/")
+        .ok_or_else(|| eyre::eyre!("Invalid derivation path '{}': must start with m/", path))?;
+    for component in components.split('/') {
+        let index = component.strip_suffix(['\'', 'h']).unwrap_or(component);
+        let value: u32 = index.parse().map_err(|_| {
+            eyre::eyre!(
+                "Invalid derivation path '{}': bad component '{}'",
+                path,
+                component
+            )
+        })?;
+        ensure!(
+            value < BIP32_HARDENED_OFFSET,
+            "Invalid derivation path '{}': index {} out of range (max {})",
+            path,
+            value,
+            BIP32_HARDENED_OFFSET - 1
+        );
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::sync::Arc;
 
     /// Helper to create a temporary keystore manager (uses fast PBKDF2 for tests)
-    fn temp_keystore() -> (KeystoreManager, TempDir) {
+    pub(super) fn temp_keystore() -> (KeystoreManager, TempDir) {
         let dir = TempDir::new().expect("Failed to create temp dir");
         let manager =
             KeystoreManager::with_pbkdf2_iterations(dir.path().join("keystore"), TEST_PBKDF2_C);
@@ -936,10 +1537,377 @@ mod tests {
         assert_eq!(decrypted, TEST_KEY);
     }
 
+    // -------------------------------------------------------------------------
+    // Test 21: import with a backup directory writes an identical keystore to both
+    // -------------------------------------------------------------------------
+    #[test]
+    fn test_import_key_writes_backup() {
+        let dir = TempDir::new().unwrap();
+        let backup = dir.path().join("secure-volume");
+        let manager =
+            KeystoreManager::with_pbkdf2_iterations(dir.path().join("keystore"), TEST_PBKDF2_C)
+                .with_backup_dir(&backup);
+        assert_eq!(manager.backup_dir(), Some(backup.as_path()));
+
+        let address = manager.import_key(TEST_KEY, TEST_PASSWORD).unwrap();
+
+        let primary_path = manager.find_keystore_path(&address).unwrap();
+        let backup_path = backup.join(primary_path.file_name().unwrap());
+        let primary = fs::read_to_string(&primary_path).unwrap();
+        let copy = fs::read_to_string(&backup_path).unwrap();
+        assert_eq!(primary, copy, "backup must be byte-identical");
+
+        // The backup decrypts on its own with the same password
+        let backup_manager = KeystoreManager::new(&backup);
+        assert_eq!(
+            backup_manager.decrypt_key(&address, TEST_PASSWORD).unwrap(),
+            TEST_KEY
+        );
+        // No temp files left behind
+        assert_eq!(fs::read_dir(&backup).unwrap().count(), 1);
+    }
+
+    // -------------------------------------------------------------------------
+    // Test 22: created accounts are backed up too
+    // -------------------------------------------------------------------------
+    #[test]
+    fn test_create_account_writes_backup() {
+        let dir = TempDir::new().unwrap();
+        let backup = dir.path().join("backup");
+        let manager =
+            KeystoreManager::with_pbkdf2_iterations(dir.path().join("keystore"), TEST_PBKDF2_C)
+                .with_backup_dir(&backup);
+
+        let address = manager.create_account(TEST_PASSWORD).unwrap();
+        assert!(KeystoreManager::new(&backup).has_account(&address));
+    }
+
+    // -------------------------------------------------------------------------
+    // Test 23: mnemonic import matches geth/Foundry's first account
+    // -------------------------------------------------------------------------
+    const TEST_MNEMONIC: &str = "test test test test test test test test test test test junk";
+
+    #[test]
+    fn test_import_mnemonic_default_path() {
+        let (manager, _dir) = temp_keystore();
+
+        let address = manager
+            .import_mnemonic(TEST_MNEMONIC, None, TEST_PASSWORD)
+            .unwrap();
+        assert_eq!(
+            address,
+            "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
+                .parse::<Address>()
+                .unwrap()
+        );
+        // Same key as the dev key imported as hex
+        assert_eq!(
+            manager.decrypt_key(&address, TEST_PASSWORD).unwrap(),
+            TEST_KEY
+        );
+
+        // Second account on an explicit path
+        let second = manager
+            .import_mnemonic(TEST_MNEMONIC, Some("m/44'/60'/0'/0/1"), TEST_PASSWORD)
+            .unwrap();
+        assert_eq!(
+            second,
+            "0x70997970C51812dc3A010C7d01b50e0d17dc79C8"
+                .parse::<Address>()
+                .unwrap()
+        );
+    }
+
+    // -------------------------------------------------------------------------
+    // Test 24: invalid mnemonics and paths are rejected
+    // -------------------------------------------------------------------------
+    #[test]
+    fn test_import_mnemonic_rejects_invalid_input() {
+        let (manager, _dir) = temp_keystore();
+
+        // Valid words, wrong checksum
+        let bad_checksum = "test test test test test test test test test test test test";
+        let err = manager
+            .import_mnemonic(bad_checksum, None, TEST_PASSWORD)
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid mnemonic"), "{err}");
+
+        for path in [
+            "44'/60'/0'/0/0",
+            "m/44'/60'/x/0/0",
+            "m/44'/60'/2147483648'/0/0",
+        ] {
+            let err = manager
+                .import_mnemonic(TEST_MNEMONIC, Some(path), TEST_PASSWORD)
+                .unwrap_err();
+            assert!(
+                err.to_string().contains("Invalid derivation path"),
+                "{path}: {err}"
+            );
+        }
+        assert!(manager.list_accounts().unwrap().is_empty());
+    }
+
+    // -------------------------------------------------------------------------
+    // Test 25: change_password rotates crypto params, keeps address and id
+    // -------------------------------------------------------------------------
+    #[test]
+    fn test_change_password_roundtrip() {
+        let (manager, _dir) = temp_keystore();
+        let address = manager.import_key(TEST_KEY, TEST_PASSWORD).unwrap();
+        let path = manager.find_keystore_path(&address).unwrap();
+        let before: KeystoreFile =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+
+        manager
+            .change_password(&address, TEST_PASSWORD, "new-password-456")
+            .unwrap();
+
+        let after: KeystoreFile =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(after.address, before.address);
+        assert_eq!(after.id, before.id);
+        assert_ne!(after.crypto.kdfparams.salt, before.crypto.kdfparams.salt);
+        assert_ne!(after.crypto.cipherparams.iv, before.crypto.cipherparams.iv);
+        assert_ne!(after.crypto.ciphertext, before.crypto.ciphertext);
+        assert_ne!(after.crypto.mac, before.crypto.mac);
+
+        assert_eq!(
+            manager.decrypt_key(&address, "new-password-456").unwrap(),
+            TEST_KEY
+        );
+        assert!(manager.decrypt_key(&address, TEST_PASSWORD).is_err());
+    }
+
+    // -------------------------------------------------------------------------
+    // Test 26: change_password with the wrong old password leaves the file intact
+    // -------------------------------------------------------------------------
+    #[test]
+    fn test_change_password_wrong_old_password() {
+        let (manager, _dir) = temp_keystore();
+        let address = manager.import_key(TEST_KEY, TEST_PASSWORD).unwrap();
+        let path = manager.find_keystore_path(&address).unwrap();
+        let before = fs::read_to_string(&path).unwrap();
+
+        let err = manager
+            .change_password(&address, "wrong-password", "new-password-456")
+            .unwrap_err();
+        assert!(err.to_string().contains("MAC verification failed"), "{err}");
+        assert_eq!(fs::read_to_string(&path).unwrap(), before);
+        assert_eq!(
+            manager.decrypt_key(&address, TEST_PASSWORD).unwrap(),
+            TEST_KEY
+        );
+    }
+
+    // -------------------------------------------------------------------------
+    // Test 27: batch load collects per-account errors and skips non-authorities
+    // -------------------------------------------------------------------------
+    #[tokio::test]
+    async fn test_load_all_into_signer_manager() {
+        let (manager, _dir) = temp_keystore();
+        let first = manager.import_key(TEST_KEY, TEST_PASSWORD).unwrap();
+        let second = manager
+            .import_key(
+                "59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d",
+                TEST_PASSWORD,
+            )
+            .unwrap();
+        let other_password = manager
+            .import_key(
+                "5de4111afa1a4b94908f83103eb1f1706367c2e68ca870fc3fb9a804cdab365a",
+                "another-password",
+            )
+            .unwrap();
+
+        // All keys: the one under a different password fails, the rest still load
+        let signer_manager = SignerManager::new();
+        let report = manager
+            .load_all_into_signer_manager(TEST_PASSWORD, &signer_manager, None)
+            .await
+            .unwrap();
+        assert_eq!(report.loaded.len(), 2);
+        assert!(report.skipped.is_empty());
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].0, other_password);
+        assert!(signer_manager.has_signer(&first).await);
+        assert!(signer_manager.has_signer(&second).await);
+
+        // Authority filter: only `second` is decrypted
+        let signer_manager = SignerManager::new();
+        let report = manager
+            .load_all_into_signer_manager(TEST_PASSWORD, &signer_manager, Some(&[second]))
+            .await
+            .unwrap();
+        assert_eq!(report.loaded, vec![second]);
+        assert_eq!(report.skipped.len(), 2);
+        assert!(report.failed.is_empty());
+        assert_eq!(signer_manager.signer_addresses().await, vec![second]);
+    }
+
+    // -------------------------------------------------------------------------
+    // Test 28: reload picks up new keystores and drops deleted ones
+    // -------------------------------------------------------------------------
+    #[tokio::test]
+    async fn test_reload_into_signer_manager() {
+        let (manager, _dir) = temp_keystore();
+        let first = manager.import_key(TEST_KEY, TEST_PASSWORD).unwrap();
+        let signer_manager = SignerManager::new();
+        manager
+            .load_all_into_signer_manager(TEST_PASSWORD, &signer_manager, None)
+            .await
+            .unwrap();
+
+        // Rotate: the new key lands in the directory, the old keystore is removed
+        let second = manager
+            .import_key(
+                "59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d",
+                TEST_PASSWORD,
+            )
+            .unwrap();
+        manager.delete_account(&first).unwrap();
+        let report = manager
+            .reload_into_signer_manager(TEST_PASSWORD, &signer_manager, None)
+            .await
+            .unwrap();
+        assert_eq!(report.loaded, vec![second]);
+        assert_eq!(report.removed, vec![first]);
+        assert_eq!(signer_manager.signer_addresses().await, vec![second]);
+
+        // Nothing changed on disk: reload is a no-op
+        let report = manager
+            .reload_into_signer_manager(TEST_PASSWORD, &signer_manager, None)
+            .await
+            .unwrap();
+        assert!(report.loaded.is_empty() && report.removed.is_empty());
+
+        // A keystore that no longer decrypts keeps its already-loaded key
+        let report = manager
+            .reload_into_signer_manager("wrong-password", &signer_manager, None)
+            .await
+            .unwrap();
+        assert_eq!(report.failed.len(), 1);
+        assert!(report.removed.is_empty());
+        assert!(signer_manager.has_signer(&second).await);
+    }
+
+    // Test 29: calibrated iteration count respects the floor and round-trips
+    #[test]
+    fn test_target_unlock_time_calibration() {
+        let dir = TempDir::new().unwrap();
+        // Far below one sample: clamped to the floor
+        let manager =
+            KeystoreManager::with_target_unlock_time(dir.path(), Duration::from_micros(1));
+        assert_eq!(manager.pbkdf2_iterations(), MIN_PBKDF2_C);
+
+        let address = manager.create_account(TEST_PASSWORD).unwrap();
+        let path = manager.find_keystore_path(&address).unwrap();
+        let keystore: KeystoreFile =
+            serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(keystore.crypto.kdfparams.c, MIN_PBKDF2_C);
+        let key = manager.decrypt_key(&address, TEST_PASSWORD).unwrap();
+        let signer: PrivateKeySigner = key.parse().unwrap();
+        assert_eq!(signer.address(), address);
+
+        // A realistic target calibrates at or above the floor
+        assert!(calibrate_pbkdf2_iterations(Duration::from_millis(250)) >= MIN_PBKDF2_C);
+    }
+
+    // Test 30: keystore files and directories are private to the owner
+    #[cfg(unix)]
+    #[test]
+    fn test_keystore_file_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = TempDir::new().unwrap();
+        let backup = dir.path().join("backup");
+        let manager =
+            KeystoreManager::with_pbkdf2_iterations(dir.path().join("keystore"), TEST_PBKDF2_C)
+                .with_backup_dir(&backup);
+        let address = manager.import_key(TEST_KEY, TEST_PASSWORD).unwrap();
+
+        let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+        let path = manager.find_keystore_path(&address).unwrap();
+        assert_eq!(mode(&path), 0o600);
+        assert_eq!(mode(&backup.join(path.file_name().unwrap())), 0o600);
+        assert_eq!(mode(&dir.path().join("keystore")), 0o700);
+        assert_eq!(mode(&backup), 0o700);
+    }
+
+    // -------------------------------------------------------------------------
+    // Test 31: V4 (argon2id + AES-256-GCM) round-trip, alongside V3
+    // -------------------------------------------------------------------------
+    #[test]
+    fn test_v4_keystore_roundtrip() {
+        let (manager, _dir) = temp_keystore();
+        let manager = manager
+            .with_format(KeystoreFormat::V4)
+            .with_argon2_params(TEST_ARGON2);
+        let address = manager.import_key(TEST_KEY, TEST_PASSWORD).unwrap();
+
+        let data = fs::read_to_string(manager.find_keystore_path(&address).unwrap()).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&data).unwrap();
+        assert_eq!(parsed["version"], 4);
+        assert_eq!(parsed["crypto"]["cipher"], "aes-256-gcm");
+        assert_eq!(parsed["crypto"]["kdf"], "argon2id");
+        assert_eq!(parsed["crypto"]["kdfparams"]["m"], TEST_ARGON2.m_cost);
+        assert!(parsed["crypto"]["kdfparams"].get("c").is_none());
+
+        assert_eq!(
+            manager.decrypt_key(&address, TEST_PASSWORD).unwrap(),
+            TEST_KEY
+        );
+        let err = manager.decrypt_key(&address, "wrong").unwrap_err();
+        assert!(err.to_string().contains("MAC verification failed"), "{err}");
+
+        // The tag covers the address: a relabelled file doesn't decrypt
+        let mut keystore: KeystoreFile = serde_json::from_str(&data).unwrap();
+        keystore.address = hex::encode(Address::with_last_byte(1));
+        assert!(decrypt_key(&keystore, TEST_PASSWORD).is_err());
+
+        // Replaced by a V3 keystore of the same key, the manager still decrypts
+        // it, and changing its password migrates it to V4
+        let v3 = encrypt_key_with_iterations(TEST_KEY, TEST_PASSWORD, TEST_PBKDF2_C).unwrap();
+        let v3_address: Address = parse_address(&v3.address).unwrap();
+        manager.delete_account(&v3_address).unwrap();
+        manager.save_keystore(&v3_address, &v3).unwrap();
+        assert_eq!(
+            manager.decrypt_key(&v3_address, TEST_PASSWORD).unwrap(),
+            TEST_KEY
+        );
+        manager
+            .change_password(&v3_address, TEST_PASSWORD, "new-password")
+            .unwrap();
+        let path = manager.find_keystore_path(&v3_address).unwrap();
+        let migrated: KeystoreFile =
+            serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(migrated.version, 4);
+        assert_eq!(migrated.id, v3.id);
+        assert_eq!(decrypt_key(&migrated, "new-password").unwrap(), TEST_KEY);
+    }
+
+    #[test]
+    fn test_v3_keystore_unchanged_by_v4_fields() {
+        let keystore = encrypt_key_with_iterations(TEST_KEY, TEST_PASSWORD, TEST_PBKDF2_C).unwrap();
+        let json = serde_json::to_value(&keystore).unwrap();
+        let kdfparams = json["crypto"]["kdfparams"].as_object().unwrap();
+        let mut keys: Vec<_> = kdfparams.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        assert_eq!(keys, vec!["c", "dklen", "prf", "salt"]);
+
+        let mut unknown = keystore;
+        unknown.version = 5;
+        let err = decrypt_key(&unknown, TEST_PASSWORD).unwrap_err();
+        assert!(
+            err.to_string().contains("Unsupported keystore version"),
+            "{err}"
+        );
+    }
+
     // -------------------------------------------------------------------------
     // Helper: TempDir using std (no external tempfile crate needed)
     // -------------------------------------------------------------------------
-    struct TempDir {
+    pub(super) struct TempDir {
         path: PathBuf,
     }
 
@@ -13,6 +13,7 @@ pub mod evm;
 pub mod genesis;
 pub mod keystore;
 pub mod metrics;
+pub mod monitor;
 pub mod node;
 pub mod onchain;
 pub mod output;
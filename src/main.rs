@@ -1,37 +1,61 @@
-use example_custom_poa_node::chainspec::{PoaChainSpec, PoaConfig};
-use example_custom_poa_node::cli::Cli;
+use example_custom_poa_node::cache::{HotStateCache, SharedCache};
+use example_custom_poa_node::chainspec::{
+    PoaBaseFeeParams, PoaChainSpec, PoaConfig, SignerCheckpoint, SignerSource,
+};
+use example_custom_poa_node::cli::{Cli, Command};
+use example_custom_poa_node::consensus::{DiversityHealth, PoaConsensus, DEFAULT_IN_TURN_BONUS};
+use example_custom_poa_node::constants::PRUNE_KEEP_BLOCKS;
 use example_custom_poa_node::genesis;
-use example_custom_poa_node::metrics::{BlockMetrics, ChainMetrics};
+use example_custom_poa_node::keystore::{self, KeystoreManager};
+use example_custom_poa_node::metrics::{
+    format_signer_counts, BlockMetrics, BlockTimings, ChainMetrics,
+};
+use example_custom_poa_node::monitor::health::unix_millis;
+use example_custom_poa_node::monitor::{
+    backfill_timelock_schedule, exceeds_block_time_budget, signed_block_channel,
+    start_health_server, CatchUpTracker, HealthState, ProxyAdminWatch, RecentSigners, ReorgGuard,
+    SignedBlock, SignerIndex, SignerLiveness, TimelockSchedule,
+};
 use example_custom_poa_node::node::PoaNode;
+use example_custom_poa_node::onchain::{
+    check_cli_params, read_miner_proxy_admin, read_signer_list, GenesisStorageReader,
+    StateProviderStorageReader,
+};
 use example_custom_poa_node::output;
+use example_custom_poa_node::payload::{block_vanity, FallbackPolicy, TransactionOrdering};
 use example_custom_poa_node::rpc::{
-    AdminApiServer, AdminRpc, CliqueApiServer, CliqueRpc, MeowApiServer, MeowRpc,
+    AdminApiServer, AdminRpc, CliqueApiServer, CliqueRpc, DebugApiServer, DebugRpc, MeowApiServer,
+    MeowRpc, Reexecutor, StateExporter, TxpoolApiServer, TxpoolRpc,
+};
+use example_custom_poa_node::signer::{self, RemoteSigner, SignerManager};
+use example_custom_poa_node::statediff::{
+    lock_shared, state_diff_channel, StateDiffBuilder, StateDiffLog,
 };
-use example_custom_poa_node::signer::{self, SignerManager};
-use example_custom_poa_node::statediff::StateDiffBuilder;
 
 use alloy_consensus::BlockHeader;
-use alloy_primitives::B256;
+use alloy_primitives::{Address, B256};
 use clap::Parser;
 use futures_util::StreamExt;
 use reth_db::init_db;
 use reth_ethereum::{
+    node::api::FullNodeComponents,
     node::builder::{NodeBuilder, NodeHandle},
     node::core::{
-        args::{
-            DatadirArgs, DevArgs, GasPriceOracleArgs, MetricArgs, NetworkArgs, PruningArgs,
-            RpcServerArgs,
-        },
+        args::{DatadirArgs, DevArgs, GasPriceOracleArgs, MetricArgs, NetworkArgs, RpcServerArgs},
         node_config::NodeConfig,
     },
-    provider::CanonStateSubscriptions,
+    provider::{BlockNumReader, CanonStateSubscriptions},
+    storage::StateProviderFactory,
     tasks::{RuntimeBuilder, RuntimeConfig, TokioConfig},
 };
 use reth_network_peers::TrustedPeer;
 use std::{
     net::{IpAddr, Ipv4Addr, SocketAddr},
-    sync::Arc,
-    time::{Duration, Instant},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 /// Main entry point for the POA node
@@ -43,20 +67,49 @@ async fn main() -> eyre::Result<()> {
     // Parse CLI arguments
     let cli = Cli::parse();
 
+    // Management commands run and exit without starting the node
+    match &cli.command {
+        Some(Command::Keystore(args)) => {
+            return keystore::commands::run(args, cli.keystore_dir.as_deref())
+        }
+        Some(Command::Genesis(args)) => return genesis::commands::run(args),
+        None => {}
+    }
+    // --keystore-dir is global so the keystore command can use it too; the node
+    // itself also needs the password to unlock the keys
+    if cli.keystore_dir.is_some() && cli.keystore_password_file.is_none() {
+        eyre::bail!("--keystore-dir needs --keystore-password-file to load signer keys");
+    }
+
     // Determine if we're in dev mode
     let is_dev_mode = !cli.no_dev && !cli.production;
 
     // Create chain specification based on CLI flags
-    let poa_chain = if cli.production {
+    let poa_chain = if let Some(path) = &cli.genesis {
+        // Shared genesis file: signers and period come from the file, not the CLI
+        let genesis = genesis::read_genesis_file(path)
+            .map_err(|e| eyre::eyre!("Failed to read genesis {}: {e}", path.display()))?;
+        let poa_config = PoaConfig::from_genesis(&genesis)
+            .map_err(|e| eyre::eyre!("Invalid genesis {}: {e}", path.display()))?;
+        PoaChainSpec::new(
+            genesis,
+            PoaConfig {
+                prioritize_governance_txs: cli.prioritize_governance_txs,
+                ..poa_config
+            },
+        )
+    } else if cli.production {
         let mut config = genesis::GenesisConfig::production();
         if let Some(gas_limit) = cli.gas_limit {
             config.gas_limit = gas_limit;
         }
-        let genesis = genesis::create_genesis(config);
+        let genesis = genesis::try_create_genesis(config)?;
         let poa_config = PoaConfig {
             period: cli.block_time,
             epoch: 30000,
             signers: genesis::dev_accounts().into_iter().take(5).collect(),
+            prioritize_governance_txs: cli.prioritize_governance_txs,
+            base_fee_params: PoaBaseFeeParams::default(),
         };
         PoaChainSpec::new(genesis, poa_config)
     } else {
@@ -67,11 +120,13 @@ async fn main() -> eyre::Result<()> {
         if let Some(gas_limit) = cli.gas_limit {
             config.gas_limit = gas_limit;
         }
-        let genesis = genesis::create_genesis(config);
+        let genesis = genesis::try_create_genesis(config)?;
         let poa_config = PoaConfig {
             period: cli.block_time,
             epoch: 30000,
             signers: genesis::dev_signers(),
+            prioritize_governance_txs: cli.prioritize_governance_txs,
+            base_fee_params: PoaBaseFeeParams::default(),
         };
         PoaChainSpec::new(genesis, poa_config)
     };
@@ -93,22 +148,92 @@ async fn main() -> eyre::Result<()> {
     };
     output::print_mode(mode_str);
     output::print_signers(poa_chain.signers());
+    let base_fee_params = poa_chain.poa_config().base_fee_params;
+    if base_fee_params != PoaBaseFeeParams::default() {
+        output::print_feature(
+            "Base fee params",
+            &format!(
+                "denominator {}, elasticity {}",
+                base_fee_params.max_change_denominator, base_fee_params.elasticity_multiplier
+            ),
+        );
+    }
+
+    if cli.min_gas_price > 0 {
+        output::print_feature("Min gas price", &format!("{} wei", cli.min_gas_price));
+    }
+    if cli.fallback_policy == FallbackPolicy::Next {
+        output::print_feature("Fallback policy", "next signer in rotation only");
+    }
+    if cli.tx_ordering != TransactionOrdering::PriorityFee {
+        output::print_feature(
+            "Transaction ordering",
+            &format!("{:?} (testing only)", cli.tx_ordering),
+        );
+    }
+    if let Some(vanity) = &cli.block_vanity {
+        output::print_feature("Block vanity", vanity);
+    }
 
     // Set up signer manager with runtime key loading
     let signer_manager = Arc::new(SignerManager::new());
 
     if let Some(key) = &cli.signer_key {
         // Load signer key from CLI/environment
-        let addr = signer_manager.add_signer_from_hex(key).await?;
+        let addr = match cli.signer_key_expires_in(SystemTime::now()) {
+            Some(remaining) => {
+                let addr = signer_manager
+                    .add_signer_from_hex_with_expiry(key, Instant::now() + remaining)
+                    .await?;
+                output::print_feature(
+                    "Signer key expires",
+                    &format!("in {}s", remaining.as_secs()),
+                );
+                addr
+            }
+            None => signer_manager.add_signer_from_hex(key).await?,
+        };
         output::print_signer_loaded(&addr);
-    } else if is_dev_mode {
-        // In dev mode, load dev signers (first 3 keys)
-        for key in signer::dev::DEV_PRIVATE_KEYS.iter().take(3) {
+        if let Some(vanity) = &cli.signer_vanity {
+            signer_manager.set_vanity(addr, vanity.as_bytes()).await?;
+            output::print_signer_vanity(vanity);
+        }
+    } else if let (Some(dir), Some(password_file)) =
+        (&cli.keystore_dir, &cli.keystore_password_file)
+    {
+        // Load every authority key from the keystore directory
+        let password = std::fs::read_to_string(password_file)?;
+        let password = password.trim_end_matches(['\r', '\n']);
+        let report = KeystoreManager::new(dir)
+            .load_all_into_signer_manager(password, &signer_manager, Some(poa_chain.signers()))
+            .await?;
+        for addr in &report.loaded {
+            output::print_signer_loaded(addr);
+        }
+        for addr in &report.skipped {
+            output::print_info(&format!(
+                "Skipping keystore {addr}: not an authorized signer"
+            ));
+        }
+        for (addr, e) in &report.failed {
+            output::print_warning(&format!("Failed to load keystore {addr}: {e}"));
+        }
+        if report.loaded.is_empty() {
+            output::print_no_signer_warning();
+        }
+    } else if let Some(url) = &cli.remote_signer_url {
+        // Keys stay in the external signer; only the addresses are registered here
+        let remote = RemoteSigner::new(url, Duration::from_millis(cli.remote_signer_timeout_ms))?;
+        for addr in &cli.remote_signer_address {
             signer_manager
-                .add_signer_from_hex(key)
-                .await
-                .expect("Dev keys should be valid");
+                .add_remote_signer(*addr, remote.clone())
+                .await;
+            output::print_signer_loaded(addr);
         }
+        output::print_feature("Remote signer", url);
+    } else if is_dev_mode {
+        // In dev mode, load the dev keys this node holds (default: the 3 genesis signers)
+        signer::dev::load_dev_signers(&signer_manager, &cli.dev_signer_indices).await?;
         output::print_dev_signers_loaded(signer_manager.signer_addresses().await.len());
     } else {
         output::print_no_signer_warning();
@@ -127,7 +252,7 @@ async fn main() -> eyre::Result<()> {
             } else {
                 Some(mining_interval)
             },
-            block_max_transactions: None,
+            block_max_transactions: cli.block_max_txs,
             ..Default::default()
         }
     };
@@ -143,6 +268,7 @@ async fn main() -> eyre::Result<()> {
         .unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
 
     // Build RPC args with CORS, API modules, connection limits, and GPO config.
+    let ipc_endpoint = cli.ipc_endpoint();
     let mut rpc_args = RpcServerArgs {
         http: true,
         http_addr,
@@ -150,6 +276,8 @@ async fn main() -> eyre::Result<()> {
         ws: true,
         ws_addr,
         ws_port: cli.ws_port,
+        ipcdisable: ipc_endpoint.is_none(),
+        ipcpath: ipc_endpoint.clone().unwrap_or_default(),
         // Wire RPC connection limits from CLI flags
         rpc_max_connections: cli.rpc_max_connections.into(),
         rpc_max_request_size: cli.rpc_max_request_size.into(),
@@ -216,19 +344,9 @@ async fn main() -> eyre::Result<()> {
         MetricArgs::default()
     };
 
-    // Configure pruning: archive mode disables all pruning.
-    // When --archive is NOT set, Reth uses its default pruning behaviour.
-    let pruning_args = if cli.archive {
-        // An empty PruningArgs (default) means no pruning flags are set,
-        // which results in no pruning config (= archive behaviour).
-        PruningArgs::default()
-    } else {
-        // "full" mode prunes old state to save disk space.
-        PruningArgs {
-            full: true,
-            ..Default::default()
-        }
-    };
+    // Configure pruning: archive mode disables all pruning, --prune-* flags prune
+    // only the selected segments, otherwise the node runs as a full node.
+    let pruning_args = cli.pruning_args();
 
     // Build node configuration with proper data directory
     let node_config = NodeConfig::default()
@@ -279,6 +397,42 @@ async fn main() -> eyre::Result<()> {
     let rpc_chain_spec = chain_spec_arc.clone();
     let rpc_signer_manager = signer_manager.clone();
     let rpc_dev_mode = is_dev_mode;
+    // Latest canonical block number: written by the block monitor, read by meow_* RPC.
+    let chain_head = Arc::new(AtomicU64::new(0));
+    let rpc_chain_head = chain_head.clone();
+    // Committed state diffs: published by the block monitor, streamed by meow_subscribeStateDiff.
+    let state_diffs = state_diff_channel();
+    let rpc_state_diffs = state_diffs.clone();
+    let signed_blocks = signed_block_channel();
+    let rpc_signed_blocks = signed_blocks.clone();
+    // Signers of recent blocks: recorded by the block monitor, read by meow_getDecentralizationHealth.
+    let recent_signers = RecentSigners::shared();
+    let rpc_recent_signers = recent_signers.clone();
+    // Build/sign times: recorded by the payload builder, committed by the block
+    // monitor, read by meow_getBlockTimings.
+    let block_timings = BlockTimings::shared();
+    let rpc_block_timings = block_timings.clone();
+    // Signer of each recent block: recorded by the block monitor, read by
+    // clique_status and meow_getSignerStatus.
+    let signer_index = SignerIndex::shared(cli.signer_index_size);
+    let rpc_signer_index = signer_index.clone();
+    let clique_signer_index = signer_index.clone();
+    // Timelock operations: scheduled ids recorded by the block monitor, read by
+    // meow_getTimelockOperations.
+    let timelock_schedule = TimelockSchedule::shared();
+    let rpc_timelock_schedule = timelock_schedule.clone();
+    // Safe mode: evaluated by the block monitor (with --safe-mode), read by the
+    // payload builder and meow_status.
+    let liveness = SignerLiveness::shared();
+    let rpc_liveness = liveness.clone();
+    let min_distinct_signers = cli.min_distinct_signers;
+    let strict_diversity = cli.strict_diversity;
+    let in_turn_bonus = cli.in_turn_bonus.unwrap_or(DEFAULT_IN_TURN_BONUS);
+    let (gpo_blocks, gpo_percentile) = (cli.gpo_blocks, cli.gpo_percentile);
+    let rpc_min_gas_price = cli.min_gas_price;
+    let rpc_max_contract_size = cli.max_contract_size;
+    // meow_exportState only writes inside <datadir>/exports
+    let export_dir = cli.datadir.join("exports");
     let clique_chain_spec = chain_spec_arc.clone();
     let clique_signer_manager = signer_manager.clone();
     let admin_chain_spec = chain_spec_arc.clone();
@@ -287,26 +441,100 @@ async fn main() -> eyre::Result<()> {
     let admin_p2p_port = cli.port;
     let node_start_time = std::time::Instant::now();
 
+    // Hot state cache: warm from --cache-file if present so the first blocks after a
+    // restart don't pay full MDBX read cost. Persisted again on graceful shutdown.
+    let mut poa_node = PoaNode::new(chain_spec_arc.clone())
+        .with_dev_mode(is_dev_mode)
+        .with_signer_manager(signer_manager.clone())
+        .with_cache_size(cli.cache_size)
+        .with_max_contract_size(cli.max_contract_size)
+        .with_calldata_gas(cli.calldata_gas)
+        .with_wiggle_ms(cli.wiggle_ms)
+        .with_fallback_policy(cli.fallback_policy)
+        .with_dns_discovery(cli.discovery_dns.clone())
+        .with_transaction_ordering(cli.tx_ordering)
+        .with_block_vanity(
+            cli.block_vanity
+                .as_deref()
+                .map(block_vanity)
+                .unwrap_or_default(),
+        )
+        .with_verify_storage_reads(cli.verify_storage_reads)
+        .with_max_transactions(cli.block_max_txs)
+        .with_min_gas_price(cli.min_gas_price)
+        .with_pool_limits(cli.pool_limits())
+        .with_block_timings(block_timings.clone())
+        .with_empty_block_suppression(cli.no_empty_blocks.then_some(cli.max_idle));
+    if cli.safe_mode {
+        poa_node = poa_node.with_safe_mode(liveness.clone());
+    }
+    let reorg_guard = ReorgGuard::shared(cli.max_reorg_depth, cli.halt_on_deep_reorg);
+    if cli.halt_on_deep_reorg {
+        poa_node = poa_node.with_reorg_guard(reorg_guard.clone());
+    }
+    // The cache is created here (rather than inside the payload builder) so the
+    // monitoring task can report its hit/miss counters.
+    let mut hot_cache = HotStateCache::new(cli.cache_size.max(1));
+    if let Some(path) = &cli.cache_file {
+        match hot_cache.load_from_disk(path) {
+            Ok(count) => output::print_feature(
+                "Hot state cache",
+                &format!("warmed {count} entries from {}", path.display()),
+            ),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => output::print_warning(&format!("Ignoring unreadable cache file: {e}")),
+        }
+    }
+    let shared_cache: SharedCache = Arc::new(Mutex::new(hot_cache));
+    poa_node = poa_node.with_shared_cache(shared_cache.clone());
+    let persisted_cache = cli
+        .cache_file
+        .clone()
+        .map(|path| (path, shared_cache.clone()));
+
     let NodeHandle {
         node,
         node_exit_future,
     } = NodeBuilder::new(node_config)
         .with_database(database)
         .with_launch_context(tasks)
-        .node(
-            PoaNode::new(chain_spec_arc.clone())
-                .with_dev_mode(is_dev_mode)
-                .with_signer_manager(signer_manager.clone())
-                .with_cache_size(cli.cache_size)
-                .with_max_contract_size(cli.max_contract_size)
-                .with_calldata_gas(cli.calldata_gas),
-        )
+        .node(poa_node)
         .extend_rpc_modules(move |ctx| {
-            let meow_rpc = MeowRpc::new(rpc_chain_spec, rpc_signer_manager, rpc_dev_mode);
+            // The monitoring task only sees blocks committed from now on; start the
+            // head at the stored tip so head-relative answers are right before then
+            if let Ok(best) = ctx.provider().best_block_number() {
+                rpc_chain_head.fetch_max(best, Ordering::Relaxed);
+            }
+            let meow_rpc = MeowRpc::new(rpc_chain_spec, rpc_signer_manager, rpc_dev_mode)
+                .with_start_time(node_start_time)
+                .with_chain_head(rpc_chain_head)
+                .with_state_diffs(rpc_state_diffs)
+                .with_signed_blocks(rpc_signed_blocks)
+                .with_recent_signers(rpc_recent_signers, min_distinct_signers)
+                .with_strict_diversity(strict_diversity)
+                .with_in_turn_bonus(in_turn_bonus)
+                .with_block_timings(rpc_block_timings)
+                .with_signer_index(rpc_signer_index)
+                .with_timelock_schedule(rpc_timelock_schedule)
+                .with_signer_liveness(rpc_liveness)
+                .with_history_source(Arc::new(ctx.provider().clone()))
+                .with_state_source(Arc::new(ctx.provider().clone()))
+                .with_gas_price_oracle(gpo_blocks, gpo_percentile)
+                .with_min_gas_price(rpc_min_gas_price)
+                .with_max_contract_size(rpc_max_contract_size)
+                .with_export_source(
+                    Arc::new(StateExporter::new(ctx.provider().clone())),
+                    export_dir,
+                )
+                .with_execution_source(Arc::new(Reexecutor::new(
+                    ctx.provider().clone(),
+                    ctx.node().evm_config().clone(),
+                )));
             ctx.modules.merge_configured(meow_rpc.into_rpc())?;
             output::print_rpc_registered("meow_*");
 
-            let clique_rpc = CliqueRpc::new(clique_chain_spec, clique_signer_manager);
+            let clique_rpc = CliqueRpc::new(clique_chain_spec, clique_signer_manager)
+                .with_signer_index(clique_signer_index);
             ctx.modules.merge_configured(clique_rpc.into_rpc())?;
             output::print_rpc_registered("clique_*");
 
@@ -316,6 +544,7 @@ async fn main() -> eyre::Result<()> {
                 node_start_time,
                 admin_dev_mode,
                 admin_p2p_port,
+                Some(Arc::new(ctx.network().clone())),
             );
             // Reth provides built-in admin_* methods (nodeInfo, peers, addPeer, removePeer).
             // Our AdminRpc adds admin_health for load balancers. If Reth's admin_* conflicts,
@@ -324,6 +553,20 @@ async fn main() -> eyre::Result<()> {
                 Ok(()) => output::print_rpc_registered("admin_*"),
                 Err(_) => output::print_rpc_registered("admin_* (using Reth built-in)"),
             }
+
+            // debug_traceTransaction: Reth's built-in debug namespace wins if enabled.
+            let debug_rpc = DebugRpc::new(ctx.provider().clone(), ctx.node().evm_config().clone());
+            match ctx.modules.merge_configured(debug_rpc.into_rpc()) {
+                Ok(()) => output::print_rpc_registered("debug_traceTransaction"),
+                Err(_) => output::print_rpc_registered("debug_* (using Reth built-in)"),
+            }
+
+            // Same for txpool_*: Reth's built-in namespace wins if it is enabled via --http.api.
+            let txpool_rpc = TxpoolRpc::new(ctx.pool().clone());
+            match ctx.modules.merge_configured(txpool_rpc.into_rpc()) {
+                Ok(()) => output::print_rpc_registered("txpool_*"),
+                Err(_) => output::print_rpc_registered("txpool_* (using Reth built-in)"),
+            }
             Ok(())
         })
         .launch_with_debug_capabilities()
@@ -331,6 +574,56 @@ async fn main() -> eyre::Result<()> {
 
     output::print_node_started(poa_chain.inner().genesis_hash());
 
+    // Compare CLI flags with governance-controlled ChainConfig values. Genesis is built
+    // from these flags, so a mismatch means governance changed the value since.
+    // The block period is the one in effect (--genesis takes it from the file).
+    if let Ok(state) = node.provider.latest() {
+        let reader = StateProviderStorageReader::new(state.as_ref());
+        let cli_block_time = (cli.block_time_ms == 0).then_some(poa_chain.block_period());
+        let cli_max_contract_size =
+            (cli.max_contract_size > 0).then_some(cli.max_contract_size as u64);
+        let mismatches = check_cli_params(
+            &reader,
+            cli.gas_limit,
+            cli_block_time,
+            cli_max_contract_size,
+        );
+        for mismatch in &mismatches {
+            output::print_param_mismatch(mismatch);
+        }
+        if cli.strict_param_check && !mismatches.is_empty() {
+            eyre::bail!("CLI parameters disagree with on-chain ChainConfig (--strict-param-check)");
+        }
+    }
+
+    // Restore the live signer list saved on the last shutdown. A fresh node (no blocks)
+    // keeps genesis signers by default, so a leftover checkpoint can't misconfigure it.
+    if let Some(path) = &cli.signer_checkpoint {
+        match SignerCheckpoint::load_from_disk(path) {
+            Ok(checkpoint) => {
+                let head = node.provider.best_block_number().unwrap_or_default();
+                let source = chain_spec_arc.apply_signer_checkpoint(
+                    &checkpoint,
+                    head,
+                    cli.signer_precedence,
+                );
+                let detail = match source {
+                    SignerSource::Checkpoint => format!(
+                        "{} signers restored from block {}",
+                        checkpoint.signers.len(),
+                        checkpoint.block_number
+                    ),
+                    SignerSource::Genesis => {
+                        format!("ignored (head {head}), using genesis signers")
+                    }
+                };
+                output::print_feature("Signer checkpoint", &detail);
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => output::print_warning(&format!("Ignoring unreadable signer checkpoint: {e}")),
+        }
+    }
+
     // Print production-grade feature status after node launch
     if cli.metrics {
         output::print_feature(
@@ -338,11 +631,25 @@ async fn main() -> eyre::Result<()> {
             &format!("http://0.0.0.0:{}/metrics", cli.metrics_port),
         );
     }
+    // Liveness/readiness probes, ready while blocks keep arriving within budget
+    let health = HealthState::shared(mining_interval);
+    if let Some(port) = cli.health_port {
+        match start_health_server(port, health.clone()).await {
+            Ok(()) => output::print_feature(
+                "Health probes",
+                &format!("http://0.0.0.0:{port}/health, /ready"),
+            ),
+            Err(e) => output::print_warning(&format!("Health server failed to start: {e}")),
+        }
+    }
     if let Some(ref cors) = cli.http_corsdomain {
         output::print_feature("CORS", cors);
     }
     output::print_info(&format!("HTTP API modules: {}", cli.http_api));
     output::print_info(&format!("WS API modules: {}", cli.ws_api));
+    if let Some(ref path) = ipc_endpoint {
+        output::print_feature("IPC", path);
+    }
     output::print_info(&format!("Max RPC connections: {}", cli.rpc_max_connections));
     output::print_info(&format!(
         "RPC payload limits: request={}MB response={}MB",
@@ -350,6 +657,20 @@ async fn main() -> eyre::Result<()> {
     ));
     if cli.archive {
         output::print_feature("Archive mode", "all historical state retained");
+    } else if cli.custom_pruning() {
+        let mut pruned = Vec::new();
+        if cli.prune_receipts {
+            pruned.push(format!("receipts (last {PRUNE_KEEP_BLOCKS} blocks kept)"));
+        }
+        if cli.prune_account_history {
+            pruned.push(format!(
+                "account history (last {PRUNE_KEEP_BLOCKS} blocks kept)"
+            ));
+        }
+        if let Some(block) = cli.prune_history_before {
+            pruned.push(format!("account/storage history before block {block}"));
+        }
+        output::print_feature("Pruning", &pruned.join(", "));
     }
     output::print_info(&format!(
         "Gas price oracle: {} blocks, {}th percentile",
@@ -358,23 +679,160 @@ async fn main() -> eyre::Result<()> {
     if cli.log_json {
         output::print_feature("JSON logging", "structured output enabled");
     }
+    if cli.min_distinct_signers > 0 {
+        output::print_feature(
+            "Signer diversity",
+            &format!(
+                "warn below {} distinct recent signers{}",
+                cli.min_distinct_signers,
+                if cli.strict_diversity {
+                    ", preferred in fork choice"
+                } else {
+                    ""
+                }
+            ),
+        );
+    }
+    if !cli.discovery_dns.is_empty() {
+        let domains: Vec<&str> = cli
+            .discovery_dns
+            .iter()
+            .map(|l| l.domain.as_str())
+            .collect();
+        output::print_feature("DNS discovery", &domains.join(", "));
+    }
+    if cli.safe_mode {
+        output::print_feature(
+            "Safe mode",
+            "in-turn blocks only while signers online < SignerRegistry threshold",
+        );
+    }
+    if cli.prioritize_governance_txs {
+        output::print_feature("Governance priority", "governance txs first at equal fee");
+    }
+    if cli.verify_storage_reads {
+        output::print_feature(
+            "Storage proofs",
+            "governance reads verified against state root",
+        );
+    }
+
+    // Optional on-disk state diff log with batched flushing.
+    let statediff_log = match &cli.statediff_log {
+        Some(path) => {
+            let flush_interval = Duration::from_millis(cli.statediff_flush_ms);
+            let log = StateDiffLog::open(path, flush_interval)?.shared();
+            output::print_feature(
+                "State diff log",
+                &format!(
+                    "{} (flush every {}ms)",
+                    path.display(),
+                    cli.statediff_flush_ms
+                ),
+            );
+            // Flush on the interval even when no new blocks arrive.
+            if !flush_interval.is_zero() {
+                let ticker_log = log.clone();
+                tokio::spawn(async move {
+                    let mut ticker = tokio::time::interval(flush_interval);
+                    loop {
+                        ticker.tick().await;
+                        if let Err(e) = lock_shared(&ticker_log).flush_if_due() {
+                            output::print_warning(&format!("State diff log flush failed: {e}"));
+                        }
+                    }
+                });
+            }
+            Some(log)
+        }
+        None => None,
+    };
+    if let Some(dir) = &cli.statediff_dir {
+        output::print_feature("State diff dir", &dir.display().to_string());
+    }
 
     // Register graceful shutdown handlers for SIGINT (Ctrl+C) and SIGTERM.
-    // These print a shutdown message before the node exits.
-    tokio::spawn(async {
+    // These print a shutdown message before the node exits, then flush buffered state diffs.
+    // On unix, SIGHUP re-scans --keystore-dir so authority keys can be rotated live.
+    #[cfg(unix)]
+    let reload_keystore = match (&cli.keystore_dir, &cli.keystore_password_file) {
+        (Some(dir), Some(password_file)) if cli.signer_key.is_none() => Some((
+            dir.clone(),
+            password_file.clone(),
+            signer_manager.clone(),
+            chain_spec_arc.clone(),
+        )),
+        _ => None,
+    };
+    let shutdown_statediff_log = statediff_log.clone();
+    let shutdown_cache = persisted_cache;
+    let shutdown_checkpoint = cli
+        .signer_checkpoint
+        .clone()
+        .map(|path| (path, chain_spec_arc.clone(), chain_head.clone()));
+    tokio::spawn(async move {
         let ctrl_c = tokio::signal::ctrl_c();
         #[cfg(unix)]
         {
             let mut sigterm =
                 tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
                     .expect("failed to register SIGTERM handler");
+            let mut sighup = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup())
+                .expect("failed to register SIGHUP handler");
+            tokio::pin!(ctrl_c);
 
-            tokio::select! {
-                _ = ctrl_c => {
-                    output::print_shutdown("Received SIGINT (Ctrl+C), shutting down...");
-                }
-                _ = sigterm.recv() => {
-                    output::print_shutdown("Received SIGTERM, shutting down...");
+            loop {
+                tokio::select! {
+                    _ = &mut ctrl_c => {
+                        output::print_shutdown("Received SIGINT (Ctrl+C), shutting down...");
+                        break;
+                    }
+                    _ = sigterm.recv() => {
+                        output::print_shutdown("Received SIGTERM, shutting down...");
+                        break;
+                    }
+                    _ = sighup.recv() => {
+                        let Some((dir, password_file, signer_manager, chain_spec)) =
+                            &reload_keystore
+                        else {
+                            output::print_info("Received SIGHUP: no keystore to reload");
+                            continue;
+                        };
+                        output::print_info("Received SIGHUP, reloading keystore...");
+                        let password = match std::fs::read_to_string(password_file) {
+                            Ok(password) => password,
+                            Err(e) => {
+                                output::print_warning(&format!(
+                                    "Keystore reload failed: {}: {e}",
+                                    password_file.display()
+                                ));
+                                continue;
+                            }
+                        };
+                        let password = password.trim_end_matches(['\r', '\n']);
+                        let authorized = chain_spec.effective_signers();
+                        match KeystoreManager::new(dir)
+                            .reload_into_signer_manager(password, signer_manager, Some(&authorized))
+                            .await
+                        {
+                            Ok(report) => {
+                                for addr in &report.loaded {
+                                    output::print_signer_loaded(addr);
+                                }
+                                for addr in &report.removed {
+                                    output::print_signer_removed(addr);
+                                }
+                                for (addr, e) in &report.failed {
+                                    output::print_warning(&format!(
+                                        "Failed to reload keystore {addr}: {e}"
+                                    ));
+                                }
+                            }
+                            Err(e) => {
+                                output::print_warning(&format!("Keystore reload failed: {e}"));
+                            }
+                        }
+                    }
                 }
             }
         }
@@ -383,6 +841,32 @@ async fn main() -> eyre::Result<()> {
             let _ = ctrl_c.await;
             output::print_shutdown("Received SIGINT (Ctrl+C), shutting down...");
         }
+        if let Some(log) = shutdown_statediff_log {
+            if let Err(e) = lock_shared(&log).flush() {
+                output::print_warning(&format!("State diff log flush failed: {e}"));
+            }
+        }
+        if let Some((path, cache)) = shutdown_cache {
+            if let Err(e) = cache
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .save_to_disk(&path)
+            {
+                output::print_warning(&format!("Hot state cache save failed: {e}"));
+            }
+        }
+        // Only live (on-chain) signers are worth saving; genesis is always available.
+        if let Some((path, chain_spec, head)) = shutdown_checkpoint {
+            if chain_spec.has_live_signers() {
+                let checkpoint = SignerCheckpoint {
+                    block_number: head.load(Ordering::Relaxed),
+                    signers: chain_spec.effective_signers(),
+                };
+                if let Err(e) = checkpoint.save_to_disk(&path) {
+                    output::print_warning(&format!("Signer checkpoint save failed: {e}"));
+                }
+            }
+        }
     });
 
     // Set up performance metrics (Phase 5)
@@ -393,7 +877,36 @@ async fn main() -> eyre::Result<()> {
     let monitoring_chain_spec = chain_spec_arc.clone();
     let monitoring_signer_manager = signer_manager.clone();
     let monitoring_metrics = chain_metrics.clone();
+    let monitoring_chain_head = chain_head;
+    let monitoring_state_diffs = state_diffs;
+    let monitoring_signed_blocks = signed_blocks;
     let monitoring_interval = mining_interval;
+    let monitoring_statediff_log = statediff_log;
+    let monitoring_statediff_dir = cli.statediff_dir.clone();
+    let monitoring_cache = shared_cache;
+    let monitoring_recent_signers = recent_signers;
+    let monitoring_block_timings = block_timings;
+    let monitoring_signer_index = signer_index;
+    let monitoring_timelock_schedule = timelock_schedule;
+    let monitoring_health = health;
+    let monitoring_liveness = cli.safe_mode.then_some(liveness);
+    let monitoring_reorg_guard = reorg_guard;
+    // Miner Proxy admin, checked against genesis after every commit.
+    let mut proxy_admin = ProxyAdminWatch::new(read_miner_proxy_admin(
+        &GenesisStorageReader::from_genesis(poa_chain.inner().genesis()),
+    ));
+    let diversity_consensus =
+        PoaConsensus::new(chain_spec_arc.clone()).with_min_distinct_signers(min_distinct_signers);
+    let mut diversity_healthy = true;
+    let mut catch_up = CatchUpTracker::new(Duration::from_secs(cli.monitor_max_age));
+    // Timelock operations scheduled before startup, from the stored receipts.
+    let backfill_provider = node.provider.clone();
+    let backfill_schedule = monitoring_timelock_schedule.clone();
+    tokio::task::spawn_blocking(move || {
+        if let Err(e) = backfill_timelock_schedule(&backfill_provider, &backfill_schedule) {
+            output::print_warning(&format!("Timelock schedule backfill failed: {e}"));
+        }
+    });
     tokio::spawn(async move {
         let mut block_stream = node.provider.canonical_state_stream();
         // Track wall-clock arrival time for block-time budget monitoring (Phase 2.16).
@@ -403,9 +916,11 @@ async fn main() -> eyre::Result<()> {
             let arrived = Instant::now();
             let elapsed_ms = last_block_arrived.elapsed().as_millis() as u64;
             last_block_arrived = arrived;
+            monitoring_health.record_block(unix_millis());
 
             let block = notification.tip();
             let block_num = block.header().number();
+            monitoring_chain_head.store(block_num, Ordering::Relaxed);
             let tx_count = block.body().transactions().count();
             let gas_used = block.header().gas_used();
 
@@ -417,41 +932,176 @@ async fn main() -> eyre::Result<()> {
             let mut diff_builder = StateDiffBuilder::new(block_num, block_hash)
                 .with_gas_used(gas_used)
                 .with_tx_count(tx_count);
-            for (addr, account) in outcome.bundle_accounts_iter() {
-                // Account-level changes: balance, nonce, code
-                match (&account.original_info, &account.info) {
-                    (Some(old), Some(new)) => {
-                        if old.balance != new.balance {
-                            diff_builder.record_balance_change(addr, old.balance, new.balance);
-                        }
-                        if old.nonce != new.nonce {
-                            diff_builder.record_nonce_change(addr, old.nonce, new.nonce);
+            diff_builder.record_bundle(&outcome.bundle);
+            let state_diff = diff_builder.build();
+
+            // Reorg: the reverted segment's bundle spans all reorged-out blocks, so its
+            // inverse rolls a replica back to the fork point. Published before the new diff.
+            if let Some(old) = notification.reverted() {
+                let depth = old.len() as u64;
+                if monitoring_reorg_guard.check(depth) {
+                    output::print_deep_reorg(
+                        block_num,
+                        depth,
+                        monitoring_reorg_guard.max_depth(),
+                        monitoring_reorg_guard.is_halted(),
+                    );
+                }
+            }
+
+            let reverted_diff = notification.reverted().map(|old| {
+                let old_tip = old.tip();
+                let mut builder = StateDiffBuilder::new(old_tip.header().number(), old_tip.hash());
+                builder.record_bundle(&old.execution_outcome().bundle);
+                builder.build().invert()
+            });
+            let accounts_changed = state_diff.touched_account_count();
+            let slots_changed = state_diff.total_storage_changes();
+            for diff in reverted_diff.into_iter().chain(std::iter::once(state_diff)) {
+                if let Some(log) = &monitoring_statediff_log {
+                    if let Err(e) = lock_shared(log).append(&diff) {
+                        output::print_warning(&format!("State diff log write failed: {e}"));
+                    }
+                }
+                // Reverted diffs are not written to the directory: they share the
+                // block number of the diff they undo, which the replica already has.
+                if let (Some(dir), false) = (&monitoring_statediff_dir, diff.reverted) {
+                    if let Err(e) = diff.write_to_dir(dir) {
+                        output::print_warning(&format!("State diff write failed: {e}"));
+                    }
+                }
+                // Never blocks; fails only when nobody is subscribed.
+                let _ = monitoring_state_diffs.send(Arc::new(diff));
+            }
+
+            // Recover who sealed the new blocks once, for meow_subscribeSignedBlocks and
+            // the signer index, which overwrites reorged-out entries at the same heights.
+            let signed: Vec<SignedBlock> = chain
+                .blocks_iter()
+                .map(|committed| {
+                    let number = committed.header().number();
+                    // Genesis (or any block without a valid seal) has no signer
+                    let signer = diversity_consensus.recover_signer(committed.header()).ok();
+                    SignedBlock {
+                        number,
+                        hash: committed.hash(),
+                        signer,
+                        in_turn: signer.is_some()
+                            && monitoring_chain_spec.expected_signer(number) == signer,
+                        tx_count: committed.body().transactions().count() as u64,
+                        gas_used: committed.header().gas_used(),
+                    }
+                })
+                .collect();
+            let sealed: Vec<(u64, Address)> = signed
+                .iter()
+                .filter_map(|block| Some((block.number, block.signer?)))
+                .collect();
+            // Never blocks; lagging subscribers drop their oldest notifications.
+            for block in signed {
+                let _ = monitoring_signed_blocks.send(Arc::new(block));
+            }
+            // Safe mode threshold, read before taking the index lock (`None` = off).
+            let threshold = monitoring_liveness.as_ref().map(|_| {
+                node.provider
+                    .latest()
+                    .ok()
+                    .and_then(|state| {
+                        read_signer_list(&StateProviderStorageReader::new(state.as_ref()))
+                    })
+                    .map_or(0, |list| list.threshold)
+            });
+            {
+                let mut index = monitoring_signer_index
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner());
+                for &(number, signer) in &sealed {
+                    let in_turn = monitoring_chain_spec.expected_signer(number) == Some(signer);
+                    index.record(number, signer, in_turn);
+                }
+
+                // Safe mode: compare distinct signers of the last epoch with the
+                // SignerRegistry threshold, logging only transitions.
+                if let (Some(liveness), Some(threshold)) = (&monitoring_liveness, threshold) {
+                    let epoch = monitoring_chain_spec.effective_epoch();
+                    match liveness.evaluate(&index, block_num, epoch, threshold) {
+                        Some((true, online)) => {
+                            output::print_safe_mode_entered(block_num, online, threshold)
                         }
-                        if old.code_hash != new.code_hash {
-                            diff_builder.record_code_change(addr);
+                        Some((false, online)) => {
+                            output::print_safe_mode_recovered(block_num, online, threshold)
                         }
+                        None => {}
                     }
-                    (None, Some(_)) => diff_builder.record_code_change(addr), // created
-                    (Some(_), None) => diff_builder.record_code_change(addr), // destroyed
-                    (None, None) => {}
                 }
-                // Storage-slot changes
-                for (slot_key, slot) in &account.storage {
-                    if slot.is_changed() {
-                        let old = B256::from(slot.previous_or_original_value.to_be_bytes::<32>());
-                        let new = B256::from(slot.present_value.to_be_bytes::<32>());
-                        diff_builder.record_storage_change(addr, *slot_key, old, new);
+            }
+
+            // A Miner Proxy admin other than genesis could redirect block fees.
+            if let Ok(state) = node.provider.latest() {
+                let live = read_miner_proxy_admin(&StateProviderStorageReader::new(state.as_ref()));
+                if let Some(admin) = proxy_admin.observe(live) {
+                    output::print_proxy_admin_changed(block_num, proxy_admin.genesis(), admin);
+                }
+            }
+
+            // Timelock operations scheduled in the new blocks.
+            {
+                let mut schedule = monitoring_timelock_schedule
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner());
+                for committed in chain.blocks_iter() {
+                    let number = committed.header().number();
+                    let receipts = outcome.receipts_by_block(number);
+                    schedule.record_logs(number, receipts.iter().flat_map(|receipt| &receipt.logs));
+                }
+            }
+
+            // Signer diversity: record who sealed the new blocks (reorgs start over).
+            if !is_dev_mode {
+                monitoring_metrics.register_signers(&monitoring_chain_spec.effective_signers());
+                let recent = {
+                    let mut recent = monitoring_recent_signers
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner());
+                    if notification.reverted().is_some() {
+                        recent.clear();
                     }
+                    for &(number, signer) in &sealed {
+                        recent.record_sealed(signer, number);
+                        monitoring_metrics.record_signer_block(signer);
+                    }
+                    recent.to_vec()
+                };
+                let health = DiversityHealth::from_signers(
+                    &recent,
+                    monitoring_chain_spec.effective_signers().len(),
+                    min_distinct_signers,
+                );
+                // Warn once on entering the unhealthy state, not on every block.
+                if diversity_healthy && !health.healthy {
+                    output::print_low_signer_diversity(block_num, &health);
                 }
+                diversity_healthy = health.healthy;
             }
-            let state_diff = diff_builder.build();
-            let accounts_changed = state_diff.touched_account_count();
-            let slots_changed = state_diff.total_storage_changes();
+
+            // Old heads (catch-up after downtime) are summarized instead of logged per block.
+            let now_secs = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            let decision =
+                catch_up.observe(block_num, block.header().timestamp(), tx_count, now_secs);
+            if let Some(summary) = &decision.summary {
+                output::print_catch_up_summary(summary);
+            }
+            let log_block = decision.log_block;
 
             // Determine which signer should sign this block (round-robin)
             let signers = monitoring_chain_spec.signers();
             if signers.is_empty() {
-                output::print_block_no_signers(block_num, tx_count);
+                if log_block {
+                    output::print_block_no_signers(block_num, tx_count);
+                }
                 continue;
             }
             let signer_index = (block_num as usize) % signers.len();
@@ -460,52 +1110,78 @@ async fn main() -> eyre::Result<()> {
             // Determine if this is an in-turn block for metrics
             let in_turn = monitoring_signer_manager.has_signer(&expected_signer).await;
 
-            // Check if we have the key for the expected signer
-            if in_turn {
-                output::print_block_in_turn(block_num, tx_count, &expected_signer);
-            } else {
-                let our_addresses = monitoring_signer_manager.signer_addresses().await;
-                let is_our_turn = our_addresses.iter().any(|addr| signers.contains(addr));
-                if is_our_turn {
-                    output::print_block_out_of_turn(block_num, tx_count, &expected_signer);
+            if log_block {
+                // Check if we have the key for the expected signer
+                if in_turn {
+                    output::print_block_in_turn(block_num, tx_count, &expected_signer);
                 } else {
-                    output::print_block_observed(block_num, tx_count, &expected_signer);
+                    let our_addresses = monitoring_signer_manager.signer_addresses().await;
+                    let is_our_turn = our_addresses.iter().any(|addr| signers.contains(addr));
+                    if is_our_turn {
+                        output::print_block_out_of_turn(block_num, tx_count, &expected_signer);
+                    } else {
+                        output::print_block_observed(block_num, tx_count, &expected_signer);
+                    }
                 }
-            }
 
-            // Print state diff when there are transactions (Phase 2.15).
-            if tx_count > 0 {
-                output::print_block_state_diff(block_num, accounts_changed, slots_changed);
-            }
+                // Print state diff when there are transactions (Phase 2.15).
+                if tx_count > 0 {
+                    output::print_block_state_diff(block_num, accounts_changed, slots_changed);
+                }
 
-            // Block time budget warning: fire if a block arrives > 3× the expected
-            // interval (Phase 2.16). 3× threshold avoids false positives from normal
-            // Reth dev-mining timer jitter (~2× is common at sub-second intervals).
-            // Skip block 1 (first arrival time is not meaningful).
-            let interval_ms = monitoring_interval.as_millis() as u64;
-            if block_num > 1 && interval_ms > 0 && elapsed_ms > interval_ms * 3 {
-                output::print_block_time_budget_warning(block_num, elapsed_ms, interval_ms);
+                // Block time budget warning: fire if a block arrives > 3× the expected
+                // interval (Phase 2.16). Skip block 1 (first arrival time is not meaningful).
+                let interval_ms = monitoring_interval.as_millis() as u64;
+                if block_num > 1 && exceeds_block_time_budget(elapsed_ms, interval_ms) {
+                    output::print_block_time_budget_warning(block_num, elapsed_ms, interval_ms);
+                }
             }
 
-            // Record block metrics (Phase 5)
+            // Record block metrics (Phase 5), with the build/sign times the payload
+            // builder measured if this node produced the block.
+            let mut tip_timing = None;
+            {
+                let mut timings = monitoring_block_timings
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner());
+                for committed in chain.blocks_iter() {
+                    if let Some(timing) = timings.commit(committed.hash()) {
+                        monitoring_metrics.record_timing(&timing);
+                        tip_timing = Some(timing);
+                    }
+                }
+            }
+            let tip_timing = tip_timing.filter(|t| t.block_hash == block_hash);
             let block_metrics = BlockMetrics {
                 block_number: block_num,
                 tx_count,
                 gas_used,
-                build_duration: Duration::ZERO, // actual timing requires payload hook
-                sign_duration: Duration::ZERO,
+                build_duration: tip_timing.map_or(Duration::ZERO, |t| t.build_duration()),
+                sign_duration: tip_timing.map_or(Duration::ZERO, |t| t.sign_duration()),
                 in_turn,
             };
             monitoring_metrics.record_block(&block_metrics);
+            monitoring_metrics.record_cache_stats(
+                &monitoring_cache
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .stats(),
+            );
 
             // Print metrics report at configured interval
-            if metrics_interval > 0 && block_num > 0 && block_num.is_multiple_of(metrics_interval) {
+            if log_block
+                && metrics_interval > 0
+                && block_num > 0
+                && block_num.is_multiple_of(metrics_interval)
+            {
                 let snap = monitoring_metrics.snapshot();
                 println!(
-                    "  [metrics] block={} total_txs={} in_turn_rate={:.1}%",
+                    "  [metrics] block={} total_txs={} in_turn_rate={:.1}% cache_hit_rate={:.1}% signer_blocks=[{}]",
                     block_num,
                     snap.total_txs,
                     snap.in_turn_rate() * 100.0,
+                    snap.cache_hit_rate() * 100.0,
+                    format_signer_counts(&monitoring_metrics.signer_block_counts()),
                 );
             }
         }
@@ -10,11 +10,24 @@
 //! - Gas throughput (gas/second, rolling window)
 //! - Cache hit/miss rates (from `cache::CacheStats`)
 //! - Signer turn statistics (in-turn vs out-of-turn blocks)
+//! - Blocks sealed per signer (to spot an offline authority)
 //!
 //! # Design
 //! Uses `std::sync::atomic` counters for thread-safe updates without locking.
 //! Heavy operations (window computation) acquire a `Mutex` only on read.
+//!
+//! Cache counters and per-signer block counts are also published through the `metrics` facade, so they show
+//! up on Reth's Prometheus endpoint when `--enable-metrics` is set (and are a
+//! no-op otherwise), as are build/sign time histograms (`meowchain_block_build_ms`,
+//! `meowchain_block_sign_ms`) fed from [`timings`].
+
+pub mod timings;
 
+pub use timings::{BlockTiming, BlockTimings, SharedBlockTimings};
+
+use crate::cache::CacheStats;
+use alloy_primitives::Address;
+use std::collections::BTreeMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
@@ -147,6 +160,10 @@ pub struct MetricsSnapshot {
     pub rolling_build_ms: f64,
     /// Rolling average block sign time (ms).
     pub rolling_sign_ms: f64,
+    /// Cumulative hot state cache hits.
+    pub cache_hits: u64,
+    /// Cumulative hot state cache misses.
+    pub cache_misses: u64,
 }
 
 impl MetricsSnapshot {
@@ -159,6 +176,11 @@ impl MetricsSnapshot {
         }
     }
 
+    /// Fraction of cache lookups served from the cache.
+    pub fn cache_hit_rate(&self) -> f64 {
+        hit_rate(self.cache_hits, self.cache_misses)
+    }
+
     /// Formatted multi-line report for logging.
     pub fn report(&self) -> String {
         format!(
@@ -169,7 +191,8 @@ impl MetricsSnapshot {
              Rolling TPS:  {:.1}\n\
              Rolling gas/s:{:.0}\n\
              Build time:   {:.1}ms avg\n\
-             Sign time:    {:.1}ms avg",
+             Sign time:    {:.1}ms avg\n\
+             Cache:        {} hits / {} misses ({:.1}%)",
             self.total_blocks,
             self.in_turn_blocks,
             self.in_turn_rate() * 100.0,
@@ -179,6 +202,9 @@ impl MetricsSnapshot {
             self.rolling_gas_per_second,
             self.rolling_build_ms,
             self.rolling_sign_ms,
+            self.cache_hits,
+            self.cache_misses,
+            self.cache_hit_rate() * 100.0,
         )
     }
 }
@@ -195,6 +221,13 @@ pub struct ChainMetrics {
     in_turn_blocks: AtomicU64,
     out_of_turn_blocks: AtomicU64,
 
+    // Hot state cache counters (cumulative, mirrored from `CacheStats`)
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
+
+    // Blocks sealed per recovered signer; known signers are present with zero
+    signer_blocks: Mutex<BTreeMap<Address, u64>>,
+
     // Rolling windows (guarded by mutex, written on every block, read on demand)
     window: Mutex<BlockWindow>,
 
@@ -232,6 +265,9 @@ impl ChainMetrics {
             total_gas: AtomicU64::new(0),
             in_turn_blocks: AtomicU64::new(0),
             out_of_turn_blocks: AtomicU64::new(0),
+            cache_hits: AtomicU64::new(0),
+            cache_misses: AtomicU64::new(0),
+            signer_blocks: Mutex::new(BTreeMap::new()),
             window: Mutex::new(BlockWindow::new(window_size)),
             window_size,
         }
@@ -266,6 +302,61 @@ impl ChainMetrics {
         }
     }
 
+    /// Publish the measured build and sign time of a block this node produced.
+    ///
+    /// Feeds the `meowchain_block_build_ms` and `meowchain_block_sign_ms` histograms,
+    /// which the Prometheus endpoint reports with p50/p95 quantiles.
+    pub fn record_timing(&self, timing: &BlockTiming) {
+        ::metrics::histogram!("meowchain_block_build_ms").record(timing.build_ms as f64);
+        ::metrics::histogram!("meowchain_block_sign_ms").record(timing.sign_ms as f64);
+    }
+
+    /// Update cache counters from the shared cache's cumulative [`CacheStats`].
+    ///
+    /// Call after each block with the stats of the cache used by `PoaPayloadBuilder`.
+    /// Also publishes `meowchain_cache_hits_total`, `meowchain_cache_misses_total`
+    /// and `meowchain_cache_hit_rate` to the Prometheus recorder, if installed.
+    pub fn record_cache_stats(&self, stats: &CacheStats) {
+        self.cache_hits.store(stats.hits, Ordering::Relaxed);
+        self.cache_misses.store(stats.misses, Ordering::Relaxed);
+
+        ::metrics::counter!("meowchain_cache_hits_total").absolute(stats.hits);
+        ::metrics::counter!("meowchain_cache_misses_total").absolute(stats.misses);
+        ::metrics::gauge!("meowchain_cache_hit_rate").set(stats.hit_rate());
+    }
+
+    /// Make sure every known signer has an entry, so one that never seals shows as zero.
+    ///
+    /// Zero entries are published as `meowchain_signer_blocks{signer="0x…"} 0`.
+    pub fn register_signers(&self, signers: &[Address]) {
+        let mut counts = self.signer_blocks.lock().unwrap_or_else(|e| e.into_inner());
+        for signer in signers {
+            counts.entry(*signer).or_insert_with(|| {
+                publish_signer_blocks(signer, 0);
+                0
+            });
+        }
+    }
+
+    /// Count a block sealed by `signer` (as recovered from its seal).
+    ///
+    /// Also publishes the `meowchain_signer_blocks{signer="0x…"}` gauge.
+    pub fn record_signer_block(&self, signer: Address) {
+        let mut counts = self.signer_blocks.lock().unwrap_or_else(|e| e.into_inner());
+        let count = counts.entry(signer).or_default();
+        *count += 1;
+        publish_signer_blocks(&signer, *count);
+    }
+
+    /// Blocks sealed per signer since the node started, including zero counts
+    /// for signers passed to [`Self::register_signers`].
+    pub fn signer_block_counts(&self) -> BTreeMap<Address, u64> {
+        self.signer_blocks
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
     /// Take a snapshot of all metrics (momentary read — values may change concurrently).
     pub fn snapshot(&self) -> MetricsSnapshot {
         let total_blocks = self.total_blocks.load(Ordering::Relaxed);
@@ -308,6 +399,8 @@ impl ChainMetrics {
             rolling_gas_per_second,
             rolling_build_ms,
             rolling_sign_ms,
+            cache_hits: self.cache_hits(),
+            cache_misses: self.cache_misses(),
         }
     }
 
@@ -321,6 +414,21 @@ impl ChainMetrics {
         self.total_txs.load(Ordering::Relaxed)
     }
 
+    /// Cumulative cache hits recorded so far.
+    pub fn cache_hits(&self) -> u64 {
+        self.cache_hits.load(Ordering::Relaxed)
+    }
+
+    /// Cumulative cache misses recorded so far.
+    pub fn cache_misses(&self) -> u64 {
+        self.cache_misses.load(Ordering::Relaxed)
+    }
+
+    /// Fraction of cache lookups served from the cache.
+    pub fn cache_hit_rate(&self) -> f64 {
+        hit_rate(self.cache_hits(), self.cache_misses())
+    }
+
     /// Configured rolling window size.
     pub fn window_size(&self) -> usize {
         self.window_size
@@ -361,6 +469,22 @@ impl PhaseTimer {
 
 // ── Utilities ─────────────────────────────────────────────────────────────────
 
+fn publish_signer_blocks(signer: &Address, count: u64) {
+    ::metrics::gauge!("meowchain_signer_blocks", "signer" => signer.to_string()).set(count as f64);
+}
+
+/// Compact `0xabcd…:N` list of per-signer block counts for log lines.
+pub fn format_signer_counts(counts: &BTreeMap<Address, u64>) -> String {
+    counts
+        .iter()
+        .map(|(signer, count)| {
+            let hex = format!("{signer:#x}");
+            format!("{}…:{count}", &hex[..6])
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 fn average(window: &SlidingWindow<u64>) -> f64 {
     if window.is_empty() {
         return 0.0;
@@ -369,6 +493,15 @@ fn average(window: &SlidingWindow<u64>) -> f64 {
     sum as f64 / window.len() as f64
 }
 
+fn hit_rate(hits: u64, misses: u64) -> f64 {
+    let total = hits + misses;
+    if total == 0 {
+        0.0
+    } else {
+        hits as f64 / total as f64
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -554,6 +687,74 @@ mod tests {
         assert!(report.contains("1000"));
     }
 
+    #[test]
+    fn test_signer_block_counts_include_absent_signers() {
+        let metrics = ChainMetrics::new(10);
+        let (a, b, offline) = (
+            Address::repeat_byte(0xaa),
+            Address::repeat_byte(0xbb),
+            Address::repeat_byte(0xcc),
+        );
+        metrics.register_signers(&[a, b, offline]);
+        metrics.record_signer_block(a);
+        metrics.record_signer_block(b);
+        metrics.record_signer_block(a);
+        // Re-registering keeps existing counts
+        metrics.register_signers(&[a, b, offline]);
+
+        let counts = metrics.signer_block_counts();
+        assert_eq!(counts[&a], 2);
+        assert_eq!(counts[&b], 1);
+        assert_eq!(counts[&offline], 0);
+        assert_eq!(
+            format_signer_counts(&counts),
+            "0xaaaa…:2,0xbbbb…:1,0xcccc…:0"
+        );
+    }
+
+    // ── Cache metrics ─────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_cache_counters_increment_after_reads() {
+        use crate::cache::{CachedStorageReader, HotStateCache, SharedCache};
+        use crate::onchain::StorageReader;
+        use alloy_primitives::{Address, B256, U256};
+
+        struct OneSlot;
+        impl StorageReader for OneSlot {
+            fn read_storage(&self, _: Address, _: U256) -> Option<B256> {
+                Some(B256::with_last_byte(1))
+            }
+        }
+
+        let cache: SharedCache = Arc::new(Mutex::new(HotStateCache::new(16)));
+        let reader = CachedStorageReader::new_shared(OneSlot, cache.clone());
+        let metrics = ChainMetrics::new(10);
+
+        reader.read_storage(Address::ZERO, U256::ZERO); // miss
+        reader.read_storage(Address::ZERO, U256::ZERO); // hit
+        reader.read_storage(Address::ZERO, U256::ZERO); // hit
+        metrics.record_cache_stats(&cache.lock().unwrap().stats());
+
+        assert_eq!(metrics.cache_hits(), 2);
+        assert_eq!(metrics.cache_misses(), 1);
+        assert!((metrics.cache_hit_rate() - 2.0 / 3.0).abs() < 1e-9);
+
+        reader.read_storage(Address::ZERO, U256::from(1)); // miss (new slot)
+        metrics.record_cache_stats(&reader.stats());
+        let snap = metrics.snapshot();
+        assert_eq!(snap.cache_hits, 2);
+        assert_eq!(snap.cache_misses, 2);
+        assert!((snap.cache_hit_rate() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cache_hit_rate_zero_when_cold() {
+        let metrics = ChainMetrics::new(10);
+        assert_eq!(metrics.cache_hit_rate(), 0.0);
+        assert_eq!(MetricsSnapshot::default().cache_hit_rate(), 0.0);
+    }
+
     // ── PhaseTimer ────────────────────────────────────────────────────────────
 
     #[test]
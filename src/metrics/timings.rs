@@ -0,0 +1,181 @@
+//! Measured build and sign times of locally produced blocks.
+//!
+//! `PoaPayloadBuilder` times every payload it builds and seals, but it doesn't know
+//! which of them becomes canonical (a slot may be rebuilt several times, and a
+//! competing signer may win). It records each sealed payload by hash in
+//! [`BlockTimings`]; the block monitoring task then [`commit`](BlockTimings::commit)s
+//! the hashes of canonical blocks, turning them into the recent timings served by
+//! `meow_getBlockTimings`. Blocks imported from peers have no timing.
+//!
+//! Canonical timings are kept in a [`BlockWindow`], so a locally built block
+//! committed by a reorg replaces the timings of the branch it displaced.
+
+use crate::monitor::BlockWindow;
+use alloy_primitives::B256;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Sealed payloads awaiting a canonical notification. Older ones were superseded.
+pub const PENDING_TIMINGS_CAPACITY: usize = 64;
+
+/// Canonical block timings retained for `meow_getBlockTimings`.
+pub const RECENT_TIMINGS_CAPACITY: usize = 1024;
+
+/// Build and sign time of one block produced by this node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockTiming {
+    /// Block number.
+    pub block_number: u64,
+    /// Hash of the sealed block.
+    pub block_hash: B256,
+    /// Milliseconds spent executing transactions and computing the state root.
+    pub build_ms: u64,
+    /// Milliseconds spent signing the header (0 for unsigned blocks).
+    pub sign_ms: u64,
+}
+
+impl BlockTiming {
+    /// Build time as a [`Duration`].
+    pub fn build_duration(&self) -> Duration {
+        Duration::from_millis(self.build_ms)
+    }
+
+    /// Sign time as a [`Duration`].
+    pub fn sign_duration(&self) -> Duration {
+        Duration::from_millis(self.sign_ms)
+    }
+}
+
+/// Timings of sealed payloads and of the canonical blocks among them.
+#[derive(Debug, Clone)]
+pub struct BlockTimings {
+    /// Sealed by the payload builder, not yet seen canonical (oldest first).
+    pending: VecDeque<BlockTiming>,
+    /// Canonical blocks, by block number.
+    recent: BlockWindow<BlockTiming>,
+}
+
+/// [`BlockTimings`] shared between the payload builder, the monitoring loop and RPC.
+pub type SharedBlockTimings = Arc<Mutex<BlockTimings>>;
+
+impl Default for BlockTimings {
+    fn default() -> Self {
+        Self {
+            pending: VecDeque::new(),
+            recent: BlockWindow::new(RECENT_TIMINGS_CAPACITY),
+        }
+    }
+}
+
+impl BlockTimings {
+    /// Create an empty, shareable store.
+    pub fn shared() -> SharedBlockTimings {
+        Arc::new(Mutex::new(Self::default()))
+    }
+
+    /// Record a payload the builder sealed, evicting the oldest pending one when full.
+    pub fn record_sealed(&mut self, timing: BlockTiming) {
+        if self.pending.len() == PENDING_TIMINGS_CAPACITY {
+            self.pending.pop_front();
+        }
+        self.pending.push_back(timing);
+    }
+
+    /// Mark `block_hash` canonical. Returns its timing if this node built it.
+    pub fn commit(&mut self, block_hash: B256) -> Option<BlockTiming> {
+        let index = self
+            .pending
+            .iter()
+            .position(|t| t.block_hash == block_hash)?;
+        let timing = self.pending.remove(index)?;
+        // Payloads for this height or below can no longer become canonical.
+        self.pending
+            .retain(|t| t.block_number > timing.block_number);
+        self.recent.record(timing.block_number, timing);
+        Some(timing)
+    }
+
+    /// The last `count` canonical block timings, oldest first.
+    pub fn recent(&self, count: usize) -> Vec<BlockTiming> {
+        self.recent
+            .latest(count)
+            .map(|(_, timing)| *timing)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(block_number: u64, hash: u8, build_ms: u64) -> BlockTiming {
+        BlockTiming {
+            block_number,
+            block_hash: B256::repeat_byte(hash),
+            build_ms,
+            sign_ms: 1,
+        }
+    }
+
+    #[test]
+    fn test_only_committed_payloads_become_recent() {
+        let mut timings = BlockTimings::default();
+        // Block 1 rebuilt twice; the second build is the canonical one
+        timings.record_sealed(timing(1, 0xa1, 10));
+        timings.record_sealed(timing(1, 0xa2, 12));
+        timings.record_sealed(timing(2, 0xb1, 20));
+
+        assert_eq!(
+            timings.commit(B256::repeat_byte(0xa2)),
+            Some(timing(1, 0xa2, 12))
+        );
+        // Superseded build of block 1 is dropped, block 2 still pending
+        assert_eq!(timings.commit(B256::repeat_byte(0xa1)), None);
+        assert_eq!(
+            timings.commit(B256::repeat_byte(0xb1)),
+            Some(timing(2, 0xb1, 20))
+        );
+        // Imported from a peer
+        assert_eq!(timings.commit(B256::repeat_byte(0xc1)), None);
+
+        assert_eq!(
+            timings.recent(10),
+            vec![timing(1, 0xa2, 12), timing(2, 0xb1, 20)]
+        );
+        assert_eq!(timings.recent(1), vec![timing(2, 0xb1, 20)]);
+    }
+
+    #[test]
+    fn test_capacities_are_bounded() {
+        let mut timings = BlockTimings::default();
+        for n in 0..(PENDING_TIMINGS_CAPACITY as u64 + 1) {
+            timings.record_sealed(BlockTiming {
+                block_number: n,
+                block_hash: B256::with_last_byte(n as u8),
+                build_ms: n,
+                sign_ms: 0,
+            });
+        }
+        // The oldest pending payload was evicted
+        assert_eq!(timings.commit(B256::with_last_byte(0)), None);
+        assert!(timings.commit(B256::with_last_byte(1)).is_some());
+
+        let mut timings = BlockTimings::default();
+        for n in 0..(RECENT_TIMINGS_CAPACITY as u64 + 5) {
+            let hash = B256::from(alloy_primitives::U256::from(n));
+            timings.record_sealed(BlockTiming {
+                block_number: n,
+                block_hash: hash,
+                build_ms: n,
+                sign_ms: 0,
+            });
+            timings.commit(hash);
+        }
+        let recent = timings.recent(usize::MAX);
+        assert_eq!(recent.len(), RECENT_TIMINGS_CAPACITY);
+        assert_eq!(recent[0].block_number, 5);
+    }
+}
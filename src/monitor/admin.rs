@@ -0,0 +1,82 @@
+//! Miner Proxy admin change detection.
+//!
+//! The Miner Proxy receives every block's fees, and its EIP-1967 admin can upgrade
+//! it to send them anywhere. Genesis sets the admin to the governance Safe, so a
+//! different admin at runtime is either a deliberate governance action or a
+//! compromise. The block monitoring loop reads the slot after each commit and
+//! [`ProxyAdminWatch`] reports every change away from the last admin seen, so the
+//! operator is warned once per change rather than once per block.
+
+use alloy_primitives::Address;
+
+/// Tracks the Miner Proxy admin against its genesis value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProxyAdminWatch {
+    genesis: Option<Address>,
+    last: Option<Address>,
+}
+
+impl ProxyAdminWatch {
+    /// Start watching from the genesis admin (`None` = no Miner Proxy in genesis).
+    pub fn new(genesis: Option<Address>) -> Self {
+        Self {
+            genesis,
+            last: genesis,
+        }
+    }
+
+    /// The admin set in genesis.
+    pub fn genesis(&self) -> Option<Address> {
+        self.genesis
+    }
+
+    /// Record the live admin. Returns it if it changed since the last observation
+    /// and differs from the genesis admin (a change back to genesis isn't reported).
+    pub fn observe(&mut self, live: Option<Address>) -> Option<Address> {
+        let changed = live != self.last;
+        self.last = live;
+        if changed && live != self.genesis {
+            live
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genesis::addresses::EIP1967_ADMIN_SLOT;
+    use crate::genesis::{create_dev_genesis, GOVERNANCE_SAFE_ADDRESS, MINER_PROXY_ADDRESS};
+    use crate::onchain::{read_miner_proxy_admin, GenesisStorageReader};
+    use alloy_primitives::B256;
+
+    #[test]
+    fn test_admin_change_detected_once() {
+        let mut genesis = create_dev_genesis();
+        let admin = read_miner_proxy_admin(&GenesisStorageReader::from_genesis(&genesis));
+        assert_eq!(admin, Some(GOVERNANCE_SAFE_ADDRESS));
+        let mut watch = ProxyAdminWatch::new(admin);
+        assert_eq!(watch.observe(admin), None);
+
+        // Overwrite the admin slot, as a changeAdmin call would
+        let attacker = Address::with_last_byte(0xbd);
+        let storage = genesis
+            .alloc
+            .get_mut(&MINER_PROXY_ADDRESS)
+            .and_then(|account| account.storage.as_mut())
+            .unwrap();
+        storage.insert(
+            EIP1967_ADMIN_SLOT,
+            B256::left_padding_from(attacker.as_slice()),
+        );
+        let live = read_miner_proxy_admin(&GenesisStorageReader::from_genesis(&genesis));
+        assert_eq!(live, Some(attacker));
+        assert_eq!(watch.observe(live), Some(attacker));
+        // Reported once, not on every block
+        assert_eq!(watch.observe(live), None);
+        // Restoring the genesis admin isn't an alert
+        assert_eq!(watch.observe(admin), None);
+        assert_eq!(watch.genesis(), Some(GOVERNANCE_SAFE_ADDRESS));
+    }
+}
@@ -0,0 +1,198 @@
+//! Liveness and readiness probes for load balancers and Kubernetes.
+//!
+//! [`start_health_server`] serves two plain HTTP endpoints on a dedicated port:
+//!
+//! - `GET /health` — liveness: 200 whenever the process is serving requests
+//! - `GET /ready` — readiness: 200 if a block was produced or imported within the
+//!   block-time budget ([`BLOCK_TIME_BUDGET_MULTIPLIER`] × the block interval),
+//!   503 otherwise (including before the first block)
+//!
+//! Like the metrics server, it uses raw tokio TCP with no framework dependency.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+use super::exceeds_block_time_budget;
+
+/// How long a client may take to send its request before the connection is dropped.
+pub const HEALTH_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Block arrival state shared between the monitoring loop and the health server.
+#[derive(Debug)]
+pub struct HealthState {
+    /// Expected block interval.
+    interval: Duration,
+    /// Unix milliseconds of the last block arrival (`0` = none yet).
+    last_block_ms: AtomicU64,
+}
+
+impl HealthState {
+    /// Create a shareable state for a chain producing a block every `interval`.
+    pub fn shared(interval: Duration) -> Arc<Self> {
+        Arc::new(Self {
+            interval,
+            last_block_ms: AtomicU64::new(0),
+        })
+    }
+
+    /// Record that a block was produced or imported at unix time `now_ms`.
+    pub fn record_block(&self, now_ms: u64) {
+        self.last_block_ms.store(now_ms, Ordering::Relaxed);
+    }
+
+    /// Milliseconds since the last block, or `None` before the first one.
+    pub fn block_age_ms(&self, now_ms: u64) -> Option<u64> {
+        match self.last_block_ms.load(Ordering::Relaxed) {
+            0 => None,
+            last => Some(now_ms.saturating_sub(last)),
+        }
+    }
+
+    /// Whether the last block is recent enough for the node to take traffic.
+    pub fn is_ready(&self, now_ms: u64) -> bool {
+        self.block_age_ms(now_ms)
+            .is_some_and(|age| !exceeds_block_time_budget(age, self.interval.as_millis() as u64))
+    }
+}
+
+/// Current unix time in milliseconds.
+pub fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+/// Status code and JSON body for a request to `path`.
+pub fn respond(path: &str, health: &HealthState, now_ms: u64) -> (u16, String) {
+    match path {
+        "/health" => (200, r#"{"status":"ok"}"#.to_string()),
+        "/ready" => {
+            let ready = health.is_ready(now_ms);
+            let age = health
+                .block_age_ms(now_ms)
+                .map_or("null".to_string(), |ms| ms.to_string());
+            let status = if ready { 200 } else { 503 };
+            (
+                status,
+                format!(r#"{{"ready":{ready},"lastBlockAgeMs":{age}}}"#),
+            )
+        }
+        _ => (404, r#"{"error":"not found"}"#.to_string()),
+    }
+}
+
+/// Start the probe server on `0.0.0.0:{port}` in a background task.
+pub async fn start_health_server(port: u16, health: Arc<HealthState>) -> eyre::Result<()> {
+    let listener = TcpListener::bind(format!("0.0.0.0:{}", port)).await?;
+    tokio::spawn(serve(listener, health));
+    Ok(())
+}
+
+async fn serve(listener: TcpListener, health: Arc<HealthState>) {
+    loop {
+        let Ok((stream, _)) = listener.accept().await else {
+            continue;
+        };
+        // One task per connection, so a client that never sends its request can't
+        // hold up the probes behind it
+        tokio::spawn(handle_connection(stream, health.clone()));
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, health: Arc<HealthState>) {
+    let mut buf = [0u8; 1024];
+    let Ok(read) = tokio::time::timeout(HEALTH_REQUEST_TIMEOUT, stream.read(&mut buf)).await else {
+        return;
+    };
+    let n = read.unwrap_or(0);
+    let request = String::from_utf8_lossy(&buf[..n]);
+    // Request line: "GET /ready HTTP/1.1"
+    let path = request.split_whitespace().nth(1).unwrap_or("/");
+    let (status, body) = respond(path, &health, unix_millis());
+    let reason = match status {
+        200 => "OK",
+        503 => "Service Unavailable",
+        _ => "Not Found",
+    };
+    let response = format!(
+        "HTTP/1.1 {status} {reason}\r\n\
+         Content-Type: application/json\r\n\
+         Content-Length: {}\r\n\
+         Connection: close\r\n\
+         \r\n\
+         {body}",
+        body.len()
+    );
+    let _ = stream.write_all(response.as_bytes()).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1_700_000_000_000;
+
+    #[test]
+    fn test_ready_within_block_time_budget() {
+        let health = HealthState::shared(Duration::from_secs(2));
+        // No block yet: alive but not ready
+        assert_eq!(respond("/health", &health, NOW).0, 200);
+        assert_eq!(
+            respond("/ready", &health, NOW),
+            (503, r#"{"ready":false,"lastBlockAgeMs":null}"#.to_string())
+        );
+
+        health.record_block(NOW);
+        assert_eq!(respond("/ready", &health, NOW + 6_000).0, 200);
+        // Past 3 × 2s without a block
+        assert_eq!(respond("/ready", &health, NOW + 6_001).0, 503);
+        assert_eq!(respond("/other", &health, NOW).0, 404);
+    }
+
+    #[tokio::test]
+    async fn test_health_server_responds() {
+        let health = HealthState::shared(Duration::from_secs(2));
+        health.record_block(unix_millis());
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(serve(listener, health));
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"GET /ready HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"), "{response}");
+        assert!(response.contains(r#""ready":true"#), "{response}");
+    }
+
+    #[tokio::test]
+    async fn test_stalled_client_does_not_block_probes() {
+        let health = HealthState::shared(Duration::from_secs(2));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(serve(listener, health));
+
+        // Connects but never sends a request
+        let _stalled = TcpStream::connect(addr).await.unwrap();
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"GET /health HTTP/1.1\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        tokio::time::timeout(Duration::from_secs(1), stream.read_to_string(&mut response))
+            .await
+            .expect("probe answered while another client stalls")
+            .unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"), "{response}");
+    }
+}
@@ -0,0 +1,101 @@
+//! Signer liveness safe mode (`--safe-mode`).
+//!
+//! With too few signers online, out-of-turn blocks from the remaining ones can
+//! race each other and fork the chain. The block monitoring loop counts the
+//! distinct signers of the last epoch in the [`SignerIndex`] and, when that falls
+//! below the on-chain `SignerRegistry` threshold, marks the node degraded. While
+//! degraded the payload builder only seals in-turn blocks; the state clears on its
+//! own once enough signers are sealing again.
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use super::signer_index::SignerIndex;
+
+/// Degraded flag shared between the monitoring loop, payload builder and RPC.
+#[derive(Debug, Default)]
+pub struct SignerLiveness {
+    degraded: AtomicBool,
+}
+
+impl SignerLiveness {
+    /// Create a shareable state, starting healthy.
+    pub fn shared() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Whether too few signers were online at the last evaluation.
+    pub fn is_degraded(&self) -> bool {
+        self.degraded.load(Ordering::Relaxed)
+    }
+
+    /// Set the state from `online` distinct signers against `threshold` (`0` =
+    /// never degraded). Returns the new state if it changed.
+    pub fn update(&self, online: usize, threshold: u64) -> Option<bool> {
+        let degraded = threshold > 0 && (online as u64) < threshold;
+        let was = self.degraded.swap(degraded, Ordering::Relaxed);
+        (was != degraded).then_some(degraded)
+    }
+
+    /// Evaluate the last `epoch` blocks of `index` (fewer near genesis) against
+    /// `threshold`, as of chain head `head`.
+    ///
+    /// Returns `None` without changing the state until the index covers the whole
+    /// window, so a freshly started node isn't degraded by its first few blocks.
+    pub fn evaluate(
+        &self,
+        index: &SignerIndex,
+        head: u64,
+        epoch: u64,
+        threshold: u64,
+    ) -> Option<(bool, usize)> {
+        let window = epoch.min(head).min(index.capacity() as u64) as usize;
+        let recent = index.latest(window);
+        if window == 0 || recent.len() < window {
+            return None;
+        }
+        let online = recent
+            .iter()
+            .map(|(_, entry)| entry.signer)
+            .collect::<HashSet<_>>()
+            .len();
+        self.update(online, threshold)
+            .map(|degraded| (degraded, online))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Address;
+
+    #[test]
+    fn test_degrades_below_threshold_and_recovers() {
+        let liveness = SignerLiveness::shared();
+        let mut index = SignerIndex::new(100);
+        let (a, b) = (Address::with_last_byte(1), Address::with_last_byte(2));
+
+        // Only block 10 indexed since startup: the window isn't covered yet
+        index.record(10, a, true);
+        assert_eq!(liveness.evaluate(&index, 10, 4, 2), None);
+        assert!(!liveness.is_degraded());
+
+        for number in 11..=13 {
+            index.record(number, a, false);
+        }
+        assert_eq!(liveness.evaluate(&index, 13, 4, 2), Some((true, 1)));
+        assert!(liveness.is_degraded());
+        // No transition, no report
+        index.record(14, a, false);
+        assert_eq!(liveness.evaluate(&index, 14, 4, 2), None);
+
+        index.record(15, b, true);
+        assert_eq!(liveness.evaluate(&index, 15, 4, 2), Some((false, 2)));
+        assert!(!liveness.is_degraded());
+
+        // Threshold 0 disables the rule
+        assert_eq!(liveness.update(0, 0), None);
+        assert!(!liveness.is_degraded());
+    }
+}
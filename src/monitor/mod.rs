@@ -0,0 +1,299 @@
+//! Block monitoring helpers for the canonical-state notification loop in `main.rs`.
+//!
+//! During a catch-up burst after downtime the node can commit thousands of blocks
+//! in a few seconds. Logging each one buries anything useful, so [`CatchUpTracker`]
+//! classifies each new head by age: blocks older than `max_age` are folded into a
+//! periodic [`CatchUpSummary`], and per-block detail resumes once the node is
+//! back at the tip.
+//!
+//! [`RecentSigners`] keeps the signers of the latest blocks so the signer
+//! diversity rule can be evaluated without re-reading headers, along with the
+//! last block each signer sealed.
+//!
+//! [`signer_index`] keeps who sealed each of the last few thousand blocks, and
+//! whether in turn, for RPC methods reporting on block authorship.
+//!
+//! [`health`] serves liveness/readiness probes based on block arrival times.
+//!
+//! [`liveness`] tracks whether enough signers are online for `--safe-mode`.
+//!
+//! [`performance`] summarises each signer's production over a window of the index.
+//!
+//! [`admin`] warns when the Miner Proxy admin moves away from its genesis value.
+//!
+//! [`reorg`] flags reorgs deeper than `--max-reorg-depth`.
+//!
+//! [`timelock`] indexes the operations scheduled on the Timelock.
+//!
+//! [`signed_blocks`] carries each committed block and its sealer to
+//! `meow_subscribeSignedBlocks`.
+//!
+//! [`window`] is the bounded per-block store behind the signer index and the
+//! block timings.
+
+pub mod admin;
+pub mod health;
+pub mod liveness;
+pub mod performance;
+pub mod reorg;
+pub mod signed_blocks;
+pub mod signer_index;
+pub mod timelock;
+pub mod window;
+
+pub use admin::ProxyAdminWatch;
+pub use health::{start_health_server, HealthState};
+pub use liveness::SignerLiveness;
+pub use performance::{PerformanceReport, SignerPerformance};
+pub use reorg::ReorgGuard;
+pub use signed_blocks::{
+    signed_block_channel, SignedBlock, SignedBlockSender, SIGNED_BLOCK_CHANNEL_CAPACITY,
+};
+pub use signer_index::{
+    IndexedSigner, SharedSignerIndex, SignerIndex, DEFAULT_SIGNER_INDEX_CAPACITY,
+};
+pub use timelock::{backfill_timelock_schedule, SharedTimelockSchedule, TimelockSchedule};
+pub use window::BlockWindow;
+
+use alloy_primitives::Address;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A block gap longer than this many block intervals is over budget.
+///
+/// 3× avoids false positives from normal dev-mining timer jitter (~2× is common
+/// at sub-second intervals).
+pub const BLOCK_TIME_BUDGET_MULTIPLIER: u64 = 3;
+
+/// Whether `elapsed_ms` since the last block exceeds the block-time budget for
+/// `interval_ms` blocks. A zero interval never exceeds it.
+pub fn exceeds_block_time_budget(elapsed_ms: u64, interval_ms: u64) -> bool {
+    interval_ms > 0 && elapsed_ms > interval_ms * BLOCK_TIME_BUDGET_MULTIPLIER
+}
+
+/// Default number of catch-up blocks folded into one summary line.
+pub const DEFAULT_SUMMARY_EVERY: u64 = 500;
+
+/// Aggregate of blocks processed while catching up.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CatchUpSummary {
+    /// First block number in this summary.
+    pub first_block: u64,
+    /// Last block number in this summary.
+    pub last_block: u64,
+    /// Number of blocks summarized.
+    pub blocks: u64,
+    /// Total transactions across the summarized blocks.
+    pub txs: u64,
+}
+
+/// What the monitoring loop should do with a new head.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HeadDecision {
+    /// Print the usual per-block detail for this head.
+    pub log_block: bool,
+    /// A completed catch-up summary to print before anything else, if any.
+    pub summary: Option<CatchUpSummary>,
+}
+
+/// Decides between per-block logging and catch-up summaries based on block age.
+#[derive(Debug, Clone)]
+pub struct CatchUpTracker {
+    /// Heads older than this (by header timestamp) count as catch-up. `ZERO` disables.
+    max_age: Duration,
+    /// Emit a summary after this many catch-up blocks.
+    summary_every: u64,
+    pending: Option<CatchUpSummary>,
+}
+
+impl CatchUpTracker {
+    /// Create a tracker. A zero `max_age` logs every block (summaries disabled).
+    pub fn new(max_age: Duration) -> Self {
+        Self {
+            max_age,
+            summary_every: DEFAULT_SUMMARY_EVERY,
+            pending: None,
+        }
+    }
+
+    /// Override how many catch-up blocks go into each summary (at least 1).
+    pub fn with_summary_every(mut self, blocks: u64) -> Self {
+        self.summary_every = blocks.max(1);
+        self
+    }
+
+    /// Whether the tracker is currently folding blocks into a summary.
+    pub fn is_catching_up(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Classify a new head with header `timestamp` (unix seconds) observed at `now_secs`.
+    pub fn observe(
+        &mut self,
+        block_number: u64,
+        timestamp: u64,
+        tx_count: usize,
+        now_secs: u64,
+    ) -> HeadDecision {
+        let age = Duration::from_secs(now_secs.saturating_sub(timestamp));
+        if self.max_age.is_zero() || age <= self.max_age {
+            // Caught up: flush whatever was pending and resume per-block detail.
+            return HeadDecision {
+                log_block: true,
+                summary: self.pending.take(),
+            };
+        }
+
+        let pending = self.pending.get_or_insert_with(|| CatchUpSummary {
+            first_block: block_number,
+            ..Default::default()
+        });
+        pending.last_block = block_number;
+        pending.blocks += 1;
+        pending.txs += tx_count as u64;
+
+        let summary = if pending.blocks >= self.summary_every {
+            self.pending.take()
+        } else {
+            None
+        };
+        HeadDecision {
+            log_block: false,
+            summary,
+        }
+    }
+}
+
+/// Number of recent block signers retained by [`RecentSigners`].
+pub const RECENT_SIGNERS_CAPACITY: usize = 256;
+
+/// Signers of the most recent canonical blocks, oldest first.
+#[derive(Debug, Clone, Default)]
+pub struct RecentSigners {
+    signers: VecDeque<Address>,
+    /// Most recent block number sealed by each signer (kept across [`Self::clear`]).
+    last_sealed: HashMap<Address, u64>,
+}
+
+/// [`RecentSigners`] shared between the monitoring loop and RPC.
+pub type SharedRecentSigners = Arc<Mutex<RecentSigners>>;
+
+impl RecentSigners {
+    /// Create an empty, shareable buffer.
+    pub fn shared() -> SharedRecentSigners {
+        Arc::new(Mutex::new(Self::default()))
+    }
+
+    /// Record the signer of the next canonical block, evicting the oldest when full.
+    pub fn record(&mut self, signer: Address) {
+        if self.signers.len() == RECENT_SIGNERS_CAPACITY {
+            self.signers.pop_front();
+        }
+        self.signers.push_back(signer);
+    }
+
+    /// Record that `signer` sealed canonical block `block_number`.
+    pub fn record_sealed(&mut self, signer: Address, block_number: u64) {
+        self.record(signer);
+        self.last_sealed.insert(signer, block_number);
+    }
+
+    /// Most recent block sealed by `signer`, if any was seen since startup.
+    pub fn last_sealed(&self, signer: &Address) -> Option<u64> {
+        self.last_sealed.get(signer).copied()
+    }
+
+    /// Forget the recent window, e.g. after a reorg.
+    ///
+    /// Last-sealed block numbers are kept; the new branch's blocks overwrite them.
+    pub fn clear(&mut self) {
+        self.signers.clear();
+    }
+
+    /// Recorded signers, oldest first.
+    pub fn to_vec(&self) -> Vec<Address> {
+        self.signers.iter().copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1_700_000_000;
+
+    #[test]
+    fn test_fresh_blocks_logged_individually() {
+        let mut tracker = CatchUpTracker::new(Duration::from_secs(60));
+        for n in 1..=5 {
+            let d = tracker.observe(n, NOW - 2, 1, NOW);
+            assert!(d.log_block);
+            assert!(d.summary.is_none());
+        }
+        assert!(!tracker.is_catching_up());
+    }
+
+    #[test]
+    fn test_burst_of_old_blocks_is_summarized() {
+        let mut tracker = CatchUpTracker::new(Duration::from_secs(60)).with_summary_every(100);
+        let mut logged = 0;
+        let mut summaries = Vec::new();
+
+        // 250 blocks from an hour ago, 2 txs each
+        for n in 1..=250 {
+            let d = tracker.observe(n, NOW - 3600 + n, 2, NOW);
+            logged += d.log_block as usize;
+            summaries.extend(d.summary);
+        }
+
+        assert_eq!(logged, 0, "no per-block lines during catch-up");
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(
+            summaries[0],
+            CatchUpSummary {
+                first_block: 1,
+                last_block: 100,
+                blocks: 100,
+                txs: 200
+            }
+        );
+        assert_eq!(summaries[1].first_block, 101);
+        assert!(tracker.is_catching_up());
+
+        // Back at the tip: remaining 50 blocks flushed, detail resumes
+        let d = tracker.observe(251, NOW, 0, NOW);
+        assert!(d.log_block);
+        let tail = d.summary.unwrap();
+        assert_eq!(
+            (tail.first_block, tail.last_block, tail.blocks),
+            (201, 250, 50)
+        );
+        assert!(!tracker.is_catching_up());
+    }
+
+    #[test]
+    fn test_zero_max_age_disables_summaries() {
+        let mut tracker = CatchUpTracker::new(Duration::ZERO);
+        let d = tracker.observe(1, 0, 0, NOW);
+        assert!(d.log_block);
+        assert!(d.summary.is_none());
+    }
+
+    #[test]
+    fn test_future_timestamp_is_not_catch_up() {
+        let mut tracker = CatchUpTracker::new(Duration::from_secs(60));
+        assert!(tracker.observe(1, NOW + 10, 0, NOW).log_block);
+    }
+
+    #[test]
+    fn test_recent_signers_evicts_oldest() {
+        let mut recent = RecentSigners::default();
+        for n in 0..RECENT_SIGNERS_CAPACITY + 2 {
+            recent.record(Address::with_last_byte(n as u8));
+        }
+        let signers = recent.to_vec();
+        assert_eq!(signers.len(), RECENT_SIGNERS_CAPACITY);
+        assert_eq!(signers[0], Address::with_last_byte(2));
+    }
+}
@@ -0,0 +1,147 @@
+//! Per-signer production report for `meow_getSignerPerformance`.
+//!
+//! Built from the [`SignerIndex`] window: each block counts as produced, in turn
+//! or out of turn for its sealer, and a block sealed out of turn counts as a
+//! missed slot for the signer that was in turn at that height. The in-turn signer
+//! is [`PoaChainSpec::expected_signer`] over the current signer list, so heights
+//! before a signer list change may be attributed to the wrong signer.
+//!
+//! [`SignerIndex`]: super::SignerIndex
+
+use alloy_primitives::Address;
+use serde::Serialize;
+
+use super::signer_index::SignerIndex;
+use crate::chainspec::PoaChainSpec;
+
+/// Block production of one signer over the report window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignerPerformance {
+    pub address: Address,
+    /// Blocks sealed by this signer
+    pub produced: u64,
+    /// Blocks sealed while in turn
+    pub in_turn: u64,
+    /// Blocks sealed out of turn
+    pub out_of_turn: u64,
+    /// In-turn slots sealed by another signer instead
+    pub missed_in_turn: u64,
+}
+
+impl SignerPerformance {
+    fn new(address: Address) -> Self {
+        Self {
+            address,
+            produced: 0,
+            in_turn: 0,
+            out_of_turn: 0,
+            missed_in_turn: 0,
+        }
+    }
+}
+
+/// Response for `meow_getSignerPerformance`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PerformanceReport {
+    /// First block of the window (`None` if nothing is indexed yet)
+    pub from_block: Option<u64>,
+    /// Last block of the window
+    pub to_block: Option<u64>,
+    /// Blocks in the window; fewer than requested if the index holds fewer
+    pub blocks: u64,
+    /// Current signers in rotation order, then any former signer that sealed in
+    /// the window
+    pub signers: Vec<SignerPerformance>,
+}
+
+impl PerformanceReport {
+    /// Report on the newest `count` blocks of `index`, against the current
+    /// signers of `chain_spec`.
+    pub fn from_index(index: &SignerIndex, count: usize, chain_spec: &PoaChainSpec) -> Self {
+        let window = index.latest(count);
+        let mut report: Vec<SignerPerformance> = chain_spec
+            .effective_signers()
+            .into_iter()
+            .map(SignerPerformance::new)
+            .collect();
+
+        for (number, sealed) in &window {
+            let performance = performance_of(&mut report, sealed.signer);
+            performance.produced += 1;
+            if sealed.in_turn {
+                performance.in_turn += 1;
+                continue;
+            }
+            performance.out_of_turn += 1;
+            let Some(expected) = chain_spec.expected_signer(*number) else {
+                continue;
+            };
+            if expected != sealed.signer {
+                performance_of(&mut report, expected).missed_in_turn += 1;
+            }
+        }
+
+        Self {
+            from_block: window.first().map(|(number, _)| *number),
+            to_block: window.last().map(|(number, _)| *number),
+            blocks: window.len() as u64,
+            signers: report,
+        }
+    }
+}
+
+/// Entry of `address` in `report`, appended if it isn't a current signer.
+fn performance_of(report: &mut Vec<SignerPerformance>, address: Address) -> &mut SignerPerformance {
+    let position = match report.iter().position(|p| p.address == address) {
+        Some(position) => position,
+        None => {
+            report.push(SignerPerformance::new(address));
+            report.len() - 1
+        }
+    };
+    &mut report[position]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missed_in_turn_slots() {
+        let signers: Vec<_> = (0..3).map(Address::with_last_byte).collect();
+        let chain_spec = PoaChainSpec::dev_chain();
+        chain_spec.update_live_signers(signers.clone());
+        let mut index = SignerIndex::new(100);
+        // Blocks 10..=18: signer 1 is in turn at 10, 13 and 16 but never seals;
+        // signer 2 covers 10 and signer 0 covers 13 and 16
+        for number in 10..=18u64 {
+            let expected = (number % 3) as usize;
+            let sealer = match (expected, number) {
+                (1, 10) => 2,
+                (1, _) => 0,
+                _ => expected,
+            };
+            index.record(number, signers[sealer], sealer == expected);
+        }
+
+        let report = PerformanceReport::from_index(&index, 100, &chain_spec);
+        assert_eq!(report.from_block, Some(10));
+        assert_eq!(report.to_block, Some(18));
+        assert_eq!(report.blocks, 9);
+
+        let [s0, s1, s2] = report.signers[..] else {
+            panic!("expected three signers, got {:?}", report.signers);
+        };
+        assert_eq!((s0.produced, s0.in_turn, s0.out_of_turn), (5, 3, 2));
+        assert_eq!((s1.produced, s1.missed_in_turn), (0, 3));
+        assert_eq!((s2.produced, s2.in_turn, s2.out_of_turn), (4, 3, 1));
+        assert_eq!(s0.missed_in_turn + s2.missed_in_turn, 0);
+
+        // Only the last three blocks (16..=18): one miss
+        let report = PerformanceReport::from_index(&index, 3, &chain_spec);
+        assert_eq!(report.blocks, 3);
+        assert_eq!(report.signers[1].missed_in_turn, 1);
+    }
+}
@@ -0,0 +1,85 @@
+//! Maximum reorg depth guard (`--max-reorg-depth`).
+//!
+//! In-turn preference makes POA fork choice settle quickly, so reorgs should be a
+//! block or two deep. A deeper one suggests the node was partitioned and is now
+//! following a long fork, possibly a malicious one. The block monitoring loop
+//! passes the length of every reverted segment to [`ReorgGuard::check`]; past the
+//! limit it logs an error and, with `--halt-on-deep-reorg`, the payload builder
+//! stops sealing until the node is restarted.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Reorg depth limit shared between the monitoring loop and payload builder.
+#[derive(Debug, Default)]
+pub struct ReorgGuard {
+    /// Deepest reorg accepted without alarm (`0` = no limit).
+    max_depth: u64,
+    /// Stop sealing once the limit is exceeded.
+    halt: bool,
+    halted: AtomicBool,
+}
+
+impl ReorgGuard {
+    /// Create a guard for reorgs deeper than `max_depth` (`0` = disabled), halting
+    /// block production on one when `halt` is set.
+    pub fn new(max_depth: u64, halt: bool) -> Self {
+        Self {
+            max_depth,
+            halt,
+            halted: AtomicBool::new(false),
+        }
+    }
+
+    /// Create a shareable guard, see [`Self::new`].
+    pub fn shared(max_depth: u64, halt: bool) -> Arc<Self> {
+        Arc::new(Self::new(max_depth, halt))
+    }
+
+    /// Configured depth limit (`0` = disabled).
+    pub fn max_depth(&self) -> u64 {
+        self.max_depth
+    }
+
+    /// Whether block production was halted by a deep reorg.
+    pub fn is_halted(&self) -> bool {
+        self.halted.load(Ordering::Relaxed)
+    }
+
+    /// Check a reorg that reverted `depth` blocks. Returns whether it exceeds the
+    /// limit, halting block production if configured to.
+    pub fn check(&self, depth: u64) -> bool {
+        let exceeded = self.max_depth > 0 && depth > self.max_depth;
+        if exceeded && self.halt {
+            self.halted.store(true, Ordering::Relaxed);
+        }
+        exceeded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deep_reorg_fires_and_halts() {
+        let guard = ReorgGuard::new(3, true);
+        assert!(!guard.check(1));
+        assert!(!guard.check(3));
+        assert!(!guard.is_halted());
+
+        // A synthetic 10-block reorg
+        assert!(guard.check(10));
+        assert!(guard.is_halted());
+
+        // Without --halt-on-deep-reorg it's only reported
+        let guard = ReorgGuard::new(3, false);
+        assert!(guard.check(10));
+        assert!(!guard.is_halted());
+
+        // 0 disables the guard
+        let guard = ReorgGuard::new(0, true);
+        assert!(!guard.check(u64::MAX));
+        assert!(!guard.is_halted());
+    }
+}
@@ -0,0 +1,39 @@
+//! POA-enriched head notifications for `meow_subscribeSignedBlocks`.
+//!
+//! `eth_subscribe("newHeads")` leaves clients to recover the sealer from
+//! `extraData` themselves. The block monitoring loop already recovers each
+//! committed block's signer, so it publishes a [`SignedBlock`] per block on a
+//! broadcast channel that the subscription forwards.
+
+use alloy_primitives::{Address, B256};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+/// Notifications buffered per subscriber before a slow subscriber starts dropping them.
+pub const SIGNED_BLOCK_CHANNEL_CAPACITY: usize = 256;
+
+/// A committed block with its POA authorship.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedBlock {
+    pub number: u64,
+    pub hash: B256,
+    /// Recovered sealer, `null` for blocks without a valid seal (genesis).
+    pub signer: Option<Address>,
+    /// Whether `signer` was the expected in-turn signer (`false` when unsigned).
+    pub in_turn: bool,
+    pub tx_count: u64,
+    pub gas_used: u64,
+}
+
+/// Broadcast sender for committed blocks (block monitor → `meow_subscribeSignedBlocks`).
+///
+/// Sending never blocks: subscribers that fall more than
+/// [`SIGNED_BLOCK_CHANNEL_CAPACITY`] blocks behind skip the oldest ones.
+pub type SignedBlockSender = broadcast::Sender<Arc<SignedBlock>>;
+
+/// Create a signed block broadcast channel with no subscribers yet.
+pub fn signed_block_channel() -> SignedBlockSender {
+    broadcast::channel(SIGNED_BLOCK_CHANNEL_CAPACITY).0
+}
@@ -0,0 +1,159 @@
+//! Bounded index of who sealed each recent canonical block.
+//!
+//! Recovering a signer means an ECDSA recovery per header, so RPC methods that look
+//! at block authorship (`clique_status`, `meow_getSignerStatus`) read it from
+//! [`SignerIndex`] instead. The block monitoring loop records every committed block
+//! once; on a reorg the new branch's blocks overwrite the entries at their heights
+//! and anything above the new tip is dropped, so the index always describes the
+//! canonical chain.
+
+use super::BlockWindow;
+use alloy_primitives::Address;
+use std::sync::{Arc, Mutex};
+
+/// Default number of blocks kept by [`SignerIndex`] (`--signer-index-size`).
+pub const DEFAULT_SIGNER_INDEX_CAPACITY: usize = 10_000;
+
+/// Authorship of one indexed block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexedSigner {
+    /// Recovered block signer.
+    pub signer: Address,
+    /// Whether the signer was the in-turn one for this block.
+    pub in_turn: bool,
+}
+
+/// Signers of the latest canonical blocks, by block number.
+#[derive(Debug, Clone)]
+pub struct SignerIndex {
+    entries: BlockWindow<IndexedSigner>,
+}
+
+/// [`SignerIndex`] shared between the monitoring loop and RPC.
+pub type SharedSignerIndex = Arc<Mutex<SignerIndex>>;
+
+impl Default for SignerIndex {
+    fn default() -> Self {
+        Self::new(DEFAULT_SIGNER_INDEX_CAPACITY)
+    }
+}
+
+impl SignerIndex {
+    /// Create an index keeping the last `capacity` blocks (at least 1).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: BlockWindow::new(capacity),
+        }
+    }
+
+    /// Create an empty, shareable index keeping the last `capacity` blocks.
+    pub fn shared(capacity: usize) -> SharedSignerIndex {
+        Arc::new(Mutex::new(Self::new(capacity)))
+    }
+
+    /// Maximum number of blocks kept.
+    pub fn capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+
+    /// Number of blocks currently indexed.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no block has been indexed yet.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Record the signer of canonical block `block_number`.
+    ///
+    /// Blocks are recorded in chain order, so `block_number` is the new tip: any
+    /// entry at or above it belongs to a reorged-out branch and is replaced or
+    /// dropped. The oldest entries are evicted beyond the capacity.
+    pub fn record(&mut self, block_number: u64, signer: Address, in_turn: bool) {
+        self.entries
+            .record(block_number, IndexedSigner { signer, in_turn });
+    }
+
+    /// Signer of block `block_number`, if indexed.
+    pub fn get(&self, block_number: u64) -> Option<IndexedSigner> {
+        self.entries.get(block_number).copied()
+    }
+
+    /// Number of the newest indexed block.
+    pub fn head(&self) -> Option<u64> {
+        self.entries.head()
+    }
+
+    /// The newest `count` indexed blocks, oldest first.
+    pub fn latest(&self, count: usize) -> Vec<(u64, IndexedSigner)> {
+        self.entries
+            .latest(count)
+            .map(|(number, entry)| (number, *entry))
+            .collect()
+    }
+
+    /// Most recent indexed block sealed by `signer`.
+    pub fn last_sealed(&self, signer: &Address) -> Option<u64> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|(_, entry)| entry.signer == *signer)
+            .map(|(number, _)| number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signer(n: u8) -> Address {
+        Address::with_last_byte(n)
+    }
+
+    #[test]
+    fn test_reorg_overwrites_with_new_chain() {
+        let mut index = SignerIndex::new(100);
+        for number in 1..=10 {
+            index.record(number, signer((number % 3) as u8), true);
+        }
+
+        // Reorg at 8: the new branch seals 8 and 9 out of turn, and is shorter
+        index.record(8, signer(7), false);
+        index.record(9, signer(8), false);
+
+        assert_eq!(index.head(), Some(9));
+        assert_eq!(index.len(), 9);
+        assert_eq!(
+            index.get(8),
+            Some(IndexedSigner {
+                signer: signer(7),
+                in_turn: false
+            })
+        );
+        assert_eq!(index.get(10), None);
+        // Block 7 predates the fork and is untouched
+        assert_eq!(index.get(7).unwrap().signer, signer(1));
+        // Signer 1 sealed 10 on the old branch only
+        assert_eq!(index.last_sealed(&signer(1)), Some(7));
+        assert_eq!(index.last_sealed(&signer(8)), Some(9));
+    }
+
+    #[test]
+    fn test_index_is_bounded() {
+        let mut index = SignerIndex::new(5);
+        for number in 1..=8 {
+            index.record(number, signer(number as u8), true);
+        }
+        assert_eq!(index.len(), 5);
+        assert_eq!(index.get(3), None);
+        assert_eq!(index.get(4).unwrap().signer, signer(4));
+        assert_eq!(
+            index.latest(2).iter().map(|(n, _)| *n).collect::<Vec<_>>(),
+            vec![7, 8]
+        );
+        assert_eq!(index.latest(100).len(), 5);
+        assert_eq!(SignerIndex::new(0).capacity(), 1);
+    }
+}
@@ -0,0 +1,221 @@
+//! Index of operations scheduled on the Timelock, for `meow_getTimelockOperations`.
+//!
+//! The Timelock keeps `timestamps[id]` per operation but no list of ids, and the
+//! target only appears in the `OperationScheduled` event. The block monitoring
+//! loop records each scheduled `(id, target)` from the committed receipts, and
+//! [`backfill_timelock_schedule`] recovers the ones scheduled before startup from
+//! the stored receipts, down to the receipts prune boundary. Only the latest
+//! [`TIMELOCK_SCHEDULE_CAPACITY`] operations are kept. Whether an operation is
+//! still queued, ready or executed is always read from the Timelock's state, so
+//! reorged-out and cancelled operations drop out without being removed here.
+
+use crate::genesis::TIMELOCK_ADDRESS;
+use alloy_primitives::{keccak256, Address, Log, B256};
+use reth_ethereum::provider::ProviderResult;
+use reth_ethereum::Receipt;
+use reth_prune_types::PruneSegment;
+use reth_storage_api::{BlockNumReader, PruneCheckpointReader, ReceiptProvider};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, Mutex};
+
+/// Number of operations kept by [`TimelockSchedule`].
+pub const TIMELOCK_SCHEDULE_CAPACITY: usize = 1024;
+
+/// Blocks of receipts read at a time by [`backfill_timelock_schedule`].
+pub const TIMELOCK_BACKFILL_CHUNK: u64 = 10_000;
+
+/// Topic of the Timelock's `OperationScheduled(bytes32,address,uint256,bytes,uint256)` event.
+pub fn operation_scheduled_topic() -> B256 {
+    keccak256("OperationScheduled(bytes32,address,uint256,bytes,uint256)")
+}
+
+/// Decode a Timelock `OperationScheduled` log into `(id, target)`.
+///
+/// The id is the indexed topic; the target is the first word of the ABI-encoded data.
+pub fn decode_operation_scheduled(log: &Log) -> Option<(B256, Address)> {
+    if log.address != TIMELOCK_ADDRESS {
+        return None;
+    }
+    match log.topics() {
+        [topic, id] if *topic == operation_scheduled_topic() => {
+            let target = log.data.data.get(12..32)?;
+            Some((*id, Address::from_slice(target)))
+        }
+        _ => None,
+    }
+}
+
+/// Latest operations seen scheduled on the Timelock.
+#[derive(Debug, Clone)]
+pub struct TimelockSchedule {
+    capacity: usize,
+    /// Scheduling block and target of each operation, by id.
+    operations: HashMap<B256, (u64, Address)>,
+    /// Operation ids by scheduling block, oldest first.
+    order: BTreeSet<(u64, B256)>,
+}
+
+/// [`TimelockSchedule`] shared between the monitoring loop and RPC.
+pub type SharedTimelockSchedule = Arc<Mutex<TimelockSchedule>>;
+
+impl Default for TimelockSchedule {
+    fn default() -> Self {
+        Self::new(TIMELOCK_SCHEDULE_CAPACITY)
+    }
+}
+
+impl TimelockSchedule {
+    /// Create a schedule keeping the latest `capacity` operations (at least 1).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            operations: HashMap::new(),
+            order: BTreeSet::new(),
+        }
+    }
+
+    /// Create an empty, shareable schedule.
+    pub fn shared() -> SharedTimelockSchedule {
+        Arc::new(Mutex::new(Self::default()))
+    }
+
+    /// Whether the schedule holds `capacity` operations, so that an operation
+    /// scheduled before all of them would be evicted right away.
+    pub fn is_full(&self) -> bool {
+        self.operations.len() >= self.capacity
+    }
+
+    /// Record that operation `id` on `target` was scheduled in block `block_number`.
+    ///
+    /// An id scheduled again (after a cancel, or on a reorg's new branch) is kept
+    /// once, at its latest scheduling block. The operations scheduled earliest are
+    /// evicted beyond the capacity.
+    pub fn record(&mut self, block_number: u64, id: B256, target: Address) {
+        if let Some(&(known, _)) = self.operations.get(&id) {
+            if known > block_number {
+                return;
+            }
+            self.order.remove(&(known, id));
+        }
+        self.operations.insert(id, (block_number, target));
+        self.order.insert((block_number, id));
+        while self.order.len() > self.capacity {
+            if let Some((_, evicted)) = self.order.pop_first() {
+                self.operations.remove(&evicted);
+            }
+        }
+    }
+
+    /// Record the operations scheduled by the Timelock events among `logs`, the
+    /// logs of block `block_number`.
+    pub fn record_logs<'a>(&mut self, block_number: u64, logs: impl IntoIterator<Item = &'a Log>) {
+        for (id, target) in logs.into_iter().filter_map(decode_operation_scheduled) {
+            self.record(block_number, id, target);
+        }
+    }
+
+    /// Recorded `(id, target)` pairs, in scheduling order.
+    pub fn to_vec(&self) -> Vec<(B256, Address)> {
+        self.order
+            .iter()
+            .filter_map(|(_, id)| Some((*id, self.operations.get(id)?.1)))
+            .collect()
+    }
+}
+
+/// Record the operations scheduled in blocks already stored by `provider`.
+///
+/// Receipts are read from the tip backwards, stopping at the receipts prune
+/// boundary or once the schedule is full, since nothing older would be kept.
+pub fn backfill_timelock_schedule<P>(
+    provider: &P,
+    schedule: &SharedTimelockSchedule,
+) -> ProviderResult<()>
+where
+    P: BlockNumReader + PruneCheckpointReader + ReceiptProvider<Receipt = Receipt>,
+{
+    let best = provider.best_block_number()?;
+    let first = provider
+        .get_prune_checkpoint(PruneSegment::Receipts)?
+        .and_then(|checkpoint| checkpoint.block_number)
+        .map_or(0, |pruned| pruned + 1);
+    let mut end = best;
+    while end >= first {
+        let start = first.max(end.saturating_sub(TIMELOCK_BACKFILL_CHUNK - 1));
+        let receipts = provider.receipts_by_block_range(start..=end)?;
+        let mut schedule = schedule.lock().unwrap_or_else(|e| e.into_inner());
+        for (block_number, receipts) in (start..).zip(&receipts) {
+            schedule.record_logs(
+                block_number,
+                receipts.iter().flat_map(|receipt| &receipt.logs),
+            );
+        }
+        if schedule.is_full() || start == 0 {
+            break;
+        }
+        end = start - 1;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Bytes, LogData};
+
+    fn scheduled_log(address: Address, id: B256, target: Address) -> Log {
+        // abi.encode(target, value, data, delay) with empty data
+        let mut data = vec![0u8; 160];
+        data[12..32].copy_from_slice(target.as_slice());
+        data[95] = 0x80;
+        Log {
+            address,
+            data: LogData::new_unchecked(vec![operation_scheduled_topic(), id], Bytes::from(data)),
+        }
+    }
+
+    #[test]
+    fn test_schedule_records_timelock_events_once() {
+        let id = B256::repeat_byte(0x11);
+        let target = Address::with_last_byte(0xcc);
+        let mut schedule = TimelockSchedule::default();
+        schedule.record_logs(
+            5,
+            &[
+                scheduled_log(TIMELOCK_ADDRESS, id, target),
+                // Same event from another contract is ignored
+                scheduled_log(
+                    Address::with_last_byte(0x01),
+                    B256::repeat_byte(0x22),
+                    target,
+                ),
+            ],
+        );
+        // Rescheduled after a cancel
+        schedule.record_logs(7, &[scheduled_log(TIMELOCK_ADDRESS, id, target)]);
+        assert_eq!(schedule.to_vec(), vec![(id, target)]);
+    }
+
+    #[test]
+    fn test_schedule_keeps_latest_operations() {
+        let target = Address::with_last_byte(0xcc);
+        let id = B256::with_last_byte;
+        let mut schedule = TimelockSchedule::new(2);
+        schedule.record(10, id(1), target);
+        schedule.record(20, id(2), target);
+        assert!(schedule.is_full());
+
+        // A newer operation evicts the oldest one
+        schedule.record(30, id(3), target);
+        assert_eq!(schedule.to_vec(), vec![(id(2), target), (id(3), target)]);
+        // An older one (e.g. found by the backfill after the monitor recorded
+        // newer blocks) is dropped right away
+        schedule.record(5, id(4), target);
+        assert_eq!(schedule.to_vec(), vec![(id(2), target), (id(3), target)]);
+        // Rescheduling moves an operation to its latest block, and a stale sighting
+        // doesn't move it back
+        schedule.record(40, id(2), target);
+        schedule.record(20, id(2), target);
+        assert_eq!(schedule.to_vec(), vec![(id(3), target), (id(2), target)]);
+    }
+}
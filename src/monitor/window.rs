@@ -0,0 +1,96 @@
+//! Bounded window of per-block records along the canonical chain.
+//!
+//! The block monitoring loop records canonical blocks in chain order, so each new
+//! record is the new tip: anything at or above its height belongs to a reorged-out
+//! branch and is dropped, and the oldest records are evicted beyond the capacity.
+//! [`SignerIndex`](super::SignerIndex) and
+//! [`BlockTimings`](crate::metrics::BlockTimings) keep their records in a
+//! [`BlockWindow`].
+
+use std::collections::BTreeMap;
+
+/// The latest `capacity` records, by block number.
+#[derive(Debug, Clone)]
+pub struct BlockWindow<T> {
+    capacity: usize,
+    entries: BTreeMap<u64, T>,
+}
+
+impl<T> BlockWindow<T> {
+    /// Create a window keeping the last `capacity` blocks (at least 1).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Maximum number of blocks kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of blocks currently recorded.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no block has been recorded yet.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Record `value` for the new canonical tip `block_number`, dropping any record
+    /// at or above it and evicting the oldest beyond the capacity.
+    pub fn record(&mut self, block_number: u64, value: T) {
+        self.entries.split_off(&block_number);
+        self.entries.insert(block_number, value);
+        while self.entries.len() > self.capacity {
+            self.entries.pop_first();
+        }
+    }
+
+    /// Record of block `block_number`, if kept.
+    pub fn get(&self, block_number: u64) -> Option<&T> {
+        self.entries.get(&block_number)
+    }
+
+    /// Number of the newest recorded block.
+    pub fn head(&self) -> Option<u64> {
+        self.entries.last_key_value().map(|(number, _)| *number)
+    }
+
+    /// Records by block number, oldest first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (u64, &T)> + ExactSizeIterator {
+        self.entries.iter().map(|(number, value)| (*number, value))
+    }
+
+    /// The newest `count` records, oldest first.
+    pub fn latest(&self, count: usize) -> impl Iterator<Item = (u64, &T)> {
+        self.iter().skip(self.len().saturating_sub(count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reorg_replaces_and_capacity_evicts() {
+        let mut window = BlockWindow::new(3);
+        for number in 1..=4 {
+            window.record(number, number * 10);
+        }
+        assert_eq!(window.len(), 3);
+        assert_eq!(window.get(1), None);
+        assert_eq!(window.get(4), Some(&40));
+
+        // Reorg back to 3: the old block 4 is gone
+        window.record(3, 99);
+        assert_eq!(window.get(3), Some(&99));
+        assert_eq!(window.get(4), None);
+        assert_eq!(window.head(), Some(3));
+        assert_eq!(window.latest(1).collect::<Vec<_>>(), vec![(3, &99)]);
+        assert_eq!(BlockWindow::<()>::new(0).capacity(), 1);
+    }
+}
@@ -1,12 +1,14 @@
 use crate::chainspec::PoaChainSpec;
 use crate::consensus::PoaConsensus;
 use crate::output;
+use alloy_consensus::Header;
 use reth_ethereum::node::builder::{
     components::ConsensusBuilder,
     node::{FullNodeTypes, NodeTypes},
     BuilderContext,
 };
 use reth_ethereum::EthPrimitives;
+use reth_storage_api::HeaderProvider;
 use std::sync::Arc;
 
 /// Custom consensus builder that provides `PoaConsensus` instead of `EthBeaconConsensus`.
@@ -41,10 +43,11 @@ impl PoaConsensusBuilder {
 impl<N> ConsensusBuilder<N> for PoaConsensusBuilder
 where
     N: FullNodeTypes<Types: NodeTypes<Primitives = EthPrimitives>>,
+    N::Provider: HeaderProvider<Header = Header>,
 {
     type Consensus = Arc<PoaConsensus>;
 
-    async fn build_consensus(self, _ctx: &BuilderContext<N>) -> eyre::Result<Self::Consensus> {
+    async fn build_consensus(self, ctx: &BuilderContext<N>) -> eyre::Result<Self::Consensus> {
         let mode = if self.dev_mode {
             "dev (relaxed)"
         } else {
@@ -56,8 +59,12 @@ where
             self.chain_spec.block_period(),
             mode,
         );
+        // Imported headers are checked against the recent-signer window, which
+        // reaches past the parent
         Ok(Arc::new(
-            PoaConsensus::new(self.chain_spec).with_dev_mode(self.dev_mode),
+            PoaConsensus::new(self.chain_spec)
+                .with_dev_mode(self.dev_mode)
+                .with_ancestors(Arc::new(ctx.provider().clone())),
         ))
     }
 }
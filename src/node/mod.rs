@@ -6,14 +6,35 @@
 
 pub mod builder;
 pub mod engine;
+pub mod network;
+pub mod pool;
+pub mod reputation;
 
 pub use builder::PoaConsensusBuilder;
 pub use engine::{strip_extra_data, PoaEngineValidator, PoaEngineValidatorBuilder};
+pub use network::PoaNetworkBuilder;
+pub use pool::{
+    check_max_tx_gas, check_min_gas_price, CalldataGasSource, CalldataGasValidator, MaxTxGasSource,
+    MaxTxGasValidator, MinGasPriceValidator, PoaPoolBuilder, PoaTransactionPool, PoolLimits,
+};
+pub use reputation::{
+    DeferredReporter, PeerBlockValidator, PenaltyReason, PoaBlockImport, PoaPenalties,
+    ReputationReporter,
+};
 
+use crate::cache::SharedCache;
 use crate::chainspec::PoaChainSpec;
-use crate::evm::PoaExecutorBuilder;
-use crate::payload::PoaPayloadBuilderBuilder;
+use crate::consensus::PoaConsensus;
+use crate::constants::{DEFAULT_WIGGLE_MS, EXTRA_VANITY_LENGTH};
+use crate::evm::{CustomPrecompiles, PoaExecutorBuilder};
+use crate::metrics::SharedBlockTimings;
+use crate::monitor::{ReorgGuard, SignerLiveness};
+use crate::payload::{FallbackPolicy, PoaPayloadBuilderBuilder, TransactionOrdering};
 use crate::signer::SignerManager;
+use alloy_evm::precompiles::DynPrecompile;
+use alloy_primitives::Address;
+use reth_dns_discovery::tree::LinkEntry;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 // Node builder types
@@ -27,10 +48,7 @@ use reth_ethereum::node::builder::{
 use reth_ethereum::node::api::{FullNodeComponents, PayloadAttributesBuilder};
 
 // Ethereum component builders (pool, network, executor, payload)
-use reth_ethereum::node::{
-    EthEngineTypes, EthereumAddOns, EthereumEthApiBuilder, EthereumNetworkBuilder,
-    EthereumPoolBuilder,
-};
+use reth_ethereum::node::{EthEngineTypes, EthereumAddOns, EthereumEthApiBuilder};
 
 // Primitive and storage types
 use reth_ethereum::{provider::EthStorage, EthPrimitives};
@@ -65,12 +83,44 @@ pub struct PoaNode {
     dev_mode: bool,
     /// Hot state cache capacity for governance reads (Phase 5.31).
     cache_size: usize,
+    /// Externally owned hot state cache (warmed from / persisted to disk).
+    shared_cache: Option<SharedCache>,
     /// Maximum deployed contract code size override (Phase 2.11).
     /// `None` = Ethereum default (24,576 bytes).
     max_contract_size: Option<usize>,
     /// Gas cost per non-zero calldata byte, 1–16 (Phase 2.12).
     /// `16` = Ethereum mainnet default. `4` = POA default (cheap calldata).
     calldata_gas_per_byte: u64,
+    /// Chain-specific precompiles for block execution and RPC calls.
+    precompiles: CustomPrecompiles,
+    /// Base out-of-turn wiggle delay in milliseconds (`0` = disabled).
+    wiggle_ms: u64,
+    /// Verify governance storage reads with Merkle proofs against the state root.
+    verify_storage_reads: bool,
+    /// Maximum transactions per produced block (`None` = no cap).
+    max_transactions: Option<usize>,
+    /// Skip empty blocks until idle this many seconds (`None` = seal them).
+    max_idle: Option<u64>,
+    /// Testing only: block numbers this node may seal (`None` = all).
+    seal_only: Option<HashSet<u64>>,
+    /// Minimum effective gas price in wei for pool admission (`0` = no floor).
+    min_gas_price: u128,
+    /// Pool capacity overrides (`--txpool-max-*`).
+    pool_limits: PoolLimits,
+    /// Build/sign times of sealed payloads, read by the block monitor (`None` = off).
+    block_timings: Option<SharedBlockTimings>,
+    /// Which of our signers may seal when the in-turn signer misses its slot.
+    fallback_policy: FallbackPolicy,
+    /// `--safe-mode`: no out-of-turn blocks while too few signers are online (`None` = off).
+    safe_mode: Option<Arc<SignerLiveness>>,
+    /// `--halt-on-deep-reorg`: stop sealing after a too-deep reorg (`None` = off).
+    reorg_guard: Option<Arc<ReorgGuard>>,
+    /// Vanity for blocks sealed by signers without their own (`--block-vanity`).
+    block_vanity: [u8; EXTRA_VANITY_LENGTH],
+    /// Order in which pending transactions are included (`--tx-ordering`).
+    tx_ordering: TransactionOrdering,
+    /// EIP-1459 ENR trees to discover peers from (`--discovery-dns`).
+    dns_discovery: Vec<LinkEntry>,
 }
 
 impl PoaNode {
@@ -81,8 +131,24 @@ impl PoaNode {
             signer_manager: Arc::new(SignerManager::new()),
             dev_mode: false,
             cache_size: 1024,
+            shared_cache: None,
             max_contract_size: None,
             calldata_gas_per_byte: 4, // POA default: cheap calldata
+            precompiles: CustomPrecompiles::default(),
+            wiggle_ms: DEFAULT_WIGGLE_MS,
+            verify_storage_reads: false,
+            max_transactions: None,
+            max_idle: None,
+            seal_only: None,
+            min_gas_price: 0,
+            pool_limits: PoolLimits::default(),
+            block_timings: None,
+            fallback_policy: FallbackPolicy::default(),
+            safe_mode: None,
+            reorg_guard: None,
+            block_vanity: [0u8; EXTRA_VANITY_LENGTH],
+            tx_ordering: TransactionOrdering::default(),
+            dns_discovery: Vec::new(),
         }
     }
 
@@ -104,6 +170,14 @@ impl PoaNode {
         self
     }
 
+    /// Use an externally owned hot state cache for the payload builder.
+    ///
+    /// Lets the caller warm the cache from disk on startup and persist it on shutdown.
+    pub fn with_shared_cache(mut self, cache: SharedCache) -> Self {
+        self.shared_cache = Some(cache);
+        self
+    }
+
     /// Override the maximum deployed contract code size (Phase 2.11).
     ///
     /// `0` → no override (use Ethereum's 24,576-byte default).
@@ -115,9 +189,112 @@ impl PoaNode {
 
     /// Set the calldata gas cost per non-zero byte (Phase 2.12).
     ///
-    /// Clamped to `[1, 16]`. `16` = Ethereum mainnet. `4` = POA default.
+    /// `16` = Ethereum mainnet. `4` = POA default. Values outside `[1, 16]` fail
+    /// the node launch.
     pub fn with_calldata_gas(mut self, cost: u64) -> Self {
-        self.calldata_gas_per_byte = cost.clamp(1, 16);
+        self.calldata_gas_per_byte = cost;
+        self
+    }
+
+    /// Add a chain-specific precompile at `address`.
+    ///
+    /// The precompile is part of every EVM the node creates (block execution,
+    /// payload building and `eth_call`), so all nodes of the chain must register the
+    /// same set or their state roots diverge.
+    pub fn with_precompile(
+        mut self,
+        address: Address,
+        precompile: impl Into<DynPrecompile>,
+    ) -> Self {
+        self.precompiles.insert(address, precompile);
+        self
+    }
+
+    /// Set the base out-of-turn wiggle delay in milliseconds (`0` = disabled).
+    pub fn with_wiggle_ms(mut self, wiggle_ms: u64) -> Self {
+        self.wiggle_ms = wiggle_ms;
+        self
+    }
+
+    /// Verify on-chain governance reads with storage proofs (high-assurance mode).
+    pub fn with_verify_storage_reads(mut self, verify: bool) -> Self {
+        self.verify_storage_reads = verify;
+        self
+    }
+
+    /// Cap the number of transactions in each produced block.
+    pub fn with_max_transactions(mut self, max: Option<usize>) -> Self {
+        self.max_transactions = max;
+        self
+    }
+
+    /// Skip empty blocks, sealing a keep-alive after `max_idle` seconds (`None` = off).
+    pub fn with_empty_block_suppression(mut self, max_idle: Option<u64>) -> Self {
+        self.max_idle = max_idle;
+        self
+    }
+
+    /// Reject pool transactions whose effective gas price is below `min_gas_price`
+    /// wei (`0` = no floor).
+    pub fn with_min_gas_price(mut self, min_gas_price: u128) -> Self {
+        self.min_gas_price = min_gas_price;
+        self
+    }
+
+    /// Bound the transaction pool's sub-pools and per-sender slots.
+    pub fn with_pool_limits(mut self, limits: PoolLimits) -> Self {
+        self.pool_limits = limits;
+        self
+    }
+
+    /// Record the build and sign time of every payload the node seals in `timings`.
+    pub fn with_block_timings(mut self, timings: SharedBlockTimings) -> Self {
+        self.block_timings = Some(timings);
+        self
+    }
+
+    /// Discover peers from the EIP-1459 ENR trees at `links`, on top of any bootnodes.
+    pub fn with_dns_discovery(mut self, links: Vec<LinkEntry>) -> Self {
+        self.dns_discovery = links;
+        self
+    }
+
+    /// Choose which out-of-turn signers may seal when the in-turn signer is offline.
+    pub fn with_fallback_policy(mut self, policy: FallbackPolicy) -> Self {
+        self.fallback_policy = policy;
+        self
+    }
+
+    /// Stop sealing out-of-turn blocks while `liveness` reports fewer signers online
+    /// than the `SignerRegistry` threshold.
+    pub fn with_safe_mode(mut self, liveness: Arc<SignerLiveness>) -> Self {
+        self.safe_mode = Some(liveness);
+        self
+    }
+
+    /// Stop sealing once `guard` sees a reorg deeper than `--max-reorg-depth`.
+    pub fn with_reorg_guard(mut self, guard: Arc<ReorgGuard>) -> Self {
+        self.reorg_guard = Some(guard);
+        self
+    }
+
+    /// Write `vanity` into the extra data of every block the node seals, unless the
+    /// sealing signer has its own (`--signer-vanity`).
+    pub fn with_block_vanity(mut self, vanity: [u8; EXTRA_VANITY_LENGTH]) -> Self {
+        self.block_vanity = vanity;
+        self
+    }
+
+    /// Include pending transactions in `ordering` instead of by priority fee.
+    pub fn with_transaction_ordering(mut self, ordering: TransactionOrdering) -> Self {
+        self.tx_ordering = ordering;
+        self
+    }
+
+    /// Testing only: restrict block production to these block numbers so reorg
+    /// tests can script which node seals which block.
+    pub fn with_seal_only_blocks(mut self, blocks: HashSet<u64>) -> Self {
+        self.seal_only = Some(blocks);
         self
     }
 }
@@ -131,16 +308,18 @@ impl NodeTypes for PoaNode {
 }
 
 // The Node implementation provides the ComponentsBuilder that wires everything together.
-// The only difference from EthereumNode is the consensus builder and the engine validator.
+// The differences from EthereumNode are the consensus builder, the engine validator,
+// the EVM factory, the payload builder, the pool's minimum gas price, DNS discovery and
+// validation of blocks announced by peers.
 impl<N> Node<N> for PoaNode
 where
     N: FullNodeTypes<Types = Self>,
 {
     type ComponentsBuilder = ComponentsBuilder<
         N,
-        EthereumPoolBuilder,
+        PoaPoolBuilder,
         BasicPayloadServiceBuilder<PoaPayloadBuilderBuilder>,
-        EthereumNetworkBuilder,
+        PoaNetworkBuilder,
         PoaExecutorBuilder,
         PoaConsensusBuilder,
     >;
@@ -157,20 +336,44 @@ where
     fn components_builder(&self) -> Self::ComponentsBuilder {
         ComponentsBuilder::default()
             .node_types::<N>()
-            .pool(EthereumPoolBuilder::default())
-            .executor(PoaExecutorBuilder::new(
-                self.max_contract_size,
-                self.calldata_gas_per_byte,
-            ))
+            .pool(
+                PoaPoolBuilder::new(self.min_gas_price)
+                    .with_limits(self.pool_limits)
+                    .with_calldata_gas(self.calldata_gas_per_byte),
+            )
+            .executor(
+                PoaExecutorBuilder::new(self.max_contract_size, self.calldata_gas_per_byte)
+                    .with_precompiles(self.precompiles.clone()),
+            )
             .payload(BasicPayloadServiceBuilder::new(
                 PoaPayloadBuilderBuilder::new(
                     self.chain_spec.clone(),
                     self.signer_manager.clone(),
                     self.dev_mode,
                 )
-                .with_cache_size(self.cache_size),
+                .with_cache_size(self.cache_size)
+                .with_wiggle_ms(self.wiggle_ms)
+                .with_shared_cache(self.shared_cache.clone())
+                .with_verify_storage_reads(self.verify_storage_reads)
+                .with_max_transactions(self.max_transactions)
+                .with_empty_block_suppression(self.max_idle)
+                .with_block_timings(self.block_timings.clone())
+                .with_fallback_policy(self.fallback_policy)
+                .with_safe_mode(self.safe_mode.clone())
+                .with_reorg_guard(self.reorg_guard.clone())
+                .with_block_vanity(self.block_vanity)
+                .with_transaction_ordering(self.tx_ordering)
+                .with_seal_only_blocks(self.seal_only.clone()),
             ))
-            .network(EthereumNetworkBuilder::default())
+            .network(
+                PoaNetworkBuilder::default()
+                    .with_dns_discovery(self.dns_discovery.clone())
+                    .with_block_validation(
+                        // Dev mode skips seal checks, leaving nothing to penalize
+                        (!self.dev_mode)
+                            .then(|| Arc::new(PoaConsensus::new(self.chain_spec.clone()))),
+                    ),
+            )
             .consensus(
                 PoaConsensusBuilder::new(self.chain_spec.clone()).with_dev_mode(self.dev_mode),
             )
@@ -241,6 +444,25 @@ mod tests {
         assert_eq!(node.chain_spec.signers().len(), 3);
     }
 
+    #[test]
+    fn test_poa_node_with_precompile_reaches_executor() {
+        use alloy_evm::precompiles::PrecompileInput;
+        use alloy_evm::revm::precompile::{PrecompileOutput, PrecompileResult};
+
+        let noop = |_: PrecompileInput<'_>| -> PrecompileResult {
+            Ok(PrecompileOutput::new(0, Default::default()))
+        };
+        let node = PoaNode::new(Arc::new(PoaChainSpec::dev_chain()))
+            .with_precompile(Address::with_last_byte(0x42), noop)
+            .with_precompile(Address::with_last_byte(0x42), noop)
+            .with_precompile(Address::with_last_byte(0x43), noop);
+        let addresses: Vec<_> = node.precompiles.addresses().copied().collect();
+        assert_eq!(
+            addresses,
+            vec![Address::with_last_byte(0x42), Address::with_last_byte(0x43)]
+        );
+    }
+
     #[test]
     fn test_poa_consensus_builder_creation() {
         let chain = Arc::new(PoaChainSpec::dev_chain());
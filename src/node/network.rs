@@ -0,0 +1,98 @@
+//! P2P network builder with EIP-1459 DNS discovery.
+//!
+//! Reth only resolves the ENR trees published for named chains, so a POA chain
+//! with its own chain ID finds peers through static `--bootnodes` alone. With
+//! `--discovery-dns`, [`PoaNetworkBuilder`] adds the given `enrtree://` links to the
+//! network's DNS discovery, so nodes keep finding peers when bootnodes rotate:
+//! operators republish the tree instead of every node's command line.
+//!
+//! With a block validator set, blocks announced by peers go through
+//! [`PoaBlockImport`], which penalizes senders of blocks failing POA validation.
+
+use crate::consensus::PoaConsensus;
+use crate::node::reputation::{DeferredReporter, PeerBlockValidator, PoaBlockImport};
+use crate::output;
+use reth_chainspec::ChainSpec;
+use reth_dns_discovery::tree::LinkEntry;
+use reth_ethereum::network::config::NetworkMode;
+use reth_ethereum::network::{EthNetworkPrimitives, NetworkHandle, NetworkManager};
+use reth_ethereum::node::api::TxTy;
+use reth_ethereum::node::builder::{
+    components::NetworkBuilder,
+    node::{FullNodeTypes, NodeTypes},
+    BuilderContext,
+};
+use reth_ethereum::{EthPrimitives, PooledTransactionVariant};
+use reth_transaction_pool::{PoolTransaction, TransactionPool};
+use std::sync::Arc;
+
+/// Network builder that adds DNS discovery ENR trees to Reth's Ethereum network.
+#[derive(Debug, Clone, Default)]
+pub struct PoaNetworkBuilder {
+    /// ENR trees to discover peers from (empty = Reth's defaults).
+    dns_networks: Vec<LinkEntry>,
+    /// Validates blocks announced by peers (`None` = Reth's default import).
+    block_validator: Option<Arc<PoaConsensus>>,
+}
+
+impl PoaNetworkBuilder {
+    /// Discover peers from the EIP-1459 ENR trees at `links`.
+    pub fn with_dns_discovery(mut self, links: Vec<LinkEntry>) -> Self {
+        self.dns_networks = links;
+        self
+    }
+
+    /// Validate blocks announced by peers with `consensus` and penalize the senders of
+    /// invalid ones.
+    ///
+    /// Reth disconnects peers that announce blocks on a proof-of-stake network, so
+    /// this also switches the network to accept block announcements, as Clique
+    /// networks gossip them.
+    pub fn with_block_validation(mut self, consensus: Option<Arc<PoaConsensus>>) -> Self {
+        self.block_validator = consensus;
+        self
+    }
+}
+
+impl<N, Pool> NetworkBuilder<N, Pool> for PoaNetworkBuilder
+where
+    N: FullNodeTypes<Types: NodeTypes<ChainSpec = ChainSpec, Primitives = EthPrimitives>>,
+    Pool: TransactionPool<
+            Transaction: PoolTransaction<
+                Consensus = TxTy<N::Types>,
+                Pooled = PooledTransactionVariant,
+            >,
+        > + Unpin
+        + 'static,
+{
+    type Network = NetworkHandle<EthNetworkPrimitives>;
+
+    async fn build_network(
+        self,
+        ctx: &BuilderContext<N>,
+        pool: Pool,
+    ) -> eyre::Result<Self::Network> {
+        let mut config = ctx.network_config()?;
+        if !self.dns_networks.is_empty() {
+            // `None` when discovery is disabled (--disable-discovery)
+            match config.dns_discovery_config.as_mut() {
+                Some(dns) => {
+                    dns.bootstrap_dns_networks = Some(self.dns_networks.into_iter().collect());
+                }
+                None => output::print_warning("--discovery-dns ignored: DNS discovery is disabled"),
+            }
+        }
+        let reporter = DeferredReporter::default();
+        if let Some(consensus) = self.block_validator {
+            config.network_mode = NetworkMode::Work;
+            config.block_import = Box::new(PoaBlockImport::new(PeerBlockValidator::new(
+                consensus,
+                reporter.clone(),
+            )));
+        }
+        let network = NetworkManager::builder(config).await?;
+        let handle = ctx.start_network(network, pool);
+        reporter.set(handle.clone());
+        Ok(handle)
+    }
+}
@@ -0,0 +1,836 @@
+//! Transaction pool with a minimum gas price and a per-transaction gas cap.
+//!
+//! [`PoaPoolBuilder`] builds the standard Ethereum pool, but wraps its validator in
+//! [`MinGasPriceValidator`], which rejects transactions paying less than
+//! `--min-gas-price` before they reach the regular checks, and [`MaxTxGasValidator`],
+//! which rejects transactions requesting more gas than the live
+//! `ChainConfig.maxTxGas`. Submitting an underpriced one fails with the usual
+//! `transaction underpriced` RPC error. [`CalldataGasValidator`] lets Reth's
+//! intrinsic gas check use the governed calldata cost, like the EVM.
+//!
+//! [`PoolLimits`] bounds the pool's pending and queued sub-pools and the slots a
+//! single sender may hold (`--txpool-max-*`), so a spammer can't grow the pool
+//! without limit. Unset limits keep Reth's pool configuration.
+
+use crate::evm::{calldata_discount, CALLDATA_GAS_RANGE};
+use crate::onchain::{read_calldata_gas_per_byte, read_max_tx_gas, StateProviderStorageReader};
+use alloy_consensus::{BlockHeader, EthereumTxEnvelope, Signed, Transaction};
+use alloy_eips::eip1559::BaseFeeParams;
+use alloy_evm::revm::interpreter::gas::{calc_tx_floor_cost, get_tokens_in_calldata};
+use alloy_primitives::B256;
+use reth_chainspec::{ChainSpec, EthChainSpec, EthereumHardforks};
+use reth_ethereum::node::builder::{
+    components::{create_blob_store_with_cache, PoolBuilder, TxPoolBuilder},
+    node::{FullNodeTypes, NodeTypes},
+    BuilderContext,
+};
+use reth_ethereum::storage::StateProviderFactory;
+use reth_ethereum::EthPrimitives;
+use reth_primitives_traits::{Recovered, SealedBlock};
+use reth_storage_api::HeaderProvider;
+use reth_transaction_pool::blobstore::DiskFileBlobStore;
+use reth_transaction_pool::error::InvalidPoolTransactionError;
+use reth_transaction_pool::validate::ValidTransaction;
+use reth_transaction_pool::{
+    CoinbaseTipOrdering, EthPooledTransaction, EthTransactionValidator, Pool, PoolConfig,
+    PoolTransaction, TransactionOrigin, TransactionValidationOutcome,
+    TransactionValidationTaskExecutor, TransactionValidator,
+};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// The pool [`PoaPoolBuilder`] builds.
+pub type PoaTransactionPool<Provider> = Pool<
+    MinGasPriceValidator<
+        MaxTxGasValidator<
+            CalldataGasValidator<
+                TransactionValidationTaskExecutor<
+                    EthTransactionValidator<Provider, EthPooledTransaction>,
+                >,
+                Provider,
+            >,
+            Provider,
+        >,
+    >,
+    CoinbaseTipOrdering<EthPooledTransaction>,
+    DiskFileBlobStore,
+>;
+
+/// Validate `transactions` with `inner`, rejecting those failing `check` up front.
+///
+/// The ones that pass go through `inner` as one batch; outcomes keep the
+/// submission order.
+async fn validate_checked<V: TransactionValidator>(
+    inner: &V,
+    transactions: Vec<(TransactionOrigin, V::Transaction)>,
+    check: impl Fn(&V::Transaction) -> Result<(), InvalidPoolTransactionError>,
+) -> Vec<TransactionValidationOutcome<V::Transaction>> {
+    let mut outcomes = Vec::with_capacity(transactions.len());
+    let mut passed = Vec::new();
+    for (origin, transaction) in transactions {
+        match check(&transaction) {
+            Err(err) => outcomes.push(Some(TransactionValidationOutcome::Invalid(
+                transaction,
+                err,
+            ))),
+            Ok(()) => {
+                outcomes.push(None);
+                passed.push((origin, transaction));
+            }
+        }
+    }
+    let mut validated = inner.validate_transactions(passed).await.into_iter();
+    outcomes
+        .into_iter()
+        .map(|outcome| {
+            outcome.unwrap_or_else(|| validated.next().expect("one outcome per transaction"))
+        })
+        .collect()
+}
+
+/// Validator wrapper rejecting transactions priced below a floor.
+///
+/// The price compared is the effective gas price at the pending block's base fee:
+/// `min(max_fee, base_fee + max_priority_fee)` for EIP-1559 transactions, the gas
+/// price for legacy ones. The base fee is tracked from each new head, so a
+/// transaction whose fee cap clears the floor but whose tip is too small to lift it
+/// there is still rejected.
+#[derive(Debug)]
+pub struct MinGasPriceValidator<V> {
+    inner: V,
+    /// Minimum effective gas price in wei (`0` = no floor).
+    min_gas_price: u128,
+    /// Base fee parameters used to derive the pending base fee from a new head.
+    base_fee_params: BaseFeeParams,
+    /// Base fee of the next block.
+    pending_base_fee: AtomicU64,
+}
+
+impl<V> MinGasPriceValidator<V> {
+    /// Wrap `inner`, rejecting transactions below `min_gas_price` wei.
+    pub fn new(inner: V, min_gas_price: u128, base_fee_params: BaseFeeParams) -> Self {
+        Self {
+            inner,
+            min_gas_price,
+            base_fee_params,
+            pending_base_fee: AtomicU64::new(0),
+        }
+    }
+
+    /// Set the base fee of the next block (updated on every new head afterwards).
+    pub fn with_pending_base_fee(self, base_fee: u64) -> Self {
+        self.pending_base_fee.store(base_fee, Ordering::Relaxed);
+        self
+    }
+
+    /// The configured floor in wei.
+    pub fn min_gas_price(&self) -> u128 {
+        self.min_gas_price
+    }
+
+    /// Reject `transaction` if its effective gas price is below the floor.
+    pub fn check_price(
+        &self,
+        transaction: &impl Transaction,
+    ) -> Result<(), InvalidPoolTransactionError> {
+        check_min_gas_price(
+            transaction,
+            self.min_gas_price,
+            self.pending_base_fee.load(Ordering::Relaxed),
+        )
+    }
+}
+
+impl<V> TransactionValidator for MinGasPriceValidator<V>
+where
+    V: TransactionValidator,
+{
+    type Transaction = V::Transaction;
+    type Block = V::Block;
+
+    async fn validate_transaction(
+        &self,
+        origin: TransactionOrigin,
+        transaction: Self::Transaction,
+    ) -> TransactionValidationOutcome<Self::Transaction> {
+        if let Err(err) = self.check_price(&transaction) {
+            return TransactionValidationOutcome::Invalid(transaction, err);
+        }
+        self.inner.validate_transaction(origin, transaction).await
+    }
+
+    async fn validate_transactions(
+        &self,
+        transactions: Vec<(TransactionOrigin, Self::Transaction)>,
+    ) -> Vec<TransactionValidationOutcome<Self::Transaction>> {
+        validate_checked(&self.inner, transactions, |tx| self.check_price(tx)).await
+    }
+
+    fn on_new_head_block(&self, new_tip_block: &SealedBlock<Self::Block>) {
+        if let Some(base_fee) = new_tip_block
+            .header()
+            .next_block_base_fee(self.base_fee_params)
+        {
+            self.pending_base_fee.store(base_fee, Ordering::Relaxed);
+        }
+        self.inner.on_new_head_block(new_tip_block);
+    }
+}
+
+/// Reject `transaction` if its effective gas price at `pending_base_fee` is below
+/// `min_gas_price` wei, as [`MinGasPriceValidator`] does.
+pub fn check_min_gas_price(
+    transaction: &impl Transaction,
+    min_gas_price: u128,
+    pending_base_fee: u64,
+) -> Result<(), InvalidPoolTransactionError> {
+    if transaction.effective_gas_price(Some(pending_base_fee)) < min_gas_price {
+        return Err(InvalidPoolTransactionError::Underpriced);
+    }
+    Ok(())
+}
+
+/// Reject `transaction` if its gas limit exceeds `max_tx_gas` (`0` = no cap), as
+/// [`MaxTxGasValidator`] does.
+pub fn check_max_tx_gas(
+    transaction: &impl Transaction,
+    max_tx_gas: u64,
+) -> Result<(), InvalidPoolTransactionError> {
+    if max_tx_gas > 0 && transaction.gas_limit() > max_tx_gas {
+        return Err(InvalidPoolTransactionError::MaxTxGasLimitExceeded(
+            transaction.gas_limit(),
+            max_tx_gas,
+        ));
+    }
+    Ok(())
+}
+
+/// Reads the live `ChainConfig.maxTxGas`.
+pub trait MaxTxGasSource: Send + Sync {
+    /// `maxTxGas` as of block `block_hash` (latest state if `None`), or `None` if
+    /// the contract isn't deployed or the state is unavailable.
+    fn max_tx_gas(&self, block_hash: Option<B256>) -> Option<u64>;
+}
+
+impl<P: StateProviderFactory + Send + Sync> MaxTxGasSource for P {
+    fn max_tx_gas(&self, block_hash: Option<B256>) -> Option<u64> {
+        let state = match block_hash {
+            Some(hash) => self.state_by_block_hash(hash),
+            None => self.latest(),
+        }
+        .ok()?;
+        read_max_tx_gas(&StateProviderStorageReader::new(state.as_ref()))
+    }
+}
+
+/// Validator wrapper rejecting transactions above the live `ChainConfig.maxTxGas`.
+///
+/// Governance tunes `maxTxGas` separately from the block gas limit to cap what a
+/// single transaction can use. The cap is read when the pool starts and again at
+/// every new head; `0` or a missing `ChainConfig` leaves transactions uncapped.
+#[derive(Debug)]
+pub struct MaxTxGasValidator<V, S> {
+    inner: V,
+    source: S,
+    /// Current `maxTxGas` (`0` = no cap).
+    max_tx_gas: AtomicU64,
+}
+
+impl<V, S: MaxTxGasSource> MaxTxGasValidator<V, S> {
+    /// Wrap `inner`, reading the cap from `source`'s latest state.
+    pub fn new(inner: V, source: S) -> Self {
+        let max_tx_gas = source.max_tx_gas(None).unwrap_or_default();
+        Self {
+            inner,
+            source,
+            max_tx_gas: AtomicU64::new(max_tx_gas),
+        }
+    }
+
+    /// The cap currently enforced (`0` = none).
+    pub fn max_tx_gas(&self) -> u64 {
+        self.max_tx_gas.load(Ordering::Relaxed)
+    }
+
+    /// Reject `transaction` if its gas limit exceeds the cap.
+    pub fn check_gas_limit(
+        &self,
+        transaction: &impl Transaction,
+    ) -> Result<(), InvalidPoolTransactionError> {
+        check_max_tx_gas(transaction, self.max_tx_gas())
+    }
+}
+
+impl<V, S> TransactionValidator for MaxTxGasValidator<V, S>
+where
+    V: TransactionValidator,
+    S: MaxTxGasSource + std::fmt::Debug,
+{
+    type Transaction = V::Transaction;
+    type Block = V::Block;
+
+    async fn validate_transaction(
+        &self,
+        origin: TransactionOrigin,
+        transaction: Self::Transaction,
+    ) -> TransactionValidationOutcome<Self::Transaction> {
+        if let Err(err) = self.check_gas_limit(&transaction) {
+            return TransactionValidationOutcome::Invalid(transaction, err);
+        }
+        self.inner.validate_transaction(origin, transaction).await
+    }
+
+    async fn validate_transactions(
+        &self,
+        transactions: Vec<(TransactionOrigin, Self::Transaction)>,
+    ) -> Vec<TransactionValidationOutcome<Self::Transaction>> {
+        validate_checked(&self.inner, transactions, |tx| self.check_gas_limit(tx)).await
+    }
+
+    fn on_new_head_block(&self, new_tip_block: &SealedBlock<Self::Block>) {
+        // Keep the last known cap if the new head's state can't be read
+        if let Some(max_tx_gas) = self.source.max_tx_gas(Some(new_tip_block.hash())) {
+            self.max_tx_gas.store(max_tx_gas, Ordering::Relaxed);
+        }
+        self.inner.on_new_head_block(new_tip_block);
+    }
+}
+
+/// Reads the live `ChainConfig.calldataGasPerByte`.
+pub trait CalldataGasSource: Send + Sync {
+    /// `calldataGasPerByte` as of block `block_hash` (latest state if `None`), or
+    /// `None` if the contract isn't deployed or the state is unavailable.
+    fn calldata_gas_per_byte(&self, block_hash: Option<B256>) -> Option<u64>;
+}
+
+impl<P: StateProviderFactory + Send + Sync> CalldataGasSource for P {
+    fn calldata_gas_per_byte(&self, block_hash: Option<B256>) -> Option<u64> {
+        let state = match block_hash {
+            Some(hash) => self.state_by_block_hash(hash),
+            None => self.latest(),
+        }
+        .ok()?;
+        read_calldata_gas_per_byte(&StateProviderStorageReader::new(state.as_ref()))
+    }
+}
+
+/// Validator wrapper admitting transactions whose gas limit covers the intrinsic gas
+/// at the governed calldata cost, as the EVM charges it (see
+/// [`intrinsic_gas`](crate::evm::intrinsic_gas)).
+///
+/// Reth's validator checks gas limits against mainnet's intrinsic gas. A transaction
+/// it rejects as `intrinsic gas too low` is validated again as a copy whose gas limit
+/// is raised by the calldata discount, which is the mainnet intrinsic gas exactly when
+/// the original limit covers the governed one; if the copy passes, the original is
+/// admitted. The copy's raised limit also counts in its balance check. The EIP-7623
+/// calldata floor keeps mainnet pricing and is checked on the original limit.
+///
+/// The cost is read like the EVM reads it: the live `ChainConfig.calldataGasPerByte`
+/// when set and within [`CALLDATA_GAS_RANGE`], `--calldata-gas` otherwise.
+#[derive(Debug)]
+pub struct CalldataGasValidator<V, S> {
+    inner: V,
+    source: S,
+    chain_spec: Arc<ChainSpec>,
+    /// Cost used while the governed one is unset or out of range.
+    default_calldata_gas: u64,
+    /// Current calldata gas cost per non-zero byte.
+    calldata_gas_per_byte: AtomicU64,
+    /// Timestamp of the current head, for the Prague calldata floor.
+    head_timestamp: AtomicU64,
+}
+
+impl<V, S: CalldataGasSource> CalldataGasValidator<V, S> {
+    /// Wrap `inner`, reading the cost from `source`'s latest state and falling back
+    /// to `default_calldata_gas`.
+    pub fn new(inner: V, source: S, chain_spec: Arc<ChainSpec>, default_calldata_gas: u64) -> Self {
+        let calldata_gas_per_byte =
+            Self::effective_cost(source.calldata_gas_per_byte(None), default_calldata_gas);
+        Self {
+            inner,
+            source,
+            chain_spec,
+            default_calldata_gas,
+            calldata_gas_per_byte: AtomicU64::new(calldata_gas_per_byte),
+            head_timestamp: AtomicU64::new(0),
+        }
+    }
+
+    /// Set the timestamp of the current head (updated on every new head afterwards).
+    pub fn with_head_timestamp(self, timestamp: u64) -> Self {
+        self.head_timestamp.store(timestamp, Ordering::Relaxed);
+        self
+    }
+
+    /// The calldata gas cost per non-zero byte currently applied.
+    pub fn calldata_gas_per_byte(&self) -> u64 {
+        self.calldata_gas_per_byte.load(Ordering::Relaxed)
+    }
+
+    fn effective_cost(governed: Option<u64>, default_calldata_gas: u64) -> u64 {
+        governed
+            .filter(|cost| CALLDATA_GAS_RANGE.contains(cost))
+            .unwrap_or(default_calldata_gas)
+    }
+
+    /// Whether `transaction`'s gas limit is below the EIP-7623 calldata floor.
+    fn below_calldata_floor(&self, transaction: &impl Transaction) -> bool {
+        let timestamp = self.head_timestamp.load(Ordering::Relaxed);
+        self.chain_spec.is_prague_active_at_timestamp(timestamp)
+            && transaction.gas_limit()
+                < calc_tx_floor_cost(get_tokens_in_calldata(transaction.input(), true))
+    }
+}
+
+impl<V, S> CalldataGasValidator<V, S>
+where
+    V: TransactionValidator<Transaction = EthPooledTransaction>,
+    S: CalldataGasSource,
+{
+    /// Validate again a transaction `outcome` rejected for mainnet's intrinsic gas,
+    /// at the governed calldata cost.
+    async fn revalidate(
+        &self,
+        origin: TransactionOrigin,
+        outcome: TransactionValidationOutcome<EthPooledTransaction>,
+    ) -> TransactionValidationOutcome<EthPooledTransaction> {
+        let transaction = match outcome {
+            TransactionValidationOutcome::Invalid(
+                transaction,
+                InvalidPoolTransactionError::IntrinsicGasTooLow,
+            ) => transaction,
+            outcome => return outcome,
+        };
+        let discount = calldata_discount(transaction.input(), self.calldata_gas_per_byte());
+        let raised = (discount > 0 && !self.below_calldata_floor(&transaction))
+            .then(|| {
+                with_gas_limit(
+                    &transaction,
+                    transaction.gas_limit().saturating_add(discount),
+                )
+            })
+            .flatten();
+        let Some(raised) = raised else {
+            return TransactionValidationOutcome::Invalid(
+                transaction,
+                InvalidPoolTransactionError::IntrinsicGasTooLow,
+            );
+        };
+        let mut outcome = self.inner.validate_transaction(origin, raised).await;
+        match &mut outcome {
+            TransactionValidationOutcome::Valid {
+                transaction: valid, ..
+            } => *valid = ValidTransaction::Valid(transaction),
+            TransactionValidationOutcome::Invalid(invalid, _) => *invalid = transaction,
+            TransactionValidationOutcome::Error(..) => {}
+        }
+        outcome
+    }
+}
+
+impl<V, S> TransactionValidator for CalldataGasValidator<V, S>
+where
+    V: TransactionValidator<Transaction = EthPooledTransaction>,
+    S: CalldataGasSource + std::fmt::Debug,
+{
+    type Transaction = EthPooledTransaction;
+    type Block = V::Block;
+
+    async fn validate_transaction(
+        &self,
+        origin: TransactionOrigin,
+        transaction: Self::Transaction,
+    ) -> TransactionValidationOutcome<Self::Transaction> {
+        let outcome = self.inner.validate_transaction(origin, transaction).await;
+        self.revalidate(origin, outcome).await
+    }
+
+    async fn validate_transactions(
+        &self,
+        transactions: Vec<(TransactionOrigin, Self::Transaction)>,
+    ) -> Vec<TransactionValidationOutcome<Self::Transaction>> {
+        let origins: Vec<_> = transactions.iter().map(|(origin, _)| *origin).collect();
+        let outcomes = self.inner.validate_transactions(transactions).await;
+        let mut validated = Vec::with_capacity(outcomes.len());
+        for (origin, outcome) in origins.into_iter().zip(outcomes) {
+            validated.push(self.revalidate(origin, outcome).await);
+        }
+        validated
+    }
+
+    fn on_new_head_block(&self, new_tip_block: &SealedBlock<Self::Block>) {
+        self.head_timestamp
+            .store(new_tip_block.header().timestamp(), Ordering::Relaxed);
+        // Keep the last known cost if the new head's state can't be read
+        if let Some(governed) = self
+            .source
+            .calldata_gas_per_byte(Some(new_tip_block.hash()))
+        {
+            let cost = Self::effective_cost(Some(governed), self.default_calldata_gas);
+            self.calldata_gas_per_byte.store(cost, Ordering::Relaxed);
+        }
+        self.inner.on_new_head_block(new_tip_block);
+    }
+}
+
+/// A copy of `transaction` with its gas limit set to `gas_limit`, keeping its hash,
+/// signature and sender. `None` for blob transactions, whose sidecar isn't copied.
+///
+/// The copy's signature doesn't match its contents, so it must only be validated,
+/// never pooled.
+fn with_gas_limit(
+    transaction: &EthPooledTransaction,
+    gas_limit: u64,
+) -> Option<EthPooledTransaction> {
+    fn raised<T>(signed: Signed<T>, set_gas_limit: impl FnOnce(&mut T)) -> Signed<T> {
+        let (mut tx, signature, hash) = signed.into_parts();
+        set_gas_limit(&mut tx);
+        Signed::new_unchecked(tx, signature, hash)
+    }
+
+    let (signed, sender) = transaction.clone_into_consensus().into_parts();
+    let signed = match signed {
+        EthereumTxEnvelope::Legacy(tx) => raised(tx, |tx| tx.gas_limit = gas_limit).into(),
+        EthereumTxEnvelope::Eip2930(tx) => raised(tx, |tx| tx.gas_limit = gas_limit).into(),
+        EthereumTxEnvelope::Eip1559(tx) => raised(tx, |tx| tx.gas_limit = gas_limit).into(),
+        EthereumTxEnvelope::Eip7702(tx) => raised(tx, |tx| tx.gas_limit = gas_limit).into(),
+        EthereumTxEnvelope::Eip4844(_) => return None,
+    };
+    Some(EthPooledTransaction::new(
+        Recovered::new_unchecked(signed, sender),
+        transaction.encoded_length(),
+    ))
+}
+
+/// Pool capacity overrides; `None` keeps the value from Reth's pool config.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolLimits {
+    /// Maximum transactions in the pending sub-pool.
+    pub max_pending: Option<usize>,
+    /// Maximum transactions in the queued sub-pool (nonce gaps, insufficient funds).
+    pub max_queued: Option<usize>,
+    /// Maximum transactions one sender may have in the pool.
+    pub max_per_account: Option<usize>,
+}
+
+impl PoolLimits {
+    /// Apply the overrides to `config`.
+    pub fn apply(&self, config: &mut PoolConfig) {
+        if let Some(max_txs) = self.max_pending {
+            config.pending_limit.max_txs = max_txs;
+        }
+        if let Some(max_txs) = self.max_queued {
+            config.queued_limit.max_txs = max_txs;
+        }
+        if let Some(slots) = self.max_per_account {
+            config.max_account_slots = slots;
+        }
+    }
+}
+
+/// Builds the Ethereum transaction pool with a [`MinGasPriceValidator`], a
+/// [`MaxTxGasValidator`] and a [`CalldataGasValidator`].
+#[derive(Debug, Clone)]
+pub struct PoaPoolBuilder {
+    /// Minimum effective gas price in wei (`0` = no floor).
+    min_gas_price: u128,
+    /// Capacity overrides for the pool config.
+    limits: PoolLimits,
+    /// Calldata gas cost per non-zero byte while `ChainConfig` doesn't set one.
+    calldata_gas_per_byte: u64,
+}
+
+impl Default for PoaPoolBuilder {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl PoaPoolBuilder {
+    /// Create a pool builder rejecting transactions below `min_gas_price` wei.
+    pub fn new(min_gas_price: u128) -> Self {
+        Self {
+            min_gas_price,
+            limits: PoolLimits::default(),
+            calldata_gas_per_byte: 16,
+        }
+    }
+
+    /// Set the calldata gas cost used while `ChainConfig.calldataGasPerByte` is
+    /// unset (`--calldata-gas`).
+    pub fn with_calldata_gas(mut self, cost: u64) -> Self {
+        self.calldata_gas_per_byte = cost;
+        self
+    }
+
+    /// Override the pool's sub-pool and per-sender limits.
+    pub fn with_limits(mut self, limits: PoolLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+}
+
+impl<N> PoolBuilder<N> for PoaPoolBuilder
+where
+    N: FullNodeTypes<Types: NodeTypes<ChainSpec = ChainSpec, Primitives = EthPrimitives>>,
+{
+    type Pool = PoaTransactionPool<N::Provider>;
+
+    async fn build_pool(self, ctx: &BuilderContext<N>) -> eyre::Result<Self::Pool> {
+        let mut pool_config = ctx.pool_config();
+        self.limits.apply(&mut pool_config);
+        let blob_store = create_blob_store_with_cache(ctx, pool_config.blob_cache_size)?;
+        let validator = TransactionValidationTaskExecutor::eth_builder(ctx.provider().clone())
+            .with_head_timestamp(ctx.head().timestamp)
+            .kzg_settings(ctx.kzg_settings()?)
+            .with_local_transactions_config(pool_config.local_transactions_config.clone())
+            .set_tx_fee_cap(ctx.config().rpc.rpc_tx_fee_cap)
+            .with_max_tx_input_bytes(ctx.config().txpool.max_tx_input_bytes)
+            .with_additional_tasks(ctx.config().txpool.additional_validation_tasks)
+            .build_with_tasks(ctx.task_executor().clone(), blob_store.clone());
+
+        let base_fee_params = ctx
+            .chain_spec()
+            .base_fee_params_at_timestamp(ctx.head().timestamp);
+        let pending_base_fee = ctx
+            .provider()
+            .sealed_header(ctx.head().number)?
+            .and_then(|header| header.next_block_base_fee(base_fee_params))
+            .unwrap_or_default();
+        let validator = CalldataGasValidator::new(
+            validator,
+            ctx.provider().clone(),
+            ctx.chain_spec(),
+            self.calldata_gas_per_byte,
+        )
+        .with_head_timestamp(ctx.head().timestamp);
+        let validator = MaxTxGasValidator::new(validator, ctx.provider().clone());
+        let validator = MinGasPriceValidator::new(validator, self.min_gas_price, base_fee_params)
+            .with_pending_base_fee(pending_base_fee);
+
+        Ok(TxPoolBuilder::new(ctx)
+            .with_validator(validator)
+            .build_and_spawn_maintenance_task(blob_store, pool_config)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Address;
+    use reth_transaction_pool::blobstore::InMemoryBlobStore;
+    use reth_transaction_pool::test_utils::{
+        MockOrdering, MockTransaction, MockTransactionValidator,
+    };
+    use reth_transaction_pool::TransactionPool;
+
+    const GWEI: u128 = 1_000_000_000;
+
+    #[tokio::test]
+    async fn test_pool_rejects_below_min_gas_price() {
+        // 1 gwei floor, pending base fee 0.5 gwei
+        let validator = MinGasPriceValidator::new(
+            MockTransactionValidator::default(),
+            GWEI,
+            BaseFeeParams::ethereum(),
+        )
+        .with_pending_base_fee((GWEI / 2) as u64);
+        let pool = Pool::new(
+            validator,
+            MockOrdering::default(),
+            InMemoryBlobStore::default(),
+            PoolConfig::default(),
+        );
+
+        // Fee cap above the floor, but 0.5 + 0.1 gwei effective: rejected
+        let underpriced = MockTransaction::eip1559()
+            .with_max_fee(2 * GWEI)
+            .with_priority_fee(GWEI / 10);
+        let err = pool
+            .add_transaction(TransactionOrigin::External, underpriced)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("underpriced"), "{err}");
+
+        // 0.5 + 0.5 gwei effective: accepted
+        let priced = MockTransaction::eip1559()
+            .with_max_fee(2 * GWEI)
+            .with_priority_fee(GWEI / 2);
+        pool.add_transaction(TransactionOrigin::External, priced)
+            .await
+            .unwrap();
+
+        // Legacy transactions compare their gas price
+        let legacy = MockTransaction::legacy().with_gas_price(GWEI - 1);
+        assert!(pool
+            .add_transaction(TransactionOrigin::External, legacy)
+            .await
+            .is_err());
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_pool_rejects_beyond_per_account_limit() {
+        let mut config = PoolConfig::default();
+        let defaults = config.clone();
+        PoolLimits::default().apply(&mut config);
+        assert_eq!(config.max_account_slots, defaults.max_account_slots);
+
+        let limits = PoolLimits {
+            max_pending: Some(100),
+            max_queued: Some(50),
+            max_per_account: Some(2),
+        };
+        limits.apply(&mut config);
+        assert_eq!(config.pending_limit.max_txs, 100);
+        assert_eq!(config.queued_limit.max_txs, 50);
+        let pool = Pool::new(
+            MockTransactionValidator::default(),
+            MockOrdering::default(),
+            InMemoryBlobStore::default(),
+            config,
+        );
+
+        let spammer = Address::with_last_byte(0x5a);
+        for nonce in 0..2 {
+            let tx = MockTransaction::eip1559()
+                .with_sender(spammer)
+                .with_nonce(nonce);
+            pool.add_transaction(TransactionOrigin::External, tx)
+                .await
+                .unwrap();
+        }
+        // A third transaction from the same sender exceeds its slots
+        let over = MockTransaction::eip1559()
+            .with_sender(spammer)
+            .with_nonce(2);
+        assert!(pool
+            .add_transaction(TransactionOrigin::External, over)
+            .await
+            .is_err());
+        // Other senders are unaffected
+        pool.add_transaction(TransactionOrigin::External, MockTransaction::eip1559())
+            .await
+            .unwrap();
+        assert_eq!(pool.len(), 3);
+    }
+
+    #[test]
+    fn test_zero_floor_accepts_everything() {
+        let validator = MinGasPriceValidator::new((), 0, BaseFeeParams::ethereum());
+        let tx = MockTransaction::legacy().with_gas_price(0);
+        assert!(validator.check_price(&tx).is_ok());
+        assert_eq!(validator.min_gas_price(), 0);
+    }
+
+    /// `ChainConfig` read from the dev genesis with `maxTxGas` lowered to 1M gas,
+    /// below the 300M block gas limit.
+    #[derive(Debug)]
+    struct GenesisMaxTxGas(alloy_genesis::Genesis);
+
+    impl GenesisMaxTxGas {
+        fn with_max_tx_gas(max_tx_gas: u64) -> Self {
+            use crate::onchain::{chain_config_slots, encode_u64};
+            let mut genesis = crate::genesis::create_dev_genesis();
+            let chain_config = genesis
+                .alloc
+                .get_mut(&crate::genesis::CHAIN_CONFIG_ADDRESS)
+                .unwrap();
+            chain_config.storage.get_or_insert_default().insert(
+                B256::from(chain_config_slots::MAX_TX_GAS.to_be_bytes()),
+                encode_u64(max_tx_gas),
+            );
+            Self(genesis)
+        }
+    }
+
+    impl MaxTxGasSource for GenesisMaxTxGas {
+        fn max_tx_gas(&self, _block_hash: Option<B256>) -> Option<u64> {
+            read_max_tx_gas(&crate::onchain::GenesisStorageReader::from_genesis(&self.0))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pool_rejects_above_max_tx_gas() {
+        let validator = MaxTxGasValidator::new(
+            MockTransactionValidator::default(),
+            GenesisMaxTxGas::with_max_tx_gas(1_000_000),
+        );
+        assert_eq!(validator.max_tx_gas(), 1_000_000);
+        let pool = Pool::new(
+            validator,
+            MockOrdering::default(),
+            InMemoryBlobStore::default(),
+            PoolConfig::default(),
+        );
+
+        let too_big = MockTransaction::eip1559().with_gas_limit(1_000_001);
+        let err = pool
+            .add_transaction(TransactionOrigin::External, too_big)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("1000000"), "{err}");
+
+        let at_cap = MockTransaction::eip1559().with_gas_limit(1_000_000);
+        pool.add_transaction(TransactionOrigin::External, at_cap)
+            .await
+            .unwrap();
+        assert_eq!(pool.len(), 1);
+
+        // A zero cap leaves transactions limited only by the block gas limit
+        let uncapped = MaxTxGasValidator::new((), GenesisMaxTxGas::with_max_tx_gas(0));
+        let tx = MockTransaction::eip1559().with_gas_limit(30_000_000);
+        assert!(uncapped.check_gas_limit(&tx).is_ok());
+    }
+
+    #[test]
+    fn test_with_gas_limit_keeps_identity() {
+        use alloy_consensus::TxEip1559;
+        use alloy_primitives::{Bytes, Signature, TxKind};
+
+        let tx = TxEip1559 {
+            chain_id: 9323310,
+            gas_limit: 25_000,
+            to: TxKind::Call(Address::with_last_byte(0xbb)),
+            input: Bytes::from(vec![0xff; 1_000]),
+            ..Default::default()
+        };
+        let hash = B256::repeat_byte(0x11);
+        let sender = Address::with_last_byte(0xaa);
+        let signed = Signed::new_unchecked(tx, Signature::test_signature(), hash);
+        let pooled = EthPooledTransaction::new(
+            Recovered::new_unchecked(EthereumTxEnvelope::Eip1559(signed), sender),
+            1_100,
+        );
+
+        let raised = with_gas_limit(&pooled, 25_000 + 12_000).unwrap();
+        assert_eq!(raised.gas_limit(), 37_000);
+        assert_eq!(*raised.hash(), hash);
+        assert_eq!(raised.sender(), sender);
+        assert_eq!(raised.encoded_length(), pooled.encoded_length());
+        assert_eq!(raised.input(), pooled.input());
+        // The original is untouched
+        assert_eq!(pooled.gas_limit(), 25_000);
+    }
+
+    #[derive(Debug)]
+    struct FixedCalldataGas(Option<u64>);
+
+    impl CalldataGasSource for FixedCalldataGas {
+        fn calldata_gas_per_byte(&self, _block_hash: Option<B256>) -> Option<u64> {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_calldata_gas_validator_ignores_out_of_range_cost() {
+        let chain_spec = Arc::new(ChainSpec::default());
+        let governed =
+            CalldataGasValidator::new((), FixedCalldataGas(Some(4)), chain_spec.clone(), 16);
+        assert_eq!(governed.calldata_gas_per_byte(), 4);
+        let unset = CalldataGasValidator::new((), FixedCalldataGas(None), chain_spec.clone(), 8);
+        assert_eq!(unset.calldata_gas_per_byte(), 8);
+        let invalid = CalldataGasValidator::new((), FixedCalldataGas(Some(20)), chain_spec, 8);
+        assert_eq!(invalid.calldata_gas_per_byte(), 8);
+    }
+}
@@ -0,0 +1,391 @@
+//! Peer reputation penalties for blocks that fail POA validation.
+//!
+//! When a peer hands us a block whose header fails POA checks (unauthorized signer,
+//! bad seal, ...), we penalize that peer on the network handle instead of silently
+//! dropping the block. Repeated offenders fall below Reth's ban threshold and are
+//! disconnected.
+//!
+//! Reth's engine-driven import does not attribute blocks to peers, so the check runs
+//! where the sending peer is known: [`PoaBlockImport`] receives the blocks peers
+//! announce with `NewBlock` and validates their headers before anything else.
+
+use crate::consensus::{PoaConsensus, PoaConsensusError};
+use crate::output;
+use alloy_consensus::Header;
+use reth_consensus::{ConsensusError, HeaderValidator};
+use reth_ethereum::network::api::{Peers, ReputationChangeKind};
+use reth_ethereum::network::import::{BlockImport, BlockImportEvent, NewBlockEvent};
+use reth_ethereum::network::{EthNetworkPrimitives, NetworkPrimitives};
+use reth_network_peers::PeerId;
+use reth_primitives_traits::SealedHeader;
+use std::sync::{Arc, OnceLock};
+use std::task::{Context, Poll};
+
+/// Block announcement payload of the Ethereum wire protocol.
+type EthNewBlock = <EthNetworkPrimitives as NetworkPrimitives>::NewBlockPayload;
+
+/// One reputation unit, matching Reth's peer manager (`REPUTATION_UNIT`).
+pub const REPUTATION_UNIT: i32 = -1024;
+
+/// Sink for reputation changes. Implemented for every Reth [`Peers`] handle.
+pub trait ReputationReporter: Send + Sync {
+    /// Apply a reputation change to `peer`.
+    fn reputation_change(&self, peer: PeerId, kind: ReputationChangeKind);
+}
+
+impl<T: Peers> ReputationReporter for T {
+    fn reputation_change(&self, peer: PeerId, kind: ReputationChangeKind) {
+        Peers::reputation_change(self, peer, kind);
+    }
+}
+
+/// Reporter whose network handle is set after construction.
+///
+/// The block import is part of the network config, so it exists before the network
+/// handle does. Changes reported before [`DeferredReporter::set`] are dropped.
+#[derive(Debug)]
+pub struct DeferredReporter<R> {
+    inner: Arc<OnceLock<R>>,
+}
+
+impl<R> DeferredReporter<R> {
+    /// Forward reputation changes to `reporter` from now on. Later calls are ignored.
+    pub fn set(&self, reporter: R) {
+        let _ = self.inner.set(reporter);
+    }
+}
+
+impl<R> Default for DeferredReporter<R> {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(OnceLock::new()),
+        }
+    }
+}
+
+impl<R> Clone for DeferredReporter<R> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<R: ReputationReporter> ReputationReporter for DeferredReporter<R> {
+    fn reputation_change(&self, peer: PeerId, kind: ReputationChangeKind) {
+        if let Some(reporter) = self.inner.get() {
+            reporter.reputation_change(peer, kind);
+        }
+    }
+}
+
+/// Why a peer's block was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PenaltyReason {
+    /// Block sealed by a signer outside the authorized set.
+    UnauthorizedSigner,
+    /// Seal missing, malformed, or unrecoverable.
+    InvalidSeal,
+    /// Any other header validation failure.
+    InvalidBlock,
+}
+
+impl PenaltyReason {
+    /// Classify a consensus validation error.
+    pub fn from_error(err: &ConsensusError) -> Self {
+        let poa = match err {
+            ConsensusError::Custom(inner) => inner.downcast_ref::<PoaConsensusError>(),
+            _ => None,
+        };
+        match poa {
+            Some(PoaConsensusError::UnauthorizedSigner { .. }) => Self::UnauthorizedSigner,
+            Some(PoaConsensusError::InvalidSignature)
+            | Some(PoaConsensusError::ExtraDataTooShort { .. }) => Self::InvalidSeal,
+            _ => Self::InvalidBlock,
+        }
+    }
+
+    /// Human-readable reason used in logs.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UnauthorizedSigner => "unauthorized signer",
+            Self::InvalidSeal => "invalid seal",
+            Self::InvalidBlock => "invalid block",
+        }
+    }
+}
+
+/// Configurable penalty (reputation delta, negative) per [`PenaltyReason`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoaPenalties {
+    /// Penalty for a block sealed by an unauthorized signer.
+    pub unauthorized_signer: i32,
+    /// Penalty for a block with a missing or forged seal.
+    pub invalid_seal: i32,
+    /// Penalty for any other invalid header.
+    pub invalid_block: i32,
+}
+
+impl Default for PoaPenalties {
+    /// Unauthorized signers and forged seals are malicious (Reth's bad-block penalty);
+    /// other failures may be honest mistakes and are penalized lightly.
+    fn default() -> Self {
+        Self {
+            unauthorized_signer: 16 * REPUTATION_UNIT,
+            invalid_seal: 16 * REPUTATION_UNIT,
+            invalid_block: 4 * REPUTATION_UNIT,
+        }
+    }
+}
+
+impl PoaPenalties {
+    /// Reputation delta for a rejection reason.
+    pub fn penalty_for(&self, reason: PenaltyReason) -> i32 {
+        match reason {
+            PenaltyReason::UnauthorizedSigner => self.unauthorized_signer,
+            PenaltyReason::InvalidSeal => self.invalid_seal,
+            PenaltyReason::InvalidBlock => self.invalid_block,
+        }
+    }
+}
+
+/// Validates headers received from peers and penalizes senders of invalid blocks.
+#[derive(Debug, Clone)]
+pub struct PeerBlockValidator<R> {
+    consensus: Arc<PoaConsensus>,
+    reporter: R,
+    penalties: PoaPenalties,
+}
+
+impl<R: ReputationReporter> PeerBlockValidator<R> {
+    /// Create a validator with default penalties.
+    pub fn new(consensus: Arc<PoaConsensus>, reporter: R) -> Self {
+        Self {
+            consensus,
+            reporter,
+            penalties: PoaPenalties::default(),
+        }
+    }
+
+    /// Override the penalty table.
+    pub fn with_penalties(mut self, penalties: PoaPenalties) -> Self {
+        self.penalties = penalties;
+        self
+    }
+
+    /// Validate a header received from `peer`, penalizing the peer on failure.
+    ///
+    /// The validation error is returned unchanged so callers still drop the block.
+    pub fn validate_from_peer(
+        &self,
+        peer: PeerId,
+        header: &SealedHeader<Header>,
+    ) -> Result<(), ConsensusError> {
+        self.consensus.validate_header(header).inspect_err(|err| {
+            let reason = PenaltyReason::from_error(err);
+            let penalty = self.penalties.penalty_for(reason);
+            output::print_peer_penalized(&peer, reason.as_str(), penalty);
+            self.reporter
+                .reputation_change(peer, ReputationChangeKind::Other(penalty));
+        })
+    }
+}
+
+/// Network block import that penalizes peers announcing blocks that fail POA
+/// validation.
+///
+/// Valid blocks are left to the engine, which imports them through the usual sync
+/// path; invalid ones are dropped here after the sender has been penalized.
+#[derive(Debug)]
+pub struct PoaBlockImport<R> {
+    validator: PeerBlockValidator<R>,
+}
+
+impl<R: ReputationReporter> PoaBlockImport<R> {
+    /// Validate announced blocks with `validator`.
+    pub fn new(validator: PeerBlockValidator<R>) -> Self {
+        Self { validator }
+    }
+
+    /// Check the header of a block announced by `peer`.
+    pub fn on_peer_block(&self, peer: PeerId, header: Header) -> Result<(), ConsensusError> {
+        self.validator
+            .validate_from_peer(peer, &SealedHeader::seal_slow(header))
+    }
+}
+
+impl<R> BlockImport<EthNewBlock> for PoaBlockImport<R>
+where
+    R: ReputationReporter + std::fmt::Debug + 'static,
+{
+    fn on_new_block(&mut self, peer_id: PeerId, incoming_block: NewBlockEvent<EthNewBlock>) {
+        // Hash-only announcements carry no header to check
+        if let NewBlockEvent::Block(message) = incoming_block {
+            let _ = self.on_peer_block(peer_id, message.block.block.header.clone());
+        }
+    }
+
+    fn poll(&mut self, _cx: &mut Context<'_>) -> Poll<BlockImportEvent<EthNewBlock>> {
+        // Penalties are applied directly; there are no import outcomes to report
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chainspec::PoaChainSpec;
+    use crate::consensus::{EXTRA_SEAL_LENGTH, EXTRA_VANITY_LENGTH};
+    use crate::signer::{dev, BlockSealer, SignerManager};
+    use std::sync::Mutex;
+
+    /// Records every reputation change instead of touching a real network.
+    #[derive(Clone, Default)]
+    struct MockNetwork {
+        calls: Arc<Mutex<Vec<(PeerId, ReputationChangeKind)>>>,
+    }
+
+    impl ReputationReporter for MockNetwork {
+        fn reputation_change(&self, peer: PeerId, kind: ReputationChangeKind) {
+            self.calls.lock().unwrap().push((peer, kind));
+        }
+    }
+
+    async fn header_signed_by(key_index: usize) -> SealedHeader<Header> {
+        let manager = Arc::new(SignerManager::new());
+        let addr = manager
+            .add_signer_from_hex(dev::DEV_PRIVATE_KEYS[key_index])
+            .await
+            .unwrap();
+        let header = Header {
+            number: 1,
+            gas_limit: 30_000_000,
+            timestamp: 12345,
+            extra_data: vec![0u8; EXTRA_VANITY_LENGTH + EXTRA_SEAL_LENGTH].into(),
+            ..Default::default()
+        };
+        let signed = BlockSealer::new(manager)
+            .seal_header(header, &addr)
+            .await
+            .unwrap();
+        SealedHeader::seal_slow(signed)
+    }
+
+    fn validator<R: ReputationReporter>(reporter: R) -> PeerBlockValidator<R> {
+        let chain = Arc::new(PoaChainSpec::dev_chain());
+        PeerBlockValidator::new(Arc::new(PoaConsensus::new(chain)), reporter)
+    }
+
+    #[tokio::test]
+    async fn test_unauthorized_signer_block_penalizes_peer() {
+        let network = MockNetwork::default();
+        let validator = validator(network.clone());
+        let peer = PeerId::repeat_byte(0x11);
+
+        // Dev chain authorizes keys 0..3; key 5 is not a signer
+        let header = header_signed_by(5).await;
+        assert!(validator.validate_from_peer(peer, &header).is_err());
+
+        let calls = network.calls.lock().unwrap();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].0, peer);
+        assert!(matches!(
+            calls[0].1,
+            ReputationChangeKind::Other(p) if p == PoaPenalties::default().unauthorized_signer
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_valid_block_does_not_penalize() {
+        let network = MockNetwork::default();
+        let validator = validator(network.clone());
+
+        let header = header_signed_by(0).await;
+        assert!(validator
+            .validate_from_peer(PeerId::repeat_byte(0x22), &header)
+            .is_ok());
+        assert!(network.calls.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_custom_penalties_and_bad_seal() {
+        let network = MockNetwork::default();
+        let penalties = PoaPenalties {
+            unauthorized_signer: -1,
+            invalid_seal: -2,
+            invalid_block: -3,
+        };
+        let validator = validator(network.clone()).with_penalties(penalties);
+
+        // Extra data too short to hold a seal
+        let header = SealedHeader::seal_slow(Header {
+            number: 1,
+            extra_data: vec![0u8; 10].into(),
+            ..Default::default()
+        });
+        assert!(validator
+            .validate_from_peer(PeerId::repeat_byte(0x33), &header)
+            .is_err());
+
+        let calls = network.calls.lock().unwrap();
+        assert!(matches!(calls[0].1, ReputationChangeKind::Other(-2)));
+    }
+
+    #[tokio::test]
+    async fn test_block_import_penalizes_unauthorized_signer() {
+        let network = MockNetwork::default();
+        // Wired the way the network builder does: handle set once the network starts
+        let reporter = DeferredReporter::default();
+        let import =
+            PoaBlockImport::new(validator(reporter.clone()).with_penalties(PoaPenalties {
+                unauthorized_signer: -7,
+                ..PoaPenalties::default()
+            }));
+        reporter.set(network.clone());
+
+        let peer = PeerId::repeat_byte(0x44);
+        let header = header_signed_by(5).await.unseal();
+        assert!(import.on_peer_block(peer, header).is_err());
+        let calls = network.calls.lock().unwrap();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].0, peer);
+        assert!(matches!(calls[0].1, ReputationChangeKind::Other(-7)));
+    }
+
+    #[test]
+    fn test_deferred_reporter_forwards_once_set() {
+        let network = MockNetwork::default();
+        let reporter = DeferredReporter::default();
+        let peer = PeerId::repeat_byte(0x55);
+
+        reporter.reputation_change(peer, ReputationChangeKind::Other(-1));
+        assert!(network.calls.lock().unwrap().is_empty());
+
+        reporter.set(network.clone());
+        reporter
+            .clone()
+            .reputation_change(peer, ReputationChangeKind::Other(-2));
+        let calls = network.calls.lock().unwrap();
+        assert_eq!(calls.len(), 1);
+        assert!(matches!(calls[0].1, ReputationChangeKind::Other(-2)));
+    }
+
+    #[test]
+    fn test_penalty_reason_classification() {
+        let err: ConsensusError = PoaConsensusError::UnauthorizedSigner {
+            signer: Default::default(),
+        }
+        .into();
+        assert_eq!(
+            PenaltyReason::from_error(&err),
+            PenaltyReason::UnauthorizedSigner
+        );
+        let err: ConsensusError = PoaConsensusError::InvalidSignature.into();
+        assert_eq!(PenaltyReason::from_error(&err), PenaltyReason::InvalidSeal);
+        let err = ConsensusError::TimestampIsInPast {
+            parent_timestamp: 10,
+            timestamp: 5,
+        };
+        assert_eq!(PenaltyReason::from_error(&err), PenaltyReason::InvalidBlock);
+    }
+}
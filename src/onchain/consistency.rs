@@ -0,0 +1,160 @@
+//! Startup check of CLI flags against governance-controlled parameters.
+//!
+//! `--gas-limit` and `--block-time` only seed genesis. Once governance changes
+//! `ChainConfig`, the flags can silently disagree with the chain. This compares
+//! them at startup and reports each mismatch with the value that actually applies:
+//!
+//! - gas limit: the on-chain value (the payload builder reads `ChainConfig.gasLimit`)
+//! - max contract size: the on-chain value (the EVM reads `ChainConfig.maxContractSize`)
+//! - block time: the CLI value (the mining interval is fixed at launch)
+
+use super::readers::{read_block_time, read_gas_limit, read_max_contract_size};
+use super::StorageReader;
+use std::fmt;
+
+/// Which side of a mismatch is in effect at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamPrecedence {
+    /// The on-chain `ChainConfig` value is used.
+    OnChain,
+    /// The CLI value is used.
+    Cli,
+}
+
+/// A CLI flag that disagrees with the on-chain `ChainConfig` value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamMismatch {
+    /// The CLI flag, e.g. `--gas-limit`.
+    pub flag: &'static str,
+    pub cli: u64,
+    pub onchain: u64,
+    pub precedence: ParamPrecedence,
+}
+
+impl fmt::Display for ParamMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (used, ignored) = match self.precedence {
+            ParamPrecedence::OnChain => ("on-chain ChainConfig", "CLI value is ignored"),
+            ParamPrecedence::Cli => ("CLI", "governance value is not applied"),
+        };
+        write!(
+            f,
+            "{} {} differs from on-chain ChainConfig {}: using {} value, {}",
+            self.flag, self.cli, self.onchain, used, ignored
+        )
+    }
+}
+
+/// Compare CLI parameters with `ChainConfig`; `None` means the flag wasn't set.
+///
+/// Parameters the reader can't resolve (e.g. contract not deployed) are skipped.
+pub fn check_cli_params(
+    reader: &impl StorageReader,
+    cli_gas_limit: Option<u64>,
+    cli_block_time: Option<u64>,
+    cli_max_contract_size: Option<u64>,
+) -> Vec<ParamMismatch> {
+    let mut mismatches = Vec::new();
+    if let (Some(cli), Some(onchain)) = (cli_gas_limit, read_gas_limit(reader)) {
+        if onchain > 0 && cli != onchain {
+            mismatches.push(ParamMismatch {
+                flag: "--gas-limit",
+                cli,
+                onchain,
+                precedence: ParamPrecedence::OnChain,
+            });
+        }
+    }
+    if let (Some(cli), Some(onchain)) = (cli_block_time, read_block_time(reader)) {
+        if onchain > 0 && cli != onchain {
+            mismatches.push(ParamMismatch {
+                flag: "--block-time",
+                cli,
+                onchain,
+                precedence: ParamPrecedence::Cli,
+            });
+        }
+    }
+    if let (Some(cli), Some(onchain)) = (cli_max_contract_size, read_max_contract_size(reader)) {
+        if onchain > 0 && cli != onchain {
+            mismatches.push(ParamMismatch {
+                flag: "--max-contract-size",
+                cli,
+                onchain,
+                precedence: ParamPrecedence::OnChain,
+            });
+        }
+    }
+    mismatches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genesis::CHAIN_CONFIG_ADDRESS;
+    use crate::onchain::{chain_config_slots, encode_u64};
+    use alloy_primitives::{Address, B256, U256};
+    use std::collections::HashMap;
+
+    struct MockReader(HashMap<(Address, U256), B256>);
+
+    impl StorageReader for MockReader {
+        fn read_storage(&self, address: Address, slot: U256) -> Option<B256> {
+            self.0.get(&(address, slot)).copied()
+        }
+    }
+
+    fn chain_config(gas_limit: u64, block_time: u64) -> MockReader {
+        MockReader(HashMap::from([
+            (
+                (CHAIN_CONFIG_ADDRESS, chain_config_slots::GAS_LIMIT),
+                encode_u64(gas_limit),
+            ),
+            (
+                (CHAIN_CONFIG_ADDRESS, chain_config_slots::BLOCK_TIME),
+                encode_u64(block_time),
+            ),
+        ]))
+    }
+
+    #[test]
+    fn test_gas_limit_mismatch_warns_with_both_values() {
+        let reader = chain_config(60_000_000, 2);
+        let mismatches = check_cli_params(&reader, Some(30_000_000), Some(2), None);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].precedence, ParamPrecedence::OnChain);
+
+        let warning = mismatches[0].to_string();
+        assert!(warning.contains("--gas-limit"), "{warning}");
+        assert!(warning.contains("30000000"), "{warning}");
+        assert!(warning.contains("60000000"), "{warning}");
+        assert!(warning.contains("using on-chain"), "{warning}");
+    }
+
+    #[test]
+    fn test_matching_or_unset_flags_are_quiet() {
+        let reader = chain_config(30_000_000, 2);
+        assert!(check_cli_params(&reader, Some(30_000_000), Some(2), None).is_empty());
+        assert!(check_cli_params(&reader, None, None, None).is_empty());
+        // Unreadable ChainConfig: nothing to compare against
+        assert!(
+            check_cli_params(&MockReader(HashMap::new()), Some(1), Some(1), Some(1)).is_empty()
+        );
+
+        let block_time = check_cli_params(&reader, None, Some(1), None);
+        assert_eq!(block_time[0].precedence, ParamPrecedence::Cli);
+    }
+
+    #[test]
+    fn test_max_contract_size_mismatch_uses_onchain() {
+        let mut reader = chain_config(30_000_000, 2);
+        reader.0.insert(
+            (CHAIN_CONFIG_ADDRESS, chain_config_slots::MAX_CONTRACT_SIZE),
+            encode_u64(24_576),
+        );
+        assert!(check_cli_params(&reader, None, None, Some(24_576)).is_empty());
+        let mismatches = check_cli_params(&reader, None, None, Some(524_288));
+        assert_eq!(mismatches[0].flag, "--max-contract-size");
+        assert_eq!(mismatches[0].precedence, ParamPrecedence::OnChain);
+    }
+}
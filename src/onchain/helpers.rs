@@ -26,6 +26,17 @@ pub fn mapping_address_bool_slot(key: Address, mapping_slot: U256) -> B256 {
     hasher.finalize()
 }
 
+/// Compute the storage slot for a Solidity `mapping(bytes32 => ...)` entry.
+///
+/// For `timestamps[id]` at mapping slot 5:
+///   slot = keccak256(abi.encode(id, 5))
+pub fn mapping_b256_slot(key: B256, mapping_slot: U256) -> B256 {
+    let mut hasher = Keccak256::new();
+    hasher.update(key.as_slice());
+    hasher.update(B256::from(mapping_slot.to_be_bytes()).as_slice());
+    hasher.finalize()
+}
+
 /// Decode an address from a B256 storage value (left-padded with zeros).
 pub fn decode_address(value: B256) -> Address {
     Address::from_slice(&value[12..32])
@@ -41,6 +52,13 @@ pub fn decode_bool(value: B256) -> bool {
     value[31] != 0
 }
 
+/// Decode a bool packed into a storage value at byte `offset`, counted from the
+/// low-order end as Solidity does (e.g. a bool declared right after an address
+/// shares its slot at offset 20).
+pub fn decode_packed_bool(value: B256, offset: usize) -> bool {
+    value[31 - offset] != 0
+}
+
 /// Encode a u64 value into a B256 storage value.
 pub fn encode_u64(value: u64) -> B256 {
     B256::from(U256::from(value).to_be_bytes())
@@ -15,6 +15,7 @@
 //!
 //! Storage layout must match genesis.rs pre-population and the Solidity contracts.
 
+pub mod consistency;
 pub mod helpers;
 pub mod providers;
 pub mod readers;
@@ -22,18 +23,23 @@ pub mod selectors;
 pub mod slots;
 
 // Re-export the StorageReader trait and key types at module level
+pub use consistency::{check_cli_params, ParamMismatch, ParamPrecedence};
 pub use helpers::{
-    decode_address, decode_bool, decode_u64, dynamic_array_base_slot, encode_address, encode_u64,
-    mapping_address_bool_slot,
+    decode_address, decode_bool, decode_packed_bool, decode_u64, dynamic_array_base_slot,
+    encode_address, encode_u64, mapping_address_bool_slot, mapping_b256_slot,
+};
+pub use providers::{
+    verify_storage_read, GenesisStorageReader, StateProviderStorageReader, StorageProofError,
 };
-pub use providers::{GenesisStorageReader, StateProviderStorageReader};
 pub use readers::{
-    is_signer_on_chain, is_timelock_paused, read_block_time, read_chain_config, read_gas_limit,
-    read_signer_list, read_timelock_delay, read_timelock_proposer, DynamicChainConfig,
-    DynamicSignerList,
+    is_signer_on_chain, is_timelock_paused, read_block_time, read_calldata_gas_per_byte,
+    read_chain_config, read_epoch, read_gas_limit, read_max_contract_size, read_max_tx_gas,
+    read_miner_proxy_admin, read_recent_signer_window, read_safe_info, read_signer_list,
+    read_timelock_delay, read_timelock_proposer, read_timelock_timestamp, DynamicChainConfig,
+    DynamicSignerList, SafeInfo,
 };
 pub use selectors::function_selector;
-pub use slots::{chain_config_slots, signer_registry_slots, timelock_slots};
+pub use slots::{chain_config_slots, safe_slots, signer_registry_slots, timelock_slots};
 
 use alloy_primitives::{Address, B256, U256};
 
@@ -50,11 +56,19 @@ pub trait StorageReader {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::evm::PoaEvmFactory;
     use crate::genesis::{
         create_dev_genesis, create_genesis, dev_accounts, dev_signers, GenesisConfig,
         CHAIN_CONFIG_ADDRESS, GOVERNANCE_SAFE_ADDRESS, SIGNER_REGISTRY_ADDRESS, TIMELOCK_ADDRESS,
     };
-    use alloy_primitives::Keccak256;
+    use alloy_evm::revm::bytecode::Bytecode;
+    use alloy_evm::revm::context::TxEnv;
+    use alloy_evm::revm::database::{CacheDB, EmptyDB};
+    use alloy_evm::revm::primitives::TxKind;
+    use alloy_evm::revm::state::AccountInfo;
+    use alloy_evm::revm::DatabaseRef;
+    use alloy_evm::{Evm, EvmEnv, EvmFactory};
+    use alloy_primitives::{keccak256, Keccak256};
     use std::collections::BTreeMap;
 
     // =========================================================================
@@ -96,6 +110,8 @@ mod tests {
         assert_eq!(chain_config_slots::CALLDATA_GAS_PER_BYTE, U256::from(4));
         assert_eq!(chain_config_slots::MAX_TX_GAS, U256::from(5));
         assert_eq!(chain_config_slots::EAGER_MINING, U256::from(6));
+        assert_eq!(chain_config_slots::RECENT_SIGNER_WINDOW, U256::from(7));
+        assert_eq!(chain_config_slots::EPOCH, U256::from(8));
     }
 
     #[test]
@@ -153,6 +169,8 @@ mod tests {
         assert_eq!(selectors::calldata_gas_per_byte().len(), 4);
         assert_eq!(selectors::max_tx_gas().len(), 4);
         assert_eq!(selectors::eager_mining().len(), 4);
+        assert_eq!(selectors::recent_signer_window().len(), 4);
+        assert_eq!(selectors::epoch().len(), 4);
         assert_eq!(selectors::governance().len(), 4);
         assert_eq!(selectors::get_signers().len(), 4);
         assert_eq!(selectors::signer_count().len(), 4);
@@ -208,6 +226,16 @@ mod tests {
         assert!(decode_bool(B256::from(b)));
     }
 
+    #[test]
+    fn test_decode_packed_bool_after_address() {
+        // admin = 0xff..ff with paused = true at offset 20
+        let mut b = [0u8; 32];
+        b[12..].fill(0xFF);
+        assert!(!decode_packed_bool(B256::from(b), 20));
+        b[11] = 1;
+        assert!(decode_packed_bool(B256::from(b), 20));
+    }
+
     #[test]
     fn test_encode_address_is_left_padded() {
         let addr = dev_accounts()[0];
@@ -354,6 +382,37 @@ mod tests {
         assert!(!config.eager_mining);
     }
 
+    #[test]
+    fn test_read_recent_signer_window() {
+        let mut mock = MockStorage::new();
+        // Unset slot reads as 0 (consensus falls back to the Clique default)
+        assert_eq!(read_recent_signer_window(&mock), 0);
+
+        // Read as stored; consensus caps it at the signer count
+        let slot = chain_config_slots::RECENT_SIGNER_WINDOW;
+        mock.set(CHAIN_CONFIG_ADDRESS, slot, encode_u64(10));
+        assert_eq!(read_recent_signer_window(&mock), 10);
+    }
+
+    #[test]
+    fn test_read_epoch() {
+        let mut mock = MockStorage::new();
+        // Unset or zero: the PoaConfig epoch applies
+        assert_eq!(read_epoch(&mock), None);
+        mock.set(
+            CHAIN_CONFIG_ADDRESS,
+            chain_config_slots::EPOCH,
+            encode_u64(0),
+        );
+        assert_eq!(read_epoch(&mock), None);
+        mock.set(
+            CHAIN_CONFIG_ADDRESS,
+            chain_config_slots::EPOCH,
+            encode_u64(60_000),
+        );
+        assert_eq!(read_epoch(&mock), Some(60_000));
+    }
+
     #[test]
     fn test_read_chain_config_missing_returns_none() {
         let mock = MockStorage::new();
@@ -474,6 +533,8 @@ mod tests {
         assert_eq!(config.calldata_gas_per_byte, 16);
         assert_eq!(config.max_tx_gas, 300_000_000); // matches gas_limit
         assert!(!config.eager_mining);
+        assert_eq!(config.recent_signer_window, 2); // floor(3/2) + 1
+        assert_eq!(config.epoch, 30_000);
     }
 
     #[test]
@@ -489,6 +550,8 @@ mod tests {
         assert_eq!(config.max_contract_size, 24_576);
         assert_eq!(config.calldata_gas_per_byte, 16);
         assert_eq!(config.max_tx_gas, 1_000_000_000); // matches gas_limit
+        assert_eq!(config.recent_signer_window, 3); // floor(5/2) + 1
+        assert_eq!(config.epoch, 30_000);
     }
 
     #[test]
@@ -743,6 +806,8 @@ mod tests {
             calldata_gas_per_byte: 16,
             max_tx_gas: 30_000_000,
             eager_mining: false,
+            recent_signer_window: 0,
+            epoch: 0,
         };
         let b = a.clone();
         assert_eq!(a, b);
@@ -758,6 +823,8 @@ mod tests {
             calldata_gas_per_byte: 0,
             max_tx_gas: 0,
             eager_mining: false,
+            recent_signer_window: 0,
+            epoch: 0,
         };
         let debug = format!("{:?}", config);
         assert!(debug.contains("DynamicChainConfig"));
@@ -957,4 +1024,202 @@ mod tests {
         );
         assert!(!is_timelock_paused(&reader));
     }
+
+    /// Storage of an in-memory EVM database, for reading state written by the
+    /// genesis bytecode.
+    struct EvmStorage<'a>(&'a CacheDB<EmptyDB>);
+
+    impl StorageReader for EvmStorage<'_> {
+        fn read_storage(&self, address: Address, slot: U256) -> Option<B256> {
+            self.0.storage_ref(address, slot).ok().map(B256::from)
+        }
+    }
+
+    /// The dev genesis Timelock (code and storage) with `operator` as proposer and admin.
+    fn timelock_db(operator: Address) -> CacheDB<EmptyDB> {
+        let genesis = create_dev_genesis();
+        let timelock = &genesis.alloc[&TIMELOCK_ADDRESS];
+        let mut db = CacheDB::new(EmptyDB::default());
+        db.insert_account_info(
+            TIMELOCK_ADDRESS,
+            AccountInfo {
+                nonce: 1,
+                code: Some(Bytecode::new_raw(timelock.code.clone().unwrap())),
+                ..Default::default()
+            },
+        );
+        for (slot, value) in timelock.storage.as_ref().unwrap() {
+            db.insert_account_storage(
+                TIMELOCK_ADDRESS,
+                U256::from_be_bytes(slot.0),
+                U256::from_be_bytes(value.0),
+            )
+            .unwrap();
+        }
+        for slot in [timelock_slots::PROPOSER, timelock_slots::ADMIN] {
+            db.insert_account_storage(
+                TIMELOCK_ADDRESS,
+                slot,
+                U256::from_be_bytes(encode_address(operator).0),
+            )
+            .unwrap();
+        }
+        db
+    }
+
+    /// Call the Timelock with ABI words `args` from `from` at block time `timestamp`,
+    /// committing the state changes. Returns whether the call succeeded.
+    fn call_timelock(
+        db: &mut CacheDB<EmptyDB>,
+        from: Address,
+        timestamp: u64,
+        signature: &str,
+        args: &[B256],
+    ) -> bool {
+        let mut data = function_selector(signature).to_vec();
+        for word in args {
+            data.extend_from_slice(word.as_slice());
+        }
+        let nonce = db
+            .basic_ref(from)
+            .unwrap()
+            .map_or(0, |account| account.nonce);
+        let mut env = EvmEnv::default();
+        env.block_env.timestamp = U256::from(timestamp);
+        PoaEvmFactory::new(None, 16)
+            .create_evm(db, env)
+            .transact_commit(TxEnv {
+                caller: from,
+                kind: TxKind::Call(TIMELOCK_ADDRESS),
+                data: data.into(),
+                gas_limit: 1_000_000,
+                nonce,
+                ..Default::default()
+            })
+            .unwrap()
+            .is_success()
+    }
+
+    /// ABI words of `schedule(target, 0, "", salt, delay)`, and the operation id
+    /// `hashOperation(target, 0, "", salt)`.
+    fn schedule_args(target: Address, salt: B256, delay: u64) -> (Vec<B256>, B256) {
+        let args = vec![
+            encode_address(target),
+            B256::ZERO,
+            encode_u64(5 * 32),
+            salt,
+            encode_u64(delay),
+            B256::ZERO,
+        ];
+        let mut encoded = Vec::new();
+        for word in [
+            encode_address(target),
+            B256::ZERO,
+            encode_u64(4 * 32),
+            salt,
+            B256::ZERO,
+        ] {
+            encoded.extend_from_slice(word.as_slice());
+        }
+        (args, keccak256(encoded))
+    }
+
+    #[test]
+    fn test_read_timelock_timestamp_from_bytecode() {
+        let operator = Address::with_last_byte(0xa1);
+        let mut db = timelock_db(operator);
+        let delay = read_timelock_delay(&EvmStorage(&db)).unwrap();
+        let (args, id) = schedule_args(
+            Address::with_last_byte(0xcc),
+            B256::repeat_byte(0x5a),
+            delay,
+        );
+
+        assert_eq!(read_timelock_timestamp(&EvmStorage(&db), id), 0);
+        assert!(call_timelock(
+            &mut db,
+            operator,
+            1_000,
+            "schedule(address,uint256,bytes,bytes32,uint256)",
+            &args,
+        ));
+        assert_eq!(read_timelock_timestamp(&EvmStorage(&db), id), 1_000 + delay);
+        // Scheduling the same operation again reverts and leaves the timestamp alone
+        assert!(!call_timelock(
+            &mut db,
+            operator,
+            2_000,
+            "schedule(address,uint256,bytes,bytes32,uint256)",
+            &args,
+        ));
+        assert_eq!(read_timelock_timestamp(&EvmStorage(&db), id), 1_000 + delay);
+    }
+
+    #[test]
+    fn test_is_timelock_paused_from_bytecode() {
+        let operator = Address::with_last_byte(0xa1);
+        let mut db = timelock_db(operator);
+        let delay = read_timelock_delay(&EvmStorage(&db)).unwrap();
+        let (args, id) = schedule_args(Address::with_last_byte(0xcc), B256::ZERO, delay);
+        assert!(!is_timelock_paused(&EvmStorage(&db)));
+
+        assert!(call_timelock(&mut db, operator, 1_000, "pause()", &[]));
+        assert!(is_timelock_paused(&EvmStorage(&db)));
+        // The admin shares the slot and still reads back
+        assert_eq!(
+            decode_address(
+                EvmStorage(&db)
+                    .read_storage(TIMELOCK_ADDRESS, timelock_slots::ADMIN)
+                    .unwrap()
+            ),
+            operator
+        );
+        // schedule() is whenNotPaused
+        assert!(!call_timelock(
+            &mut db,
+            operator,
+            1_000,
+            "schedule(address,uint256,bytes,bytes32,uint256)",
+            &args,
+        ));
+        assert_eq!(read_timelock_timestamp(&EvmStorage(&db), id), 0);
+
+        assert!(call_timelock(&mut db, operator, 1_000, "unpause()", &[]));
+        assert!(!is_timelock_paused(&EvmStorage(&db)));
+    }
+
+    #[test]
+    fn test_read_safe_info_walks_owner_list() {
+        let safe = Address::with_last_byte(0x5a);
+        let owners = [Address::with_last_byte(0x10), Address::with_last_byte(0x20)];
+        let owner_slot = |owner: Address| {
+            U256::from_be_bytes(mapping_address_bool_slot(owner, safe_slots::OWNERS).0)
+        };
+        let mut mock = MockStorage::new();
+        // SENTINEL -> owners[0] -> owners[1] -> SENTINEL
+        let sentinel = safe_slots::SENTINEL_OWNERS;
+        mock.set(safe, owner_slot(sentinel), encode_address(owners[0]));
+        mock.set(safe, owner_slot(owners[0]), encode_address(owners[1]));
+        mock.set(safe, owner_slot(owners[1]), encode_address(sentinel));
+        mock.set(safe, safe_slots::OWNER_COUNT, encode_u64(2));
+        mock.set(safe, safe_slots::THRESHOLD, encode_u64(2));
+        mock.set(safe, safe_slots::NONCE, encode_u64(7));
+
+        assert_eq!(
+            read_safe_info(&mock, safe),
+            Some(SafeInfo {
+                address: safe,
+                owners: owners.to_vec(),
+                threshold: 2,
+                nonce: 7,
+            })
+        );
+
+        // Not a Safe: no threshold (empty account) or a non-Safe contract's storage
+        assert_eq!(read_safe_info(&mock, Address::with_last_byte(0x99)), None);
+        let genesis = create_dev_genesis();
+        let reader = GenesisStorageReader::from_genesis(&genesis);
+        assert_eq!(read_safe_info(&reader, GOVERNANCE_SAFE_ADDRESS), None);
+        assert_eq!(read_safe_info(&reader, CHAIN_CONFIG_ADDRESS), None);
+    }
 }
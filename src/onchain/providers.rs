@@ -1,5 +1,8 @@
 use super::StorageReader;
+use crate::output;
 use alloy_primitives::{Address, B256, U256};
+use reth_storage_api::StateProofProvider;
+use reth_trie_common::AccountProof;
 
 /// Wraps a Reth `StateProvider` reference to implement the `StorageReader` trait.
 ///
@@ -7,25 +10,127 @@ use alloy_primitives::{Address, B256, U256};
 /// Used by `PoaPayloadBuilder` to read `ChainConfig` and `SignerRegistry` contracts
 /// at each block, enabling live governance updates without node restart.
 ///
+/// With [`with_proof_verification`](Self::with_proof_verification) every read is also
+/// checked against the state root via a Merkle storage proof (`--verify-storage-reads`).
+/// This detects database corruption or a compromised provider at the cost of a proof
+/// per slot; a slot that fails verification reads as `None`.
+///
 /// # Usage
 /// ```ignore
 /// let state = provider.latest()?;
-/// let reader = StateProviderStorageReader(state.as_ref());
+/// let reader = StateProviderStorageReader::new(state.as_ref());
 /// let gas_limit = read_gas_limit(&reader);
 /// let signers   = read_signer_list(&reader);
 /// ```
-pub struct StateProviderStorageReader<'a>(pub &'a dyn reth_storage_api::StateProvider);
+pub struct StateProviderStorageReader<'a> {
+    state: &'a dyn reth_storage_api::StateProvider,
+    /// State root that reads must prove against (`None` = no verification).
+    verify_root: Option<B256>,
+}
+
+impl<'a> StateProviderStorageReader<'a> {
+    /// Wrap a state provider without proof verification.
+    pub fn new(state: &'a dyn reth_storage_api::StateProvider) -> Self {
+        Self {
+            state,
+            verify_root: None,
+        }
+    }
+
+    /// Verify every read against `state_root` (`None` leaves verification off).
+    ///
+    /// `state_root` must be the root of the state `state` was opened at.
+    pub fn with_proof_verification(mut self, state_root: Option<B256>) -> Self {
+        self.verify_root = state_root;
+        self
+    }
+
+    /// Whether reads are verified with storage proofs.
+    pub fn verifies_proofs(&self) -> bool {
+        self.verify_root.is_some()
+    }
+}
 
 impl<'a> StorageReader for StateProviderStorageReader<'a> {
     fn read_storage(&self, address: Address, slot: U256) -> Option<B256> {
         // Convert U256 slot to B256 storage key (big-endian, Solidity layout)
         let key = B256::from(slot.to_be_bytes());
-        self.0
-            .storage(address, key)
-            .ok()
-            .flatten()
-            .map(|v| B256::from(v.to_be_bytes()))
+        let value = self.state.storage(address, key).ok().flatten();
+
+        if let Some(root) = self.verify_root {
+            let verified = self
+                .state
+                .proof(Default::default(), address, &[key])
+                .map_err(|e| StorageProofError::Unavailable(e.to_string()))
+                .and_then(|proof| {
+                    verify_storage_read(root, key, value.unwrap_or_default(), &proof)
+                });
+            if let Err(e) = verified {
+                output::print_warning(&format!(
+                    "Storage proof verification failed for {address} slot {key}: {e}"
+                ));
+                return None;
+            }
+        }
+
+        value.map(|v| B256::from(v.to_be_bytes()))
+    }
+}
+
+/// Why a storage read failed proof verification.
+#[derive(Debug, thiserror::Error)]
+pub enum StorageProofError {
+    /// The provider could not produce a proof.
+    #[error("proof unavailable: {0}")]
+    Unavailable(String),
+    /// The proof does not cover the requested slot.
+    #[error("proof does not cover slot {0}")]
+    MissingSlot(B256),
+    /// The value read from the database differs from the proven value.
+    #[error("read {read} but proof commits to {proven}")]
+    ValueMismatch {
+        /// Value returned by the database.
+        read: U256,
+        /// Value committed to by the proof.
+        proven: U256,
+    },
+    /// The proof does not hash up to the expected state root.
+    #[error("proof does not verify against state root {root}: {reason}")]
+    InvalidProof {
+        /// Expected state root.
+        root: B256,
+        /// Verification failure detail.
+        reason: String,
+    },
+}
+
+/// Check that `value` read at storage `key` is committed to by `proof` under `state_root`.
+///
+/// Both the account proof (state trie) and the slot's storage proof (account storage
+/// trie) are verified. Absent slots read as zero, matching trie semantics.
+pub fn verify_storage_read(
+    state_root: B256,
+    key: B256,
+    value: U256,
+    proof: &AccountProof,
+) -> Result<(), StorageProofError> {
+    let slot_proof = proof
+        .storage_proofs
+        .iter()
+        .find(|p| p.key == key)
+        .ok_or(StorageProofError::MissingSlot(key))?;
+    if slot_proof.value != value {
+        return Err(StorageProofError::ValueMismatch {
+            read: value,
+            proven: slot_proof.value,
+        });
     }
+    proof
+        .verify(state_root)
+        .map_err(|e| StorageProofError::InvalidProof {
+            root: state_root,
+            reason: e.to_string(),
+        })
 }
 
 /// A StorageReader that reads from the genesis configuration's alloc.
@@ -54,3 +159,89 @@ impl StorageReader for GenesisStorageReader {
         storage.get(&slot_key).copied()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genesis::CHAIN_CONFIG_ADDRESS;
+    use crate::onchain::chain_config_slots;
+    use alloy_primitives::{keccak256, Bytes};
+    use reth_primitives_traits::Account;
+    use reth_trie_common::{LeafNode, Nibbles, StorageProof};
+
+    /// Encode a single-leaf trie keyed by `keccak256(path)`; returns (root, node).
+    fn single_leaf_trie(path: &[u8], value: Vec<u8>) -> (B256, Bytes) {
+        let leaf = LeafNode::new(Nibbles::unpack(keccak256(path)), value);
+        let node = Bytes::from(alloy_rlp::encode(&leaf));
+        (keccak256(&node), node)
+    }
+
+    /// A state containing only ChainConfig with a single gasLimit slot, plus its proof.
+    fn chain_config_proof(gas_limit: u64) -> (B256, B256, AccountProof) {
+        let key = B256::from(chain_config_slots::GAS_LIMIT.to_be_bytes());
+        let value = U256::from(gas_limit);
+        let (storage_root, storage_node) =
+            single_leaf_trie(key.as_slice(), alloy_rlp::encode(value));
+
+        let account = Account::default();
+        let (state_root, account_node) = single_leaf_trie(
+            CHAIN_CONFIG_ADDRESS.as_slice(),
+            alloy_rlp::encode(account.into_trie_account(storage_root)),
+        );
+
+        let mut slot_proof = StorageProof::new(key);
+        slot_proof.value = value;
+        slot_proof.proof = vec![storage_node];
+        let proof = AccountProof {
+            address: CHAIN_CONFIG_ADDRESS,
+            info: Some(account),
+            proof: vec![account_node],
+            storage_root,
+            storage_proofs: vec![slot_proof],
+        };
+        (state_root, key, proof)
+    }
+
+    #[test]
+    fn test_valid_chain_config_proof_passes() {
+        let (root, key, proof) = chain_config_proof(30_000_000);
+        verify_storage_read(root, key, U256::from(30_000_000u64), &proof).unwrap();
+    }
+
+    #[test]
+    fn test_corrupted_read_value_fails() {
+        let (root, key, proof) = chain_config_proof(30_000_000);
+        let err = verify_storage_read(root, key, U256::from(1u64), &proof).unwrap_err();
+        assert!(matches!(err, StorageProofError::ValueMismatch { .. }));
+    }
+
+    #[test]
+    fn test_tampered_proof_value_fails() {
+        // Database and proof agree on a forged value, but the trie doesn't commit to it
+        let (root, key, mut proof) = chain_config_proof(30_000_000);
+        proof.storage_proofs[0].value = U256::from(1u64);
+        let err = verify_storage_read(root, key, U256::from(1u64), &proof).unwrap_err();
+        assert!(matches!(err, StorageProofError::InvalidProof { .. }));
+    }
+
+    #[test]
+    fn test_wrong_state_root_fails() {
+        let (_, key, proof) = chain_config_proof(30_000_000);
+        let err = verify_storage_read(
+            B256::repeat_byte(0xab),
+            key,
+            U256::from(30_000_000u64),
+            &proof,
+        )
+        .unwrap_err();
+        assert!(matches!(err, StorageProofError::InvalidProof { .. }));
+    }
+
+    #[test]
+    fn test_proof_missing_slot_fails() {
+        let (root, _, proof) = chain_config_proof(30_000_000);
+        let other = B256::from(chain_config_slots::BLOCK_TIME.to_be_bytes());
+        let err = verify_storage_read(root, other, U256::ZERO, &proof).unwrap_err();
+        assert!(matches!(err, StorageProofError::MissingSlot(k) if k == other));
+    }
+}
@@ -1,16 +1,22 @@
 use super::helpers::{
-    decode_address, decode_bool, decode_u64, dynamic_array_base_slot, mapping_address_bool_slot,
+    decode_address, decode_bool, decode_packed_bool, decode_u64, dynamic_array_base_slot,
+    mapping_address_bool_slot, mapping_b256_slot,
 };
-use super::slots::{chain_config_slots, signer_registry_slots, timelock_slots};
+use super::slots::{chain_config_slots, safe_slots, signer_registry_slots, timelock_slots};
 use super::StorageReader;
-use crate::genesis::{CHAIN_CONFIG_ADDRESS, SIGNER_REGISTRY_ADDRESS, TIMELOCK_ADDRESS};
+use crate::genesis::addresses::EIP1967_ADMIN_SLOT;
+use crate::genesis::{
+    CHAIN_CONFIG_ADDRESS, MINER_PROXY_ADDRESS, SIGNER_REGISTRY_ADDRESS, TIMELOCK_ADDRESS,
+};
 use alloy_primitives::{Address, B256, U256};
+use serde::Serialize;
 
 /// Dynamic chain configuration read from the on-chain ChainConfig contract.
 ///
 /// Replaces hardcoded values from genesis/CLI with governance-controlled parameters.
 /// Updated via: Governance Safe → ChainConfig.setGasLimit(300_000_000)
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct DynamicChainConfig {
     /// Governance address (the Safe multisig)
     pub governance: Address,
@@ -26,13 +32,18 @@ pub struct DynamicChainConfig {
     pub max_tx_gas: u64,
     /// Mine on tx arrival vs interval (default: false)
     pub eager_mining: bool,
+    /// Recent-signer window as stored (0 = `floor(N/2) + 1`; see [`read_recent_signer_window`])
+    pub recent_signer_window: u64,
+    /// Epoch length as stored (0 = the `PoaConfig` epoch; see [`read_epoch`])
+    pub epoch: u64,
 }
 
 /// Dynamic signer list read from the on-chain SignerRegistry contract.
 ///
 /// Updated via: Governance Safe → SignerRegistry.addSigner(addr)
 /// Changes take effect at the next epoch block.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct DynamicSignerList {
     /// Governance address (the Safe multisig)
     pub governance: Address,
@@ -42,6 +53,20 @@ pub struct DynamicSignerList {
     pub threshold: u64,
 }
 
+/// Owners and threshold of a Gnosis Safe, read from its storage.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SafeInfo {
+    /// The Safe (proxy) address
+    pub address: Address,
+    /// Owners in the Safe's linked-list order
+    pub owners: Vec<Address>,
+    /// Owner signatures required to execute a transaction
+    pub threshold: u64,
+    /// Nonce of the next Safe transaction
+    pub nonce: u64,
+}
+
 /// Read the full ChainConfig from on-chain storage.
 ///
 /// This is called by PoaPayloadBuilder at each block to get the current gas limit
@@ -58,6 +83,12 @@ pub fn read_chain_config(reader: &impl StorageReader) -> Option<DynamicChainConf
     let eager_mining_val = reader
         .read_storage(addr, chain_config_slots::EAGER_MINING)
         .unwrap_or(B256::ZERO);
+    let recent_signer_window_val = reader
+        .read_storage(addr, chain_config_slots::RECENT_SIGNER_WINDOW)
+        .unwrap_or(B256::ZERO);
+    let epoch_val = reader
+        .read_storage(addr, chain_config_slots::EPOCH)
+        .unwrap_or(B256::ZERO);
 
     Some(DynamicChainConfig {
         governance: decode_address(governance_val),
@@ -67,6 +98,8 @@ pub fn read_chain_config(reader: &impl StorageReader) -> Option<DynamicChainConf
         calldata_gas_per_byte: decode_u64(calldata_gas_val),
         max_tx_gas: decode_u64(max_tx_gas_val),
         eager_mining: decode_bool(eager_mining_val),
+        recent_signer_window: decode_u64(recent_signer_window_val),
+        epoch: decode_u64(epoch_val),
     })
 }
 
@@ -77,6 +110,13 @@ pub fn read_gas_limit(reader: &impl StorageReader) -> Option<u64> {
         .map(decode_u64)
 }
 
+/// Read just the per-transaction gas cap from ChainConfig.
+pub fn read_max_tx_gas(reader: &impl StorageReader) -> Option<u64> {
+    reader
+        .read_storage(CHAIN_CONFIG_ADDRESS, chain_config_slots::MAX_TX_GAS)
+        .map(decode_u64)
+}
+
 /// Read just the block time from ChainConfig.
 pub fn read_block_time(reader: &impl StorageReader) -> Option<u64> {
     reader
@@ -84,6 +124,48 @@ pub fn read_block_time(reader: &impl StorageReader) -> Option<u64> {
         .map(decode_u64)
 }
 
+/// Read just the calldata gas cost per non-zero byte from ChainConfig.
+pub fn read_calldata_gas_per_byte(reader: &impl StorageReader) -> Option<u64> {
+    reader
+        .read_storage(
+            CHAIN_CONFIG_ADDRESS,
+            chain_config_slots::CALLDATA_GAS_PER_BYTE,
+        )
+        .map(decode_u64)
+}
+
+/// Read just the max contract code size from ChainConfig.
+pub fn read_max_contract_size(reader: &impl StorageReader) -> Option<u64> {
+    reader
+        .read_storage(CHAIN_CONFIG_ADDRESS, chain_config_slots::MAX_CONTRACT_SIZE)
+        .map(decode_u64)
+}
+
+/// Read the recent-signer window from ChainConfig, as stored (0 when unset).
+///
+/// A signer may seal at most one block in any window of this many blocks.
+/// `PoaConsensus::recent_signer_limit` turns 0 into the Clique default
+/// `floor(N/2) + 1` and caps other values at the signer count.
+pub fn read_recent_signer_window(reader: &impl StorageReader) -> u64 {
+    reader
+        .read_storage(
+            CHAIN_CONFIG_ADDRESS,
+            chain_config_slots::RECENT_SIGNER_WINDOW,
+        )
+        .map_or(0, decode_u64)
+}
+
+/// Read the epoch length from ChainConfig.
+///
+/// Returns `None` for an unset or zero slot, meaning the `PoaConfig` epoch applies.
+/// A new value takes effect at an epoch boundary, see `PoaChainSpec::update_epoch`.
+pub fn read_epoch(reader: &impl StorageReader) -> Option<u64> {
+    reader
+        .read_storage(CHAIN_CONFIG_ADDRESS, chain_config_slots::EPOCH)
+        .map(decode_u64)
+        .filter(|&epoch| epoch > 0)
+}
+
 /// Read the full signer list from SignerRegistry storage.
 ///
 /// This is called by PoaConsensus at epoch blocks to update the authorized
@@ -137,10 +219,75 @@ pub fn read_timelock_proposer(reader: &impl StorageReader) -> Option<Address> {
         .map(decode_address)
 }
 
-/// Check if the Timelock is paused.
+/// Check if the Timelock is paused (packed into the admin slot).
 pub fn is_timelock_paused(reader: &impl StorageReader) -> bool {
     reader
         .read_storage(TIMELOCK_ADDRESS, timelock_slots::PAUSED)
-        .map(decode_bool)
-        .unwrap_or(false)
+        .is_some_and(|value| decode_packed_bool(value, timelock_slots::PAUSED_OFFSET))
+}
+
+/// Read the Timelock's `timestamps[id]`: when operation `id` becomes executable,
+/// [`timelock_slots::DONE_TIMESTAMP`] once executed, or 0 if it isn't queued.
+pub fn read_timelock_timestamp(reader: &impl StorageReader, id: B256) -> u64 {
+    let slot_hash = mapping_b256_slot(id, timelock_slots::TIMESTAMPS_MAPPING);
+    reader
+        .read_storage(TIMELOCK_ADDRESS, U256::from_be_bytes(slot_hash.0))
+        .map(decode_u64)
+        .unwrap_or(0)
+}
+
+/// Read the Miner Proxy's admin from its EIP-1967 admin slot.
+///
+/// Genesis sets it to the governance Safe; `None` if the proxy isn't deployed.
+pub fn read_miner_proxy_admin(reader: &impl StorageReader) -> Option<Address> {
+    reader
+        .read_storage(
+            MINER_PROXY_ADDRESS,
+            U256::from_be_bytes(EIP1967_ADMIN_SLOT.0),
+        )
+        .map(decode_address)
+}
+
+/// Read the owners and threshold of the Gnosis Safe at `safe`.
+///
+/// Returns `None` if `safe` doesn't hold a set-up Safe's storage: a threshold
+/// between 1 and the number of owners. The owners list is walked from
+/// `SENTINEL_OWNERS`, bounded by the stored owner count so corrupt storage can't
+/// loop forever.
+pub fn read_safe_info(reader: &impl StorageReader, safe: Address) -> Option<SafeInfo> {
+    let threshold = decode_u64(reader.read_storage(safe, safe_slots::THRESHOLD)?);
+    if threshold == 0 {
+        return None;
+    }
+    let owner_count = reader
+        .read_storage(safe, safe_slots::OWNER_COUNT)
+        .map(decode_u64)
+        .unwrap_or(0);
+    let nonce = reader
+        .read_storage(safe, safe_slots::NONCE)
+        .map(decode_u64)
+        .unwrap_or(0);
+
+    let mut owners = Vec::new();
+    let mut current = safe_slots::SENTINEL_OWNERS;
+    while (owners.len() as u64) < owner_count {
+        // `owners` is a mapping(address => address): same slot derivation as isSigner
+        let slot = U256::from_be_bytes(mapping_address_bool_slot(current, safe_slots::OWNERS).0);
+        let next = match reader.read_storage(safe, slot).map(decode_address) {
+            Some(next) if next != Address::ZERO && next != safe_slots::SENTINEL_OWNERS => next,
+            _ => break,
+        };
+        owners.push(next);
+        current = next;
+    }
+    if threshold > owners.len() as u64 {
+        return None;
+    }
+
+    Some(SafeInfo {
+        address: safe,
+        owners,
+        threshold,
+        nonce,
+    })
 }
@@ -27,6 +27,12 @@ pub fn max_tx_gas() -> [u8; 4] {
 pub fn eager_mining() -> [u8; 4] {
     function_selector("eagerMining()")
 }
+pub fn recent_signer_window() -> [u8; 4] {
+    function_selector("recentSignerWindow()")
+}
+pub fn epoch() -> [u8; 4] {
+    function_selector("epoch()")
+}
 pub fn governance() -> [u8; 4] {
     function_selector("governance()")
 }
@@ -18,6 +18,12 @@ pub mod chain_config_slots {
     pub const MAX_TX_GAS: U256 = U256::from_limbs([5, 0, 0, 0]);
     /// slot 6: eagerMining (bool)
     pub const EAGER_MINING: U256 = U256::from_limbs([6, 0, 0, 0]);
+    /// slot 7: recentSignerWindow (uint256, 0 = Clique default `floor(N/2) + 1`).
+    /// Written at genesis; governance changes it with `setRecentSignerWindow`.
+    pub const RECENT_SIGNER_WINDOW: U256 = U256::from_limbs([7, 0, 0, 0]);
+    /// slot 8: epoch (uint256, 0 = the `PoaConfig` epoch length).
+    /// Written at genesis; governance changes it with `setEpoch`.
+    pub const EPOCH: U256 = U256::from_limbs([8, 0, 0, 0]);
 }
 
 /// SignerRegistry contract storage layout.
@@ -50,6 +56,35 @@ pub mod timelock_slots {
     pub const EXECUTOR: U256 = U256::from_limbs([2, 0, 0, 0]);
     /// slot 3: admin (address)
     pub const ADMIN: U256 = U256::from_limbs([3, 0, 0, 0]);
-    /// slot 4: paused (bool)
-    pub const PAUSED: U256 = U256::from_limbs([4, 0, 0, 0]);
+    /// slot 3: paused (bool), packed after admin at byte [`PAUSED_OFFSET`]
+    pub const PAUSED: U256 = U256::from_limbs([3, 0, 0, 0]);
+    /// Byte offset of `paused` within its slot, from the low-order end
+    pub const PAUSED_OFFSET: usize = 20;
+    /// slot 4: timestamps mapping base (mapping(bytes32 => uint256)) — when each
+    /// operation becomes executable (0 = not queued)
+    pub const TIMESTAMPS_MAPPING: U256 = U256::from_limbs([4, 0, 0, 0]);
+    /// `timestamps` value of an executed operation (`_DONE_TIMESTAMP`)
+    pub const DONE_TIMESTAMP: u64 = 1;
+}
+
+/// Gnosis Safe (v1.3.0) storage layout.
+///
+/// Matches `GnosisSafe.sol` as deployed behind the canonical Safe proxies.
+pub mod safe_slots {
+    use alloy_primitives::{address, Address, U256};
+
+    /// slot 0: singleton (address of the Safe implementation)
+    pub const SINGLETON: U256 = U256::from_limbs([0, 0, 0, 0]);
+    /// slot 1: modules linked list (mapping(address => address))
+    pub const MODULES: U256 = U256::from_limbs([1, 0, 0, 0]);
+    /// slot 2: owners linked list (mapping(address => address))
+    pub const OWNERS: U256 = U256::from_limbs([2, 0, 0, 0]);
+    /// slot 3: ownerCount (uint256)
+    pub const OWNER_COUNT: U256 = U256::from_limbs([3, 0, 0, 0]);
+    /// slot 4: threshold (uint256)
+    pub const THRESHOLD: U256 = U256::from_limbs([4, 0, 0, 0]);
+    /// slot 5: nonce (uint256)
+    pub const NONCE: U256 = U256::from_limbs([5, 0, 0, 0]);
+    /// Head and tail marker of the owners linked list (`SENTINEL_OWNERS`)
+    pub const SENTINEL_OWNERS: Address = address!("0x0000000000000000000000000000000000000001");
 }
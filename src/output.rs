@@ -4,6 +4,9 @@
 //! Color scheme: blue+bold headers, cyan values, green success,
 //! yellow warnings, dimmed secondary text.
 
+use crate::consensus::DiversityHealth;
+use crate::monitor::CatchUpSummary;
+use crate::onchain::ParamMismatch;
 use alloy_primitives::Address;
 use colored::Colorize;
 use std::fmt;
@@ -74,6 +77,20 @@ pub fn print_signer_loaded(addr: &Address) {
     );
 }
 
+/// Print that a signer key was dropped on keystore reload.
+pub fn print_signer_removed(addr: &Address) {
+    println!(
+        "  {} Signer key removed: {}",
+        "OK".green().bold(),
+        format!("{addr}").cyan()
+    );
+}
+
+/// Print the vanity identifier configured for the loaded signer.
+pub fn print_signer_vanity(vanity: &str) {
+    println!("  {} Signer vanity: {}", "OK".green().bold(), vanity.cyan());
+}
+
 /// Print confirmation that dev signers were loaded.
 pub fn print_dev_signers_loaded(count: usize) {
     println!(
@@ -252,6 +269,15 @@ pub fn print_onchain_signers(count: usize) {
     );
 }
 
+/// Print a prominent warning when a CLI flag disagrees with governance.
+pub fn print_param_mismatch(mismatch: &ParamMismatch) {
+    println!(
+        "  {} {}",
+        "CONFIG MISMATCH".red().bold(),
+        mismatch.to_string().yellow(),
+    );
+}
+
 // ── Payload / Block Production ─────────────────────────────────────
 
 /// Print when signers are refreshed at an epoch block.
@@ -264,6 +290,17 @@ pub fn print_epoch_refresh(block_number: u64, signer_count: usize) {
     );
 }
 
+/// Print when a new epoch length from ChainConfig is scheduled to take effect.
+pub fn print_epoch_length_scheduled(at_block: u64, current: u64, new: u64) {
+    println!(
+        "  {} Epoch length {} -> {} from block #{}",
+        "OK".green().bold(),
+        current.to_string().cyan(),
+        new.to_string().cyan(),
+        at_block.to_string().cyan(),
+    );
+}
+
 /// Print when a block is signed by a POA signer.
 ///
 /// `build_ms` is the wall-clock time spent building the block (Phase 2.17 timing).
@@ -366,6 +403,142 @@ pub fn print_block_time_budget_warning(block_num: u64, elapsed_ms: u64, interval
     );
 }
 
+/// Print that a peer was penalized for sending an invalid POA block.
+pub fn print_peer_penalized(peer: &impl fmt::Display, reason: &str, penalty: i32) {
+    println!(
+        "  {} Peer {} sent block with {}, reputation {}",
+        "PENALTY".red().bold(),
+        format!("{peer}").dimmed(),
+        reason.yellow(),
+        penalty,
+    );
+}
+
+/// Print a summary of blocks processed while catching up to the chain tip.
+pub fn print_catch_up_summary(summary: &CatchUpSummary) {
+    println!(
+        "  {} Catching up: blocks #{}..#{} ({} blocks, {} txs)",
+        ">>".dimmed(),
+        summary.first_block.to_string().cyan(),
+        summary.last_block.to_string().cyan(),
+        summary.blocks.to_string().cyan(),
+        summary.txs.to_string().cyan(),
+    );
+}
+
+/// Print that too few distinct signers sealed the recent blocks.
+pub fn print_low_signer_diversity(block_num: u64, health: &DiversityHealth) {
+    println!(
+        "  {} Block #{}: only {} distinct signer(s) in the last {} blocks (minimum {})",
+        "WARN".yellow().bold(),
+        block_num.to_string().cyan(),
+        health.distinct_signers.to_string().yellow(),
+        health.window,
+        health.min_distinct_signers,
+    );
+}
+
+/// Print that too few signers are online and safe mode stopped out-of-turn blocks.
+pub fn print_safe_mode_entered(block_num: u64, online: usize, threshold: u64) {
+    println!(
+        "  {} Block #{}: only {} signer(s) online in the last epoch (threshold {}), sealing in-turn blocks only",
+        "DEGRADED".red().bold(),
+        block_num.to_string().cyan(),
+        online.to_string().yellow(),
+        threshold,
+    );
+}
+
+/// Print that enough signers are back online to leave safe mode.
+pub fn print_safe_mode_recovered(block_num: u64, online: usize, threshold: u64) {
+    println!(
+        "  {} Block #{}: {} signer(s) online in the last epoch (threshold {}), safe mode cleared",
+        "RECOVERED".green().bold(),
+        block_num.to_string().cyan(),
+        online.to_string().green(),
+        threshold,
+    );
+}
+
+/// Print that a reorg reverted more blocks than `--max-reorg-depth` allows.
+pub fn print_deep_reorg(block_num: u64, depth: u64, max_depth: u64, halted: bool) {
+    println!(
+        "  {} Block #{}: reorg reverted {} blocks (max {}){}",
+        "ERROR".red().bold(),
+        block_num.to_string().cyan(),
+        depth.to_string().red(),
+        max_depth,
+        if halted {
+            ", block production halted"
+        } else {
+            ""
+        },
+    );
+}
+
+/// Print that the Miner Proxy admin no longer matches genesis.
+pub fn print_proxy_admin_changed(block_num: u64, genesis: Option<Address>, admin: Address) {
+    let genesis = genesis.map_or_else(|| "none".to_string(), |a| a.to_string());
+    println!(
+        "  {} Block #{}: Miner Proxy admin changed from genesis {} to {} (possible compromise)",
+        "WARNING".red().bold(),
+        block_num.to_string().cyan(),
+        genesis,
+        admin.to_string().yellow(),
+    );
+}
+
+// ── Keystore Command ───────────────────────────────────────────────
+
+/// Print the result of a keystore operation on one account.
+pub fn print_keystore_account(action: &str, addr: &Address) {
+    println!(
+        "  {} {} {}",
+        "OK".green().bold(),
+        action,
+        format!("{addr}").cyan()
+    );
+}
+
+/// Print the accounts found in a keystore directory.
+pub fn print_keystore_accounts(accounts: &[Address]) {
+    println!(
+        "{}",
+        format!("Keystores ({}):", accounts.len()).blue().bold()
+    );
+    for (i, account) in accounts.iter().enumerate() {
+        println!(
+            "  {}. {}",
+            (i + 1).to_string().dimmed(),
+            format!("{account}").cyan()
+        );
+    }
+}
+
+// ── Genesis Command ────────────────────────────────────────────────
+
+/// Print where a generated genesis was written.
+pub fn print_genesis_written(path: &Path, chain_id: u64, signers: &[Address]) {
+    println!(
+        "  {} Genesis written: {}",
+        "OK".green().bold(),
+        path.display().to_string().cyan()
+    );
+    println!("  Chain ID: {}", chain_id.to_string().cyan());
+    print_signers(signers);
+}
+
+/// Print the result of a `meow_exportState` call.
+pub fn print_state_exported(path: &Path, block_number: u64, accounts: u64) {
+    println!(
+        "  {} State at block #{} exported: {} ({} accounts)",
+        "OK".green().bold(),
+        block_number.to_string().cyan(),
+        path.display().to_string().cyan(),
+        accounts
+    );
+}
+
 // ── Shutdown & Info ──────────────────────────────────────────────────
 
 /// Print a shutdown message with the reason.
@@ -379,6 +552,11 @@ pub fn print_info(msg: &str) {
     println!("  {} {}", "INFO".blue().bold(), msg,);
 }
 
+/// Print a generic warning message.
+pub fn print_warning(msg: &str) {
+    println!("  {} {}", "WARNING:".yellow().bold(), msg);
+}
+
 /// Print that a feature was enabled, with a detail string.
 pub fn print_feature(name: &str, detail: &str) {
     println!(
@@ -1,6 +1,10 @@
 use crate::cache::{CacheConfig, CachedStorageReader, HotStateCache, SharedCache};
 use crate::chainspec::PoaChainSpec;
 use crate::consensus::{EXTRA_SEAL_LENGTH, EXTRA_VANITY_LENGTH};
+use crate::constants::DEFAULT_WIGGLE_MS;
+use crate::genesis::addresses::{CHAIN_CONFIG_ADDRESS, SIGNER_REGISTRY_ADDRESS};
+use crate::metrics::SharedBlockTimings;
+use crate::monitor::{ReorgGuard, SignerLiveness};
 use crate::onchain::{read_gas_limit, read_signer_list, StateProviderStorageReader};
 use crate::output;
 use crate::signer::SignerManager;
@@ -17,10 +21,14 @@ use reth_ethereum_engine_primitives::{
 use reth_ethereum_payload_builder::EthereumBuilderConfig;
 use reth_evm::{ConfigureEvm, NextBlockEnvAttributes};
 use reth_payload_primitives::PayloadTypes;
+use reth_storage_api::BlockNumReader;
 use reth_transaction_pool::{PoolTransaction, TransactionPool};
+use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
 
-use super::PoaPayloadBuilder;
+use super::{
+    storage_proof_root, FallbackPolicy, PoaPayloadBuilder, TransactionOrdering, WiggleDeadline,
+};
 
 /// Component-level builder that creates `PoaPayloadBuilder` instances.
 /// Plugs into `BasicPayloadServiceBuilder` in the node's `ComponentsBuilder`.
@@ -31,6 +39,33 @@ pub struct PoaPayloadBuilderBuilder {
     pub(crate) dev_mode: bool,
     /// Capacity for the per-builder hot state cache (number of (address, slot) entries).
     pub(crate) cache_size: usize,
+    /// Base out-of-turn wiggle delay in milliseconds. The maximum delay is
+    /// `(N/2 + 1) * wiggle_ms` for N signers. `0` disables the delay.
+    pub(crate) wiggle_ms: u64,
+    /// Pre-built cache (e.g. warmed from disk) to use instead of creating a fresh one.
+    pub(crate) shared_cache: Option<SharedCache>,
+    /// Verify governance storage reads against the state root with Merkle proofs.
+    pub(crate) verify_storage_reads: bool,
+    /// Testing only: block numbers this node may seal (`None` = all).
+    pub(crate) seal_only: Option<HashSet<u64>>,
+    /// Gas limit to steer toward instead of the on-chain `ChainConfig.gasLimit`.
+    pub(crate) gas_limit_target: Option<u64>,
+    /// Maximum transactions per block (`None` = limited by gas only).
+    pub(crate) max_transactions: Option<usize>,
+    /// Skip empty blocks until idle this many seconds (`None` = seal them).
+    pub(crate) max_idle: Option<u64>,
+    /// Where sealed payloads' build/sign times are recorded (`None` = not recorded).
+    pub(crate) block_timings: Option<SharedBlockTimings>,
+    /// Which of our signers may seal when the in-turn signer misses its slot.
+    pub(crate) fallback_policy: FallbackPolicy,
+    /// Safe mode: no out-of-turn blocks while too few signers are online (`None` = off).
+    pub(crate) safe_mode: Option<Arc<SignerLiveness>>,
+    /// Stops sealing after a reorg deeper than its limit (`None` = never).
+    pub(crate) reorg_guard: Option<Arc<ReorgGuard>>,
+    /// Vanity for blocks sealed by signers without their own.
+    pub(crate) block_vanity: [u8; EXTRA_VANITY_LENGTH],
+    /// Order in which pending transactions are included.
+    pub(crate) tx_ordering: TransactionOrdering,
 }
 
 impl PoaPayloadBuilderBuilder {
@@ -45,6 +80,19 @@ impl PoaPayloadBuilderBuilder {
             signer_manager,
             dev_mode,
             cache_size: CacheConfig::default().max_entries,
+            wiggle_ms: DEFAULT_WIGGLE_MS,
+            shared_cache: None,
+            verify_storage_reads: false,
+            seal_only: None,
+            gas_limit_target: None,
+            max_transactions: None,
+            max_idle: None,
+            block_timings: None,
+            fallback_policy: FallbackPolicy::default(),
+            safe_mode: None,
+            reorg_guard: None,
+            block_vanity: [0u8; EXTRA_VANITY_LENGTH],
+            tx_ordering: TransactionOrdering::default(),
         }
     }
 
@@ -53,6 +101,92 @@ impl PoaPayloadBuilderBuilder {
         self.cache_size = size.max(1); // at least 1 entry
         self
     }
+
+    /// Use an existing hot state cache (e.g. warmed from disk) instead of a fresh one.
+    ///
+    /// The caller keeps a handle so it can persist the cache on shutdown.
+    /// `cache_size` is ignored when a shared cache is provided.
+    pub fn with_shared_cache(mut self, cache: Option<SharedCache>) -> Self {
+        self.shared_cache = cache;
+        self
+    }
+
+    /// Override the base out-of-turn wiggle delay (milliseconds, `0` = disabled).
+    pub fn with_wiggle_ms(mut self, wiggle_ms: u64) -> Self {
+        self.wiggle_ms = wiggle_ms;
+        self
+    }
+
+    /// Verify on-chain governance reads with storage proofs (expensive, opt-in).
+    pub fn with_verify_storage_reads(mut self, verify: bool) -> Self {
+        self.verify_storage_reads = verify;
+        self
+    }
+
+    /// Steer block gas limits toward `target` instead of `ChainConfig.gasLimit`.
+    ///
+    /// The limit still moves by at most `parent / 1024` per block.
+    pub fn with_gas_limit_target(mut self, target: Option<u64>) -> Self {
+        self.gas_limit_target = target;
+        self
+    }
+
+    /// Include at most `max` transactions per block (`None` = no cap).
+    pub fn with_max_transactions(mut self, max: Option<usize>) -> Self {
+        self.max_transactions = max;
+        self
+    }
+
+    /// Suppress empty blocks, sealing a keep-alive after `max_idle` seconds
+    /// without one (`None` = seal empty blocks as usual).
+    pub fn with_empty_block_suppression(mut self, max_idle: Option<u64>) -> Self {
+        self.max_idle = max_idle;
+        self
+    }
+
+    /// Record each sealed payload's build and sign time in `timings`.
+    pub fn with_block_timings(mut self, timings: Option<SharedBlockTimings>) -> Self {
+        self.block_timings = timings;
+        self
+    }
+
+    /// Choose which out-of-turn signers may seal a missed slot (see [`FallbackPolicy`]).
+    pub fn with_fallback_policy(mut self, policy: FallbackPolicy) -> Self {
+        self.fallback_policy = policy;
+        self
+    }
+
+    /// Only seal in-turn blocks while `liveness` reports too few signers online
+    /// (`None` = always allow out-of-turn blocks).
+    pub fn with_safe_mode(mut self, liveness: Option<Arc<SignerLiveness>>) -> Self {
+        self.safe_mode = liveness;
+        self
+    }
+
+    /// Stop sealing once `guard` sees a reorg deeper than its limit (`None` = never).
+    pub fn with_reorg_guard(mut self, guard: Option<Arc<ReorgGuard>>) -> Self {
+        self.reorg_guard = guard;
+        self
+    }
+
+    /// Write `vanity` into blocks sealed by signers without a vanity of their own.
+    pub fn with_block_vanity(mut self, vanity: [u8; EXTRA_VANITY_LENGTH]) -> Self {
+        self.block_vanity = vanity;
+        self
+    }
+
+    /// Override the pool's priority-fee order when filling blocks (see
+    /// [`TransactionOrdering`]).
+    pub fn with_transaction_ordering(mut self, ordering: TransactionOrdering) -> Self {
+        self.tx_ordering = ordering;
+        self
+    }
+
+    /// Testing only: seal just these block numbers (see [`PoaPayloadBuilder::seal_only_blocks`]).
+    pub fn with_seal_only_blocks(mut self, blocks: Option<HashSet<u64>>) -> Self {
+        self.seal_only = blocks;
+        self
+    }
 }
 
 impl<Types, Node, Pool, Evm> PayloadBuilderBuilder<Node, Pool, Evm> for PoaPayloadBuilderBuilder
@@ -84,14 +218,37 @@ where
 
         // Create the shared hot state cache (Phase 5.31).
         // Startup reads populate the cache; subsequent epoch reads re-use it.
-        let cache: SharedCache = Arc::new(Mutex::new(HotStateCache::new(self.cache_size)));
+        let cache: SharedCache = self
+            .shared_cache
+            .clone()
+            .unwrap_or_else(|| Arc::new(Mutex::new(HotStateCache::new(self.cache_size))));
+
+        // With --verify-storage-reads, startup reads are proven against the latest
+        // state root. They must not be answered by a cache loaded from disk.
+        let verify_root = if self.verify_storage_reads {
+            let mut cache = cache.lock().unwrap_or_else(|e| e.into_inner());
+            cache.invalidate_address(CHAIN_CONFIG_ADDRESS);
+            cache.invalidate_address(SIGNER_REGISTRY_ADDRESS);
+            match ctx.provider().best_block_number() {
+                Ok(best) => storage_proof_root(ctx.provider(), best.into()),
+                Err(e) => {
+                    output::print_warning(&format!(
+                        "Storage proof verification skipped: chain head unreadable: {e}"
+                    ));
+                    None
+                }
+            }
+        } else {
+            None
+        };
 
         // Read gas limit from on-chain ChainConfig contract (Phase 3: item 20).
         // Falls back to CLI/genesis default if the contract isn't readable yet.
         // Uses the shared cache so the reads warm it up for future block builds.
         let gas_limit = match ctx.provider().latest() {
             Ok(state) => {
-                let reader = StateProviderStorageReader(state.as_ref());
+                let reader = StateProviderStorageReader::new(state.as_ref())
+                    .with_proof_verification(verify_root);
                 let cached = CachedStorageReader::new_shared(reader, Arc::clone(&cache));
                 let onchain = read_gas_limit(&cached).filter(|&gl| gl > 0);
                 if let Some(gl) = onchain {
@@ -108,7 +265,8 @@ where
 
         // Also seed the live signer cache from SignerRegistry at startup.
         if let Ok(state) = ctx.provider().latest() {
-            let reader = StateProviderStorageReader(state.as_ref());
+            let reader = StateProviderStorageReader::new(state.as_ref())
+                .with_proof_verification(verify_root);
             let cached = CachedStorageReader::new_shared(reader, Arc::clone(&cache));
             if let Some(list) = read_signer_list(&cached) {
                 if !list.signers.is_empty() {
@@ -119,31 +277,56 @@ where
         }
 
         // In production mode, pre-allocate POA extra_data (vanity + seal placeholder).
-        // In dev mode, leave extra_data empty — blocks are unsigned and Reth's engine
-        // rejects extra_data > 32 bytes (Ethereum mainnet limit).
+        // In dev mode, blocks are unsigned and Reth's engine rejects extra_data > 32
+        // bytes (Ethereum mainnet limit), so only a configured vanity is written.
         let extra_data = if self.dev_mode {
-            Bytes::new()
+            if self.block_vanity == [0u8; EXTRA_VANITY_LENGTH] {
+                Bytes::new()
+            } else {
+                Bytes::copy_from_slice(&self.block_vanity)
+            }
         } else {
             Bytes::from(vec![0u8; EXTRA_VANITY_LENGTH + EXTRA_SEAL_LENGTH])
         };
 
+        let builder_config = EthereumBuilderConfig::new()
+            .with_gas_limit(gas_limit)
+            .with_max_blobs_per_block(conf.max_blobs_per_block())
+            .with_extra_data(extra_data);
         let inner = reth_ethereum_payload_builder::EthereumPayloadBuilder::new(
             ctx.provider().clone(),
-            pool,
-            evm_config,
-            EthereumBuilderConfig::new()
-                .with_gas_limit(gas_limit)
-                .with_max_blobs_per_block(conf.max_blobs_per_block())
-                .with_extra_data(extra_data),
+            pool.clone(),
+            evm_config.clone(),
+            builder_config.clone(),
         );
 
-        Ok(PoaPayloadBuilder {
+        let builder = PoaPayloadBuilder {
             inner,
+            pool,
+            evm_config,
+            builder_config,
             chain_spec: self.chain_spec,
             signer_manager: self.signer_manager,
             dev_mode: self.dev_mode,
             client: ctx.provider().clone(),
             cache,
+            wiggle_ms: self.wiggle_ms,
+            wiggle_deadline: WiggleDeadline::default(),
+            verify_storage_reads: self.verify_storage_reads,
+            seal_only: None,
+            gas_limit_target: self.gas_limit_target,
+            max_transactions: self.max_transactions,
+            max_idle: self.max_idle,
+            block_timings: self.block_timings,
+            fallback_policy: self.fallback_policy,
+            safe_mode: self.safe_mode,
+            reorg_guard: self.reorg_guard,
+            block_vanity: self.block_vanity,
+            tx_ordering: self.tx_ordering,
+        };
+        Ok(match self.seal_only {
+            Some(blocks) => builder.seal_only_blocks(blocks),
+            None => builder,
         })
     }
 }
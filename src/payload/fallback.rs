@@ -0,0 +1,212 @@
+//! Out-of-turn fallback policy.
+//!
+//! When the in-turn signer misses its slot, Clique lets every other authorized
+//! signer seal out of turn and relies on the wiggle delay to spread them out. On a
+//! small network two backups still regularly seal competing blocks at the same
+//! height. With [`FallbackPolicy::Next`] only one backup is allowed per slot: the
+//! first signer after the in-turn one, in rotation order, that may seal under the
+//! recent-signer rule. Every node computes the same answer from the block number,
+//! the signer set and the chain's recent headers.
+//!
+//! If that backup is offline too, the slot's timestamp decides: each further block
+//! period without a block hands the slot to the next eligible signer, so the chain
+//! keeps moving with one producer at a time.
+
+use alloy_primitives::Address;
+use std::str::FromStr;
+
+/// Which signers may seal a block out of turn.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FallbackPolicy {
+    /// Any authorized signer not in the recent-signer window (Clique behaviour).
+    #[default]
+    Any,
+    /// Only the next eligible signer after the in-turn one.
+    Next,
+}
+
+impl FallbackPolicy {
+    /// Whether `signer` may seal `block_number` out of turn.
+    ///
+    /// `rank` counts the block periods missed beyond the first (see [`fallback_rank`]);
+    /// `may_seal` is the recent-signer rule.
+    pub fn allows(
+        self,
+        signer: &Address,
+        signers: &[Address],
+        block_number: u64,
+        rank: u64,
+        may_seal: impl Fn(&Address) -> bool,
+    ) -> bool {
+        match self {
+            Self::Any => may_seal(signer),
+            Self::Next => fallback_signer(signers, block_number, rank, may_seal) == Some(*signer),
+        }
+    }
+}
+
+impl FromStr for FallbackPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "any" => Ok(Self::Any),
+            "next" => Ok(Self::Next),
+            other => Err(format!(
+                "unknown fallback policy '{other}' (expected any or next)"
+            )),
+        }
+    }
+}
+
+/// The single signer allowed to seal `block_number` out of turn at `rank`.
+///
+/// Candidates are the signers after the in-turn one (`block_number % N`) in rotation
+/// order that pass `may_seal`; `rank` picks among them, wrapping around. Returns
+/// `None` if no other signer may seal.
+pub fn fallback_signer(
+    signers: &[Address],
+    block_number: u64,
+    rank: u64,
+    may_seal: impl Fn(&Address) -> bool,
+) -> Option<Address> {
+    let n = signers.len();
+    if n == 0 {
+        return None;
+    }
+    let in_turn = (block_number % n as u64) as usize;
+    let candidates: Vec<Address> = (1..n)
+        .map(|offset| signers[(in_turn + offset) % n])
+        .filter(|signer| may_seal(signer))
+        .collect();
+    if candidates.is_empty() {
+        return None;
+    }
+    Some(candidates[(rank % candidates.len() as u64) as usize])
+}
+
+/// Fallback rank of a block sealed `idle_secs` after its parent: `0` within the
+/// second block period, then one more per period missed.
+pub fn fallback_rank(idle_secs: u64, period: u64) -> u64 {
+    if period == 0 {
+        return 0;
+    }
+    (idle_secs / period).saturating_sub(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn signers(n: u8) -> Vec<Address> {
+        (1..=n).map(Address::with_last_byte).collect()
+    }
+
+    /// Seal `blocks` on a network where each signer runs its own node and the
+    /// `offline` ones never seal. Returns the producers of each slot.
+    ///
+    /// The in-turn signer seals if online; otherwise every online node applies
+    /// `policy`, escalating the rank one period at a time until someone seals.
+    fn simulate(
+        policy: FallbackPolicy,
+        signers: &[Address],
+        offline: &HashSet<Address>,
+        blocks: u64,
+    ) -> Vec<Vec<Address>> {
+        // Clique: a signer may seal once per `N/2 + 1` consecutive blocks
+        let limit = signers.len() / 2 + 1;
+        let mut sealed: Vec<Address> = Vec::new();
+        let mut slots = Vec::new();
+        for number in 1..=blocks {
+            let recent: Vec<Address> = sealed.iter().rev().take(limit - 1).copied().collect();
+            let may_seal = |a: &Address| !recent.contains(a);
+            let in_turn = signers[(number % signers.len() as u64) as usize];
+            let producers = if !offline.contains(&in_turn) && may_seal(&in_turn) {
+                vec![in_turn]
+            } else {
+                (0..signers.len() as u64)
+                    .map(|rank| {
+                        signers
+                            .iter()
+                            .filter(|s| **s != in_turn && !offline.contains(s))
+                            .filter(|s| policy.allows(s, signers, number, rank, may_seal))
+                            .copied()
+                            .collect::<Vec<_>>()
+                    })
+                    .find(|producers| !producers.is_empty())
+                    .expect("some signer seals the slot")
+            };
+            sealed.push(producers[0]);
+            slots.push(producers);
+        }
+        slots
+    }
+
+    #[test]
+    fn test_single_fallback_producer_per_missed_slot() {
+        let signers = signers(5);
+        let offline = HashSet::from([signers[2]]);
+        let slots = simulate(FallbackPolicy::Next, &signers, &offline, 100);
+        for (i, producers) in slots.iter().enumerate() {
+            assert_eq!(
+                producers.len(),
+                1,
+                "block {} has competing producers",
+                i + 1
+            );
+        }
+        // The first missed slot (block 2) goes to the signer after the offline one
+        assert_eq!(slots[1], vec![signers[3]]);
+
+        // Without the policy, several backups race for the same slot
+        let slots = simulate(FallbackPolicy::Any, &signers, &offline, 100);
+        assert!(slots.iter().any(|producers| producers.len() > 1));
+    }
+
+    #[test]
+    fn test_fallback_escalates_past_offline_backup() {
+        // The in-turn signer and its first backup are both down
+        let signers = signers(5);
+        let offline = HashSet::from([signers[2], signers[3]]);
+        let slots = simulate(FallbackPolicy::Next, &signers, &offline, 100);
+        assert!(slots.iter().all(|producers| producers.len() == 1));
+        assert!(slots
+            .iter()
+            .all(|producers| !offline.contains(&producers[0])));
+    }
+
+    #[test]
+    fn test_fallback_signer_rotation() {
+        let signers = signers(5);
+        // Block 7: in turn is index 2, next is index 3
+        assert_eq!(fallback_signer(&signers, 7, 0, |_| true), Some(signers[3]));
+        assert_eq!(fallback_signer(&signers, 7, 1, |_| true), Some(signers[4]));
+        // Wraps past the end of the rotation, never back to the in-turn signer
+        assert_eq!(fallback_signer(&signers, 7, 3, |_| true), Some(signers[1]));
+        assert_eq!(fallback_signer(&signers, 7, 4, |_| true), Some(signers[3]));
+        // Recent signers are skipped
+        assert_eq!(
+            fallback_signer(&signers, 7, 0, |a| *a != signers[3]),
+            Some(signers[4])
+        );
+        assert_eq!(fallback_signer(&signers, 7, 0, |_| false), None);
+        assert_eq!(fallback_signer(&[], 7, 0, |_| true), None);
+    }
+
+    #[test]
+    fn test_fallback_rank() {
+        assert_eq!(fallback_rank(2, 2), 0);
+        assert_eq!(fallback_rank(3, 2), 0);
+        assert_eq!(fallback_rank(4, 2), 1);
+        assert_eq!(fallback_rank(10, 2), 4);
+        assert_eq!(fallback_rank(10, 0), 0);
+    }
+
+    #[test]
+    fn test_policy_from_str() {
+        assert_eq!("any".parse(), Ok(FallbackPolicy::Any));
+        assert_eq!("next".parse(), Ok(FallbackPolicy::Next));
+        assert!("first".parse::<FallbackPolicy>().is_err());
+    }
+}
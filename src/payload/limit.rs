@@ -0,0 +1,80 @@
+//! Per-block transaction cap for block building.
+//!
+//! With `--block-max-txs N`, the payload builder wraps the pool's
+//! best-transactions iterator in [`MaxTransactions`], which stops after `N`
+//! transactions. The remaining pending transactions stay in the pool for the
+//! next block. Transactions the builder skips (e.g. out of gas) still count
+//! toward the cap, so a block may carry fewer than `N`.
+
+use reth_transaction_pool::error::InvalidPoolTransactionError;
+use reth_transaction_pool::BestTransactions;
+
+/// Best-transactions iterator that yields at most `max` transactions from `inner`.
+#[derive(Debug)]
+pub struct MaxTransactions<I> {
+    inner: I,
+    remaining: usize,
+}
+
+impl<I> MaxTransactions<I> {
+    /// Wrap a best-transactions iterator.
+    pub fn new(inner: I, max: usize) -> Self {
+        Self {
+            inner,
+            remaining: max,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for MaxTransactions<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let tx = self.inner.next()?;
+        self.remaining -= 1;
+        Some(tx)
+    }
+}
+
+impl<I: BestTransactions> BestTransactions for MaxTransactions<I> {
+    fn mark_invalid(&mut self, transaction: &Self::Item, kind: &InvalidPoolTransactionError) {
+        self.inner.mark_invalid(transaction, kind);
+    }
+
+    fn no_updates(&mut self) {
+        self.inner.no_updates();
+    }
+
+    fn set_skip_blobs(&mut self, skip_blobs: bool) {
+        self.inner.set_skip_blobs(skip_blobs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_transaction_pool::test_utils::{testing_pool, MockTransaction};
+    use reth_transaction_pool::{TransactionOrigin, TransactionPool};
+
+    #[tokio::test]
+    async fn test_pending_txs_split_across_capped_blocks() {
+        const N: usize = 3;
+        let pool = testing_pool();
+        for _ in 0..2 * N {
+            pool.add_transaction(TransactionOrigin::External, MockTransaction::eip1559())
+                .await
+                .unwrap();
+        }
+
+        // Each "block" takes N transactions; included ones leave the pool
+        for _ in 0..2 {
+            let block: Vec<_> = MaxTransactions::new(pool.best_transactions(), N).collect();
+            assert_eq!(block.len(), N);
+            pool.remove_transactions(block.iter().map(|tx| *tx.hash()).collect());
+        }
+        assert_eq!(MaxTransactions::new(pool.best_transactions(), N).count(), 0);
+    }
+}
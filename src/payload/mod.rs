@@ -6,20 +6,45 @@
 //! 1. Set difficulty = 0 (Engine API compatibility; authority is via ECDSA signature)
 //! 2. Build extra_data with POA format (vanity + [signers at epoch] + signature)
 //! 3. Sign the block header with the appropriate signer key
+//!
+//! With empty-block suppression (`--no-empty-blocks`), blocks without transactions
+//! are dropped instead of sealed, except for a keep-alive block once the chain has
+//! been idle for `--max-idle` seconds.
 
 pub mod builder;
+pub mod fallback;
+pub mod limit;
+pub mod ordering;
+pub mod pause;
+pub mod priority;
 
 pub use builder::PoaPayloadBuilderBuilder;
+pub use fallback::{fallback_rank, fallback_signer, FallbackPolicy};
+pub use limit::MaxTransactions;
+pub use ordering::{Reordered, TransactionOrdering};
+pub use pause::{
+    changes_paused_governance, is_paused_target, PausedGovernance, PAUSABLE_ADDRESSES,
+    PAUSED_GOVERNANCE_ERROR,
+};
+pub use priority::{is_governance_target, GovernanceFirst, GOVERNANCE_ADDRESSES};
 
 use crate::cache::{CachedStorageReader, SharedCache};
 use crate::chainspec::PoaChainSpec;
-use crate::consensus::{EXTRA_SEAL_LENGTH, EXTRA_VANITY_LENGTH};
-use crate::genesis::addresses::SIGNER_REGISTRY_ADDRESS;
-use crate::metrics::PhaseTimer;
-use crate::onchain::{read_signer_list, StateProviderStorageReader};
+use crate::consensus::{
+    PoaConsensus, EXTRA_SEAL_LENGTH, EXTRA_VANITY_LENGTH, NONCE_AUTH_VOTE, NONCE_DROP_VOTE,
+};
+use crate::genesis::addresses::{CHAIN_CONFIG_ADDRESS, SIGNER_REGISTRY_ADDRESS};
+use crate::metrics::{BlockTiming, PhaseTimer, SharedBlockTimings};
+use crate::monitor::{ReorgGuard, SignerLiveness};
+use crate::onchain::{
+    is_timelock_paused, read_epoch, read_gas_limit, read_recent_signer_window, read_signer_list,
+    StateProviderStorageReader,
+};
 use crate::output;
 use crate::signer::{BlockSealer, SignerManager};
-use alloy_primitives::{Address, Bytes, U256};
+use alloy_consensus::{BlockHeader, Header};
+use alloy_eips::BlockHashOrNumber;
+use alloy_primitives::{keccak256, Address, Bytes, B256, B64, U256};
 use reth_basic_payload_builder::{
     BuildArguments, BuildOutcome, MissingPayloadBehaviour, PayloadBuilder, PayloadConfig,
 };
@@ -27,12 +52,16 @@ use reth_chainspec::{ChainSpecProvider, EthereumHardforks};
 use reth_ethereum::storage::StateProviderFactory;
 use reth_ethereum::EthPrimitives;
 use reth_ethereum_engine_primitives::{EthBuiltPayload, EthPayloadBuilderAttributes};
+use reth_ethereum_payload_builder::{default_ethereum_payload, EthereumBuilderConfig};
 use reth_evm::{ConfigureEvm, NextBlockEnvAttributes};
 use reth_payload_builder_primitives::PayloadBuilderError;
 use reth_payload_primitives::BuiltPayload;
 use reth_primitives_traits::block::SealedBlock;
+use reth_storage_api::HeaderProvider;
 use reth_transaction_pool::{PoolTransaction, TransactionPool};
-use std::sync::Arc;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 /// POA payload builder that wraps `EthereumPayloadBuilder`.
 ///
@@ -48,6 +77,12 @@ pub struct PoaPayloadBuilder<Pool, Client, EvmConfig> {
     /// The inner Ethereum payload builder that does the actual block construction.
     pub(crate) inner:
         reth_ethereum_payload_builder::EthereumPayloadBuilder<Pool, Client, EvmConfig>,
+    /// Transaction pool, also held here to build with a reordered transaction iterator.
+    pub(crate) pool: Pool,
+    /// EVM config matching `inner`'s.
+    pub(crate) evm_config: EvmConfig,
+    /// Builder config matching `inner`'s.
+    pub(crate) builder_config: EthereumBuilderConfig,
     /// POA chain specification with signer list, epoch, period.
     pub(crate) chain_spec: Arc<PoaChainSpec>,
     /// Signer manager with signing keys.
@@ -58,12 +93,42 @@ pub struct PoaPayloadBuilder<Pool, Client, EvmConfig> {
     pub(crate) client: Client,
     /// Hot state cache shared across block builds (Phase 5.31).
     pub(crate) cache: SharedCache,
+    /// Base out-of-turn wiggle delay in milliseconds (`0` = disabled).
+    pub(crate) wiggle_ms: u64,
+    /// When the out-of-turn payload being rebuilt may be published.
+    pub(crate) wiggle_deadline: WiggleDeadline,
+    /// Verify epoch governance reads against the parent state root with storage proofs.
+    pub(crate) verify_storage_reads: bool,
+    /// Testing only: seal just these block numbers (`None` = seal every block).
+    pub(crate) seal_only: Option<Arc<HashSet<u64>>>,
+    /// Gas limit to steer toward instead of the on-chain `ChainConfig.gasLimit`.
+    pub(crate) gas_limit_target: Option<u64>,
+    /// Maximum transactions per block (`None` = limited by gas only).
+    pub(crate) max_transactions: Option<usize>,
+    /// Empty-block suppression: skip empty blocks until the chain has been idle
+    /// this many seconds (`None` = always seal empty blocks).
+    pub(crate) max_idle: Option<u64>,
+    /// Where sealed payloads' build/sign times are recorded (`None` = not recorded).
+    pub(crate) block_timings: Option<SharedBlockTimings>,
+    /// Which of our signers may seal when the in-turn signer misses its slot.
+    pub(crate) fallback_policy: FallbackPolicy,
+    /// `--safe-mode`: no out-of-turn blocks while too few signers are online.
+    pub(crate) safe_mode: Option<Arc<SignerLiveness>>,
+    /// `--halt-on-deep-reorg`: no payloads are built once a too-deep reorg was seen.
+    pub(crate) reorg_guard: Option<Arc<ReorgGuard>>,
+    /// Vanity for blocks sealed by signers without their own (`--block-vanity`).
+    pub(crate) block_vanity: [u8; EXTRA_VANITY_LENGTH],
+    /// Order in which pending transactions are included (`--tx-ordering`).
+    pub(crate) tx_ordering: TransactionOrdering,
 }
 
 impl<Pool, Client, EvmConfig> PayloadBuilder for PoaPayloadBuilder<Pool, Client, EvmConfig>
 where
     EvmConfig: ConfigureEvm<Primitives = EthPrimitives, NextBlockEnvCtx = NextBlockEnvAttributes>,
-    Client: StateProviderFactory + ChainSpecProvider<ChainSpec: EthereumHardforks> + Clone,
+    Client: StateProviderFactory
+        + HeaderProvider<Header = Header>
+        + ChainSpecProvider<ChainSpec: EthereumHardforks>
+        + Clone,
     Pool:
         TransactionPool<Transaction: PoolTransaction<Consensus = reth_ethereum::TransactionSigned>>,
 {
@@ -72,15 +137,74 @@ where
 
     fn try_build(
         &self,
-        args: BuildArguments<EthPayloadBuilderAttributes, EthBuiltPayload>,
+        mut args: BuildArguments<EthPayloadBuilderAttributes, EthBuiltPayload>,
     ) -> Result<BuildOutcome<EthBuiltPayload>, PayloadBuilderError> {
+        // 0. If we have a pending signer vote, the candidate must be the coinbase.
+        //    This has to happen before execution since fees are credited to the coinbase.
+        let block_number = args.config.parent_header.number + 1;
+        if self.is_halted() || !seals_block(self.seal_only.as_deref(), block_number) {
+            return Ok(BuildOutcome::Cancelled);
+        }
+        if let Some((candidate, _)) = self.vote_for_block(block_number) {
+            args.config.attributes.suggested_fee_recipient = candidate;
+        }
+
         // 1. Let the inner builder construct the block (transactions, state, etc.)
+        //    The pool's fee order can be overridden (testing), governance priority
+        //    reorders equal-fee runs, governance calls wait while the Timelock is paused,
+        //    and a transaction cap stops after N transactions.
+        //    The gas limit moves one bounded step toward the target each block.
+        let parent = &args.config.parent_header;
+        let idle_secs = args
+            .config
+            .attributes
+            .timestamp
+            .saturating_sub(parent.timestamp);
+        let gas_limit = next_gas_limit(parent.gas_limit, self.gas_limit_target(parent.hash()));
+        let builder_config = self.builder_config.clone().with_gas_limit(gas_limit);
+        let prioritize = self.chain_spec.poa_config().prioritize_governance_txs;
+        let paused = self.timelock_paused(parent.hash());
         let build_timer = PhaseTimer::start();
-        let outcome = self.inner.try_build(args)?;
+        let outcome = default_ethereum_payload(
+            self.evm_config.clone(),
+            self.client.clone(),
+            self.pool.clone(),
+            builder_config,
+            args,
+            |attributes| {
+                let mut best = self.pool.best_transactions_with_attributes(attributes);
+                if self.tx_ordering != TransactionOrdering::PriorityFee {
+                    best = Box::new(Reordered::new(best, self.tx_ordering));
+                }
+                if prioritize {
+                    best = Box::new(GovernanceFirst::new(best));
+                }
+                best = Box::new(PausedGovernance::new(best, paused));
+                if let Some(max) = self.max_transactions {
+                    best = Box::new(MaxTransactions::new(best, max));
+                }
+                best
+            },
+        )?;
         let build_ms = build_timer.elapsed_ms();
 
-        // 2. Post-process: sign the block if we have a signer
+        // 2. Post-process: drop suppressed empty blocks, sign the rest
+        let skip = |payload: &EthBuiltPayload| {
+            skips_empty_block(
+                self.max_idle,
+                payload.block().body().transactions.is_empty(),
+                idle_secs,
+            )
+        };
         match outcome {
+            BuildOutcome::Better {
+                payload,
+                cached_reads,
+            } if skip(&payload) => Ok(BuildOutcome::Aborted {
+                fee: payload.fees(),
+                cached_reads,
+            }),
+            BuildOutcome::Freeze(payload) if skip(&payload) => Ok(BuildOutcome::Cancelled),
             BuildOutcome::Better {
                 payload,
                 cached_reads,
@@ -103,13 +227,33 @@ where
         &self,
         args: BuildArguments<Self::Attributes, Self::BuiltPayload>,
     ) -> MissingPayloadBehaviour<Self::BuiltPayload> {
+        // Racing an empty payload would defeat empty-block suppression; wait for
+        // the in-flight build instead (which may itself be suppressed).
+        if self.max_idle.is_some() {
+            return MissingPayloadBehaviour::AwaitInProgress;
+        }
         self.inner.on_missing_payload(args)
     }
 
     fn build_empty_payload(
         &self,
-        config: PayloadConfig<Self::Attributes>,
+        mut config: PayloadConfig<Self::Attributes>,
     ) -> Result<EthBuiltPayload, PayloadBuilderError> {
+        if self.is_halted()
+            || !seals_block(self.seal_only.as_deref(), config.parent_header.number + 1)
+        {
+            return Err(PayloadBuilderError::MissingPayload);
+        }
+        let idle_secs = config
+            .attributes
+            .timestamp
+            .saturating_sub(config.parent_header.timestamp);
+        if skips_empty_block(self.max_idle, true, idle_secs) {
+            return Err(PayloadBuilderError::MissingPayload);
+        }
+        if let Some((candidate, _)) = self.vote_for_block(config.parent_header.number + 1) {
+            config.attributes.suggested_fee_recipient = candidate;
+        }
         let build_timer = PhaseTimer::start();
         let payload = self.inner.build_empty_payload(config)?;
         let build_ms = build_timer.elapsed_ms();
@@ -117,54 +261,164 @@ where
     }
 }
 
+impl<Pool, Client, EvmConfig> PoaPayloadBuilder<Pool, Client, EvmConfig> {
+    /// Testing affordance: only build and seal the given block numbers.
+    ///
+    /// For any other block the builder produces no payload, so a test harness can
+    /// script which node seals which block and deliberately create forks.
+    pub fn seal_only_blocks(mut self, blocks: HashSet<u64>) -> Self {
+        self.seal_only = Some(Arc::new(blocks));
+        self
+    }
+
+    /// Whether a reorg deeper than --max-reorg-depth halted block production.
+    ///
+    /// Checked before anything is built, so a halted node neither seals blocks nor
+    /// refreshes the live signers at epoch blocks.
+    fn is_halted(&self) -> bool {
+        self.reorg_guard
+            .as_ref()
+            .is_some_and(|guard| guard.is_halted())
+    }
+}
+
 impl<Pool, Client, EvmConfig> PoaPayloadBuilder<Pool, Client, EvmConfig>
 where
-    Client: StateProviderFactory + Clone,
+    Client: StateProviderFactory + HeaderProvider<Header = Header> + Clone,
 {
-    /// Sign a built payload with POA signature.
+    /// Pending signer vote (candidate, authorize) to cast in `block_number`, if any.
+    ///
+    /// Votes are never cast in dev mode or at epoch (checkpoint) blocks, matching Clique.
+    fn vote_for_block(&self, block_number: u64) -> Option<(Address, bool)> {
+        if self.dev_mode || (block_number > 0 && self.chain_spec.is_epoch_block(block_number)) {
+            return None;
+        }
+        self.signer_manager
+            .pending_vote(&self.chain_spec.effective_signers())
+    }
+
+    /// Gas limit the chain should converge to for a child of `parent_hash`.
+    ///
+    /// An explicit override wins; otherwise `ChainConfig.gasLimit` is read from the
+    /// parent state, uncached so a governance change applies from the next block.
+    /// Falls back to the startup gas limit when the contract isn't readable.
+    fn gas_limit_target(&self, parent_hash: B256) -> u64 {
+        if let Some(target) = self.gas_limit_target {
+            return target;
+        }
+        let verify_root = self
+            .verify_storage_reads
+            .then(|| storage_proof_root(&self.client, parent_hash.into()))
+            .flatten();
+        self.client
+            .state_by_block_hash(parent_hash)
+            .ok()
+            .and_then(|state| {
+                read_gas_limit(
+                    &StateProviderStorageReader::new(state.as_ref())
+                        .with_proof_verification(verify_root),
+                )
+            })
+            .filter(|&gl| gl > 0)
+            .unwrap_or(self.builder_config.desired_gas_limit)
+    }
+
+    /// Whether the Timelock is paused in the state of `parent_hash`, freezing
+    /// governance calls in the child block. Unreadable state counts as unpaused.
+    fn timelock_paused(&self, parent_hash: B256) -> bool {
+        self.client
+            .state_by_block_hash(parent_hash)
+            .ok()
+            .is_some_and(|state| {
+                is_timelock_paused(&StateProviderStorageReader::new(state.as_ref()))
+            })
+    }
+
+    /// Sign a built payload and record its build/sign times for the metrics pipeline.
     ///
     /// `build_ms` is the wall-clock time spent building the block (Phase 2.17 timing).
+    fn sign_payload(
+        &self,
+        payload: EthBuiltPayload,
+        build_ms: u64,
+    ) -> Result<EthBuiltPayload, PayloadBuilderError> {
+        let (payload, sign_ms) = self.seal_payload(payload, build_ms)?;
+        if let Some(timings) = &self.block_timings {
+            let block = payload.block();
+            timings
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .record_sealed(BlockTiming {
+                    block_number: block.header().number,
+                    block_hash: block.hash(),
+                    build_ms,
+                    sign_ms,
+                });
+        }
+        Ok(payload)
+    }
+
+    /// Sign a built payload with POA signature, returning it with the time spent
+    /// signing in milliseconds.
     ///
     /// In dev mode, returns the payload unchanged.
     /// In production mode:
     /// 1. At epoch blocks — refreshes live signer list from on-chain SignerRegistry
-    /// 2. Determines which signer should sign (round-robin using effective_signers)
-    /// 3. Sets difficulty = 0 (Engine API compatibility)
+    /// 2. Determines which signer should sign (round-robin using effective_signers),
+    ///    skipping our signers that sealed a block within the recent-signer window
+    /// 3. Sets difficulty = 0 (Engine API compatibility) and the vote nonce
     /// 4. Builds extra_data with POA format (vanity + [signers at epoch] + signature)
     /// 5. Signs the header via BlockSealer
     /// 6. Reconstructs the sealed block
-    fn sign_payload(
+    fn seal_payload(
         &self,
         payload: EthBuiltPayload,
         build_ms: u64,
-    ) -> Result<EthBuiltPayload, PayloadBuilderError> {
+    ) -> Result<(EthBuiltPayload, u64), PayloadBuilderError> {
         if self.dev_mode {
-            return Ok(payload);
+            return Ok((payload, 0));
         }
 
         let block = payload.block();
         let block_number = block.header().number;
-        let epoch = self.chain_spec.epoch();
-        let is_epoch = block_number > 0 && block_number.is_multiple_of(epoch);
+        let is_epoch = block_number > 0 && self.chain_spec.is_epoch_block(block_number);
 
-        // At epoch blocks, refresh live signer list from SignerRegistry.
-        // Invalidate the cached SignerRegistry slots first so we get the latest governance
+        // At epoch blocks, refresh live signer list from SignerRegistry and the
+        // recent-signer window and epoch length from ChainConfig.
+        // Invalidate the cached SignerRegistry and ChainConfig slots first so we get the latest governance
         // state, then re-populate the cache with the fresh read.
         if is_epoch {
             if let Ok(state) = self.client.latest() {
-                // Invalidate stale signer registry entries before refreshing
-                self.cache
-                    .lock()
-                    .expect("cache lock")
-                    .invalidate_address(SIGNER_REGISTRY_ADDRESS);
-                let reader = StateProviderStorageReader(state.as_ref());
+                // Invalidate stale governance entries before refreshing
+                {
+                    let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+                    cache.invalidate_address(SIGNER_REGISTRY_ADDRESS);
+                    cache.invalidate_address(CHAIN_CONFIG_ADDRESS);
+                }
+                let verify_root = self
+                    .verify_storage_reads
+                    .then(|| storage_proof_root(&self.client, (block_number - 1).into()))
+                    .flatten();
+                let reader = StateProviderStorageReader::new(state.as_ref())
+                    .with_proof_verification(verify_root);
                 let cached = CachedStorageReader::new_shared(reader, Arc::clone(&self.cache));
                 if let Some(list) = read_signer_list(&cached) {
                     if !list.signers.is_empty() {
                         output::print_epoch_refresh(block_number, list.signers.len());
+                        let window = read_recent_signer_window(&cached);
                         self.chain_spec.update_live_signers(list.signers);
+                        self.chain_spec.update_recent_signer_window(window);
                     }
                 }
+                let current = self.chain_spec.effective_epoch();
+                let epoch = read_epoch(&cached);
+                if let Some(at) = self.chain_spec.update_epoch(block_number, epoch) {
+                    output::print_epoch_length_scheduled(
+                        at,
+                        current,
+                        epoch.unwrap_or(self.chain_spec.epoch()),
+                    );
+                }
             }
         }
 
@@ -172,13 +426,43 @@ where
         let signers = self.chain_spec.effective_signers();
         if signers.is_empty() {
             // No signers configured, return unsigned
-            return Ok(payload);
+            return Ok((payload, 0));
         }
 
         // Determine which signer should sign this block (round-robin)
         let in_turn_signer = match self.chain_spec.expected_signer(block_number) {
             Some(s) => s,
-            None => return Ok(payload),
+            None => return Ok((payload, 0)),
+        };
+
+        // Clique rule: a signer may seal only one block per recent-signer window.
+        // Skip any of our keys that sealed one of the recent blocks.
+        let consensus = PoaConsensus::new(self.chain_spec.clone());
+        let window = consensus.recent_signer_limit();
+        let recent_headers = self
+            .client
+            .headers_range(block_number.saturating_sub(window - 1)..block_number)
+            .unwrap_or_default();
+        let signed_recently = |addr: &Address| {
+            consensus
+                .last_signed_within_window(addr, block_number, &recent_headers)
+                .is_some()
+        };
+        // Under the `next` fallback policy, each block period missed beyond the first
+        // hands the slot to the following backup signer.
+        let rank = match self.fallback_policy {
+            FallbackPolicy::Any => 0,
+            FallbackPolicy::Next => self
+                .client
+                .header_by_number(block_number - 1)
+                .ok()
+                .flatten()
+                .map_or(0, |parent| {
+                    fallback_rank(
+                        block.header().timestamp.saturating_sub(parent.timestamp),
+                        self.chain_spec.block_period(),
+                    )
+                }),
         };
 
         // Find a signer we control.
@@ -186,54 +470,66 @@ where
         // (dev mode) and async task contexts (production+mining mode).
         let handle = tokio::runtime::Handle::current();
         let signer_manager = self.signer_manager.clone();
+        let safe_mode = self
+            .safe_mode
+            .as_ref()
+            .is_some_and(|liveness| liveness.is_degraded());
 
-        let (signer_addr, is_in_turn) = tokio::task::block_in_place(|| {
+        let (signer_addr, is_in_turn, vanity) = tokio::task::block_in_place(|| {
             handle.block_on(async {
                 // Prefer in-turn signer if we have it
-                if signer_manager.has_signer(&in_turn_signer).await {
-                    return (in_turn_signer, true);
-                }
-                // Otherwise find any authorized signer we control
-                let our_addrs = signer_manager.signer_addresses().await;
-                for addr in &our_addrs {
-                    if signers.contains(addr) {
-                        return (*addr, false);
-                    }
-                }
-                (Address::ZERO, false)
+                let (addr, in_turn) = if signer_manager.has_signer(&in_turn_signer).await
+                    && !signed_recently(&in_turn_signer)
+                {
+                    (in_turn_signer, true)
+                } else if safe_mode {
+                    // Too few signers online: out-of-turn blocks would only race
+                    (Address::ZERO, false)
+                } else {
+                    // Otherwise find an authorized signer we control that the fallback
+                    // policy lets seal now
+                    let our_addrs = signer_manager.signer_addresses().await;
+                    our_addrs
+                        .into_iter()
+                        .find(|addr| {
+                            signers.contains(addr)
+                                && self.fallback_policy.allows(
+                                    addr,
+                                    &signers,
+                                    block_number,
+                                    rank,
+                                    |a: &Address| !signed_recently(a),
+                                )
+                        })
+                        .map(|addr| (addr, false))
+                        .unwrap_or((Address::ZERO, false))
+                };
+                let vanity = signer_manager.vanity_or(&addr, self.block_vanity).await;
+                (addr, in_turn, vanity)
             })
         });
 
         if signer_addr == Address::ZERO {
-            // No authorized signer key available, return unsigned
-            return Ok(payload);
+            // No authorized signer key available (or all signed recently), return unsigned
+            return Ok((payload, 0));
         }
 
-        // Clone header and body from the built block
-        let mut header = block.header().clone();
-        let body = block.body().clone();
-
-        // Difficulty must be 0 for Engine API compatibility.
-        header.difficulty = U256::ZERO;
-
-        // Build extra_data with POA format
-        let mut extra_data = Vec::with_capacity(
-            EXTRA_VANITY_LENGTH + if is_epoch { signers.len() * 20 } else { 0 } + EXTRA_SEAL_LENGTH,
+        // Cast our pending vote for the candidate placed in the coinbase by try_build.
+        let vote = if is_epoch {
+            None
+        } else {
+            self.signer_manager
+                .proposals()
+                .get(&block.header().beneficiary)
+                .copied()
+        };
+        let header = poa_header(
+            block.header().clone(),
+            &vanity,
+            is_epoch.then_some(signers.as_slice()),
+            vote,
         );
-
-        // Vanity (32 zero bytes)
-        extra_data.extend_from_slice(&[0u8; EXTRA_VANITY_LENGTH]);
-
-        // At epoch blocks, embed the effective (live) signer list
-        if is_epoch {
-            for signer in signers.iter() {
-                extra_data.extend_from_slice(signer.as_slice());
-            }
-        }
-
-        // Placeholder for signature (65 bytes — will be replaced by seal_header)
-        extra_data.extend_from_slice(&[0u8; EXTRA_SEAL_LENGTH]);
-        header.extra_data = Bytes::from(extra_data);
+        let body = block.body().clone();
 
         // Sign the header (Phase 5: timed for performance metrics)
         let sign_timer = PhaseTimer::start();
@@ -244,6 +540,20 @@ where
         .map_err(|e| PayloadBuilderError::Other(Box::new(e)))?;
         let sign_ms = sign_timer.elapsed_ms();
 
+        // Out-of-turn: wait a deterministic wiggle so the in-turn signer usually wins
+        // the race and we avoid needless out-of-turn blocks and reorgs. Like signing,
+        // the wait runs on the runtime's timer without stalling its worker threads.
+        if !is_in_turn {
+            let wiggle =
+                out_of_turn_wiggle(&signer_addr, block_number, signers.len(), self.wiggle_ms);
+            let remaining = self
+                .wiggle_deadline
+                .remaining(block.header().parent_hash, wiggle);
+            if !remaining.is_zero() {
+                tokio::task::block_in_place(|| handle.block_on(tokio::time::sleep(remaining)));
+            }
+        }
+
         output::print_block_signed(block_number, &signer_addr, is_in_turn, build_ms, sign_ms);
 
         // Reconstruct the sealed block with the signed header
@@ -253,21 +563,194 @@ where
         };
         let sealed = SealedBlock::seal_slow(new_block);
 
-        Ok(EthBuiltPayload::new(
-            payload.id(),
-            Arc::new(sealed),
-            payload.fees(),
-            payload.requests(),
+        Ok((
+            EthBuiltPayload::new(
+                payload.id(),
+                Arc::new(sealed),
+                payload.fees(),
+                payload.requests(),
+            ),
+            sign_ms,
         ))
     }
 }
 
+/// State root that `--verify-storage-reads` proves reads of `block`'s state
+/// against.
+///
+/// The reads go unverified without it, so a missing header is reported rather
+/// than silently skipping verification.
+pub(crate) fn storage_proof_root<C>(client: &C, block: BlockHashOrNumber) -> Option<B256>
+where
+    C: HeaderProvider<Header: BlockHeader>,
+{
+    match client.header_by_hash_or_number(block) {
+        Ok(Some(header)) => Some(header.state_root()),
+        Ok(None) => {
+            output::print_warning(&format!(
+                "Storage proof verification skipped: header of block {block} not found"
+            ));
+            None
+        }
+        Err(e) => {
+            output::print_warning(&format!(
+                "Storage proof verification skipped: header of block {block} unreadable: {e}"
+            ));
+            None
+        }
+    }
+}
+
+/// Deadline of the out-of-turn wiggle for the payload currently being built.
+///
+/// A payload job rebuilds its block several times on the same parent. Only the
+/// first build starts the wiggle; later rebuilds wait for whatever is left of it
+/// instead of the full delay again.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct WiggleDeadline(Arc<Mutex<Option<(B256, Instant)>>>);
+
+impl WiggleDeadline {
+    /// Time still to wait before publishing a payload on `parent`, starting a
+    /// `wiggle` countdown if this is the first build on that parent.
+    pub(crate) fn remaining(&self, parent: B256, wiggle: Duration) -> Duration {
+        let now = Instant::now();
+        let mut current = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        let deadline = match *current {
+            Some((hash, deadline)) if hash == parent => deadline,
+            _ => {
+                let deadline = now + wiggle;
+                *current = Some((parent, deadline));
+                deadline
+            }
+        };
+        deadline.saturating_duration_since(now)
+    }
+}
+
+/// Deterministic out-of-turn delay for `signer` at `block_number`.
+///
+/// Clique picks a random delay in `[0, (N/2 + 1) * wiggle)`; we derive it from
+/// `keccak256(signer ++ block_number)` instead so each signer gets a stable,
+/// distinct slot per block and competing out-of-turn signers rarely collide.
+pub(crate) fn out_of_turn_wiggle(
+    signer: &Address,
+    block_number: u64,
+    signer_count: usize,
+    base_ms: u64,
+) -> Duration {
+    let max_ms = (signer_count as u64 / 2 + 1) * base_ms;
+    if max_ms == 0 {
+        return Duration::ZERO;
+    }
+    let mut seed = [0u8; 28];
+    seed[..20].copy_from_slice(signer.as_slice());
+    seed[20..].copy_from_slice(&block_number.to_be_bytes());
+    let hash = keccak256(seed);
+    let r = u64::from_be_bytes(hash[..8].try_into().expect("8 bytes"));
+    Duration::from_millis(r % max_ms)
+}
+
+/// Child gas limit after one step from `parent` toward `target`.
+///
+/// Each step is at most `parent / 1024 - 1`, strictly inside the ±`parent / 1024`
+/// bound `PoaConsensus` enforces (and what Reth's own builder clamps to), so the
+/// result passes both unchanged.
+pub(crate) fn next_gas_limit(parent: u64, target: u64) -> u64 {
+    let max_step = (parent / 1024).saturating_sub(1);
+    target.clamp(
+        parent.saturating_sub(max_step),
+        parent.saturating_add(max_step),
+    )
+}
+
+/// Whether an empty block should be skipped under `--no-empty-blocks`.
+///
+/// `idle_secs` is the gap between the parent and the new block's timestamp. Once
+/// it reaches `max_idle`, an empty keep-alive block is sealed so the chain's
+/// clock keeps advancing.
+pub(crate) fn skips_empty_block(max_idle: Option<u64>, is_empty: bool, idle_secs: u64) -> bool {
+    is_empty && max_idle.is_some_and(|max| idle_secs < max)
+}
+
+/// Whether a builder restricted to `seal_only` (if any) produces `block_number`.
+pub(crate) fn seals_block(seal_only: Option<&HashSet<u64>>, block_number: u64) -> bool {
+    seal_only.is_none_or(|blocks| blocks.contains(&block_number))
+}
+
+/// Header nonce encoding a signer vote: `0xff..ff` to authorize, `0x00..00` to drop.
+pub(crate) const fn vote_nonce(authorize: bool) -> B64 {
+    if authorize {
+        NONCE_AUTH_VOTE
+    } else {
+        NONCE_DROP_VOTE
+    }
+}
+
+/// Set the POA fields of a built block's header before sealing: difficulty 0
+/// (Engine API compatibility), the `vote` nonce if casting one, and extra_data with
+/// `vanity`, the `epoch_signers` at epoch blocks and an empty seal.
+///
+/// Every field set here is part of the block hash, so any change to it forks
+/// nodes running different versions (see `test_canonical_block_hash_unchanged`).
+pub(crate) fn poa_header(
+    mut header: Header,
+    vanity: &[u8; EXTRA_VANITY_LENGTH],
+    epoch_signers: Option<&[Address]>,
+    vote: Option<bool>,
+) -> Header {
+    header.difficulty = U256::ZERO;
+    if let Some(authorize) = vote {
+        header.nonce = vote_nonce(authorize);
+    }
+    header.extra_data = build_extra_data(vanity, epoch_signers);
+    header
+}
+
+/// Pad or truncate `text` to a 32-byte vanity (`--block-vanity`).
+pub fn block_vanity(text: &str) -> [u8; EXTRA_VANITY_LENGTH] {
+    let mut vanity = [0u8; EXTRA_VANITY_LENGTH];
+    let len = text.len().min(EXTRA_VANITY_LENGTH);
+    vanity[..len].copy_from_slice(&text.as_bytes()[..len]);
+    vanity
+}
+
+/// Build POA extra_data: `vanity (32) + [signers N*20 at epoch] + seal placeholder (65)`.
+///
+/// The vanity is whatever the sealing signer configured via
+/// [`SignerManager::set_vanity`], else the node's `--block-vanity` (zeros by
+/// default). The seal region is left zeroed and is filled in by
+/// [`BlockSealer::seal_header`].
+pub(crate) fn build_extra_data(
+    vanity: &[u8; EXTRA_VANITY_LENGTH],
+    epoch_signers: Option<&[Address]>,
+) -> Bytes {
+    let signer_bytes = epoch_signers.map_or(0, |s| s.len() * 20);
+    let mut extra_data = Vec::with_capacity(EXTRA_VANITY_LENGTH + signer_bytes + EXTRA_SEAL_LENGTH);
+
+    extra_data.extend_from_slice(vanity);
+
+    // At epoch blocks, embed the effective (live) signer list
+    if let Some(signers) = epoch_signers {
+        for signer in signers {
+            extra_data.extend_from_slice(signer.as_slice());
+        }
+    }
+
+    // Placeholder for signature (65 bytes — will be replaced by seal_header)
+    extra_data.extend_from_slice(&[0u8; EXTRA_SEAL_LENGTH]);
+    Bytes::from(extra_data)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::chainspec::PoaChainSpec;
+    use crate::genesis::MINER_PROXY_ADDRESS;
     use crate::signer::{dev, BlockSealer};
-    use alloy_consensus::Header;
+    use alloy_consensus::{EMPTY_OMMER_ROOT_HASH, EMPTY_ROOT_HASH};
+    use alloy_eips::eip7685::EMPTY_REQUESTS_HASH;
+    use alloy_primitives::b256;
+    use reth_primitives_traits::SealedHeader;
 
     #[tokio::test]
     async fn test_poa_payload_builder_builder_creation() {
@@ -279,6 +762,59 @@ mod tests {
         assert_eq!(builder.chain_spec.signers().len(), 3);
     }
 
+    #[test]
+    fn test_seal_only_even_blocks_skips_odd() {
+        let builder = PoaPayloadBuilderBuilder::new(
+            Arc::new(PoaChainSpec::dev_chain()),
+            Arc::new(SignerManager::new()),
+            false,
+        )
+        .with_seal_only_blocks(Some((0..20).step_by(2).collect()));
+        let whitelist = builder.seal_only.as_ref();
+
+        for n in 0..20u64 {
+            assert_eq!(seals_block(whitelist, n), n % 2 == 0, "block {n}");
+        }
+        assert!(!seals_block(whitelist, 20), "outside the configured set");
+        assert!(seals_block(None, 21), "no whitelist seals everything");
+    }
+
+    #[test]
+    fn test_gas_limit_steps_toward_target() {
+        let parent = 30_000_000u64;
+        let step = parent / 1024 - 1;
+        assert_eq!(next_gas_limit(parent, 60_000_000), parent + step);
+        assert_eq!(next_gas_limit(parent, 15_000_000), parent - step);
+        // Within one step the target is hit exactly, and then held
+        assert_eq!(next_gas_limit(parent, parent + 100), parent + 100);
+        assert_eq!(next_gas_limit(parent, parent), parent);
+
+        // Reth's config clamp leaves the stepped value alone
+        let config =
+            EthereumBuilderConfig::new().with_gas_limit(next_gas_limit(parent, 60_000_000));
+        assert_eq!(config.gas_limit(parent), parent + step);
+
+        // Override is exposed on the component builder
+        let builder = PoaPayloadBuilderBuilder::new(
+            Arc::new(PoaChainSpec::dev_chain()),
+            Arc::new(SignerManager::new()),
+            false,
+        )
+        .with_gas_limit_target(Some(60_000_000));
+        assert_eq!(builder.gas_limit_target, Some(60_000_000));
+    }
+
+    #[test]
+    fn test_empty_blocks_skipped_until_idle() {
+        // Suppression off: everything is sealed
+        assert!(!skips_empty_block(None, true, 0));
+        // Within the idle window only empty blocks are skipped
+        assert!(skips_empty_block(Some(60), true, 2));
+        assert!(!skips_empty_block(Some(60), false, 2));
+        // Keep-alive once the chain has been idle long enough
+        assert!(!skips_empty_block(Some(60), true, 60));
+    }
+
     #[tokio::test]
     async fn test_sign_payload_components() {
         // Test the signing logic components work together
@@ -365,6 +901,7 @@ mod tests {
             period: 2,
             epoch: 30000,
             signers: vec![], // No signers
+            ..crate::chainspec::PoaConfig::default()
         };
         let chain = Arc::new(PoaChainSpec::new(genesis, poa_config));
 
@@ -447,6 +984,61 @@ mod tests {
         assert_eq!(extra_data.len(), 97);
     }
 
+    /// Hash of [`canonical_test_block`]. Only update it for an intentional,
+    /// coordinated change to the header format: nodes producing a different hash
+    /// for the same block fork from the network.
+    const CANONICAL_BLOCK_HASH: B256 =
+        b256!("74b6184659445a23547ae3ac301e99a177c7e57da8f7a156047bbc2d52ee227d");
+
+    /// Seal a fixed epoch block (Prague header fields, no transactions) with the
+    /// first dev signer, through the same steps as [`PoaPayloadBuilder`].
+    async fn canonical_test_block() -> SealedHeader {
+        let chain = PoaChainSpec::dev_chain();
+        let signers = chain.signers().to_vec();
+        let built = Header {
+            parent_hash: B256::repeat_byte(0x01),
+            ommers_hash: EMPTY_OMMER_ROOT_HASH,
+            beneficiary: MINER_PROXY_ADDRESS,
+            state_root: B256::repeat_byte(0x02),
+            transactions_root: EMPTY_ROOT_HASH,
+            receipts_root: EMPTY_ROOT_HASH,
+            number: 30_000,
+            gas_limit: 300_000_000,
+            timestamp: 1_700_000_000,
+            base_fee_per_gas: Some(875_000_000),
+            withdrawals_root: Some(EMPTY_ROOT_HASH),
+            blob_gas_used: Some(0),
+            excess_blob_gas: Some(0),
+            parent_beacon_block_root: Some(B256::ZERO),
+            requests_hash: Some(EMPTY_REQUESTS_HASH),
+            ..Default::default()
+        };
+        assert!(chain.is_epoch_block(built.number));
+        let header = poa_header(built, &block_vanity("meowchain"), Some(&signers), None);
+
+        let sealer = BlockSealer::new(dev::setup_dev_signers().await);
+        let signed = sealer.seal_header(header, &signers[0]).await.unwrap();
+        SealedHeader::seal_slow(signed)
+    }
+
+    #[tokio::test]
+    async fn test_canonical_block_hash_unchanged() {
+        let block = canonical_test_block().await;
+        // Deterministic: RFC 6979 signatures, no wall-clock or random inputs
+        assert_eq!(block.hash(), canonical_test_block().await.hash());
+        assert_eq!(
+            block.hash(),
+            CANONICAL_BLOCK_HASH,
+            "block hash changed: a header field or sealing step changed, which forks \
+             the network on upgrade"
+        );
+        let consensus = crate::consensus::PoaConsensus::new(Arc::new(PoaChainSpec::dev_chain()));
+        assert_eq!(
+            consensus.recover_signer(block.header()).unwrap(),
+            crate::genesis::dev_signers()[0]
+        );
+    }
+
     #[tokio::test]
     async fn test_signed_header_verifiable_by_consensus() {
         let chain = Arc::new(PoaChainSpec::dev_chain());
@@ -476,6 +1068,295 @@ mod tests {
         assert!(consensus.validate_signer(&recovered).is_ok());
     }
 
+    // ── Signer-specific vanity ─────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_signed_block_carries_signer_vanity() {
+        let chain = Arc::new(PoaChainSpec::dev_chain());
+        let manager = dev::setup_dev_signers().await;
+        let signer_addr = chain.signers()[0];
+        manager
+            .set_vanity(signer_addr, b"meow-node-eu-1")
+            .await
+            .unwrap();
+
+        let vanity = manager.vanity(&signer_addr).await;
+        let header = Header {
+            number: 1,
+            gas_limit: 30_000_000,
+            timestamp: 12345,
+            extra_data: build_extra_data(&vanity, None),
+            ..Default::default()
+        };
+
+        let sealer = BlockSealer::new(manager);
+        let signed = sealer.seal_header(header, &signer_addr).await.unwrap();
+
+        assert_eq!(&signed.extra_data[..14], b"meow-node-eu-1");
+        assert!(signed.extra_data[14..EXTRA_VANITY_LENGTH]
+            .iter()
+            .all(|b| *b == 0));
+
+        // Vanity content does not affect signer recovery
+        let consensus = crate::consensus::PoaConsensus::new(chain);
+        assert_eq!(consensus.recover_signer(&signed).unwrap(), signer_addr);
+    }
+
+    #[tokio::test]
+    async fn test_block_vanity_on_epoch_and_regular_blocks() {
+        let chain = Arc::new(PoaChainSpec::dev_chain());
+        let manager = dev::setup_dev_signers().await;
+        let signers = chain.signers().to_vec();
+        let configured = block_vanity("meowchain/v0.1.0");
+        assert_eq!(&configured[..16], b"meowchain/v0.1.0");
+        assert!(configured[16..].iter().all(|b| *b == 0));
+        // Longer text is truncated to 32 bytes
+        assert_eq!(&block_vanity(&"x".repeat(40)), &[b'x'; EXTRA_VANITY_LENGTH]);
+
+        let consensus = crate::consensus::PoaConsensus::new(chain.clone());
+        let sealer = BlockSealer::new(manager.clone());
+        for (number, epoch_signers) in [(1, None), (chain.epoch(), Some(signers.as_slice()))] {
+            let signer = signers[(number % signers.len() as u64) as usize];
+            let vanity = manager.vanity_or(&signer, configured).await;
+            let header = Header {
+                number,
+                gas_limit: 30_000_000,
+                timestamp: 12345,
+                extra_data: build_extra_data(&vanity, epoch_signers),
+                ..Default::default()
+            };
+            let signed = sealer.seal_header(header, &signer).await.unwrap();
+            assert_eq!(&signed.extra_data[..EXTRA_VANITY_LENGTH], &configured);
+            assert_eq!(consensus.recover_signer(&signed).unwrap(), signer);
+        }
+
+        // A signer's own vanity takes precedence
+        manager.set_vanity(signers[0], b"node-eu-1").await.unwrap();
+        let vanity = manager.vanity_or(&signers[0], configured).await;
+        assert_eq!(&vanity[..9], b"node-eu-1");
+        assert_eq!(manager.vanity_or(&signers[1], configured).await, configured);
+    }
+
+    #[test]
+    fn test_build_extra_data_epoch_layout() {
+        let chain = PoaChainSpec::dev_chain();
+        let signers = chain.signers();
+        let vanity = [7u8; EXTRA_VANITY_LENGTH];
+
+        let extra = build_extra_data(&vanity, Some(signers));
+        assert_eq!(
+            extra.len(),
+            EXTRA_VANITY_LENGTH + 3 * 20 + EXTRA_SEAL_LENGTH
+        );
+        assert_eq!(&extra[..EXTRA_VANITY_LENGTH], &vanity);
+        assert_eq!(
+            &extra[EXTRA_VANITY_LENGTH..EXTRA_VANITY_LENGTH + 20],
+            signers[0].as_slice()
+        );
+    }
+
+    // ── Out-of-turn wiggle ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_out_of_turn_wiggle_bounded_and_deterministic() {
+        let chain = PoaChainSpec::dev_chain();
+        let signer = chain.signers()[0];
+        // 3 signers → max = (3/2 + 1) * 500 = 1000ms
+        for block in 1..50 {
+            let w = out_of_turn_wiggle(&signer, block, 3, 500);
+            assert!(w < Duration::from_millis(1000));
+            assert_eq!(w, out_of_turn_wiggle(&signer, block, 3, 500));
+        }
+    }
+
+    #[test]
+    fn test_out_of_turn_wiggle_differs_per_signer() {
+        let chain = PoaChainSpec::dev_chain();
+        let signers = chain.signers();
+        let a: Vec<_> = (1..20)
+            .map(|b| out_of_turn_wiggle(&signers[0], b, 3, 500))
+            .collect();
+        let b: Vec<_> = (1..20)
+            .map(|b| out_of_turn_wiggle(&signers[1], b, 3, 500))
+            .collect();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_out_of_turn_wiggle_disabled() {
+        let signer = Address::with_last_byte(1);
+        assert_eq!(out_of_turn_wiggle(&signer, 7, 3, 0), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_wiggle_deadline_set_once_per_parent() {
+        let deadline = WiggleDeadline::default();
+        let wiggle = Duration::from_millis(200);
+        let parent = B256::with_last_byte(1);
+
+        assert!(deadline.remaining(parent, wiggle) <= wiggle);
+        std::thread::sleep(Duration::from_millis(50));
+        // A rebuild on the same parent only waits for what is left
+        assert!(deadline.remaining(parent, wiggle) <= Duration::from_millis(150));
+
+        // A new parent starts a fresh wiggle
+        assert!(deadline.remaining(B256::with_last_byte(2), wiggle) > Duration::from_millis(150));
+    }
+
+    #[tokio::test]
+    async fn test_payload_builder_builder_wiggle_ms() {
+        let chain = Arc::new(PoaChainSpec::dev_chain());
+        let manager = Arc::new(SignerManager::new());
+        let builder = PoaPayloadBuilderBuilder::new(chain.clone(), manager.clone(), false);
+        assert_eq!(builder.wiggle_ms, crate::constants::DEFAULT_WIGGLE_MS);
+        let builder = PoaPayloadBuilderBuilder::new(chain, manager, false).with_wiggle_ms(0);
+        assert_eq!(builder.wiggle_ms, 0);
+    }
+
+    #[tokio::test]
+    async fn test_payload_builder_builder_fallback_policy() {
+        let chain = Arc::new(PoaChainSpec::dev_chain());
+        let manager = Arc::new(SignerManager::new());
+        let builder = PoaPayloadBuilderBuilder::new(chain.clone(), manager.clone(), false);
+        assert_eq!(builder.fallback_policy, FallbackPolicy::Any);
+        let builder = PoaPayloadBuilderBuilder::new(chain, manager, false)
+            .with_fallback_policy(FallbackPolicy::Next);
+        assert_eq!(builder.fallback_policy, FallbackPolicy::Next);
+    }
+
+    #[tokio::test]
+    async fn test_payload_builder_builder_safe_mode() {
+        let chain = Arc::new(PoaChainSpec::dev_chain());
+        let manager = Arc::new(SignerManager::new());
+        let builder = PoaPayloadBuilderBuilder::new(chain.clone(), manager.clone(), false);
+        assert!(builder.safe_mode.is_none());
+        let liveness = SignerLiveness::shared();
+        let builder = PoaPayloadBuilderBuilder::new(chain, manager, false)
+            .with_safe_mode(Some(liveness.clone()));
+        assert!(builder
+            .safe_mode
+            .is_some_and(|shared| Arc::ptr_eq(&shared, &liveness)));
+    }
+
+    // Sealing waits through `block_in_place`, which needs the multi-threaded runtime
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_no_sealed_payload_after_reorg_halt() {
+        use alloy_rpc_types_engine::PayloadAttributes;
+        use reth_ethereum::provider::providers::BlockchainProvider;
+        use reth_ethereum::provider::test_utils::create_test_provider_factory_with_chain_spec;
+        use reth_evm_ethereum::EthEvmConfig;
+        use reth_transaction_pool::test_utils::testing_pool;
+
+        let chain = Arc::new(PoaChainSpec::dev_chain());
+        let factory = create_test_provider_factory_with_chain_spec(chain.inner().clone());
+        reth_db_common::init::init_genesis(&factory).unwrap();
+        let client = BlockchainProvider::new(factory).unwrap();
+        let pool = testing_pool();
+        let evm_config = EthEvmConfig::new(chain.inner().clone());
+        let extra_data = vec![0u8; EXTRA_VANITY_LENGTH + EXTRA_SEAL_LENGTH];
+        let builder_config = EthereumBuilderConfig::new().with_extra_data(extra_data.into());
+        let guard = ReorgGuard::shared(8, true);
+        let builder = PoaPayloadBuilder {
+            inner: reth_ethereum_payload_builder::EthereumPayloadBuilder::new(
+                client.clone(),
+                pool.clone(),
+                evm_config.clone(),
+                builder_config.clone(),
+            ),
+            pool,
+            evm_config,
+            builder_config,
+            chain_spec: chain.clone(),
+            signer_manager: dev::setup_dev_signers().await,
+            dev_mode: false,
+            client,
+            cache: Arc::new(std::sync::Mutex::new(crate::cache::HotStateCache::new(16))),
+            wiggle_ms: 0,
+            wiggle_deadline: WiggleDeadline::default(),
+            verify_storage_reads: false,
+            seal_only: None,
+            gas_limit_target: None,
+            max_transactions: None,
+            max_idle: None,
+            block_timings: None,
+            fallback_policy: FallbackPolicy::Any,
+            safe_mode: None,
+            reorg_guard: Some(guard.clone()),
+            block_vanity: [0u8; EXTRA_VANITY_LENGTH],
+            tx_ordering: TransactionOrdering::PriorityFee,
+        };
+
+        let parent = Arc::new(chain.inner().sealed_genesis_header());
+        let attributes = EthPayloadBuilderAttributes::new(
+            parent.hash(),
+            PayloadAttributes {
+                timestamp: parent.timestamp + chain.block_period(),
+                prev_randao: B256::ZERO,
+                suggested_fee_recipient: Address::ZERO,
+                withdrawals: Some(vec![]),
+                parent_beacon_block_root: Some(B256::ZERO),
+            },
+        );
+        let config = || PayloadConfig::new(parent.clone(), attributes.clone());
+
+        // Block 1 is sealed by its in-turn dev signer
+        let payload = builder.build_empty_payload(config()).unwrap();
+        let consensus = PoaConsensus::new(chain.clone());
+        assert_eq!(
+            consensus.recover_signer(payload.block().header()).unwrap(),
+            chain.expected_signer(1).unwrap()
+        );
+
+        // A reorg past --max-reorg-depth: nothing is built or sealed any more
+        assert!(guard.check(9));
+        assert!(matches!(
+            builder.build_empty_payload(config()),
+            Err(PayloadBuilderError::MissingPayload)
+        ));
+        let outcome = builder
+            .try_build(BuildArguments::new(
+                Default::default(),
+                config(),
+                Default::default(),
+                None,
+            ))
+            .unwrap();
+        assert!(matches!(outcome, BuildOutcome::Cancelled));
+    }
+
+    // ── Signer voting (clique_propose) ─────────────────────────────────────
+
+    #[test]
+    fn test_vote_nonce_encoding() {
+        assert_eq!(vote_nonce(true), B64::new([0xff; 8]));
+        assert_eq!(vote_nonce(false), B64::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_pending_vote_skips_noop_proposals() {
+        let chain = PoaChainSpec::dev_chain();
+        let signers = chain.signers();
+        let manager = SignerManager::new();
+        let candidate = Address::with_last_byte(0x42);
+
+        // Authorizing an existing signer is a no-op and must not be cast
+        manager.propose(signers[0], true);
+        assert_eq!(manager.pending_vote(signers), None);
+
+        manager.propose(candidate, true);
+        assert_eq!(manager.pending_vote(signers), Some((candidate, true)));
+
+        // Once the candidate is in the signer set, the vote stops being cast
+        let mut with_candidate = signers.to_vec();
+        with_candidate.push(candidate);
+        assert_eq!(manager.pending_vote(&with_candidate), None);
+
+        // Removing an existing signer is a real vote
+        manager.discard(&candidate);
+        manager.propose(signers[1], false);
+        assert_eq!(manager.pending_vote(signers), Some((signers[1], false)));
+    }
+
     // ── Phase 5.31: shared hot state cache wiring ──────────────────────────
 
     #[tokio::test]
@@ -0,0 +1,284 @@
+//! Transaction ordering override for block building.
+//!
+//! The pool hands the payload builder its pending transactions by priority fee.
+//! To reproduce ordering-dependent bugs deterministically, `--tx-ordering` can
+//! replace that order: [`TransactionOrdering::Fifo`] includes transactions in the
+//! order they arrived at the pool, [`TransactionOrdering::SenderNonce`] includes each
+//! sender's transactions back to back, senders sorted by address.
+//!
+//! Either way each sender's transactions keep their nonce order. The override has
+//! to see every pending transaction before yielding the first one, so it is meant
+//! for testing rather than for busy networks.
+
+use alloy_primitives::Address;
+use reth_transaction_pool::error::InvalidPoolTransactionError;
+use reth_transaction_pool::{BestTransactions, PoolTransaction, ValidPoolTransaction};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap, VecDeque};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Order in which the payload builder includes pending transactions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TransactionOrdering {
+    /// Highest priority fee first (the pool's own order).
+    #[default]
+    PriorityFee,
+    /// First in, first out, by arrival at the pool.
+    Fifo,
+    /// All transactions of one sender together, senders sorted by address.
+    SenderNonce,
+}
+
+impl FromStr for TransactionOrdering {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "priority" => Ok(Self::PriorityFee),
+            "fifo" => Ok(Self::Fifo),
+            "sender" => Ok(Self::SenderNonce),
+            other => Err(format!(
+                "unknown transaction ordering '{other}' (expected priority, fifo or sender)"
+            )),
+        }
+    }
+}
+
+/// The transaction fields needed to reorder pending transactions.
+pub trait OrderingCandidate {
+    /// Sender of the transaction.
+    fn sender(&self) -> Address;
+    /// Sender nonce.
+    fn nonce(&self) -> u64;
+    /// When the transaction entered the pool.
+    fn arrival(&self) -> Instant;
+}
+
+impl<T: PoolTransaction> OrderingCandidate for Arc<ValidPoolTransaction<T>> {
+    fn sender(&self) -> Address {
+        ValidPoolTransaction::sender(self)
+    }
+
+    fn nonce(&self) -> u64 {
+        ValidPoolTransaction::nonce(self)
+    }
+
+    fn arrival(&self) -> Instant {
+        self.timestamp
+    }
+}
+
+/// Reorder `txs` (in the pool's fee order) according to `ordering`.
+pub fn order_transactions<T: OrderingCandidate>(
+    ordering: TransactionOrdering,
+    txs: Vec<T>,
+) -> Vec<T> {
+    match ordering {
+        TransactionOrdering::PriorityFee => txs,
+        TransactionOrdering::SenderNonce => by_sender(txs).into_values().flatten().collect(),
+        TransactionOrdering::Fifo => {
+            // Merge the per-sender queues by the arrival of each queue's next
+            // transaction, so a later nonce never overtakes an earlier one.
+            let mut queues: Vec<VecDeque<T>> =
+                by_sender(txs).into_values().map(VecDeque::from).collect();
+            let mut heads: BinaryHeap<_> = queues
+                .iter()
+                .enumerate()
+                .map(|(index, queue)| Reverse((queue[0].arrival(), index)))
+                .collect();
+            let mut ordered = Vec::new();
+            while let Some(Reverse((_, index))) = heads.pop() {
+                let queue = &mut queues[index];
+                ordered.extend(queue.pop_front());
+                if let Some(next) = queue.front() {
+                    heads.push(Reverse((next.arrival(), index)));
+                }
+            }
+            ordered
+        }
+    }
+}
+
+/// Each sender's transactions in nonce order, senders sorted by address.
+fn by_sender<T: OrderingCandidate>(txs: Vec<T>) -> BTreeMap<Address, Vec<T>> {
+    let mut by_sender: BTreeMap<Address, Vec<T>> = BTreeMap::new();
+    for tx in txs {
+        by_sender.entry(tx.sender()).or_default().push(tx);
+    }
+    for queue in by_sender.values_mut() {
+        queue.sort_by_key(|tx| tx.nonce());
+    }
+    by_sender
+}
+
+/// Best-transactions iterator yielding `inner`'s transactions in another order.
+#[derive(Debug)]
+pub struct Reordered<I: Iterator> {
+    inner: I,
+    ordering: TransactionOrdering,
+    /// Reordered transactions, filled from `inner` on the first call to `next`.
+    ready: Option<VecDeque<I::Item>>,
+}
+
+impl<I: Iterator> Reordered<I>
+where
+    I::Item: OrderingCandidate,
+{
+    /// Wrap a best-transactions iterator.
+    pub fn new(inner: I, ordering: TransactionOrdering) -> Self {
+        Self {
+            inner,
+            ordering,
+            ready: None,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Reordered<I>
+where
+    I::Item: OrderingCandidate,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ordering = self.ordering;
+        let inner = &mut self.inner;
+        self.ready
+            .get_or_insert_with(|| order_transactions(ordering, inner.collect()).into())
+            .pop_front()
+    }
+}
+
+impl<I: BestTransactions> BestTransactions for Reordered<I>
+where
+    I::Item: OrderingCandidate + Send,
+{
+    fn mark_invalid(&mut self, transaction: &Self::Item, kind: &InvalidPoolTransactionError) {
+        // Buffered transactions from the same sender depend on the invalid one.
+        let sender = transaction.sender();
+        if let Some(ready) = self.ready.as_mut() {
+            ready.retain(|tx| tx.sender() != sender);
+        }
+        self.inner.mark_invalid(transaction, kind);
+    }
+
+    fn no_updates(&mut self) {
+        self.inner.no_updates();
+    }
+
+    fn set_skip_blobs(&mut self, skip_blobs: bool) {
+        self.inner.set_skip_blobs(skip_blobs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_transaction_pool::test_utils::{testing_pool, MockTransaction};
+    use reth_transaction_pool::{TransactionOrigin, TransactionPool};
+    use std::time::Duration;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct MockTx {
+        sender: Address,
+        nonce: u64,
+        arrival: Instant,
+    }
+
+    impl OrderingCandidate for MockTx {
+        fn sender(&self) -> Address {
+            self.sender
+        }
+        fn nonce(&self) -> u64 {
+            self.nonce
+        }
+        fn arrival(&self) -> Instant {
+            self.arrival
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fifo_and_priority_include_in_different_order() {
+        const GWEI: u128 = 1_000_000_000;
+        let pool = testing_pool();
+        // Cheapest first: FIFO keeps this order, priority fee reverses it
+        let mut hashes = Vec::new();
+        for tip in 1..=3 {
+            let tx = MockTransaction::eip1559()
+                .with_max_fee(100 * GWEI)
+                .with_priority_fee(tip * GWEI);
+            hashes.push(*tx.hash());
+            pool.add_transaction(TransactionOrigin::External, tx)
+                .await
+                .unwrap();
+            std::thread::sleep(Duration::from_millis(2));
+        }
+
+        let by_fee: Vec<_> = pool.best_transactions().map(|tx| *tx.hash()).collect();
+        let fifo: Vec<_> = Reordered::new(pool.best_transactions(), TransactionOrdering::Fifo)
+            .map(|tx| *tx.hash())
+            .collect();
+        assert_eq!(fifo, hashes);
+        assert_eq!(by_fee, hashes.iter().rev().copied().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_fifo_keeps_nonce_order() {
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+        let (a, b) = (Address::with_last_byte(0xa), Address::with_last_byte(0xb));
+        // A's nonce 1 arrived before its nonce 0 (e.g. a resubmission)
+        let a1 = MockTx {
+            sender: a,
+            nonce: 1,
+            arrival: at(0),
+        };
+        let b0 = MockTx {
+            sender: b,
+            nonce: 0,
+            arrival: at(1),
+        };
+        let a0 = MockTx {
+            sender: a,
+            nonce: 0,
+            arrival: at(2),
+        };
+        let ordered = order_transactions(
+            TransactionOrdering::Fifo,
+            vec![a0.clone(), a1.clone(), b0.clone()],
+        );
+        assert_eq!(ordered, vec![b0, a0, a1]);
+    }
+
+    #[test]
+    fn test_sender_nonce_groups_by_sender() {
+        let now = Instant::now();
+        let tx = |sender: u8, nonce| MockTx {
+            sender: Address::with_last_byte(sender),
+            nonce,
+            arrival: now,
+        };
+        let ordered = order_transactions(
+            TransactionOrdering::SenderNonce,
+            vec![tx(2, 0), tx(1, 0), tx(2, 1), tx(1, 1)],
+        );
+        assert_eq!(ordered, vec![tx(1, 0), tx(1, 1), tx(2, 0), tx(2, 1)]);
+
+        // Priority fee leaves the pool's order alone
+        let input = vec![tx(2, 0), tx(1, 0)];
+        assert_eq!(
+            order_transactions(TransactionOrdering::PriorityFee, input.clone()),
+            input
+        );
+    }
+
+    #[test]
+    fn test_ordering_from_str() {
+        assert_eq!("priority".parse(), Ok(TransactionOrdering::PriorityFee));
+        assert_eq!("fifo".parse(), Ok(TransactionOrdering::Fifo));
+        assert_eq!("sender".parse(), Ok(TransactionOrdering::SenderNonce));
+        assert!("random".parse::<TransactionOrdering>().is_err());
+    }
+}
@@ -0,0 +1,211 @@
+//! Governance freeze while the Timelock is paused.
+//!
+//! Pausing the Timelock is meant to stop governance changes, but calls to the
+//! governance contracts, straight or through the governance Safe, would still go
+//! through. The rule is enforced at execution: `PoaEvm` rejects, as an invalid
+//! transaction, any transaction that changes the storage of `ChainConfig`,
+//! `SignerRegistry` or the Treasury while the Timelock is paused
+//! ([`changes_paused_governance`]). Block import runs the same EVM, so a block
+//! sealed with such a transaction is rejected by every node.
+//!
+//! The payload builder also wraps the pool's best-transactions iterator in
+//! [`PausedGovernance`], which, when the parent state has the Timelock paused,
+//! leaves out transactions calling those contracts directly before executing them.
+//! The sender's later transactions are left out too, since they depend on the
+//! skipped nonce. Everything left out stays in the pool and is included once the
+//! Timelock is unpaused; other transactions flow as usual.
+
+use crate::genesis::addresses::{CHAIN_CONFIG_ADDRESS, SIGNER_REGISTRY_ADDRESS, TREASURY_ADDRESS};
+use alloy_evm::revm::state::EvmState;
+use alloy_primitives::Address;
+use reth_transaction_pool::error::InvalidPoolTransactionError;
+use reth_transaction_pool::BestTransactions;
+use std::collections::HashSet;
+
+use super::priority::PriorityCandidate;
+
+/// Contracts that don't accept transactions while the Timelock is paused.
+pub const PAUSABLE_ADDRESSES: [Address; 3] = [
+    CHAIN_CONFIG_ADDRESS,
+    SIGNER_REGISTRY_ADDRESS,
+    TREASURY_ADDRESS,
+];
+
+/// Rejection reason for a transaction that changes governance state while the
+/// Timelock is paused.
+pub const PAUSED_GOVERNANCE_ERROR: &str =
+    "governance contracts are frozen while the Timelock is paused";
+
+/// Whether a transaction with recipient `to` is held back while the Timelock is paused.
+pub fn is_paused_target(to: Option<Address>) -> bool {
+    to.is_some_and(|to| PAUSABLE_ADDRESSES.contains(&to))
+}
+
+/// Whether the state changes of an executed transaction write to the storage of
+/// one of the [`PAUSABLE_ADDRESSES`], whichever frame made the call.
+pub fn changes_paused_governance(state: &EvmState) -> bool {
+    PAUSABLE_ADDRESSES.iter().any(|address| {
+        state
+            .get(address)
+            .is_some_and(|account| account.storage.values().any(|slot| slot.is_changed()))
+    })
+}
+
+/// Best-transactions iterator that, while `paused`, skips transactions calling
+/// [`PAUSABLE_ADDRESSES`] and every later transaction from their senders.
+#[derive(Debug)]
+pub struct PausedGovernance<I> {
+    inner: I,
+    /// Whether the Timelock is paused in the parent state.
+    paused: bool,
+    /// Senders with a held-back transaction.
+    frozen_senders: HashSet<Address>,
+}
+
+impl<I> PausedGovernance<I> {
+    /// Wrap a best-transactions iterator; it passes everything through unless `paused`.
+    pub fn new(inner: I, paused: bool) -> Self {
+        Self {
+            inner,
+            paused,
+            frozen_senders: HashSet::new(),
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for PausedGovernance<I>
+where
+    I::Item: PriorityCandidate,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let tx = self.inner.next()?;
+            if !self.paused {
+                return Some(tx);
+            }
+            if self.frozen_senders.contains(&tx.sender()) {
+                continue;
+            }
+            if is_paused_target(tx.to()) {
+                self.frozen_senders.insert(tx.sender());
+                continue;
+            }
+            return Some(tx);
+        }
+    }
+}
+
+impl<I: BestTransactions> BestTransactions for PausedGovernance<I>
+where
+    I::Item: PriorityCandidate + Send,
+{
+    fn mark_invalid(&mut self, transaction: &Self::Item, kind: &InvalidPoolTransactionError) {
+        self.inner.mark_invalid(transaction, kind);
+    }
+
+    fn no_updates(&mut self) {
+        self.inner.no_updates();
+    }
+
+    fn set_skip_blobs(&mut self, skip_blobs: bool) {
+        self.inner.set_skip_blobs(skip_blobs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genesis::addresses::TIMELOCK_ADDRESS;
+    use crate::onchain::function_selector;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct MockTx {
+        sender: Address,
+        to: Option<Address>,
+        input: Vec<u8>,
+    }
+
+    impl PriorityCandidate for MockTx {
+        fn sender(&self) -> Address {
+            self.sender
+        }
+        fn to(&self) -> Option<Address> {
+            self.to
+        }
+        fn priority_fee(&self) -> u128 {
+            0
+        }
+    }
+
+    fn tx(sender: u8, to: Address) -> MockTx {
+        MockTx {
+            sender: Address::with_last_byte(sender),
+            to: Some(to),
+            input: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_set_gas_limit_held_back_while_paused() {
+        let mut set_gas_limit = tx(1, CHAIN_CONFIG_ADDRESS);
+        set_gas_limit.input = function_selector("setGasLimit(uint256)").to_vec();
+        let after = tx(1, Address::with_last_byte(0xee));
+        let ordinary = tx(2, Address::with_last_byte(0xee));
+        let timelock = tx(3, TIMELOCK_ADDRESS);
+        let pending = vec![
+            set_gas_limit.clone(),
+            after.clone(),
+            ordinary.clone(),
+            timelock.clone(),
+        ];
+
+        // Paused: the setGasLimit call and its sender's next transaction wait
+        let block: Vec<_> = PausedGovernance::new(pending.clone().into_iter(), true).collect();
+        assert_eq!(block, vec![ordinary, timelock]);
+
+        // Unpaused: everything is included, in order
+        let block: Vec<_> = PausedGovernance::new(pending.clone().into_iter(), false).collect();
+        assert_eq!(block, pending);
+    }
+
+    #[test]
+    fn test_changes_paused_governance_only_on_storage_writes() {
+        use alloy_evm::revm::state::{Account, EvmStorageSlot};
+        use alloy_primitives::U256;
+
+        let account = |written: bool| {
+            let mut account = Account::default();
+            let present = if written {
+                U256::from(2)
+            } else {
+                U256::from(1)
+            };
+            account.storage.insert(
+                U256::ZERO,
+                EvmStorageSlot::new_changed(U256::from(1), present, 0),
+            );
+            account
+        };
+        let mut state = EvmState::default();
+        // Read (or written back unchanged) by a view call, or any other contract written
+        state.insert(CHAIN_CONFIG_ADDRESS, account(false));
+        state.insert(Address::with_last_byte(0xee), account(true));
+        assert!(!changes_paused_governance(&state));
+
+        // Written through an internal call, e.g. from the governance Safe
+        state.insert(SIGNER_REGISTRY_ADDRESS, account(true));
+        assert!(changes_paused_governance(&state));
+    }
+
+    #[test]
+    fn test_is_paused_target() {
+        for addr in PAUSABLE_ADDRESSES {
+            assert!(is_paused_target(Some(addr)));
+        }
+        // The Timelock itself must stay reachable to be unpaused
+        assert!(!is_paused_target(Some(TIMELOCK_ADDRESS)));
+        assert!(!is_paused_target(None));
+    }
+}
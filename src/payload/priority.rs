@@ -0,0 +1,239 @@
+//! Governance transaction priority for block building.
+//!
+//! Transactions calling the governance contracts (`ChainConfig`, `SignerRegistry`,
+//! `Timelock`, the governance Safe) must not be starved by spam. With
+//! `PoaConfig.prioritize_governance_txs` enabled, the payload builder wraps the
+//! pool's best-transactions iterator in [`GovernanceFirst`], which moves
+//! governance-targeted transactions ahead of ordinary ones paying the same fee.
+//!
+//! Fee order between different fee levels is left untouched, and each sender's
+//! transactions keep their nonce order.
+
+use crate::genesis::addresses::{
+    CHAIN_CONFIG_ADDRESS, GOVERNANCE_SAFE_ADDRESS, SIGNER_REGISTRY_ADDRESS, TIMELOCK_ADDRESS,
+};
+use alloy_consensus::Transaction;
+use alloy_primitives::Address;
+use reth_transaction_pool::error::InvalidPoolTransactionError;
+use reth_transaction_pool::{BestTransactions, PoolTransaction, ValidPoolTransaction};
+use std::collections::{HashSet, VecDeque};
+use std::sync::Arc;
+
+/// Contracts whose callers get priority at equal fee.
+pub const GOVERNANCE_ADDRESSES: [Address; 4] = [
+    CHAIN_CONFIG_ADDRESS,
+    SIGNER_REGISTRY_ADDRESS,
+    TIMELOCK_ADDRESS,
+    GOVERNANCE_SAFE_ADDRESS,
+];
+
+/// Whether a transaction with recipient `to` targets a governance contract.
+pub fn is_governance_target(to: Option<Address>) -> bool {
+    to.is_some_and(|to| GOVERNANCE_ADDRESSES.contains(&to))
+}
+
+/// The transaction fields needed to decide governance priority.
+pub trait PriorityCandidate {
+    /// Sender of the transaction.
+    fn sender(&self) -> Address;
+    /// Recipient, `None` for contract creation.
+    fn to(&self) -> Option<Address>;
+    /// Priority fee (or gas price for legacy transactions).
+    fn priority_fee(&self) -> u128;
+}
+
+impl<T: PoolTransaction> PriorityCandidate for Arc<ValidPoolTransaction<T>> {
+    fn sender(&self) -> Address {
+        ValidPoolTransaction::sender(self)
+    }
+
+    fn to(&self) -> Option<Address> {
+        self.transaction.to()
+    }
+
+    fn priority_fee(&self) -> u128 {
+        self.transaction.priority_fee_or_price()
+    }
+}
+
+/// Reorder a run of equal-fee transactions so governance-targeted ones come first.
+///
+/// A governance transaction is only moved ahead if every earlier transaction from
+/// the same sender is moved too, so per-sender nonce order is preserved.
+pub fn governance_first<T: PriorityCandidate>(run: Vec<T>) -> Vec<T> {
+    let mut promoted = Vec::with_capacity(run.len());
+    let mut rest = Vec::new();
+    let mut held_back = HashSet::new();
+    for tx in run {
+        if is_governance_target(tx.to()) && !held_back.contains(&tx.sender()) {
+            promoted.push(tx);
+        } else {
+            held_back.insert(tx.sender());
+            rest.push(tx);
+        }
+    }
+    promoted.extend(rest);
+    promoted
+}
+
+/// Best-transactions iterator that yields governance transactions first within
+/// each run of equal-fee transactions from `inner`.
+#[derive(Debug)]
+pub struct GovernanceFirst<I: Iterator> {
+    inner: I,
+    /// Reordered transactions of the current fee run.
+    ready: VecDeque<I::Item>,
+    /// First transaction of the next fee run, pulled while finding the end of this one.
+    lookahead: Option<I::Item>,
+}
+
+impl<I: Iterator> GovernanceFirst<I>
+where
+    I::Item: PriorityCandidate,
+{
+    /// Wrap a best-transactions iterator.
+    pub fn new(inner: I) -> Self {
+        Self {
+            inner,
+            ready: VecDeque::new(),
+            lookahead: None,
+        }
+    }
+
+    /// Pull the next run of equal-fee transactions and reorder it.
+    fn fill_run(&mut self) {
+        let Some(first) = self.lookahead.take().or_else(|| self.inner.next()) else {
+            return;
+        };
+        let fee = first.priority_fee();
+        let mut run = vec![first];
+        loop {
+            match self.inner.next() {
+                Some(tx) if tx.priority_fee() == fee => run.push(tx),
+                next => {
+                    self.lookahead = next;
+                    break;
+                }
+            }
+        }
+        self.ready = governance_first(run).into();
+    }
+}
+
+impl<I: Iterator> Iterator for GovernanceFirst<I>
+where
+    I::Item: PriorityCandidate,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ready.is_empty() {
+            self.fill_run();
+        }
+        self.ready.pop_front()
+    }
+}
+
+impl<I: BestTransactions> BestTransactions for GovernanceFirst<I>
+where
+    I::Item: PriorityCandidate + Send,
+{
+    fn mark_invalid(&mut self, transaction: &Self::Item, kind: &InvalidPoolTransactionError) {
+        // Buffered transactions from the same sender depend on the invalid one.
+        let sender = transaction.sender();
+        self.ready.retain(|tx| tx.sender() != sender);
+        if self
+            .lookahead
+            .as_ref()
+            .is_some_and(|tx| tx.sender() == sender)
+        {
+            self.lookahead = None;
+        }
+        self.inner.mark_invalid(transaction, kind);
+    }
+
+    fn no_updates(&mut self) {
+        self.inner.no_updates();
+    }
+
+    fn set_skip_blobs(&mut self, skip_blobs: bool) {
+        self.inner.set_skip_blobs(skip_blobs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct MockTx {
+        sender: Address,
+        to: Option<Address>,
+        fee: u128,
+    }
+
+    impl PriorityCandidate for MockTx {
+        fn sender(&self) -> Address {
+            self.sender
+        }
+        fn to(&self) -> Option<Address> {
+            self.to
+        }
+        fn priority_fee(&self) -> u128 {
+            self.fee
+        }
+    }
+
+    fn tx(sender: u8, to: Address, fee: u128) -> MockTx {
+        MockTx {
+            sender: Address::with_last_byte(sender),
+            to: Some(to),
+            fee,
+        }
+    }
+
+    fn ordinary() -> Address {
+        Address::with_last_byte(0xee)
+    }
+
+    #[test]
+    fn test_governance_tx_ahead_of_equal_fee_ordinary() {
+        let spam = tx(1, ordinary(), 10);
+        let gov = tx(2, CHAIN_CONFIG_ADDRESS, 10);
+        let ordered: Vec<_> =
+            GovernanceFirst::new(vec![spam.clone(), gov.clone()].into_iter()).collect();
+        assert_eq!(ordered, vec![gov, spam]);
+    }
+
+    #[test]
+    fn test_higher_fee_still_wins() {
+        let rich = tx(1, ordinary(), 20);
+        let gov = tx(2, SIGNER_REGISTRY_ADDRESS, 10);
+        let ordered: Vec<_> =
+            GovernanceFirst::new(vec![rich.clone(), gov.clone()].into_iter()).collect();
+        assert_eq!(ordered, vec![rich, gov]);
+    }
+
+    #[test]
+    fn test_sender_nonce_order_preserved() {
+        // Sender 1's ordinary tx (lower nonce) precedes its governance tx
+        let first = tx(1, ordinary(), 10);
+        let gov_same_sender = tx(1, TIMELOCK_ADDRESS, 10);
+        let gov_other = tx(2, GOVERNANCE_SAFE_ADDRESS, 10);
+        let ordered = governance_first(vec![
+            first.clone(),
+            gov_same_sender.clone(),
+            gov_other.clone(),
+        ]);
+        assert_eq!(ordered, vec![gov_other, first, gov_same_sender]);
+    }
+
+    #[test]
+    fn test_is_governance_target() {
+        for addr in GOVERNANCE_ADDRESSES {
+            assert!(is_governance_target(Some(addr)));
+        }
+        assert!(!is_governance_target(Some(ordinary())));
+        assert!(!is_governance_target(None));
+    }
+}
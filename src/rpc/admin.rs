@@ -7,6 +7,9 @@ use crate::chainspec::PoaChainSpec;
 use crate::signer::SignerManager;
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use reth_chainspec::EthChainSpec;
+use reth_ethereum::network::api::{PeerInfo, PeerKind, Peers};
+use std::collections::HashMap;
+use std::fmt;
 use std::sync::Arc;
 use std::time::Instant;
 use tokio::sync::RwLock;
@@ -20,7 +23,7 @@ pub trait AdminApi {
     #[method(name = "nodeInfo")]
     async fn node_info(&self) -> RpcResult<AdminNodeInfo>;
 
-    /// Returns a list of connected peers.
+    /// Returns the connected peers (geth `admin_peers` shape).
     #[method(name = "peers")]
     async fn peers(&self) -> RpcResult<Vec<AdminPeerInfo>>;
 
@@ -37,6 +40,56 @@ pub trait AdminApi {
     async fn health(&self) -> RpcResult<HealthStatus>;
 }
 
+/// Live view of the peers connected to the node's network.
+#[async_trait::async_trait]
+pub trait PeerSource: Send + Sync + fmt::Debug {
+    /// Currently connected peers.
+    async fn connected_peers(&self) -> Vec<AdminPeerInfo>;
+}
+
+#[async_trait::async_trait]
+impl<T: Peers + fmt::Debug> PeerSource for T {
+    async fn connected_peers(&self) -> Vec<AdminPeerInfo> {
+        self.get_all_peers()
+            .await
+            .map(|peers| peers.iter().map(admin_peer_info).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Convert Reth's session info to the geth `admin_peers` entry.
+pub fn admin_peer_info(info: &PeerInfo) -> AdminPeerInfo {
+    let caps = info.capabilities.capabilities();
+    AdminPeerInfo {
+        enode: info.enode.clone(),
+        enr: info.enr.clone(),
+        id: format!("{:x}", info.remote_id),
+        name: info.client_version.to_string(),
+        caps: caps.iter().map(ToString::to_string).collect(),
+        network: AdminPeerNetwork {
+            local_address: info
+                .local_addr
+                .map(|addr| addr.to_string())
+                .unwrap_or_default(),
+            remote_address: info.remote_addr.to_string(),
+            inbound: info.direction.is_incoming(),
+            trusted: matches!(info.kind, PeerKind::Trusted),
+            static_node: matches!(info.kind, PeerKind::Static),
+        },
+        protocols: caps
+            .iter()
+            .map(|cap| {
+                (
+                    cap.name.to_string(),
+                    AdminPeerProtocol {
+                        version: cap.version,
+                    },
+                )
+            })
+            .collect(),
+    }
+}
+
 /// Tracks locally managed peer state for the admin namespace.
 #[derive(Debug)]
 struct PeerState {
@@ -65,6 +118,8 @@ pub struct AdminRpc {
     p2p_port: u16,
     /// Locally tracked peer state.
     peer_state: RwLock<PeerState>,
+    /// The node's network, for live peer info. Falls back to `peer_state` when unset.
+    network: Option<Arc<dyn PeerSource>>,
 }
 
 impl AdminRpc {
@@ -75,6 +130,7 @@ impl AdminRpc {
         start_time: Instant,
         dev_mode: bool,
         p2p_port: u16,
+        network: Option<Arc<dyn PeerSource>>,
     ) -> Self {
         Self {
             chain_spec,
@@ -83,6 +139,15 @@ impl AdminRpc {
             dev_mode,
             p2p_port,
             peer_state: RwLock::new(PeerState::new()),
+            network,
+        }
+    }
+
+    /// Connected peers from the network, or the locally tracked list without one.
+    async fn current_peers(&self) -> Vec<AdminPeerInfo> {
+        match &self.network {
+            Some(network) => network.connected_peers().await,
+            None => self.peer_state.read().await.peers.clone(),
         }
     }
 
@@ -139,8 +204,7 @@ impl AdminApiServer for AdminRpc {
     }
 
     async fn peers(&self) -> RpcResult<Vec<AdminPeerInfo>> {
-        let state = self.peer_state.read().await;
-        Ok(state.peers.clone())
+        Ok(self.current_peers().await)
     }
 
     async fn add_peer(&self, enode: String) -> RpcResult<bool> {
@@ -162,18 +226,20 @@ impl AdminApiServer for AdminRpc {
             return Ok(true);
         }
 
+        // Not connected yet: no handshake, so no caps or protocols.
         let peer = AdminPeerInfo {
             enode: enode.clone(),
+            enr: None,
             id,
             name: "unknown".to_string(),
+            caps: Vec::new(),
             network: AdminPeerNetwork {
                 local_address: format!("127.0.0.1:{}", self.p2p_port),
                 remote_address: remote_addr,
+                static_node: true,
+                ..Default::default()
             },
-            protocols: std::collections::HashMap::from([(
-                "eth".to_string(),
-                format!("{}", self.chain_spec.inner().chain.id()),
-            )]),
+            protocols: HashMap::new(),
         };
 
         state.peers.push(peer);
@@ -190,7 +256,7 @@ impl AdminApiServer for AdminRpc {
     async fn health(&self) -> RpcResult<HealthStatus> {
         let local_signers = self.signer_manager.signer_addresses().await;
         let authorized_signers = self.chain_spec.effective_signers();
-        let peer_count = self.peer_state.read().await.peers.len();
+        let peer_count = self.current_peers().await.len();
         let uptime = self.start_time.elapsed().as_secs();
 
         // A node is an active signer if any of its local signers are in the authorized set.
@@ -235,6 +301,7 @@ mod tests {
             period: 2,
             epoch: 30000,
             signers: genesis::dev_signers(),
+            ..PoaConfig::default()
         };
         Arc::new(PoaChainSpec::new(genesis, poa_config))
     }
@@ -246,12 +313,13 @@ mod tests {
             period: 2,
             epoch: 30000,
             signers: vec![],
+            ..PoaConfig::default()
         };
         Arc::new(PoaChainSpec::new(genesis, poa_config))
     }
 
     fn make_rpc(chain: Arc<PoaChainSpec>, manager: Arc<SignerManager>, dev: bool) -> AdminRpc {
-        AdminRpc::new(chain, manager, Instant::now(), dev, 30303)
+        AdminRpc::new(chain, manager, Instant::now(), dev, 30303, None)
     }
 
     // --- admin_nodeInfo ---
@@ -282,7 +350,7 @@ mod tests {
     async fn test_admin_node_info_name_and_ports() {
         let chain = test_chain_spec();
         let manager = Arc::new(SignerManager::new());
-        let rpc = AdminRpc::new(chain, manager, Instant::now(), true, 31000);
+        let rpc = AdminRpc::new(chain, manager, Instant::now(), true, 31000, None);
 
         let info = rpc.node_info().await.unwrap();
         assert_eq!(info.name, NODE_VERSION);
@@ -497,12 +565,70 @@ mod tests {
         let manager = Arc::new(SignerManager::new());
         // Use a start_time slightly in the past
         let start = Instant::now() - std::time::Duration::from_secs(42);
-        let rpc = AdminRpc::new(chain, manager, start, true, 30303);
+        let rpc = AdminRpc::new(chain, manager, start, true, 30303, None);
 
         let health = rpc.health().await.unwrap();
         assert!(health.uptime_seconds >= 42);
     }
 
+    // --- live peers ---
+
+    /// Stands in for the network handle with a fixed set of sessions.
+    #[derive(Debug)]
+    struct MockNetwork(Vec<AdminPeerInfo>);
+
+    #[async_trait::async_trait]
+    impl PeerSource for MockNetwork {
+        async fn connected_peers(&self) -> Vec<AdminPeerInfo> {
+            self.0.clone()
+        }
+    }
+
+    fn connected_peer(last_octet: u8, inbound: bool) -> AdminPeerInfo {
+        AdminPeerInfo {
+            enode: format!("enode://{}@10.0.0.{last_octet}:30303", "ab".repeat(64)),
+            enr: None,
+            id: "ab".repeat(64),
+            name: "reth/v1.0.0".to_string(),
+            caps: vec!["eth/68".to_string()],
+            network: AdminPeerNetwork {
+                local_address: "10.0.0.100:30303".to_string(),
+                remote_address: format!("10.0.0.{last_octet}:30303"),
+                inbound,
+                ..Default::default()
+            },
+            protocols: HashMap::from([("eth".to_string(), AdminPeerProtocol { version: 68 })]),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_admin_peers_from_network() {
+        let network = MockNetwork(vec![connected_peer(1, true), connected_peer(2, false)]);
+        let rpc = AdminRpc::new(
+            test_chain_spec(),
+            Arc::new(SignerManager::new()),
+            Instant::now(),
+            false,
+            30303,
+            Some(Arc::new(network)),
+        );
+
+        let peers = rpc.peers().await.unwrap();
+        assert_eq!(peers.len(), 2);
+        assert!(peers[0].network.inbound);
+        assert!(!peers[1].network.inbound);
+        assert_eq!(rpc.health().await.unwrap().peer_count, 2);
+
+        // geth field names, so existing ops scripts keep working
+        let json = serde_json::to_value(&peers[0]).unwrap();
+        assert_eq!(json["caps"][0], "eth/68");
+        assert_eq!(json["protocols"]["eth"]["version"], 68);
+        assert_eq!(json["network"]["remoteAddress"], "10.0.0.1:30303");
+        assert_eq!(json["network"]["inbound"], true);
+        assert_eq!(json["network"]["static"], false);
+        assert!(json.get("enr").is_none());
+    }
+
     // --- serialization ---
 
     #[tokio::test]
@@ -72,28 +72,46 @@ pub struct AdminCliqueConfig {
     pub epoch: u64,
 }
 
-/// Response for `admin_peers`
+/// Response for `admin_peers` (same shape as geth's)
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AdminPeerInfo {
     /// Peer's enode URL
     pub enode: String,
+    /// Peer's ENR, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enr: Option<String>,
     /// Peer ID
     pub id: String,
     /// Peer name/client
     pub name: String,
+    /// Capabilities advertised in the handshake, e.g. `eth/68`
+    pub caps: Vec<String>,
     /// Network addresses
     pub network: AdminPeerNetwork,
-    /// Protocol versions
-    pub protocols: HashMap<String, String>,
+    /// Negotiated protocols and their versions
+    pub protocols: HashMap<String, AdminPeerProtocol>,
 }
 
 /// Peer network info
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AdminPeerNetwork {
     pub local_address: String,
     pub remote_address: String,
+    /// Whether the peer dialed us
+    pub inbound: bool,
+    /// Whether the peer is in the trusted set
+    pub trusted: bool,
+    /// Whether the peer is a static peer
+    #[serde(rename = "static")]
+    pub static_node: bool,
+}
+
+/// A protocol spoken with a peer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AdminPeerProtocol {
+    pub version: usize,
 }
 
 /// Response for health check
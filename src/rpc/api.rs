@@ -1,7 +1,27 @@
-use alloy_primitives::Address;
-use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use alloy_eips::{BlockId, BlockNumberOrTag};
+use alloy_primitives::{Address, Bytes, B256};
+use alloy_rpc_types_eth::{EIP1186AccountProofResponse, TransactionRequest};
+use alloy_rpc_types_trace::geth::{GethCallConfig, TraceResult};
+use jsonrpsee::{
+    core::{RpcResult, SubscriptionResult},
+    proc_macros::rpc,
+};
 
-use super::types::{ChainConfigResponse, NodeInfoResponse};
+use super::check::TxCheck;
+use super::estimate::GasEstimate;
+use super::export::StateExport;
+use super::fee_history::PoaFeeHistory;
+use super::fees::{BlockFees, BlockReward};
+use super::governance::{GovernanceState, TimelockOperation};
+use super::types::{
+    BlockSigner, ChainConfigResponse, DecodedExtraData, EpochInfo, InTurnScheduleEntry,
+    NodeInfoResponse, NodeStatus, ReorgSimulation, SignerStatus,
+};
+use crate::consensus::{DiversityHealth, SignatureStats};
+use crate::metrics::BlockTiming;
+use crate::monitor::{PerformanceReport, SignedBlock};
+use crate::onchain::{DynamicChainConfig, DynamicSignerList, SafeInfo};
+use crate::statediff::StateDiff;
 
 /// The `meow_*` RPC namespace definition.
 #[rpc(server, namespace = "meow")]
@@ -17,4 +37,206 @@ pub trait MeowApi {
     /// Returns node information including local signer status.
     #[method(name = "nodeInfo")]
     async fn node_info(&self) -> RpcResult<NodeInfoResponse>;
+
+    /// Returns chain ID, chain head, signer count, whether this node is an authority,
+    /// uptime, mode, block period and whether `--safe-mode` has degraded the node, in
+    /// one flat object.
+    #[method(name = "status")]
+    async fn status(&self) -> RpcResult<NodeStatus>;
+
+    /// Returns who sealed `block` (by number, hash or tag), the signer whose turn it
+    /// was and whether they match. The genesis block is reported as unsigned.
+    #[method(name = "getBlockSigner")]
+    async fn get_block_signer(&self, block: BlockId) -> RpcResult<BlockSigner>;
+
+    /// Returns the in-turn signer for the next `count` blocks after the chain head,
+    /// marking the blocks whose in-turn key this node holds (capped at 1024 blocks).
+    #[method(name = "getInTurnSchedule")]
+    async fn get_in_turn_schedule(&self, count: u64) -> RpcResult<Vec<InTurnScheduleEntry>>;
+
+    /// Returns the epoch length, the chain head's epoch, the next epoch block (where
+    /// a staged `SignerRegistry` change goes live) and whether the live signer list
+    /// has been refreshed from the registry yet.
+    #[method(name = "getEpochInfo")]
+    async fn get_epoch_info(&self) -> RpcResult<EpochInfo>;
+
+    /// Returns how many distinct signers sealed the last `signers.len()` blocks and
+    /// whether that meets the configured minimum.
+    #[method(name = "getDecentralizationHealth")]
+    async fn get_decentralization_health(&self) -> RpcResult<DiversityHealth>;
+
+    /// Returns each effective signer with whether this node holds its key, the last
+    /// block it sealed (since startup) and whether it is in turn for the next block.
+    #[method(name = "getSignerStatus")]
+    async fn get_signer_status(&self) -> RpcResult<Vec<SignerStatus>>;
+
+    /// Returns each signer's produced, in-turn and out-of-turn block counts over the
+    /// last `blocks` indexed blocks, and the in-turn slots another signer sealed
+    /// instead (missed). Limited to the `--signer-index-size` window.
+    #[method(name = "getSignerPerformance")]
+    async fn get_signer_performance(&self, blocks: u64) -> RpcResult<PerformanceReport>;
+
+    /// Returns the EIP-1186 account proof and per-slot storage proofs for `address`
+    /// at `block` (default: latest). Missing accounts and slots get exclusion proofs.
+    #[method(name = "getStorageProof")]
+    async fn get_storage_proof(
+        &self,
+        address: Address,
+        slots: Vec<B256>,
+        block: Option<BlockId>,
+    ) -> RpcResult<EIP1186AccountProofResponse>;
+
+    /// Returns how many of the last `windowBlocks` blocks (capped at 1024) had
+    /// recoverable seals, unauthorized signers, or malformed extra_data.
+    #[method(name = "getSignerSignatureStats")]
+    async fn get_signer_signature_stats(&self, window_blocks: u64) -> RpcResult<SignatureStats>;
+
+    /// Returns the fees paid in block `blockNumber`, split into the burned base-fee
+    /// portion and the priority-fee portion paid to the block's beneficiary.
+    #[method(name = "getBlockFees")]
+    async fn get_block_fees(&self, block_number: u64) -> RpcResult<BlockFees>;
+
+    /// Returns the priority fees paid to the coinbase and the base fee burned in
+    /// block `blockNumber`, computed from its transactions and receipts.
+    #[method(name = "getBlockReward")]
+    async fn get_block_reward(&self, block_number: u64) -> RpcResult<BlockReward>;
+
+    /// Returns the `eth_feeHistory` fields for `blockCount` blocks ending at
+    /// `newestBlock`, plus each block's recovered signer and whether it was in turn.
+    /// `blockCount` 0 uses `--gpo-blocks`; omitted percentiles use `--gpo-percentile`.
+    #[method(name = "getFeeHistory")]
+    async fn get_fee_history(
+        &self,
+        block_count: u64,
+        newest_block: BlockNumberOrTag,
+        reward_percentiles: Option<Vec<f64>>,
+    ) -> RpcResult<PoaFeeHistory>;
+
+    /// Returns the build and sign times of the last `count` canonical blocks this node
+    /// produced, oldest first. Blocks imported from peers are not included.
+    #[method(name = "getBlockTimings")]
+    async fn get_block_timings(&self, count: u64) -> RpcResult<Vec<BlockTiming>>;
+
+    /// Re-executes `block` on its parent state and returns its state diff, in the
+    /// same shape as `meow_subscribeStateDiff`. Block 0 returns the genesis alloc.
+    #[method(name = "replayBlock")]
+    async fn replay_block(&self, block: BlockId) -> RpcResult<StateDiff>;
+
+    /// Re-executes blocks `from..=to` and returns their state diffs in block order,
+    /// as `meow_replayBlock` would. Fails if the range is longer than `maxBlocks`
+    /// (itself capped at 256), so clients page through long ranges.
+    #[method(name = "getStateDiffRange")]
+    async fn get_state_diff_range(
+        &self,
+        from: u64,
+        to: u64,
+        max_blocks: u64,
+    ) -> RpcResult<Vec<StateDiff>>;
+
+    /// Re-executes `block` and returns the `callTracer` trace of each transaction,
+    /// in block order. `config` is the call tracer config; `onlyTopCall` keeps
+    /// traces of large blocks small.
+    #[method(name = "traceBlock")]
+    async fn trace_block(
+        &self,
+        block: BlockId,
+        config: Option<GethCallConfig>,
+    ) -> RpcResult<Vec<TraceResult>>;
+
+    /// Returns the governance parameters currently stored in `ChainConfig` at the
+    /// latest block, or `null` if the contract isn't deployed.
+    #[method(name = "getChainConfig")]
+    async fn get_chain_config(&self) -> RpcResult<Option<DynamicChainConfig>>;
+
+    /// Returns the signer list, threshold and governance address currently stored in
+    /// `SignerRegistry` at the latest block (ahead of the next epoch refresh), or
+    /// `null` if the contract isn't deployed.
+    #[method(name = "getSignerRegistry")]
+    async fn get_signer_registry(&self) -> RpcResult<Option<DynamicSignerList>>;
+
+    /// Returns `ChainConfig`, the `SignerRegistry` list and threshold, and the
+    /// Timelock delay, proposer and paused flag, all read at the latest block. Each
+    /// field is `null` if its contract isn't deployed.
+    #[method(name = "getGovernanceState")]
+    async fn get_governance_state(&self) -> RpcResult<GovernanceState>;
+
+    /// Returns the operations scheduled on the Timelock with their `id`, `target`,
+    /// `eta` (unix seconds, `null` once executed), and whether they're `ready` to
+    /// execute or `executed`, read at the latest block. Cancelled operations are
+    /// left out; the list is empty when nothing was scheduled.
+    #[method(name = "getTimelockOperations")]
+    async fn get_timelock_operations(&self) -> RpcResult<Vec<TimelockOperation>>;
+
+    /// Returns the Miner Proxy's admin (EIP-1967 admin slot) at the latest block, or
+    /// `null` if the proxy isn't deployed. Genesis sets it to the governance Safe.
+    #[method(name = "getMinerProxyAdmin")]
+    async fn get_miner_proxy_admin(&self) -> RpcResult<Option<Address>>;
+
+    /// Checks the EIP-2718 encoded `rawTx` against the pool's rules (minimum gas price,
+    /// live `ChainConfig` gas and contract size limits, sender nonce and balance)
+    /// without submitting it, returning the first reason it would be rejected.
+    #[method(name = "checkTx")]
+    async fn check_tx(&self, raw_tx: Bytes) -> RpcResult<TxCheck>;
+
+    /// Estimates `tx` at the latest state on the node's EVM, charging calldata at the
+    /// live `ChainConfig.calldataGasPerByte`. Returns the gas it will use and the
+    /// lowest gas limit it succeeds with, which still has to cover Ethereum's
+    /// 16 gas per calldata byte.
+    #[method(name = "estimateGas")]
+    async fn estimate_gas(&self, tx: TransactionRequest) -> RpcResult<GasEstimate>;
+
+    /// Returns the owners and threshold of the Gnosis Safe at `safe` (default: the
+    /// governance Safe) at the latest block, or `null` if no set-up Safe is there.
+    #[method(name = "getSafeInfo")]
+    async fn get_safe_info(&self, safe: Option<Address>) -> RpcResult<Option<SafeInfo>>;
+
+    /// Splits a block's POA `extraData` into its 32-byte vanity, the signer list
+    /// (present in epoch blocks only) and the 65-byte seal.
+    #[method(name = "decodeExtraData")]
+    async fn decode_extra_data(&self, extra_data: Bytes) -> RpcResult<DecodedExtraData>;
+
+    /// Writes every account and storage slot as of `blockNumber` as a genesis file,
+    /// which `--genesis` can launch a fresh chain from, to `fileName` in the node's
+    /// `<datadir>/exports` directory. `fileName` must be a plain file name; existing
+    /// files are never overwritten. Dev mode only.
+    #[method(name = "exportState")]
+    async fn export_state(&self, block_number: u64, file_name: String) -> RpcResult<StateExport>;
+
+    /// Signs `hash` with the node's key for `address` and returns the 65-byte
+    /// `r || s || v` signature, as it would appear in a block seal. Fails if the node
+    /// doesn't control `address`. Dev mode only.
+    #[method(name = "signHash")]
+    async fn sign_hash(&self, address: Address, hash: B256) -> RpcResult<Bytes>;
+
+    /// Decodes an RLP-encoded header and returns the signer recovered from its seal,
+    /// exactly as block validation does. Dev mode only.
+    #[method(name = "recoverSigner")]
+    async fn recover_signer(&self, header_rlp: Bytes) -> RpcResult<Address>;
+
+    /// Scores the RLP-encoded `sideChainHeaders` (consecutive blocks forking off the
+    /// canonical chain) against the canonical blocks they would replace, with the
+    /// fork-choice rule block import uses. Dev mode only.
+    #[method(name = "simulateReorg")]
+    async fn simulate_reorg(&self, side_chain_headers: Vec<Bytes>) -> RpcResult<ReorgSimulation>;
+
+    /// Subscribe to each committed block's state diff (WebSocket/IPC only).
+    ///
+    /// Subscribers that can't keep up skip diffs instead of slowing the node.
+    #[subscription(
+        name = "subscribeStateDiff" => "stateDiff",
+        unsubscribe = "unsubscribeStateDiff",
+        item = StateDiff
+    )]
+    async fn subscribe_state_diff(&self) -> SubscriptionResult;
+
+    /// Subscribe to each committed block's number, hash, recovered signer, in-turn
+    /// flag, transaction count and gas used (WebSocket/IPC only).
+    ///
+    /// Subscribers that can't keep up skip blocks instead of slowing the node.
+    #[subscription(
+        name = "subscribeSignedBlocks" => "signedBlock",
+        unsubscribe = "unsubscribeSignedBlocks",
+        item = SignedBlock
+    )]
+    async fn subscribe_signed_blocks(&self) -> SubscriptionResult;
 }
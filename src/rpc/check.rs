@@ -0,0 +1,246 @@
+//! Dry-run transaction checks for `meow_checkTx`.
+//!
+//! Applies the rules a transaction meets on its way into the pool without inserting
+//! it: the POA limits first (`--min-gas-price`, the live `ChainConfig` gas limits and
+//! contract size), then the sender's nonce and balance at the latest state. The gas
+//! price floor and `maxTxGas` go through the pool validators' own checks. The
+//! first failure is reported with a specific reason, which says more than the bare
+//! `eth_sendRawTransaction` rejection.
+
+use crate::node::{check_max_tx_gas, check_min_gas_price};
+use crate::onchain::DynamicChainConfig;
+use alloy_consensus::{Header, Transaction};
+use alloy_evm::revm::primitives::eip170::MAX_CODE_SIZE;
+use alloy_primitives::U256;
+use reth_primitives_traits::Account;
+use serde::Serialize;
+
+/// Response for `meow_checkTx`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxCheck {
+    /// Whether the pool would accept the transaction
+    pub valid: bool,
+    /// Why it would be rejected (`null` when valid)
+    pub reason: Option<String>,
+}
+
+impl TxCheck {
+    /// A transaction the pool would accept.
+    pub fn accepted() -> Self {
+        Self {
+            valid: true,
+            reason: None,
+        }
+    }
+
+    /// A transaction the pool would reject for `reason`.
+    pub fn rejected(reason: impl Into<String>) -> Self {
+        Self {
+            valid: false,
+            reason: Some(reason.into()),
+        }
+    }
+}
+
+impl From<Result<(), String>> for TxCheck {
+    fn from(result: Result<(), String>) -> Self {
+        result.map_or_else(Self::rejected, |()| Self::accepted())
+    }
+}
+
+/// Latest state a transaction is checked against.
+#[derive(Debug, Clone, Default)]
+pub struct TxCheckState {
+    /// The chain head, whose successor the transaction would be included in
+    pub head: Option<Header>,
+    /// Live `ChainConfig` parameters (`None` without the contract)
+    pub chain_config: Option<DynamicChainConfig>,
+    /// The sender's account (`None` if it doesn't exist yet)
+    pub sender: Option<Account>,
+}
+
+/// Check `tx` against the pool's rules, returning the first violation.
+///
+/// `pending_base_fee` is the base fee of the block after `state.head`.
+/// `max_contract_size` is the node's `--max-contract-size`, which applies while
+/// `ChainConfig.maxContractSize` is unset (`None` = EIP-170's limit).
+pub fn check_transaction(
+    tx: &impl Transaction,
+    chain_id: u64,
+    min_gas_price: u128,
+    pending_base_fee: u64,
+    max_contract_size: Option<u64>,
+    state: &TxCheckState,
+) -> Result<(), String> {
+    if let Some(tx_chain_id) = tx.chain_id() {
+        if tx_chain_id != chain_id {
+            return Err(format!(
+                "invalid chain id: expected {chain_id}, got {tx_chain_id}"
+            ));
+        }
+    }
+
+    let gas_limit = tx.gas_limit();
+    if let Some(config) = &state.chain_config {
+        check_max_tx_gas(tx, config.max_tx_gas).map_err(|_| {
+            format!(
+                "gas limit {gas_limit} exceeds ChainConfig maxTxGas {}",
+                config.max_tx_gas
+            )
+        })?;
+        if gas_limit > config.gas_limit {
+            return Err(format!(
+                "gas limit {gas_limit} exceeds the block gas limit {}",
+                config.gas_limit
+            ));
+        }
+    }
+
+    // EIP-3860: initcode may be at most twice the contract size limit, which the EVM
+    // takes from ChainConfig, then --max-contract-size, then EIP-170
+    let max_contract_size = state
+        .chain_config
+        .as_ref()
+        .map(|config| config.max_contract_size)
+        .filter(|&size| size > 0)
+        .or(max_contract_size)
+        .unwrap_or(MAX_CODE_SIZE as u64);
+    let max_initcode = max_contract_size.saturating_mul(2);
+    if tx.is_create() && tx.input().len() as u64 > max_initcode {
+        return Err(format!(
+            "initcode size {} exceeds the limit of {max_initcode} bytes",
+            tx.input().len()
+        ));
+    }
+
+    if tx.max_fee_per_gas() < u128::from(pending_base_fee) {
+        return Err(format!(
+            "max fee per gas {} is below the pending base fee {pending_base_fee}",
+            tx.max_fee_per_gas()
+        ));
+    }
+    check_min_gas_price(tx, min_gas_price, pending_base_fee).map_err(|_| {
+        format!(
+            "transaction underpriced: effective gas price {} is below the minimum {min_gas_price}",
+            tx.effective_gas_price(Some(pending_base_fee))
+        )
+    })?;
+
+    let account = state.sender.unwrap_or_default();
+    if tx.nonce() < account.nonce {
+        return Err(format!(
+            "nonce too low: next nonce {}, transaction nonce {}",
+            account.nonce,
+            tx.nonce()
+        ));
+    }
+    let cost = U256::from(gas_limit) * U256::from(tx.max_fee_per_gas()) + tx.value();
+    if cost > account.balance {
+        return Err(format!(
+            "insufficient funds for gas * price + value: balance {}, cost {cost}",
+            account.balance
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::TxEip1559;
+    use alloy_primitives::{Address, TxKind};
+
+    const GWEI: u128 = 1_000_000_000;
+
+    fn state(max_tx_gas: u64) -> TxCheckState {
+        TxCheckState {
+            head: None,
+            chain_config: Some(DynamicChainConfig {
+                governance: Address::ZERO,
+                gas_limit: 30_000_000,
+                block_time: 2,
+                max_contract_size: 24_576,
+                calldata_gas_per_byte: 16,
+                max_tx_gas,
+                eager_mining: false,
+                recent_signer_window: 0,
+                epoch: 0,
+            }),
+            sender: Some(Account {
+                nonce: 3,
+                balance: U256::from(10u128.pow(18)),
+                bytecode_hash: None,
+            }),
+        }
+    }
+
+    fn tx(gas_limit: u64, nonce: u64) -> TxEip1559 {
+        TxEip1559 {
+            chain_id: 1,
+            nonce,
+            gas_limit,
+            max_fee_per_gas: 2 * GWEI,
+            max_priority_fee_per_gas: GWEI,
+            to: TxKind::Call(Address::with_last_byte(1)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_check_rejects_each_rule() {
+        let check = |tx: &TxEip1559, state: &TxCheckState| {
+            check_transaction(tx, 1, GWEI, GWEI as u64 / 2, None, state)
+        };
+        assert_eq!(check(&tx(21_000, 3), &state(1_000_000)), Ok(()));
+
+        let err = check(&tx(2_000_000, 3), &state(1_000_000)).unwrap_err();
+        assert_eq!(
+            err,
+            "gas limit 2000000 exceeds ChainConfig maxTxGas 1000000"
+        );
+        // maxTxGas 0 leaves only the block gas limit
+        assert_eq!(check(&tx(2_000_000, 3), &state(0)), Ok(()));
+        assert!(check(&tx(40_000_000, 3), &state(0))
+            .unwrap_err()
+            .contains("block gas limit"));
+
+        let mut create = tx(1_000_000, 3);
+        create.to = TxKind::Create;
+        create.input = vec![0u8; 2 * 24_576 + 1].into();
+        assert!(check(&create, &state(0)).unwrap_err().contains("initcode"));
+        // Unset maxContractSize: the static override applies, then EIP-170
+        let mut unset = state(0);
+        unset.chain_config.as_mut().unwrap().max_contract_size = 0;
+        create.input = vec![0u8; 1_000].into();
+        assert_eq!(check(&create, &unset), Ok(()));
+        create.input = vec![0u8; 2 * 24_576 + 1].into();
+        assert!(check(&create, &unset).unwrap_err().contains("49152"));
+        assert_eq!(
+            check_transaction(&create, 1, GWEI, GWEI as u64 / 2, Some(65_536), &unset),
+            Ok(())
+        );
+
+        let mut cheap = tx(21_000, 3);
+        cheap.max_priority_fee_per_gas = GWEI / 10;
+        assert!(check(&cheap, &state(0))
+            .unwrap_err()
+            .contains("underpriced"));
+        cheap.max_fee_per_gas = GWEI / 4;
+        assert!(check(&cheap, &state(0)).unwrap_err().contains("base fee"));
+
+        assert!(check(&tx(21_000, 2), &state(0))
+            .unwrap_err()
+            .contains("nonce too low"));
+        let mut rich = tx(21_000, 3);
+        rich.value = U256::from(10u128.pow(18));
+        assert!(check(&rich, &state(0))
+            .unwrap_err()
+            .contains("insufficient funds"));
+        let mut other_chain = tx(21_000, 3);
+        other_chain.chain_id = 5;
+        assert!(check(&other_chain, &state(0))
+            .unwrap_err()
+            .contains("chain id"));
+    }
+}
@@ -2,17 +2,21 @@
 //!
 //! Implementation of the standard `clique_*` RPC API that tools like MetaMask
 //! and Blockscout expect for Clique POA networks. Provides signer queries,
-//! snapshot inspection, and local proposal management.
+//! snapshot inspection, and signer voting via proposals.
 
 use alloy_primitives::{Address, B256};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::sync::Arc;
 
 use super::clique_types::*;
 use crate::chainspec::PoaChainSpec;
+use crate::monitor::{SharedSignerIndex, SignerIndex, DEFAULT_SIGNER_INDEX_CAPACITY};
 use crate::signer::SignerManager;
 
+/// Number of recent blocks `clique_status` reports on (same as geth).
+pub const CLIQUE_STATUS_BLOCKS: usize = 64;
+
 /// The `clique_*` RPC namespace - standard Clique POA API.
 ///
 /// Provides the methods that Ethereum tooling (MetaMask, Blockscout, etc.)
@@ -36,7 +40,8 @@ pub trait CliqueApi {
     async fn get_snapshot_at_hash(&self, hash: B256) -> RpcResult<CliqueSnapshot>;
 
     /// Propose a new signer (authorize=true) or remove an existing one (authorize=false).
-    /// This is a local proposal; the vote is included in subsequent blocks signed by us.
+    /// The vote is staged locally and cast in subsequent blocks sealed by this node
+    /// (candidate in `beneficiary`, vote in `nonce`).
     #[method(name = "propose")]
     async fn propose(&self, address: Address, authorize: bool) -> RpcResult<()>;
 
@@ -56,13 +61,11 @@ pub trait CliqueApi {
 /// Implementation of the `clique_*` RPC namespace.
 pub struct CliqueRpc {
     chain_spec: Arc<PoaChainSpec>,
-    /// Signer manager for checking local signer status.
-    /// Reserved for future use in status enrichment and historical block lookups.
-    #[allow(dead_code)]
+    /// Signer manager holding the pending votes (address -> authorize).
+    /// Shared with the payload builder, which casts the votes in sealed blocks.
     signer_manager: Arc<SignerManager>,
-    /// Local proposals: address -> authorize (true=add, false=remove).
-    /// Protected by `RwLock` for concurrent access from RPC handlers.
-    proposals: Arc<RwLock<HashMap<Address, bool>>>,
+    /// Signer of each recent canonical block, recorded by the block monitoring task.
+    signer_index: SharedSignerIndex,
 }
 
 impl CliqueRpc {
@@ -71,17 +74,23 @@ impl CliqueRpc {
         Self {
             chain_spec,
             signer_manager,
-            proposals: Arc::new(RwLock::new(HashMap::new())),
+            signer_index: SignerIndex::shared(DEFAULT_SIGNER_INDEX_CAPACITY),
         }
     }
 
+    /// Share the signer index the block monitoring task records, for `clique_status`.
+    pub fn with_signer_index(mut self, signer_index: SharedSignerIndex) -> Self {
+        self.signer_index = signer_index;
+        self
+    }
+
     /// Build a snapshot from the current chain state and local proposals.
     ///
     /// Uses `effective_signers()` to respect live on-chain governance changes
     /// (same as consensus and payload builder).
     fn current_snapshot(&self) -> CliqueSnapshot {
         let signers = self.chain_spec.effective_signers();
-        let proposals = self.proposals.read().unwrap_or_else(|e| e.into_inner());
+        let proposals = self.signer_manager.proposals();
 
         let votes: Vec<CliqueVote> = proposals
             .iter()
@@ -138,40 +147,53 @@ impl CliqueApiServer for CliqueRpc {
     }
 
     async fn propose(&self, address: Address, authorize: bool) -> RpcResult<()> {
-        let mut proposals = self.proposals.write().unwrap_or_else(|e| e.into_inner());
-        proposals.insert(address, authorize);
+        self.signer_manager.propose(address, authorize);
         Ok(())
     }
 
     async fn discard(&self, address: Address) -> RpcResult<()> {
-        let mut proposals = self.proposals.write().unwrap_or_else(|e| e.into_inner());
-        proposals.remove(&address);
+        self.signer_manager.discard(&address);
         Ok(())
     }
 
     async fn status(&self) -> RpcResult<CliqueStatus> {
         let signers = self.chain_spec.effective_signers();
-        let mut sealers_activity = HashMap::new();
-        for signer in &signers {
-            sealers_activity.insert(
-                *signer,
-                SealerActivity {
-                    signed: 0,
-                    total: 0,
-                },
-            );
+        let mut sealers_activity: HashMap<Address, SealerActivity> = signers
+            .iter()
+            .map(|signer| {
+                (
+                    *signer,
+                    SealerActivity {
+                        signed: 0,
+                        total: 0,
+                    },
+                )
+            })
+            .collect();
+        let blocks = self
+            .signer_index
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .latest(CLIQUE_STATUS_BLOCKS);
+        for (number, entry) in &blocks {
+            if let Some(activity) = sealers_activity.get_mut(&entry.signer) {
+                activity.signed += 1;
+            }
+            let expected = self.chain_spec.expected_signer(*number);
+            if let Some(activity) = expected.and_then(|e| sealers_activity.get_mut(&e)) {
+                activity.total += 1;
+            }
         }
         Ok(CliqueStatus {
             signer_count: signers.len(),
-            num_blocks: 0,
+            num_blocks: blocks.len() as u64,
             sealers_activity,
         })
     }
 
     async fn proposals(&self) -> RpcResult<CliqueProposals> {
-        let proposals = self.proposals.read().unwrap_or_else(|e| e.into_inner());
         Ok(CliqueProposals {
-            proposals: proposals.clone(),
+            proposals: self.signer_manager.proposals(),
         })
     }
 }
@@ -190,6 +212,7 @@ mod tests {
             period: 2,
             epoch: 30000,
             signers: genesis::dev_signers(),
+            ..PoaConfig::default()
         };
         Arc::new(PoaChainSpec::new(genesis, poa_config))
     }
@@ -202,6 +225,7 @@ mod tests {
             period: 12,
             epoch: 30000,
             signers: genesis::dev_accounts().into_iter().take(5).collect(),
+            ..PoaConfig::default()
         };
         Arc::new(PoaChainSpec::new(genesis, poa_config))
     }
@@ -214,6 +238,7 @@ mod tests {
             period: 2,
             epoch: 30000,
             signers: vec![],
+            ..PoaConfig::default()
         };
         Arc::new(PoaChainSpec::new(genesis, poa_config))
     }
@@ -225,6 +250,36 @@ mod tests {
 
     // ── getSigners tests ──
 
+    #[tokio::test]
+    async fn test_get_signers_over_ipc() {
+        use jsonrpsee::core::client::ClientT;
+        use jsonrpsee::rpc_params;
+
+        let chain = test_chain_spec();
+        let path = std::env::temp_dir()
+            .join(format!("meowchain-clique-{}.ipc", std::process::id()))
+            .display()
+            .to_string();
+        // clique is merged into the configured transports, IPC included
+        let server = reth_ipc::server::Builder::default().build(path.clone());
+        let handle = server
+            .start(make_rpc(chain.clone()).into_rpc())
+            .await
+            .unwrap();
+
+        let client = reth_ipc::client::IpcClientBuilder::default()
+            .build(&path)
+            .await
+            .unwrap();
+        let signers: Vec<Address> = client
+            .request("clique_getSigners", rpc_params![])
+            .await
+            .unwrap();
+        assert_eq!(signers, chain.signers());
+
+        handle.stop().unwrap();
+    }
+
     #[tokio::test]
     async fn test_get_signers_returns_authorized_list() {
         let chain = test_chain_spec();
@@ -350,6 +405,20 @@ mod tests {
         assert_eq!(proposals.proposals.get(&addr3), Some(&true));
     }
 
+    #[tokio::test]
+    async fn test_proposals_shared_with_signer_manager() {
+        let manager = Arc::new(SignerManager::new());
+        let rpc = CliqueRpc::new(test_chain_spec(), manager.clone());
+        let addr = Address::with_last_byte(0x42);
+
+        rpc.propose(addr, true).await.unwrap();
+        // The payload builder sees the vote through the same SignerManager
+        assert_eq!(manager.pending_vote(&[]), Some((addr, true)));
+
+        rpc.discard(addr).await.unwrap();
+        assert_eq!(manager.pending_vote(&[]), None);
+    }
+
     // ── snapshot tests ──
 
     #[tokio::test]
@@ -437,6 +506,38 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_status_activity_from_signer_index() {
+        let chain = test_chain_spec();
+        let signers = chain.signers().to_vec();
+        let index = SignerIndex::shared(1000);
+        {
+            let mut index = index.lock().unwrap();
+            // Signer 2 is offline: signer 0 covers its slots out of turn
+            for number in 1..=(CLIQUE_STATUS_BLOCKS as u64 + 6) {
+                let expected = signers[(number % 3) as usize];
+                let signer = if expected == signers[2] {
+                    signers[0]
+                } else {
+                    expected
+                };
+                index.record(number, signer, signer == expected);
+            }
+        }
+        let rpc = make_rpc(chain).with_signer_index(index);
+
+        // Last 64 blocks: 7..=70, 21 or 22 in-turn slots each
+        let status = rpc.status().await.unwrap();
+        assert_eq!(status.num_blocks, CLIQUE_STATUS_BLOCKS as u64);
+        let activity = |i: usize| {
+            let a = &status.sealers_activity[&signers[i]];
+            (a.signed, a.total)
+        };
+        assert_eq!(activity(0), (42, 21));
+        assert_eq!(activity(1), (22, 22));
+        assert_eq!(activity(2), (0, 21));
+    }
+
     // ── JSON serialization tests ──
 
     #[test]
@@ -0,0 +1,474 @@
+//! Debug RPC Namespace
+//!
+//! `debug_traceTransaction` for chains without Reth's built-in `debug` namespace
+//! enabled. The transaction's block is replayed on top of its parent state with
+//! the node's EVM config (pre-block system calls and all earlier transactions),
+//! then the transaction itself runs under a tracing inspector.
+//!
+//! Supported tracers: the default struct logger (honouring `disableStorage`,
+//! `disableMemory`, `disableStack` and `enableReturnData`) and `callTracer`.
+//!
+//! `meow_traceBlock` uses the same inspector to trace every transaction of a
+//! block with `callTracer`, through [`trace_block`]. Call trees of a large
+//! block can get big: a block trace returning more than
+//! [`MAX_BLOCK_TRACE_FRAMES`] call frames fails, and `onlyTopCall` keeps one
+//! frame per transaction.
+
+use alloy_consensus::{BlockHeader, Transaction as _};
+use alloy_eips::BlockId;
+use alloy_evm::Evm;
+use alloy_primitives::B256;
+use alloy_rpc_types_trace::geth::{
+    GethCallConfig, GethDebugBuiltInTracerType, GethDebugTracerType, GethDebugTracingOptions,
+    GethTrace, TraceResult,
+};
+use jsonrpsee::types::error::ErrorObjectOwned;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_ethereum::provider::ProviderError;
+use reth_ethereum::storage::StateProviderFactory;
+use reth_ethereum::{Block, EthPrimitives};
+use reth_evm::execute::BlockExecutor;
+use reth_evm::ConfigureEvm;
+use reth_primitives_traits::RecoveredBlock;
+use reth_revm::database::StateProviderDatabase;
+use reth_revm::db::State;
+use reth_storage_api::{
+    BlockIdReader, BlockReader, StateProviderBox, TransactionVariant, TransactionsProvider,
+};
+use revm_inspectors::tracing::{TracingInspector, TracingInspectorConfig};
+
+use super::{internal_error, invalid_params};
+
+/// Maximum number of call frames returned by one [`trace_block`].
+pub const MAX_BLOCK_TRACE_FRAMES: usize = 100_000;
+
+/// The `debug_*` RPC namespace definition.
+#[rpc(server, namespace = "debug")]
+pub trait DebugApi {
+    /// Re-executes a mined transaction and returns its trace (struct logs by
+    /// default, or a call tree with `{"tracer": "callTracer"}`).
+    #[method(name = "traceTransaction")]
+    async fn trace_transaction(
+        &self,
+        tx_hash: B256,
+        opts: Option<GethDebugTracingOptions>,
+    ) -> RpcResult<GethTrace>;
+}
+
+/// Errors from re-executing a transaction for tracing.
+#[derive(Debug, thiserror::Error)]
+pub enum TraceError {
+    /// No mined transaction with this hash.
+    #[error("transaction {0} not found")]
+    TransactionNotFound(B256),
+
+    /// The transaction's block is missing from the database.
+    #[error("block {0} not found")]
+    BlockNotFound(u64),
+
+    /// The requested tracer isn't implemented.
+    #[error("unsupported tracer: {0}")]
+    UnsupportedTracer(String),
+
+    /// `tracerConfig` doesn't match the tracer.
+    #[error("invalid tracer config: {0}")]
+    InvalidTracerConfig(String),
+
+    /// The block's call trees exceed [`MAX_BLOCK_TRACE_FRAMES`].
+    #[error("block trace exceeds {MAX_BLOCK_TRACE_FRAMES} call frames, use onlyTopCall")]
+    TooManyFrames,
+
+    /// Reading historical state failed (e.g. pruned).
+    #[error(transparent)]
+    Provider(#[from] ProviderError),
+
+    /// Replaying the block or the traced transaction failed.
+    #[error("execution failed: {0}")]
+    Execution(String),
+}
+
+impl From<TraceError> for ErrorObjectOwned {
+    fn from(err: TraceError) -> Self {
+        match err {
+            TraceError::UnsupportedTracer(_)
+            | TraceError::InvalidTracerConfig(_)
+            | TraceError::TooManyFrames => invalid_params(err.to_string()),
+            _ => internal_error(err.to_string()),
+        }
+    }
+}
+
+/// Which tracer a request selects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TracerKind {
+    /// Default opcode-level struct logger.
+    StructLogger,
+    /// Call tree (`callTracer`).
+    CallTracer,
+}
+
+impl TracerKind {
+    /// Pick the tracer from the request options.
+    pub fn from_opts(opts: &GethDebugTracingOptions) -> Result<Self, TraceError> {
+        match &opts.tracer {
+            None => Ok(Self::StructLogger),
+            Some(GethDebugTracerType::BuiltInTracer(GethDebugBuiltInTracerType::CallTracer)) => {
+                Ok(Self::CallTracer)
+            }
+            Some(other) => Err(TraceError::UnsupportedTracer(format!("{other:?}"))),
+        }
+    }
+}
+
+/// Implementation of the `debug_*` RPC namespace.
+#[derive(Debug, Clone)]
+pub struct DebugRpc<Provider, EvmConfig> {
+    provider: Provider,
+    evm_config: EvmConfig,
+}
+
+impl<Provider, EvmConfig> DebugRpc<Provider, EvmConfig> {
+    /// Create a new DebugRpc instance.
+    pub fn new(provider: Provider, evm_config: EvmConfig) -> Self {
+        Self {
+            provider,
+            evm_config,
+        }
+    }
+}
+
+impl<Provider, EvmConfig> DebugRpc<Provider, EvmConfig>
+where
+    Provider: BlockReader<Block = Block> + TransactionsProvider + StateProviderFactory,
+    EvmConfig: ConfigureEvm<Primitives = EthPrimitives>,
+{
+    /// Replay the block containing `tx_hash` up to the transaction and trace it.
+    pub fn trace(
+        &self,
+        tx_hash: B256,
+        opts: GethDebugTracingOptions,
+    ) -> Result<GethTrace, TraceError> {
+        let kind = TracerKind::from_opts(&opts)?;
+        let (_, meta) = self
+            .provider
+            .transaction_by_hash_with_meta(tx_hash)?
+            .ok_or(TraceError::TransactionNotFound(tx_hash))?;
+        let block = self
+            .provider
+            .recovered_block(meta.block_number.into(), TransactionVariant::WithHash)?
+            .ok_or(TraceError::BlockNotFound(meta.block_number))?;
+        let index = meta.index as usize;
+        let tx = block
+            .transactions_recovered()
+            .nth(index)
+            .ok_or(TraceError::TransactionNotFound(tx_hash))?;
+        let gas_limit = tx.gas_limit();
+
+        let mut db = state_before_tx(&self.provider, &self.evm_config, &block, index)?;
+        let execution = |e: &dyn std::fmt::Display| TraceError::Execution(e.to_string());
+        let evm_env = self
+            .evm_config
+            .evm_env(block.header())
+            .map_err(|e| execution(&e))?;
+        let GethDebugTracingOptions {
+            config,
+            tracer_config,
+            ..
+        } = opts;
+
+        match kind {
+            TracerKind::StructLogger => {
+                let mut inspector =
+                    TracingInspector::new(TracingInspectorConfig::from_geth_config(&config));
+                let result = {
+                    let mut evm = self.evm_config.evm_with_env_and_inspector(
+                        &mut db,
+                        evm_env,
+                        &mut inspector,
+                    );
+                    evm.transact(tx).map_err(|e| execution(&e))?.result
+                };
+                let output = result.output().cloned().unwrap_or_default();
+                let frame = inspector
+                    .with_transaction_gas_limit(gas_limit)
+                    .into_geth_builder()
+                    .geth_traces(result.gas_used(), output, config);
+                Ok(frame.into())
+            }
+            TracerKind::CallTracer => {
+                let call_config = tracer_config
+                    .into_call_config()
+                    .map_err(|e| TraceError::InvalidTracerConfig(e.to_string()))?;
+                let mut inspector = TracingInspector::new(
+                    TracingInspectorConfig::from_geth_call_config(&call_config),
+                );
+                let result = {
+                    let mut evm = self.evm_config.evm_with_env_and_inspector(
+                        &mut db,
+                        evm_env,
+                        &mut inspector,
+                    );
+                    evm.transact(tx).map_err(|e| execution(&e))?.result
+                };
+                let frame = inspector
+                    .with_transaction_gas_limit(gas_limit)
+                    .into_geth_builder()
+                    .geth_call_traces(call_config, result.gas_used());
+                Ok(frame.into())
+            }
+        }
+    }
+}
+
+/// `callTracer` output of every transaction in `block`, in block order, or
+/// `None` if the block is unknown.
+pub fn trace_block<Provider, EvmConfig>(
+    provider: &Provider,
+    evm_config: &EvmConfig,
+    block: BlockId,
+    call_config: GethCallConfig,
+) -> Result<Option<Vec<TraceResult>>, TraceError>
+where
+    Provider: BlockReader<Block = Block> + BlockIdReader + StateProviderFactory,
+    EvmConfig: ConfigureEvm<Primitives = EthPrimitives>,
+{
+    let Some(number) = provider.block_number_for_id(block)? else {
+        return Ok(None);
+    };
+    let Some(block) = provider.recovered_block(number.into(), TransactionVariant::WithHash)? else {
+        return Ok(None);
+    };
+
+    let mut db = state_before_tx(provider, evm_config, &block, 0)?;
+    let execution = |e: &dyn std::fmt::Display| TraceError::Execution(e.to_string());
+    let evm_env = evm_config
+        .evm_env(block.header())
+        .map_err(|e| execution(&e))?;
+    let inspector_config = TracingInspectorConfig::from_geth_call_config(&call_config);
+    let only_top_call = call_config.only_top_call.unwrap_or_default();
+
+    // One inspector per transaction, committing each one's state so the next
+    // sees it. Only one call tree is held in the inspector at a time.
+    let mut traces = Vec::with_capacity(block.body().transactions.len());
+    let mut frames = 0;
+    for tx in block.transactions_recovered() {
+        let tx_hash = *tx.tx_hash();
+        let gas_limit = tx.gas_limit();
+        let mut inspector = TracingInspector::new(inspector_config);
+        let result = {
+            let mut evm =
+                evm_config.evm_with_env_and_inspector(&mut db, evm_env.clone(), &mut inspector);
+            evm.transact_commit(tx).map_err(|e| execution(&e))?
+        };
+        frames += if only_top_call {
+            1
+        } else {
+            inspector.traces().nodes().len()
+        };
+        if frames > MAX_BLOCK_TRACE_FRAMES {
+            return Err(TraceError::TooManyFrames);
+        }
+        let frame = inspector
+            .with_transaction_gas_limit(gas_limit)
+            .into_geth_builder()
+            .geth_call_traces(call_config.clone(), result.gas_used());
+        traces.push(TraceResult::Success {
+            result: frame.into(),
+            tx_hash: Some(tx_hash),
+        });
+    }
+    Ok(Some(traces))
+}
+
+/// State of `block`'s parent with the pre-block system calls and the first
+/// `prior_txs` transactions of `block` applied.
+fn state_before_tx<Provider, EvmConfig>(
+    provider: &Provider,
+    evm_config: &EvmConfig,
+    block: &RecoveredBlock<Block>,
+    prior_txs: usize,
+) -> Result<State<StateProviderDatabase<StateProviderBox>>, TraceError>
+where
+    Provider: StateProviderFactory,
+    EvmConfig: ConfigureEvm<Primitives = EthPrimitives>,
+{
+    let state = provider.history_by_block_hash(block.header().parent_hash())?;
+    let mut db = State::builder()
+        .with_database(StateProviderDatabase::new(state))
+        .with_bundle_update()
+        .build();
+    let execution = |e: &dyn std::fmt::Display| TraceError::Execution(e.to_string());
+    {
+        let mut executor = evm_config
+            .executor_for_block(&mut db, block.sealed_block())
+            .map_err(|e| execution(&e))?;
+        executor
+            .apply_pre_execution_changes()
+            .map_err(|e| execution(&e))?;
+        for prior in block.transactions_recovered().take(prior_txs) {
+            executor
+                .execute_transaction(prior)
+                .map_err(|e| execution(&e))?;
+        }
+    }
+    Ok(db)
+}
+
+#[async_trait::async_trait]
+impl<Provider, EvmConfig> DebugApiServer for DebugRpc<Provider, EvmConfig>
+where
+    Provider: BlockReader<Block = Block>
+        + TransactionsProvider
+        + StateProviderFactory
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+    EvmConfig: ConfigureEvm<Primitives = EthPrimitives> + Clone + Send + Sync + 'static,
+{
+    async fn trace_transaction(
+        &self,
+        tx_hash: B256,
+        opts: Option<GethDebugTracingOptions>,
+    ) -> RpcResult<GethTrace> {
+        // Replaying a block is CPU-bound; keep it off the RPC worker threads.
+        let this = self.clone();
+        tokio::task::spawn_blocking(move || this.trace(tx_hash, opts.unwrap_or_default()))
+            .await
+            .map_err(|e| TraceError::Execution(e.to_string()))?
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genesis::create_dev_genesis;
+    use crate::signer::dev::DEV_PRIVATE_KEYS;
+    use alloy_consensus::{Header, SignableTransaction, TxEip1559};
+    use alloy_primitives::{Address, TxKind, U256};
+    use alloy_signer::SignerSync;
+    use alloy_signer_local::PrivateKeySigner;
+    use jsonrpsee::types::error::INVALID_PARAMS_CODE;
+    use reth_chainspec::{ChainSpec, EthChainSpec};
+    use reth_ethereum::provider::providers::BlockchainProvider;
+    use reth_ethereum::provider::test_utils::create_test_provider_factory_with_chain_spec;
+    use reth_ethereum::{BlockBody, TransactionSigned};
+    use reth_evm_ethereum::EthEvmConfig;
+    use reth_storage_api::{BlockWriter, DBProvider, DatabaseProviderFactory};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_tracer_selection_from_opts() {
+        let opts: GethDebugTracingOptions =
+            serde_json::from_str(r#"{"disableStorage": true, "disableMemory": true}"#).unwrap();
+        assert_eq!(
+            TracerKind::from_opts(&opts).unwrap(),
+            TracerKind::StructLogger
+        );
+        assert_eq!(opts.config.disable_storage, Some(true));
+        assert_eq!(opts.config.disable_memory, Some(true));
+
+        let opts: GethDebugTracingOptions = serde_json::from_str(
+            r#"{"tracer": "callTracer", "tracerConfig": {"onlyTopCall": true}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            TracerKind::from_opts(&opts).unwrap(),
+            TracerKind::CallTracer
+        );
+        let call_config = opts.tracer_config.into_call_config().unwrap();
+        assert_eq!(call_config.only_top_call, Some(true));
+
+        let opts: GethDebugTracingOptions =
+            serde_json::from_str(r#"{"tracer": "prestateTracer"}"#).unwrap();
+        let err = TracerKind::from_opts(&opts).unwrap_err();
+        assert!(matches!(err, TraceError::UnsupportedTracer(_)), "{err}");
+        assert_eq!(ErrorObjectOwned::from(err).code(), INVALID_PARAMS_CODE);
+    }
+
+    #[test]
+    fn test_trace_block_executes_stored_block() {
+        let chain_spec = Arc::new(ChainSpec::from_genesis(create_dev_genesis()));
+        let factory = create_test_provider_factory_with_chain_spec(chain_spec.clone());
+        reth_db_common::init::init_genesis(&factory).unwrap();
+
+        // Block 1: two transfers from the first dev account. The second only
+        // executes if the first one's nonce bump was committed.
+        let key: PrivateKeySigner = DEV_PRIVATE_KEYS[0].parse().unwrap();
+        let recipient = Address::with_last_byte(0xbb);
+        let transfer = |nonce| -> TransactionSigned {
+            let tx = TxEip1559 {
+                chain_id: chain_spec.chain().id(),
+                nonce,
+                gas_limit: 21_000,
+                max_fee_per_gas: 2_000_000_000,
+                to: TxKind::Call(recipient),
+                value: U256::from(1),
+                ..Default::default()
+            };
+            let signature = key.sign_hash_sync(&tx.signature_hash()).unwrap();
+            tx.into_signed(signature).into()
+        };
+        let genesis = chain_spec.genesis_header();
+        let header = Header {
+            parent_hash: chain_spec.genesis_hash(),
+            number: genesis.number + 1,
+            timestamp: genesis.timestamp + 2,
+            gas_limit: genesis.gas_limit,
+            base_fee_per_gas: genesis.base_fee_per_gas,
+            blob_gas_used: Some(0),
+            excess_blob_gas: Some(0),
+            parent_beacon_block_root: Some(B256::ZERO),
+            ..Default::default()
+        };
+        let body = BlockBody {
+            transactions: vec![transfer(0), transfer(1)],
+            ..Default::default()
+        };
+        let tx_hashes: Vec<_> = body.transactions.iter().map(|tx| *tx.tx_hash()).collect();
+        let block = RecoveredBlock::new_unhashed(
+            Block { header, body },
+            vec![key.address(), key.address()],
+        );
+        let provider_rw = factory.database_provider_rw().unwrap();
+        provider_rw.insert_block(block).unwrap();
+        provider_rw.commit().unwrap();
+
+        let provider = BlockchainProvider::new(factory).unwrap();
+        let evm_config = EthEvmConfig::new(chain_spec);
+        let traces = trace_block(
+            &provider,
+            &evm_config,
+            BlockId::number(1),
+            GethCallConfig::default(),
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(traces.len(), 2);
+        for (trace, tx_hash) in traces.into_iter().zip(tx_hashes) {
+            let TraceResult::Success {
+                result: GethTrace::CallTracer(frame),
+                tx_hash: traced,
+            } = trace
+            else {
+                panic!("expected a call frame");
+            };
+            assert_eq!(traced, Some(tx_hash));
+            assert_eq!(frame.from, key.address());
+            assert_eq!(frame.to, Some(recipient));
+            assert_eq!(frame.value, Some(U256::from(1)));
+            assert_eq!(frame.gas_used, U256::from(21_000));
+            assert!(frame.error.is_none(), "{:?}", frame.error);
+        }
+        assert!(trace_block(
+            &provider,
+            &evm_config,
+            BlockId::number(2),
+            GethCallConfig::default()
+        )
+        .unwrap()
+        .is_none());
+    }
+}
@@ -0,0 +1,302 @@
+//! Gas estimation under the governed calldata cost for `meow_estimateGas`.
+//!
+//! The node's EVM charges `ChainConfig.calldataGasPerByte` per calldata byte in
+//! intrinsic gas, so a transaction uses (and needs) less gas than mainnet pricing
+//! suggests. [`estimate_with_evm`] runs the transaction on the node's EVM and
+//! reports both: the gas it will use, and the lowest gas limit it succeeds with.
+//!
+//! Like `eth_estimateGas`, the request is converted with Reth's [`TryIntoTxEnv`]
+//! (transaction type, nonce, chain id, authorization and blob fields) and run in
+//! the environment of the block after the head.
+
+use super::{internal_error, invalid_params};
+use crate::onchain::{read_chain_config, StateProviderStorageReader};
+use alloy_consensus::Header;
+use alloy_evm::block::BlockExecutorFactory;
+use alloy_evm::revm::context::TxEnv;
+use alloy_evm::revm::context_interface::result::ExecutionResult;
+use alloy_evm::{Evm, EvmEnv, EvmFactory};
+use alloy_primitives::Bytes;
+use alloy_rpc_types_eth::TransactionRequest;
+use jsonrpsee::types::error::ErrorObjectOwned;
+use reth_ethereum::provider::ProviderError;
+use reth_ethereum::storage::StateProviderFactory;
+use reth_ethereum::EthPrimitives;
+use reth_evm::{ConfigureEvm, NextBlockEnvAttributes};
+use reth_revm::database::StateProviderDatabase;
+use reth_revm::db::State;
+use reth_rpc_convert::TryIntoTxEnv;
+use reth_storage_api::{BlockNumReader, HeaderProvider};
+use serde::Serialize;
+
+/// Response for `meow_estimateGas`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasEstimate {
+    /// Gas the transaction uses, charged at the governed calldata cost
+    pub gas_used: u64,
+    /// Lowest gas limit the transaction succeeds with (at least the intrinsic gas,
+    /// and from Prague the EIP-7623 calldata floor)
+    pub gas_limit: u64,
+    /// Live `ChainConfig.calldataGasPerByte` (`null` without the contract, in which
+    /// case the node's static setting applied)
+    pub calldata_gas_per_byte: Option<u64>,
+}
+
+/// Errors from estimating a transaction's gas.
+#[derive(Debug, thiserror::Error)]
+pub enum EstimateError {
+    /// The transaction is invalid even at the gas cap (e.g. insufficient funds).
+    #[error("invalid transaction: {0}")]
+    Invalid(String),
+
+    /// The transaction reverts even at the gas cap.
+    #[error("execution reverted: {0}")]
+    Reverted(Bytes),
+
+    /// The transaction halts even at the gas cap (e.g. out of gas, bad opcode).
+    #[error("execution halted: {0}")]
+    Halted(String),
+
+    /// The chain head is missing from the database.
+    #[error("chain head not found")]
+    HeadNotFound,
+
+    /// Reading the latest state failed.
+    #[error(transparent)]
+    Provider(#[from] ProviderError),
+}
+
+impl From<EstimateError> for ErrorObjectOwned {
+    fn from(err: EstimateError) -> Self {
+        match err {
+            EstimateError::Invalid(_) | EstimateError::Reverted(_) | EstimateError::Halted(_) => {
+                invalid_params(err.to_string())
+            }
+            EstimateError::HeadNotFound | EstimateError::Provider(_) => {
+                internal_error(err.to_string())
+            }
+        }
+    }
+}
+
+/// Estimate `tx` on `evm`, searching gas limits up to `tx.gas_limit`.
+///
+/// Nothing is committed, so `evm` can be reused. Returns `(gas_used, gas_limit)`.
+pub fn estimate_with_evm<E>(evm: &mut E, tx: TxEnv) -> Result<(u64, u64), EstimateError>
+where
+    E: Evm<Tx = TxEnv>,
+{
+    let cap = tx.gas_limit;
+    let mut run = |gas_limit: u64| {
+        evm.transact(TxEnv {
+            gas_limit,
+            ..tx.clone()
+        })
+        .map(|outcome| outcome.result)
+        .map_err(|e| e.to_string())
+    };
+
+    let mut gas_used = match run(cap).map_err(EstimateError::Invalid)? {
+        ExecutionResult::Success { gas_used, .. } => gas_used,
+        ExecutionResult::Revert { output, .. } => return Err(EstimateError::Reverted(output)),
+        ExecutionResult::Halt { reason, .. } => {
+            return Err(EstimateError::Halted(format!("{reason:?}")))
+        }
+    };
+
+    // No limit below the gas used can succeed; above it, refunds, the 63/64 call
+    // rule and the EIP-7623 calldata floor can each require more.
+    let (mut low, mut high) = (gas_used.saturating_sub(1), cap);
+    while low + 1 < high {
+        let mid = low + (high - low) / 2;
+        match run(mid) {
+            Ok(result) if result.is_success() => {
+                high = mid;
+                gas_used = result.gas_used();
+            }
+            _ => low = mid,
+        }
+    }
+    Ok((gas_used, high))
+}
+
+/// Convert `request` to the transaction run in `evm_env`, as `eth_estimateGas`
+/// does: no fee is charged, and without a `nonce` the sender's isn't checked.
+pub fn request_tx_env<Spec>(
+    request: TransactionRequest,
+    evm_env: &mut EvmEnv<Spec>,
+) -> Result<TxEnv, EstimateError> {
+    evm_env.cfg_env.disable_base_fee = true;
+    evm_env.cfg_env.disable_eip3607 = true;
+    evm_env.cfg_env.disable_nonce_check = request.nonce.is_none();
+    request
+        .try_into_tx_env(&evm_env.cfg_env, &evm_env.block_env)
+        .map_err(|e| EstimateError::Invalid(e.to_string()))
+}
+
+/// Estimate `request` on top of the latest state, in the next block's
+/// environment, capped at its `gas` (default: the block gas limit).
+pub fn estimate_gas<Provider, EvmConfig>(
+    provider: &Provider,
+    evm_config: &EvmConfig,
+    request: TransactionRequest,
+) -> Result<GasEstimate, EstimateError>
+where
+    Provider: StateProviderFactory + HeaderProvider<Header = Header> + BlockNumReader,
+    EvmConfig: ConfigureEvm<
+        Primitives = EthPrimitives,
+        NextBlockEnvCtx = NextBlockEnvAttributes,
+        BlockExecutorFactory: BlockExecutorFactory<EvmFactory: EvmFactory<Tx = TxEnv>>,
+    >,
+{
+    let head = provider
+        .header_by_number(provider.best_block_number()?)?
+        .ok_or(EstimateError::HeadNotFound)?;
+    let state = provider.latest()?;
+    let calldata_gas_per_byte = read_chain_config(&StateProviderStorageReader::new(state.as_ref()))
+        .map(|config| config.calldata_gas_per_byte);
+
+    let next_block = NextBlockEnvAttributes {
+        timestamp: head.timestamp + 1,
+        suggested_fee_recipient: head.beneficiary,
+        prev_randao: head.mix_hash,
+        gas_limit: head.gas_limit,
+        parent_beacon_block_root: head.parent_beacon_block_root,
+        withdrawals: None,
+        extra_data: Bytes::new(),
+    };
+    let mut evm_env = evm_config
+        .next_evm_env(&head, &next_block)
+        .map_err(|e| EstimateError::Invalid(e.to_string()))?;
+    let tx = request_tx_env(request, &mut evm_env)?;
+
+    let mut db = State::builder()
+        .with_database(StateProviderDatabase::new(state))
+        .build();
+    let mut evm = evm_config.evm_with_env(&mut db, evm_env);
+    let (gas_used, gas_limit) = estimate_with_evm(&mut evm, tx)?;
+    Ok(GasEstimate {
+        gas_used,
+        gas_limit,
+        calldata_gas_per_byte,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evm::PoaEvmFactory;
+    use crate::genesis::CHAIN_CONFIG_ADDRESS;
+    use crate::onchain::chain_config_slots;
+    use alloy_evm::revm::bytecode::Bytecode;
+    use alloy_evm::revm::database::{CacheDB, EmptyDB};
+    use alloy_evm::revm::primitives::hardfork::SpecId;
+    use alloy_evm::revm::primitives::TxKind;
+    use alloy_evm::revm::state::AccountInfo;
+    use alloy_primitives::{Address, U256};
+
+    #[test]
+    fn test_estimate_matches_execution_at_governed_calldata_cost() {
+        // A contract that just STOPs, and ChainConfig.calldataGasPerByte = 4
+        let to = Address::with_last_byte(0xbb);
+        let mut db = CacheDB::new(EmptyDB::default());
+        db.insert_account_info(
+            to,
+            AccountInfo {
+                code: Some(Bytecode::new_raw(Bytes::from_static(&[0x00]))),
+                ..Default::default()
+            },
+        );
+        db.insert_account_info(
+            CHAIN_CONFIG_ADDRESS,
+            AccountInfo {
+                nonce: 1,
+                ..Default::default()
+            },
+        );
+        db.insert_account_storage(
+            CHAIN_CONFIG_ADDRESS,
+            chain_config_slots::CALLDATA_GAS_PER_BYTE,
+            U256::from(4),
+        )
+        .unwrap();
+        // Cancun: no EIP-7623 calldata floor
+        let mut env = EvmEnv::default();
+        env.cfg_env.spec = SpecId::CANCUN;
+        let tx = TxEnv {
+            kind: TxKind::Call(to),
+            data: Bytes::from(vec![0xff; 1_000]),
+            gas_limit: 1_000_000,
+            ..Default::default()
+        };
+        let factory = PoaEvmFactory::new(None, 16);
+
+        let mut evm = factory.create_evm(db.clone(), env.clone());
+        let (gas_used, gas_limit) = estimate_with_evm(&mut evm, tx.clone()).unwrap();
+        // Charged and limited at 4 gas/byte
+        assert_eq!(gas_used, 21_000 + 4 * 1_000);
+        assert_eq!(gas_limit, gas_used);
+
+        // Executing with the estimated limit uses exactly the estimated gas
+        let executed = factory
+            .create_evm(db.clone(), env.clone())
+            .transact(TxEnv {
+                gas_limit,
+                ..tx.clone()
+            })
+            .unwrap()
+            .result;
+        assert!(executed.is_success());
+        assert_eq!(executed.gas_used(), gas_used);
+        // One gas less is rejected
+        assert!(factory
+            .create_evm(db, env)
+            .transact(TxEnv {
+                gas_limit: gas_limit - 1,
+                ..tx
+            })
+            .is_err());
+    }
+
+    #[test]
+    fn test_request_tx_env_keeps_typed_fields() {
+        use alloy_eips::eip7702::{Authorization, SignedAuthorization};
+
+        let mut env = EvmEnv::default();
+        env.cfg_env.chain_id = 9323310;
+        env.block_env.gas_limit = 30_000_000;
+        let authorization = SignedAuthorization::new_unchecked(
+            Authorization {
+                chain_id: U256::from(9323310),
+                address: Address::with_last_byte(0xde),
+                nonce: 0,
+            },
+            0,
+            U256::from(1),
+            U256::from(1),
+        );
+        let request = TransactionRequest {
+            from: Some(Address::with_last_byte(0xaa)),
+            to: Some(TxKind::Call(Address::with_last_byte(0xbb))),
+            nonce: Some(7),
+            max_fee_per_gas: Some(2_000_000_000),
+            max_priority_fee_per_gas: Some(1),
+            authorization_list: Some(vec![authorization]),
+            ..Default::default()
+        };
+
+        let tx = request_tx_env(request, &mut env).unwrap();
+        assert_eq!(tx.tx_type, 4);
+        assert_eq!(tx.nonce, 7);
+        assert_eq!(tx.chain_id, Some(9323310));
+        assert_eq!(tx.authorization_list.len(), 1);
+        assert_eq!(tx.gas_limit, 30_000_000);
+        // A requested nonce is checked; fees are not charged
+        assert!(!env.cfg_env.disable_nonce_check);
+        assert!(env.cfg_env.disable_base_fee);
+
+        request_tx_env(TransactionRequest::default(), &mut env).unwrap();
+        assert!(env.cfg_env.disable_nonce_check);
+    }
+}
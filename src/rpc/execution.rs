@@ -0,0 +1,91 @@
+//! Re-execution on the node's EVM for `meow_replayBlock`, `meow_traceBlock` and
+//! `meow_estimateGas`.
+//!
+//! All three run blocks or transactions through the node's EVM config against
+//! stored state, so they share one object-safe [`ExecutionSource`], implemented
+//! by [`Reexecutor`] over the node's provider and EVM config.
+
+use super::debug::{self, TraceError};
+use super::estimate::{self, EstimateError, GasEstimate};
+use super::replay::{self, ReplayError};
+use crate::statediff::StateDiff;
+use alloy_consensus::Header;
+use alloy_eips::BlockId;
+use alloy_evm::block::BlockExecutorFactory;
+use alloy_evm::revm::context::TxEnv;
+use alloy_evm::EvmFactory;
+use alloy_rpc_types_eth::TransactionRequest;
+use alloy_rpc_types_trace::geth::{GethCallConfig, TraceResult};
+use reth_chainspec::{ChainSpecProvider, EthChainSpec};
+use reth_ethereum::storage::StateProviderFactory;
+use reth_ethereum::{Block, EthPrimitives};
+use reth_evm::{ConfigureEvm, NextBlockEnvAttributes};
+use reth_storage_api::{BlockIdReader, BlockReader, HeaderProvider};
+
+/// Re-executes stored blocks and candidate transactions.
+pub trait ExecutionSource: Send + Sync {
+    /// State diff of `block`, or `None` if the block is unknown.
+    fn replay_block(&self, block: BlockId) -> Result<Option<StateDiff>, ReplayError>;
+
+    /// `callTracer` output of every transaction in `block`, in block order, or
+    /// `None` if the block is unknown.
+    fn trace_block(
+        &self,
+        block: BlockId,
+        call_config: GethCallConfig,
+    ) -> Result<Option<Vec<TraceResult>>, TraceError>;
+
+    /// Estimate `request` on top of the latest state, in the next block's
+    /// environment, capped at its `gas` (default: the block gas limit).
+    fn estimate_gas(&self, request: TransactionRequest) -> Result<GasEstimate, EstimateError>;
+}
+
+/// [`ExecutionSource`] over the node's provider and EVM config.
+#[derive(Debug, Clone)]
+pub struct Reexecutor<Provider, EvmConfig> {
+    provider: Provider,
+    evm_config: EvmConfig,
+}
+
+impl<Provider, EvmConfig> Reexecutor<Provider, EvmConfig> {
+    /// Create a new Reexecutor.
+    pub fn new(provider: Provider, evm_config: EvmConfig) -> Self {
+        Self {
+            provider,
+            evm_config,
+        }
+    }
+}
+
+impl<Provider, EvmConfig> ExecutionSource for Reexecutor<Provider, EvmConfig>
+where
+    Provider: BlockReader<Block = Block>
+        + HeaderProvider<Header = Header>
+        + BlockIdReader
+        + StateProviderFactory
+        + ChainSpecProvider<ChainSpec: EthChainSpec>
+        + Send
+        + Sync,
+    EvmConfig: ConfigureEvm<
+            Primitives = EthPrimitives,
+            NextBlockEnvCtx = NextBlockEnvAttributes,
+            BlockExecutorFactory: BlockExecutorFactory<EvmFactory: EvmFactory<Tx = TxEnv>>,
+        > + Send
+        + Sync,
+{
+    fn replay_block(&self, block: BlockId) -> Result<Option<StateDiff>, ReplayError> {
+        replay::replay_block(&self.provider, &self.evm_config, block)
+    }
+
+    fn trace_block(
+        &self,
+        block: BlockId,
+        call_config: GethCallConfig,
+    ) -> Result<Option<Vec<TraceResult>>, TraceError> {
+        debug::trace_block(&self.provider, &self.evm_config, block, call_config)
+    }
+
+    fn estimate_gas(&self, request: TransactionRequest) -> Result<GasEstimate, EstimateError> {
+        estimate::estimate_gas(&self.provider, &self.evm_config, request)
+    }
+}
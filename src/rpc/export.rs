@@ -0,0 +1,320 @@
+//! State export for `meow_exportState`.
+//!
+//! Walks every account and storage slot as of a block and streams them into a
+//! genesis file ([`GenesisWriter`]), so a new network can be forked from the current
+//! state or a node bootstrapped without syncing history.
+//!
+//! The plain state tables only hold the latest values, so the exporter walks the
+//! plain state in address order, merges in the keys changed after the target block
+//! (accounts and slots that existed then but were since cleared), and reads each
+//! one through a historical state provider. Only those changed keys and one
+//! account's storage are held in memory; everything else goes straight to disk.
+//!
+//! Exports are only written inside the node's export directory
+//! (`<datadir>/exports`), under a plain file name checked by [`export_path`].
+
+use alloy_consensus::BlockHeader;
+use alloy_genesis::{Genesis, GenesisAccount};
+use alloy_primitives::{Address, B256};
+use reth_chainspec::{ChainSpecProvider, EthChainSpec};
+use reth_db::cursor::{DbCursorRO, DbDupCursorRO};
+use reth_db::models::BlockNumberAddress;
+use reth_db::tables;
+use reth_db::transaction::DbTx;
+use reth_db::DatabaseError;
+use reth_ethereum::provider::{ProviderError, ProviderResult};
+use reth_ethereum::storage::StateProviderFactory;
+use reth_storage_api::{DBProvider, DatabaseProviderFactory, HeaderProvider, StateProvider};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Component, Path, PathBuf};
+
+use crate::genesis::GenesisWriter;
+
+/// Errors from exporting state.
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    /// Reading the state failed (e.g. history pruned past the block).
+    #[error(transparent)]
+    Provider(#[from] ProviderError),
+
+    /// Walking the state tables failed.
+    #[error(transparent)]
+    Database(#[from] DatabaseError),
+
+    /// Writing the export file failed.
+    #[error("writing export: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The target file exists; exports never overwrite.
+    #[error("{0} already exists")]
+    FileExists(PathBuf),
+
+    /// The requested name isn't a plain file name (separators, `..`, absolute paths).
+    #[error("invalid export file name {0:?}: expected a plain file name")]
+    InvalidFileName(String),
+}
+
+/// Resolve `file_name` inside the export directory `dir`.
+///
+/// Only a single plain path component is accepted, so callers can't write outside
+/// `dir` with separators, `..` or an absolute path.
+pub fn export_path(dir: &Path, file_name: &str) -> Result<PathBuf, ExportError> {
+    let mut components = Path::new(file_name).components();
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(name)), None) => Ok(dir.join(name)),
+        _ => Err(ExportError::InvalidFileName(file_name.to_string())),
+    }
+}
+
+/// Result of a completed export.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateExport {
+    /// Block whose post-state was exported.
+    pub block_number: u64,
+    /// Hash of that block.
+    pub block_hash: B256,
+    /// Accounts written to the alloc.
+    pub accounts: u64,
+    /// Non-zero storage slots written across all accounts.
+    pub storage_slots: u64,
+    /// Where the genesis file was written.
+    pub path: PathBuf,
+}
+
+/// Exports the state at a block as a genesis file.
+pub trait StateExportSource: Send + Sync {
+    /// Write the state after `block_number` to `path`, or return `None` if the
+    /// block is unknown. Fails rather than overwriting an existing file.
+    fn export_state(
+        &self,
+        block_number: u64,
+        path: &Path,
+    ) -> Result<Option<StateExport>, ExportError>;
+}
+
+/// [`StateExportSource`] over the node's database provider.
+#[derive(Debug, Clone)]
+pub struct StateExporter<Provider> {
+    provider: Provider,
+}
+
+impl<Provider> StateExporter<Provider> {
+    /// Create a new StateExporter.
+    pub fn new(provider: Provider) -> Self {
+        Self { provider }
+    }
+}
+
+impl<Provider> StateExporter<Provider>
+where
+    Provider: DatabaseProviderFactory<Provider: DBProvider>,
+{
+    /// Accounts and slots changed after `block_number`, which may be missing from
+    /// the plain state.
+    fn changed_keys(&self, block_number: u64) -> ProviderResult<BTreeMap<Address, BTreeSet<B256>>> {
+        let db = self.provider.database_provider_ro()?;
+        let tx = db.tx_ref();
+        let mut keys: BTreeMap<Address, BTreeSet<B256>> = BTreeMap::new();
+
+        let mut accounts = tx.cursor_dup_read::<tables::AccountChangeSets>()?;
+        for entry in accounts.walk_range(block_number + 1..)? {
+            keys.entry(entry?.1.address).or_default();
+        }
+        let mut storage = tx.cursor_dup_read::<tables::StorageChangeSets>()?;
+        let from = BlockNumberAddress((block_number + 1, Address::ZERO));
+        for entry in storage.walk_range(from..)? {
+            let (key, slot) = entry?;
+            keys.entry(key.address()).or_default().insert(slot.key);
+        }
+        Ok(keys)
+    }
+
+    /// Stream every account that may exist at `state`'s block, in address order,
+    /// into a genesis file at `path`. Returns the accounts and slots written.
+    fn write_export(
+        &self,
+        path: &Path,
+        template: &Genesis,
+        state: &dyn StateProvider,
+        block_number: u64,
+    ) -> Result<(u64, u64), ExportError> {
+        let mut changed = self.changed_keys(block_number)?.into_iter().peekable();
+        let db = self.provider.database_provider_ro()?;
+        let tx = db.tx_ref();
+        let mut accounts = tx.cursor_read::<tables::PlainAccountState>()?;
+        let mut storage = tx.cursor_dup_read::<tables::PlainStorageState>()?;
+
+        let mut writer = GenesisWriter::new(BufWriter::new(File::create(path)?), template)?;
+        for entry in accounts.walk(None)? {
+            let address = entry?.0;
+            while let Some((cleared, slots)) = changed.next_if(|(a, _)| *a < address) {
+                write_account(&mut writer, state, cleared, slots)?;
+            }
+            let mut slots = changed
+                .next_if(|(a, _)| *a == address)
+                .map(|(_, slots)| slots)
+                .unwrap_or_default();
+            for slot in storage.walk_dup(Some(address), None)? {
+                slots.insert(slot?.1.key);
+            }
+            write_account(&mut writer, state, address, slots)?;
+        }
+        for (cleared, slots) in changed {
+            write_account(&mut writer, state, cleared, slots)?;
+        }
+
+        let counts = (writer.accounts(), writer.storage_slots());
+        writer.finish()?;
+        Ok(counts)
+    }
+}
+
+impl<Provider> StateExportSource for StateExporter<Provider>
+where
+    Provider: DatabaseProviderFactory<Provider: DBProvider>
+        + StateProviderFactory
+        + HeaderProvider
+        + ChainSpecProvider<ChainSpec: EthChainSpec>
+        + Send
+        + Sync,
+{
+    fn export_state(
+        &self,
+        block_number: u64,
+        path: &Path,
+    ) -> Result<Option<StateExport>, ExportError> {
+        if path.exists() {
+            return Err(ExportError::FileExists(path.to_path_buf()));
+        }
+        let Some(header) = self.provider.sealed_header(block_number)? else {
+            return Ok(None);
+        };
+        let state = self.provider.history_by_block_number(block_number)?;
+
+        // Header fields come from the chain's genesis, with the block's gas limit and
+        // base fee so the forked chain starts where this one is.
+        let mut template = self.provider.chain_spec().genesis().clone();
+        template.alloc.clear();
+        template.gas_limit = header.gas_limit();
+        template.base_fee_per_gas = header.base_fee_per_gas().map(u128::from);
+
+        // Write next to the target and rename, so a failed export leaves no partial file.
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        let tmp = PathBuf::from(tmp);
+        let written = self
+            .write_export(&tmp, &template, state.as_ref(), block_number)
+            .and_then(|counts| {
+                std::fs::rename(&tmp, path)?;
+                Ok(counts)
+            });
+        let (accounts, storage_slots) = written.inspect_err(|_| {
+            let _ = std::fs::remove_file(&tmp);
+        })?;
+
+        Ok(Some(StateExport {
+            block_number,
+            block_hash: header.hash(),
+            accounts,
+            storage_slots,
+            path: path.to_path_buf(),
+        }))
+    }
+}
+
+/// Write `address` as of `state` with whichever of `slots` are non-zero. Accounts
+/// that don't exist at that block are skipped.
+fn write_account<W: Write>(
+    writer: &mut GenesisWriter<W>,
+    state: &dyn StateProvider,
+    address: Address,
+    slots: BTreeSet<B256>,
+) -> Result<(), ExportError> {
+    let Some(account) = state.basic_account(&address)? else {
+        return Ok(());
+    };
+    let code = state
+        .account_code(&address)?
+        .map(|code| code.original_bytes())
+        .filter(|code| !code.is_empty());
+    let mut storage = BTreeMap::new();
+    for slot in slots {
+        if let Some(value) = state.storage(address, slot)?.filter(|v| !v.is_zero()) {
+            storage.insert(slot, B256::from(value));
+        }
+    }
+    let entry = GenesisAccount {
+        balance: account.balance,
+        nonce: (account.nonce > 0).then_some(account.nonce),
+        code,
+        storage: (!storage.is_empty()).then_some(storage),
+        private_key: None,
+    };
+    writer.write_account(address, &entry)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genesis::{create_dev_genesis, read_genesis_file};
+    use reth_chainspec::ChainSpec;
+    use reth_ethereum::provider::providers::BlockchainProvider;
+    use reth_ethereum::provider::test_utils::create_test_provider_factory_with_chain_spec;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_export_path_stays_in_export_dir() {
+        let dir = Path::new("/data/exports");
+        assert_eq!(
+            export_path(dir, "state.json").unwrap(),
+            dir.join("state.json")
+        );
+        for name in [
+            "",
+            ".",
+            "..",
+            "../state.json",
+            "a/state.json",
+            "/etc/passwd",
+        ] {
+            assert!(
+                matches!(export_path(dir, name), Err(ExportError::InvalidFileName(_))),
+                "{name:?} accepted"
+            );
+        }
+    }
+
+    #[test]
+    fn test_export_round_trips_state_root() {
+        let chain_spec = Arc::new(ChainSpec::from_genesis(create_dev_genesis()));
+        let factory = create_test_provider_factory_with_chain_spec(chain_spec.clone());
+        reth_db_common::init::init_genesis(&factory).unwrap();
+        let exporter = StateExporter::new(BlockchainProvider::new(factory).unwrap());
+
+        let dir = std::env::temp_dir().join(format!(
+            "meowchain-export-{}",
+            hex::encode(&B256::random()[..8])
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("state.json");
+        let export = exporter.export_state(0, &path).unwrap().unwrap();
+        assert_eq!(export.block_hash, chain_spec.genesis_hash());
+
+        // A chain launched from the export starts from the same state
+        let loaded = ChainSpec::from_genesis(read_genesis_file(&path).unwrap());
+        assert_eq!(
+            loaded.genesis_header().state_root,
+            chain_spec.genesis_header().state_root
+        );
+        assert!(exporter
+            .export_state(1, &dir.join("missing.json"))
+            .unwrap()
+            .is_none());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
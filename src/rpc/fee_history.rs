@@ -0,0 +1,208 @@
+//! POA-aware fee history for `meow_getFeeHistory`.
+//!
+//! Returns the standard `eth_feeHistory` fields, plus the recovered signer of each
+//! block and whether it sealed in turn, so fee analytics can be lined up against
+//! authority behaviour (e.g. an out-of-turn signer packing cheaper blocks).
+//! Blob fee fields are left empty.
+
+use alloy_consensus::{BlockHeader, Header, Transaction, TxReceipt};
+use alloy_eips::eip1559::BaseFeeParams;
+use alloy_primitives::Address;
+use alloy_rpc_types_eth::FeeHistory;
+use reth_chainspec::EthChainSpec;
+use reth_ethereum::provider::ProviderResult;
+use reth_ethereum::{Block, Receipt};
+use serde::Serialize;
+use std::sync::Arc;
+
+use super::ChainHistorySource;
+use crate::chainspec::PoaChainSpec;
+use crate::consensus::PoaConsensus;
+
+/// Maximum number of blocks returned by `meow_getFeeHistory` (same cap as Reth's
+/// `eth_feeHistory`).
+pub const MAX_FEE_HISTORY_BLOCKS: u64 = 1024;
+
+/// Response for `meow_getFeeHistory`: `eth_feeHistory` plus per-block authorship.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoaFeeHistory {
+    /// The standard fee history fields.
+    #[serde(flatten)]
+    pub history: FeeHistory,
+    /// Recovered signer of each block, oldest first (`null` if the seal is invalid).
+    pub signers: Vec<Option<Address>>,
+    /// Whether each block was sealed by its in-turn signer (`null` if unknown).
+    pub in_turn: Vec<Option<bool>>,
+}
+
+/// A block's header and the `(gas used, effective tip)` of each transaction.
+#[derive(Debug, Clone, Default)]
+pub struct FeeHistoryBlock {
+    pub header: Header,
+    pub tx_tips: Vec<(u64, u128)>,
+}
+
+impl FeeHistoryBlock {
+    /// Take the header and per-transaction tips from a block and its receipts.
+    pub fn from_block(block: Block, receipts: &[Receipt]) -> Self {
+        let base_fee = block.header.base_fee_per_gas().unwrap_or_default();
+        let mut prev_cumulative = 0;
+        let tx_tips = block
+            .body
+            .transactions
+            .iter()
+            .zip(receipts)
+            .map(|(tx, receipt)| {
+                let cumulative = receipt.cumulative_gas_used();
+                let gas_used = cumulative - prev_cumulative;
+                prev_cumulative = cumulative;
+                (
+                    gas_used,
+                    tx.effective_tip_per_gas(base_fee).unwrap_or_default(),
+                )
+            })
+            .collect();
+        Self {
+            header: block.header,
+            tx_tips,
+        }
+    }
+}
+
+/// Check that reward percentiles are within 0–100 and non-decreasing.
+pub fn validate_percentiles(percentiles: &[f64]) -> Result<(), String> {
+    let mut prev = 0.0;
+    for &p in percentiles {
+        if !(0.0..=100.0).contains(&p) || p < prev {
+            return Err(format!(
+                "invalid reward percentiles {percentiles:?}: must be non-decreasing values in 0..=100"
+            ));
+        }
+        prev = p;
+    }
+    Ok(())
+}
+
+/// Tip paid at each percentile of a block's gas, weighted by gas used (as in
+/// geth's `eth_feeHistory`). Empty blocks report zero.
+pub fn rewards_at_percentiles(tx_tips: &[(u64, u128)], percentiles: &[f64]) -> Vec<u128> {
+    let mut sorted = tx_tips.to_vec();
+    sorted.sort_by_key(|&(_, tip)| tip);
+    let total_gas: u64 = sorted.iter().map(|&(gas, _)| gas).sum();
+
+    percentiles
+        .iter()
+        .map(|&p| {
+            let threshold = (total_gas as f64 * p / 100.0) as u64;
+            let mut cumulative = 0;
+            sorted
+                .iter()
+                .find(|&&(gas, _)| {
+                    cumulative += gas;
+                    cumulative >= threshold
+                })
+                .or(sorted.last())
+                .map_or(0, |&(_, tip)| tip)
+        })
+        .collect()
+}
+
+/// Assemble the fee history of consecutive `blocks` (oldest first).
+///
+/// The extra trailing base fee is the one the block after the newest will have,
+/// under `base_fee_params`.
+pub fn build_fee_history(
+    blocks: &[FeeHistoryBlock],
+    percentiles: &[f64],
+    consensus: &PoaConsensus,
+    base_fee_params: BaseFeeParams,
+) -> PoaFeeHistory {
+    let mut history = FeeHistory {
+        oldest_block: blocks.first().map_or(0, |b| b.header.number),
+        reward: (!percentiles.is_empty()).then(Vec::new),
+        ..Default::default()
+    };
+    let mut signers = Vec::with_capacity(blocks.len());
+    let mut in_turn = Vec::with_capacity(blocks.len());
+
+    for block in blocks {
+        let header = &block.header;
+        history
+            .base_fee_per_gas
+            .push(header.base_fee_per_gas.unwrap_or_default().into());
+        history.gas_used_ratio.push(if header.gas_limit == 0 {
+            0.0
+        } else {
+            header.gas_used as f64 / header.gas_limit as f64
+        });
+        if let Some(reward) = history.reward.as_mut() {
+            reward.push(rewards_at_percentiles(&block.tx_tips, percentiles));
+        }
+        signers.push(consensus.recover_signer(header).ok());
+        in_turn.push(consensus.is_in_turn(header));
+    }
+    if let Some(newest) = blocks.last() {
+        let next = newest.header.next_block_base_fee(base_fee_params);
+        history
+            .base_fee_per_gas
+            .push(next.unwrap_or_default().into());
+    }
+
+    PoaFeeHistory {
+        history,
+        signers,
+        in_turn,
+    }
+}
+
+/// Fee history of blocks `oldest..=newest`, or `None` if any of them is unknown.
+pub fn fee_history(
+    source: &dyn ChainHistorySource,
+    chain_spec: Arc<PoaChainSpec>,
+    oldest: u64,
+    newest: u64,
+    percentiles: &[f64],
+) -> ProviderResult<Option<PoaFeeHistory>> {
+    let mut blocks = Vec::new();
+    for number in oldest..=newest {
+        let Some(block) = source.fee_history_block(number)? else {
+            return Ok(None);
+        };
+        blocks.push(block);
+    }
+    let base_fee_params =
+        chain_spec.base_fee_params_at_timestamp(blocks.last().map_or(0, |b| b.header.timestamp));
+    let consensus = PoaConsensus::new(chain_spec);
+    Ok(Some(build_fee_history(
+        &blocks,
+        percentiles,
+        &consensus,
+        base_fee_params,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rewards_weighted_by_gas() {
+        // 21k gas at tip 1, 63k gas at tip 5
+        let tips = [(63_000, 5), (21_000, 1)];
+        assert_eq!(
+            rewards_at_percentiles(&tips, &[0.0, 25.0, 26.0, 100.0]),
+            vec![1, 1, 5, 5]
+        );
+        assert_eq!(rewards_at_percentiles(&[], &[50.0]), vec![0]);
+    }
+
+    #[test]
+    fn test_validate_percentiles() {
+        assert!(validate_percentiles(&[]).is_ok());
+        assert!(validate_percentiles(&[10.0, 10.0, 90.0]).is_ok());
+        assert!(validate_percentiles(&[50.0, 10.0]).is_err());
+        assert!(validate_percentiles(&[101.0]).is_err());
+        assert!(validate_percentiles(&[-1.0]).is_err());
+    }
+}
@@ -0,0 +1,179 @@
+//! Per-block fee breakdown for `meow_getBlockFees` and `meow_getBlockReward`.
+//!
+//! Every transaction pays `gas_used * effective_gas_price`. The base-fee part is
+//! burned (standard EIP-1559 — Meowchain doesn't redirect it), the priority part
+//! goes to the block's beneficiary, which is the Miner Proxy on production chains.
+//! Blob gas fees are not included.
+
+use alloy_consensus::{BlockHeader, Transaction, TxReceipt};
+use alloy_primitives::{Address, U256};
+use reth_ethereum::{Receipt, TransactionSigned};
+use serde::Serialize;
+
+/// Response for `meow_getBlockFees`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockFees {
+    pub block_number: u64,
+    pub base_fee_per_gas: u64,
+    pub gas_used: u64,
+    pub tx_count: usize,
+    /// Total fees paid by all transactions (`base_fees + priority_fees`).
+    pub total_fees: U256,
+    /// Base-fee portion, burned.
+    pub base_fees: U256,
+    /// Priority-fee portion, paid to `fee_recipient`.
+    pub priority_fees: U256,
+    /// Block beneficiary receiving the priority fees.
+    pub fee_recipient: Address,
+}
+
+impl BlockFees {
+    /// Compute the breakdown from a block's header, transactions and receipts.
+    ///
+    /// Per-transaction gas is the difference between consecutive cumulative gas values.
+    pub fn compute(
+        header: &impl BlockHeader,
+        transactions: &[TransactionSigned],
+        receipts: &[Receipt],
+    ) -> Self {
+        let base_fee = header.base_fee_per_gas().unwrap_or_default();
+        let mut fees = Self {
+            block_number: header.number(),
+            base_fee_per_gas: base_fee,
+            gas_used: header.gas_used(),
+            tx_count: transactions.len(),
+            fee_recipient: header.beneficiary(),
+            ..Default::default()
+        };
+
+        let mut prev_cumulative = 0;
+        for (tx, receipt) in transactions.iter().zip(receipts) {
+            let cumulative = receipt.cumulative_gas_used();
+            let gas_used = U256::from(cumulative - prev_cumulative);
+            prev_cumulative = cumulative;
+
+            let tip = tx.effective_tip_per_gas(base_fee).unwrap_or_default();
+            fees.base_fees += gas_used * U256::from(base_fee);
+            fees.priority_fees += gas_used * U256::from(tip);
+        }
+        fees.total_fees = fees.base_fees + fees.priority_fees;
+        fees
+    }
+}
+
+/// Response for `meow_getBlockReward`: the fee revenue of one block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockReward {
+    pub block_number: u64,
+    /// Priority fees paid to `coinbase` (sum of effective tip × gas used).
+    pub fees: U256,
+    /// Base fee burned (base fee × gas used).
+    pub burned: U256,
+    /// Block beneficiary receiving `fees`.
+    pub coinbase: Address,
+}
+
+impl From<&BlockFees> for BlockReward {
+    fn from(fees: &BlockFees) -> Self {
+        Self {
+            block_number: fees.block_number,
+            fees: fees.priority_fees,
+            burned: fees.base_fees,
+            coinbase: fees.fee_recipient,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{Header, Signed, TxEip1559, TxType};
+    use alloy_primitives::{Signature, TxKind};
+
+    #[test]
+    fn test_one_transaction_fee_breakdown() {
+        let coinbase = Address::with_last_byte(0xcb);
+        let header = Header {
+            number: 7,
+            base_fee_per_gas: Some(10),
+            gas_used: 21_000,
+            beneficiary: coinbase,
+            ..Default::default()
+        };
+        // max fee 15, max tip 3 → effective tip min(3, 15 - 10) = 3
+        let tx = TxEip1559 {
+            gas_limit: 50_000,
+            max_fee_per_gas: 15,
+            max_priority_fee_per_gas: 3,
+            to: TxKind::Call(Address::with_last_byte(1)),
+            ..Default::default()
+        };
+        let signed: TransactionSigned =
+            Signed::new_unhashed(tx, Signature::test_signature()).into();
+        let receipt = Receipt {
+            tx_type: TxType::Eip1559,
+            success: true,
+            cumulative_gas_used: 21_000,
+            logs: vec![],
+        };
+
+        let fees = BlockFees::compute(&header, &[signed], &[receipt]);
+        assert_eq!(fees.block_number, 7);
+        assert_eq!(fees.tx_count, 1);
+        assert_eq!(fees.base_fees, U256::from(21_000 * 10));
+        assert_eq!(fees.priority_fees, U256::from(21_000 * 3));
+        assert_eq!(fees.total_fees, U256::from(21_000 * 13));
+        assert_eq!(fees.fee_recipient, coinbase);
+    }
+
+    #[test]
+    fn test_block_reward_sums_priority_fees() {
+        let coinbase = Address::with_last_byte(0xcb);
+        let header = Header {
+            base_fee_per_gas: Some(10),
+            gas_used: 71_000,
+            beneficiary: coinbase,
+            ..Default::default()
+        };
+        let signed = |max_fee, tip| -> TransactionSigned {
+            let tx = TxEip1559 {
+                gas_limit: 100_000,
+                max_fee_per_gas: max_fee,
+                max_priority_fee_per_gas: tip,
+                to: TxKind::Call(Address::with_last_byte(1)),
+                ..Default::default()
+            };
+            Signed::new_unhashed(tx, Signature::test_signature()).into()
+        };
+        let receipt = |cumulative_gas_used| Receipt {
+            tx_type: TxType::Eip1559,
+            success: true,
+            cumulative_gas_used,
+            logs: vec![],
+        };
+        // 21k gas at tip 3, then 50k gas whose tip 5 is capped to 12 - 10 = 2
+        let fees = BlockFees::compute(
+            &header,
+            &[signed(20, 3), signed(12, 5)],
+            &[receipt(21_000), receipt(71_000)],
+        );
+
+        let reward = BlockReward::from(&fees);
+        assert_eq!(reward.fees, U256::from(21_000 * 3 + 50_000 * 2));
+        assert_eq!(reward.burned, U256::from(71_000 * 10));
+        assert_eq!(reward.coinbase, coinbase);
+    }
+
+    #[test]
+    fn test_empty_block_has_no_fees() {
+        let header = Header {
+            base_fee_per_gas: Some(7),
+            ..Default::default()
+        };
+        let fees = BlockFees::compute(&header, &[], &[]);
+        assert_eq!(fees.total_fees, U256::ZERO);
+        assert_eq!(fees.tx_count, 0);
+    }
+}
@@ -0,0 +1,90 @@
+//! Live governance state for `meow_getChainConfig`, `meow_getSignerRegistry`,
+//! `meow_getSafeInfo` and `meow_getGovernanceState`.
+//!
+//! `meow_chainConfig` and `meow_signers` report the genesis/CLI values the node
+//! launched with; [`ChainStateSource`](super::ChainStateSource) reads the
+//! `ChainConfig` and `SignerRegistry` contracts at the latest state, so tooling
+//! can confirm a governance change took effect without waiting for the next epoch
+//! refresh. It also reads who controls a Gnosis Safe such as the governance Safe,
+//! the Miner Proxy's admin (`meow_getMinerProxyAdmin`), and the state of the
+//! Timelock's scheduled operations (`meow_getTimelockOperations`).
+
+use crate::onchain::{
+    is_timelock_paused, read_chain_config, read_signer_list, read_timelock_delay,
+    read_timelock_proposer, read_timelock_timestamp, timelock_slots, DynamicChainConfig,
+    DynamicSignerList, StorageReader,
+};
+use alloy_primitives::{Address, B256};
+use serde::Serialize;
+
+/// Response for `meow_getGovernanceState`: every governance contract in one read.
+///
+/// Each field is `null` when its contract isn't deployed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GovernanceState {
+    /// Live `ChainConfig` parameters
+    pub chain_config: Option<DynamicChainConfig>,
+    /// Live `SignerRegistry` signer list and threshold
+    pub signer_registry: Option<DynamicSignerList>,
+    /// Timelock minimum delay in seconds
+    pub timelock_delay: Option<u64>,
+    /// Address allowed to queue Timelock operations
+    pub timelock_proposer: Option<Address>,
+    /// Whether the Timelock is paused
+    pub timelock_paused: Option<bool>,
+}
+
+/// Read every governance contract from `reader` at one state.
+pub fn read_governance_state(reader: &impl StorageReader) -> GovernanceState {
+    let timelock_delay = read_timelock_delay(reader);
+    let timelock_proposer = read_timelock_proposer(reader);
+    // An unset paused slot reads as `false`, so only report it with a Timelock present
+    let timelock_deployed = timelock_delay.is_some() || timelock_proposer.is_some();
+    GovernanceState {
+        chain_config: read_chain_config(reader),
+        signer_registry: read_signer_list(reader),
+        timelock_delay,
+        timelock_proposer,
+        timelock_paused: timelock_deployed.then(|| is_timelock_paused(reader)),
+    }
+}
+
+/// A Timelock operation in `meow_getTimelockOperations`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelockOperation {
+    /// `hashOperation(target, value, data, salt)`
+    pub id: B256,
+    pub target: Address,
+    /// Unix time the operation becomes executable (`null` once executed, as the
+    /// Timelock overwrites it)
+    pub eta: Option<u64>,
+    /// Whether the operation can be executed now
+    pub ready: bool,
+    pub executed: bool,
+}
+
+/// Read the state of each `(id, target)` in `scheduled` from the Timelock at `now`
+/// (unix seconds). Operations that aren't queued (cancelled, or never scheduled
+/// at this state) are left out.
+pub fn read_timelock_operations(
+    reader: &impl StorageReader,
+    scheduled: &[(B256, Address)],
+    now: u64,
+) -> Vec<TimelockOperation> {
+    scheduled
+        .iter()
+        .filter_map(|&(id, target)| {
+            let timestamp = read_timelock_timestamp(reader, id);
+            let executed = timestamp == timelock_slots::DONE_TIMESTAMP;
+            (timestamp != 0).then(|| TimelockOperation {
+                id,
+                target,
+                eta: (!executed).then_some(timestamp),
+                ready: !executed && now >= timestamp,
+                executed,
+            })
+        })
+        .collect()
+}
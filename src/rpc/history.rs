@@ -0,0 +1,101 @@
+//! Chain history for the `meow_*` methods that read stored blocks.
+//!
+//! Headers, fee breakdowns and fee history all come from the canonical blocks
+//! and receipts. Like [`ChainStateSource`](super::ChainStateSource), this keeps
+//! `MeowRpc` independent of the node's concrete provider type.
+
+use super::fee_history::FeeHistoryBlock;
+use super::fees::BlockFees;
+use alloy_consensus::Header;
+use alloy_eips::BlockId;
+use reth_ethereum::provider::ProviderResult;
+use reth_ethereum::{Block, Receipt};
+use reth_storage_api::{
+    BlockIdReader, BlockNumReader, BlockReader, HeaderProvider, ReceiptProvider,
+};
+
+/// Reads canonical headers, blocks and receipts.
+pub trait ChainHistorySource: Send + Sync {
+    /// Number of the chain head.
+    fn best_block_number(&self) -> ProviderResult<u64>;
+
+    /// Number of the oldest block still stored (non-zero on pruned nodes).
+    fn earliest_block_number(&self) -> ProviderResult<u64>;
+
+    /// Up to `count` headers ending at the chain head, oldest first.
+    fn recent_headers(&self, count: u64) -> ProviderResult<Vec<Header>>;
+
+    /// The canonical header of `block`, or `None` if unknown.
+    fn header_by_id(&self, block: BlockId) -> ProviderResult<Option<Header>>;
+
+    /// Fee breakdown of block `number`, or `None` if the block is unknown.
+    fn block_fees(&self, number: u64) -> ProviderResult<Option<BlockFees>>;
+
+    /// Header and transaction tips of block `number`, or `None` if unknown.
+    fn fee_history_block(&self, number: u64) -> ProviderResult<Option<FeeHistoryBlock>>;
+}
+
+impl<P> ChainHistorySource for P
+where
+    P: HeaderProvider<Header = Header>
+        + BlockReader<Block = Block>
+        + ReceiptProvider<Receipt = Receipt>
+        + BlockIdReader
+        + Send
+        + Sync,
+{
+    fn best_block_number(&self) -> ProviderResult<u64> {
+        BlockNumReader::best_block_number(self)
+    }
+
+    fn earliest_block_number(&self) -> ProviderResult<u64> {
+        BlockNumReader::earliest_block_number(self)
+    }
+
+    fn recent_headers(&self, count: u64) -> ProviderResult<Vec<Header>> {
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+        let head = BlockNumReader::best_block_number(self)?;
+        self.headers_range(head.saturating_sub(count - 1)..=head)
+    }
+
+    fn header_by_id(&self, block: BlockId) -> ProviderResult<Option<Header>> {
+        match self.block_number_for_id(block)? {
+            Some(number) => self.header_by_number(number),
+            None => Ok(None),
+        }
+    }
+
+    fn block_fees(&self, number: u64) -> ProviderResult<Option<BlockFees>> {
+        let Some((block, receipts)) = block_with_receipts(self, number)? else {
+            return Ok(None);
+        };
+        Ok(Some(BlockFees::compute(
+            &block.header,
+            &block.body.transactions,
+            &receipts,
+        )))
+    }
+
+    fn fee_history_block(&self, number: u64) -> ProviderResult<Option<FeeHistoryBlock>> {
+        Ok(block_with_receipts(self, number)?
+            .map(|(block, receipts)| FeeHistoryBlock::from_block(block, &receipts)))
+    }
+}
+
+/// Block `number` with its receipts, or `None` if either is missing.
+fn block_with_receipts<P>(
+    provider: &P,
+    number: u64,
+) -> ProviderResult<Option<(Block, Vec<Receipt>)>>
+where
+    P: BlockReader<Block = Block> + ReceiptProvider<Receipt = Receipt>,
+{
+    let Some(block) = provider.block_by_number(number)? else {
+        return Ok(None);
+    };
+    Ok(provider
+        .receipts_by_block(number.into())?
+        .map(|receipts| (block, receipts)))
+}
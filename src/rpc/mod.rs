@@ -6,28 +6,153 @@
 pub mod admin;
 pub mod admin_types;
 pub mod api;
+pub mod check;
 pub mod clique;
 pub mod clique_types;
+pub mod debug;
+pub mod estimate;
+pub mod execution;
+pub mod export;
+pub mod fee_history;
+pub mod fees;
+pub mod governance;
+pub mod history;
+pub mod proof;
+pub mod replay;
+pub mod state;
+pub mod txpool;
 pub mod types;
 
-pub use admin::{AdminApiServer, AdminRpc};
+pub use admin::{AdminApiServer, AdminRpc, PeerSource};
 pub use admin_types::NODE_VERSION;
 pub use api::MeowApiServer;
+pub use check::{TxCheck, TxCheckState};
 pub use clique::{CliqueApiServer, CliqueRpc};
-pub use types::{ChainConfigResponse, NodeInfoResponse};
+pub use debug::{DebugApiServer, DebugRpc};
+pub use estimate::GasEstimate;
+pub use execution::{ExecutionSource, Reexecutor};
+pub use export::{StateExport, StateExportSource, StateExporter};
+pub use fee_history::PoaFeeHistory;
+pub use fees::{BlockFees, BlockReward};
+pub use governance::{
+    read_governance_state, read_timelock_operations, GovernanceState, TimelockOperation,
+};
+pub use history::ChainHistorySource;
+pub use state::ChainStateSource;
+pub use txpool::{TxpoolApiServer, TxpoolRpc};
+pub use types::{
+    BlockSigner, ChainConfigResponse, ChainOrdering, DecodedExtraData, EpochInfo,
+    InTurnScheduleEntry, NodeInfoResponse, NodeStatus, ReorgSimulation, SignerStatus,
+};
 
 use crate::chainspec::PoaChainSpec;
+use crate::consensus::{DiversityHealth, PoaConsensus, SignatureStats, DEFAULT_IN_TURN_BONUS};
 use crate::genesis::{
     CHAIN_CONFIG_ADDRESS, GOVERNANCE_SAFE_ADDRESS, SIGNER_REGISTRY_ADDRESS, TREASURY_ADDRESS,
 };
-use crate::signer::SignerManager;
+use crate::metrics::timings::RECENT_TIMINGS_CAPACITY;
+use crate::metrics::{BlockTiming, BlockTimings, SharedBlockTimings};
+use crate::monitor::{
+    signed_block_channel, PerformanceReport, RecentSigners, SharedRecentSigners, SharedSignerIndex,
+    SharedTimelockSchedule, SignedBlockSender, SignerIndex, SignerLiveness, TimelockSchedule,
+    DEFAULT_SIGNER_INDEX_CAPACITY,
+};
+use crate::onchain::{DynamicChainConfig, DynamicSignerList, SafeInfo};
+use crate::output;
+use crate::signer::{signature_to_bytes, SignerManager};
+use crate::statediff::{state_diff_channel, StateDiff, StateDiffSender};
+use alloy_consensus::transaction::SignerRecoverable;
+use alloy_eips::eip2718::Decodable2718;
+use alloy_eips::{BlockId, BlockNumberOrTag};
+use alloy_primitives::{Address, Bytes, B256};
+use alloy_rpc_types_eth::{EIP1186AccountProofResponse, TransactionRequest};
+use alloy_rpc_types_trace::geth::{GethCallConfig, TraceResult};
+use jsonrpsee::core::SubscriptionResult;
+use jsonrpsee::types::error::{
+    ErrorObjectOwned, INTERNAL_ERROR_CODE, INVALID_PARAMS_CODE, METHOD_NOT_FOUND_CODE,
+};
+use jsonrpsee::{PendingSubscriptionSink, SubscriptionMessage, SubscriptionSink};
+use reth_chainspec::EthChainSpec;
+use serde::Serialize;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+
+/// Maximum number of blocks returned by `meow_getInTurnSchedule`.
+pub const MAX_SCHEDULE_BLOCKS: u64 = 1024;
+
+/// Maximum window inspected by `meow_getSignerSignatureStats`.
+pub const MAX_SIGNATURE_STATS_BLOCKS: u64 = 1024;
+
+/// Maximum side chain length (and reorg depth) accepted by `meow_simulateReorg`.
+pub const MAX_SIMULATED_REORG_BLOCKS: usize = 1024;
+
+/// Maximum number of blocks re-executed by one `meow_getStateDiffRange` call.
+pub const MAX_STATE_DIFF_RANGE_BLOCKS: u64 = 256;
+
+/// An `INTERNAL_ERROR_CODE` error with `msg`.
+pub(crate) fn internal_error(msg: impl Into<String>) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(INTERNAL_ERROR_CODE, msg.into(), None::<()>)
+}
+
+/// An `INVALID_PARAMS_CODE` error with `msg`.
+pub(crate) fn invalid_params(msg: impl Into<String>) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(INVALID_PARAMS_CODE, msg.into(), None::<()>)
+}
 
 /// Implementation of the `meow_*` RPC namespace.
 pub struct MeowRpc {
     chain_spec: Arc<PoaChainSpec>,
     signer_manager: Arc<SignerManager>,
     dev_mode: bool,
+    /// When the node started, for `meow_status` uptime.
+    start_time: Instant,
+    /// Latest canonical block number, updated by the block monitoring task.
+    chain_head: Arc<AtomicU64>,
+    /// Committed state diffs, broadcast by the block monitoring task.
+    state_diffs: StateDiffSender,
+    /// Committed blocks with their sealer, broadcast by the block monitoring task.
+    signed_blocks: SignedBlockSender,
+    /// Signers of recent canonical blocks, recorded by the block monitoring task.
+    recent_signers: SharedRecentSigners,
+    /// Minimum distinct recent signers for a healthy chain (0 = rule disabled).
+    min_distinct_signers: usize,
+    /// Let the diversity rule take precedence in reorg simulation.
+    strict_diversity: bool,
+    /// Fork-choice bonus per in-turn block for reorg simulation.
+    in_turn_bonus: u64,
+    /// Build/sign times of canonical blocks, committed by the block monitoring task.
+    block_timings: SharedBlockTimings,
+    /// Signer of each recent canonical block, recorded by the block monitoring task.
+    signer_index: SharedSignerIndex,
+    /// Safe-mode state, evaluated by the block monitoring task.
+    liveness: Arc<SignerLiveness>,
+    /// Operations scheduled on the Timelock, recorded by the block monitoring task.
+    timelock_schedule: SharedTimelockSchedule,
+    /// Canonical blocks and receipts for the history-based methods (unavailable
+    /// until set).
+    history: Option<Arc<dyn ChainHistorySource>>,
+    /// Account and contract state for proofs, `meow_checkTx` and the live
+    /// governance methods (unavailable until set).
+    state: Option<Arc<dyn ChainStateSource>>,
+    /// Re-execution for `meow_replayBlock`, `meow_traceBlock` and
+    /// `meow_estimateGas` (unavailable until set).
+    execution: Option<Arc<dyn ExecutionSource>>,
+    /// `--gpo-blocks`: default `meow_getFeeHistory` block count.
+    gpo_blocks: u64,
+    /// `--gpo-percentile`: default `meow_getFeeHistory` reward percentile.
+    gpo_percentile: f64,
+    /// State walking for `meow_exportState` and the directory exports are written
+    /// to (unavailable until set).
+    export: Option<(Arc<dyn StateExportSource>, PathBuf)>,
+    /// `--min-gas-price`: the pool's effective gas price floor, for `meow_checkTx`.
+    min_gas_price: u128,
+    /// `--max-contract-size` (`None` = EIP-170), for `meow_checkTx` while
+    /// `ChainConfig.maxContractSize` is unset.
+    max_contract_size: Option<u64>,
 }
 
 impl MeowRpc {
@@ -41,7 +166,158 @@ impl MeowRpc {
             chain_spec,
             signer_manager,
             dev_mode,
+            start_time: Instant::now(),
+            chain_head: Arc::new(AtomicU64::new(0)),
+            state_diffs: state_diff_channel(),
+            signed_blocks: signed_block_channel(),
+            recent_signers: RecentSigners::shared(),
+            min_distinct_signers: 0,
+            strict_diversity: false,
+            in_turn_bonus: DEFAULT_IN_TURN_BONUS,
+            block_timings: BlockTimings::shared(),
+            signer_index: SignerIndex::shared(DEFAULT_SIGNER_INDEX_CAPACITY),
+            liveness: SignerLiveness::shared(),
+            timelock_schedule: TimelockSchedule::shared(),
+            history: None,
+            state: None,
+            execution: None,
+            gpo_blocks: 20,
+            gpo_percentile: 60.0,
+            export: None,
+            min_gas_price: 0,
+            max_contract_size: None,
+        }
+    }
+
+    /// Report uptime relative to the node's start rather than this instance's creation.
+    pub fn with_start_time(mut self, start_time: Instant) -> Self {
+        self.start_time = start_time;
+        self
+    }
+
+    /// Share the chain head counter kept up to date by the block monitoring task.
+    pub fn with_chain_head(mut self, chain_head: Arc<AtomicU64>) -> Self {
+        self.chain_head = chain_head;
+        self
+    }
+
+    /// Share the state diff channel the block monitoring task publishes to.
+    pub fn with_state_diffs(mut self, state_diffs: StateDiffSender) -> Self {
+        self.state_diffs = state_diffs;
+        self
+    }
+
+    /// Share the signed block channel the block monitoring task publishes to.
+    pub fn with_signed_blocks(mut self, signed_blocks: SignedBlockSender) -> Self {
+        self.signed_blocks = signed_blocks;
+        self
+    }
+
+    /// Share the recent signer buffer and the diversity threshold it is judged against.
+    pub fn with_recent_signers(
+        mut self,
+        recent_signers: SharedRecentSigners,
+        min_distinct_signers: usize,
+    ) -> Self {
+        self.recent_signers = recent_signers;
+        self.min_distinct_signers = min_distinct_signers;
+        self
+    }
+
+    /// Prefer a chain that meets the diversity threshold when simulating fork choice.
+    pub fn with_strict_diversity(mut self, strict: bool) -> Self {
+        self.strict_diversity = strict;
+        self
+    }
+
+    /// Weigh in-turn blocks by `bonus` when simulating fork choice.
+    pub fn with_in_turn_bonus(mut self, bonus: u64) -> Self {
+        self.in_turn_bonus = bonus;
+        self
+    }
+
+    /// Share the block timings the payload builder and block monitoring task record.
+    pub fn with_block_timings(mut self, block_timings: SharedBlockTimings) -> Self {
+        self.block_timings = block_timings;
+        self
+    }
+
+    /// Share the signer index the block monitoring task records.
+    pub fn with_signer_index(mut self, signer_index: SharedSignerIndex) -> Self {
+        self.signer_index = signer_index;
+        self
+    }
+
+    /// Share the Timelock schedule the block monitoring task records.
+    pub fn with_timelock_schedule(mut self, timelock_schedule: SharedTimelockSchedule) -> Self {
+        self.timelock_schedule = timelock_schedule;
+        self
+    }
+
+    /// Share the safe-mode state the block monitoring task evaluates.
+    pub fn with_signer_liveness(mut self, liveness: Arc<SignerLiveness>) -> Self {
+        self.liveness = liveness;
+        self
+    }
+
+    /// Serve history-based methods from the node's canonical blocks and receipts.
+    pub fn with_history_source(mut self, history: Arc<dyn ChainHistorySource>) -> Self {
+        self.history = Some(history);
+        self
+    }
+
+    /// Serve state-based methods from the node's state (any `StateProviderFactory`).
+    pub fn with_state_source(mut self, state: Arc<dyn ChainStateSource>) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Serve `meow_replayBlock`, `meow_traceBlock` and `meow_estimateGas` by
+    /// executing on the node's EVM.
+    pub fn with_execution_source(mut self, execution: Arc<dyn ExecutionSource>) -> Self {
+        self.execution = Some(execution);
+        self
+    }
+
+    /// Use the gas price oracle settings (`--gpo-blocks`/`--gpo-percentile`) as the
+    /// `meow_getFeeHistory` defaults.
+    pub fn with_gas_price_oracle(mut self, blocks: u32, percentile: u32) -> Self {
+        self.gpo_blocks = blocks.into();
+        self.gpo_percentile = percentile.into();
+        self
+    }
+
+    /// Serve `meow_exportState` from the node's database, writing into `dir`
+    /// (`<datadir>/exports`).
+    pub fn with_export_source(mut self, export: Arc<dyn StateExportSource>, dir: PathBuf) -> Self {
+        self.export = Some((export, dir));
+        self
+    }
+
+    /// Check `meow_checkTx` transactions against the pool's `--min-gas-price` floor.
+    pub fn with_min_gas_price(mut self, min_gas_price: u128) -> Self {
+        self.min_gas_price = min_gas_price;
+        self
+    }
+
+    /// Check `meow_checkTx` contract creations against `--max-contract-size`
+    /// (`0` = EIP-170's limit) while `ChainConfig` doesn't set one.
+    pub fn with_max_contract_size(mut self, size: usize) -> Self {
+        self.max_contract_size = (size > 0).then_some(size as u64);
+        self
+    }
+
+    /// Refuse `method` unless the node runs in dev mode, so signing helpers are never
+    /// reachable on a production node.
+    fn require_dev_mode(&self, method: &str) -> Result<(), ErrorObjectOwned> {
+        if self.dev_mode {
+            return Ok(());
         }
+        Err(ErrorObjectOwned::owned(
+            METHOD_NOT_FOUND_CODE,
+            format!("meow_{method} is only available in dev mode"),
+            None::<()>,
+        ))
     }
 }
 
@@ -52,7 +328,7 @@ impl MeowApiServer for MeowRpc {
             chain_id: self.chain_spec.inner().chain.id(),
             gas_limit: self.chain_spec.inner().genesis().gas_limit,
             block_time: self.chain_spec.block_period(),
-            epoch: self.chain_spec.epoch(),
+            epoch: self.chain_spec.effective_epoch(),
             signer_count: self.chain_spec.signers().len(),
             governance_safe: GOVERNANCE_SAFE_ADDRESS,
             chain_config_contract: CHAIN_CONFIG_ADDRESS,
@@ -78,6 +354,636 @@ impl MeowApiServer for MeowRpc {
             authorized_signers: authorized.to_vec(),
         })
     }
+
+    async fn status(&self) -> jsonrpsee::core::RpcResult<NodeStatus> {
+        let head = match &self.history {
+            Some(source) => source
+                .recent_headers(1)
+                .map_err(|e| internal_error(e.to_string()))?
+                .pop(),
+            None => None,
+        };
+        let (block_number, block_hash) = match head {
+            Some(header) => (header.number, Some(header.hash_slow())),
+            None => (self.chain_head.load(Ordering::Relaxed), None),
+        };
+        let signers = self.chain_spec.effective_signers();
+        let local_signers = self.signer_manager.signer_addresses().await;
+
+        Ok(NodeStatus {
+            chain_id: self.chain_spec.inner().chain.id(),
+            block_number,
+            block_hash,
+            signer_count: signers.len(),
+            is_authority: local_signers.iter().any(|a| signers.contains(a)),
+            uptime_seconds: self.start_time.elapsed().as_secs(),
+            dev_mode: self.dev_mode,
+            block_period: self.chain_spec.block_period(),
+            degraded: self.liveness.is_degraded(),
+        })
+    }
+
+    async fn get_block_signer(&self, block: BlockId) -> jsonrpsee::core::RpcResult<BlockSigner> {
+        let source = self
+            .history
+            .as_ref()
+            .ok_or_else(|| internal_error("chain history not available"))?;
+        let header = source
+            .header_by_id(block)
+            .map_err(|e| internal_error(e.to_string()))?
+            .ok_or_else(|| internal_error(format!("block {block} not found")))?;
+        let hash = header.hash_slow();
+        if header.number == self.chain_spec.genesis_header().number {
+            return Ok(BlockSigner::genesis(header.number, hash));
+        }
+        let signer = PoaConsensus::new(self.chain_spec.clone())
+            .recover_signer(&header)
+            .map_err(|e| internal_error(format!("block {}: {e}", header.number)))?;
+        Ok(BlockSigner::sealed(
+            header.number,
+            hash,
+            signer,
+            self.chain_spec.expected_signer(header.number),
+        ))
+    }
+
+    async fn get_in_turn_schedule(
+        &self,
+        count: u64,
+    ) -> jsonrpsee::core::RpcResult<Vec<InTurnScheduleEntry>> {
+        let local_signers = self.signer_manager.signer_addresses().await;
+        let next = self.chain_head.load(Ordering::Relaxed) + 1;
+
+        Ok((next..next + count.min(MAX_SCHEDULE_BLOCKS))
+            .filter_map(|block_number| {
+                let signer = self.chain_spec.expected_signer(block_number)?;
+                Some(InTurnScheduleEntry {
+                    block_number,
+                    signer,
+                    ours: local_signers.contains(&signer),
+                })
+            })
+            .collect())
+    }
+
+    async fn get_epoch_info(&self) -> jsonrpsee::core::RpcResult<EpochInfo> {
+        let head = self.chain_head.load(Ordering::Relaxed);
+        let (anchor, epoch_length) = self.chain_spec.epoch_at(head);
+        Ok(EpochInfo::from_anchor(
+            head,
+            anchor,
+            epoch_length,
+            self.chain_spec.has_live_signers(),
+            self.chain_spec.effective_signers().len(),
+        ))
+    }
+
+    async fn get_decentralization_health(&self) -> jsonrpsee::core::RpcResult<DiversityHealth> {
+        let recent = self
+            .recent_signers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .to_vec();
+        Ok(DiversityHealth::from_signers(
+            &recent,
+            self.chain_spec.effective_signers().len(),
+            self.min_distinct_signers,
+        ))
+    }
+
+    async fn get_signer_status(&self) -> jsonrpsee::core::RpcResult<Vec<SignerStatus>> {
+        let local_signers = self.signer_manager.signer_addresses().await;
+        let in_turn = self
+            .chain_spec
+            .expected_signer(self.chain_head.load(Ordering::Relaxed) + 1);
+        let recent = self
+            .recent_signers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let index = self.signer_index.lock().unwrap_or_else(|e| e.into_inner());
+
+        Ok(self
+            .chain_spec
+            .effective_signers()
+            .into_iter()
+            .map(|address| SignerStatus {
+                address,
+                controlled: local_signers.contains(&address),
+                // The index follows reorgs; fall back to what was seen since startup
+                // for signers that haven't sealed within it.
+                last_block: index
+                    .last_sealed(&address)
+                    .or_else(|| recent.last_sealed(&address)),
+                in_turn: in_turn == Some(address),
+            })
+            .collect())
+    }
+
+    async fn get_signer_performance(
+        &self,
+        blocks: u64,
+    ) -> jsonrpsee::core::RpcResult<PerformanceReport> {
+        if blocks == 0 {
+            return Err(invalid_params("blocks must be at least 1"));
+        }
+        let index = self.signer_index.lock().unwrap_or_else(|e| e.into_inner());
+        let count = usize::try_from(blocks).unwrap_or(usize::MAX);
+        Ok(PerformanceReport::from_index(
+            &index,
+            count,
+            &self.chain_spec,
+        ))
+    }
+
+    async fn get_storage_proof(
+        &self,
+        address: Address,
+        slots: Vec<B256>,
+        block: Option<BlockId>,
+    ) -> jsonrpsee::core::RpcResult<EIP1186AccountProofResponse> {
+        let proofs = self
+            .state
+            .as_ref()
+            .ok_or_else(|| internal_error("state proofs not available"))?;
+        let proof = proofs
+            .account_proof(address, &slots, block.unwrap_or_default())
+            .map_err(|e| internal_error(e.to_string()))?;
+        Ok(proof::to_eip1186_response(proof, &slots))
+    }
+
+    async fn get_signer_signature_stats(
+        &self,
+        window_blocks: u64,
+    ) -> jsonrpsee::core::RpcResult<SignatureStats> {
+        let source = self
+            .history
+            .as_ref()
+            .ok_or_else(|| internal_error("chain history not available"))?;
+        let headers = source
+            .recent_headers(window_blocks.min(MAX_SIGNATURE_STATS_BLOCKS))
+            .map_err(|e| internal_error(e.to_string()))?;
+        Ok(PoaConsensus::new(self.chain_spec.clone()).signature_stats(&headers))
+    }
+
+    async fn get_block_fees(&self, block_number: u64) -> jsonrpsee::core::RpcResult<BlockFees> {
+        let source = self
+            .history
+            .as_ref()
+            .ok_or_else(|| internal_error("chain history not available"))?;
+        source
+            .block_fees(block_number)
+            .map_err(|e| internal_error(e.to_string()))?
+            .ok_or_else(|| internal_error(format!("block {block_number} not found")))
+    }
+
+    async fn get_block_reward(&self, block_number: u64) -> jsonrpsee::core::RpcResult<BlockReward> {
+        self.get_block_fees(block_number)
+            .await
+            .map(|fees| BlockReward::from(&fees))
+    }
+
+    async fn get_fee_history(
+        &self,
+        block_count: u64,
+        newest_block: BlockNumberOrTag,
+        reward_percentiles: Option<Vec<f64>>,
+    ) -> jsonrpsee::core::RpcResult<PoaFeeHistory> {
+        let source = self
+            .history
+            .clone()
+            .ok_or_else(|| internal_error("chain history not available"))?;
+        let percentiles = reward_percentiles.unwrap_or_else(|| vec![self.gpo_percentile]);
+        fee_history::validate_percentiles(&percentiles).map_err(|e| invalid_params(e))?;
+
+        let block_count = match block_count {
+            0 => self.gpo_blocks,
+            n => n,
+        }
+        .clamp(1, fee_history::MAX_FEE_HISTORY_BLOCKS);
+        let head = source
+            .best_block_number()
+            .map_err(|e| internal_error(e.to_string()))?;
+        let newest = match newest_block {
+            BlockNumberOrTag::Number(n) if n > head => {
+                return Err(internal_error(format!("block {n} not found")));
+            }
+            BlockNumberOrTag::Number(n) => n,
+            BlockNumberOrTag::Earliest => source
+                .earliest_block_number()
+                .map_err(|e| internal_error(e.to_string()))?,
+            _ => head,
+        };
+        let oldest = newest.saturating_sub(block_count - 1);
+
+        let chain_spec = self.chain_spec.clone();
+        tokio::task::spawn_blocking(move || {
+            fee_history::fee_history(source.as_ref(), chain_spec, oldest, newest, &percentiles)
+        })
+        .await
+        .map_err(|e| internal_error(e.to_string()))?
+        .map_err(|e| internal_error(e.to_string()))?
+        .ok_or_else(|| internal_error(format!("blocks {oldest}..={newest} not available")))
+    }
+
+    async fn get_block_timings(&self, count: u64) -> jsonrpsee::core::RpcResult<Vec<BlockTiming>> {
+        let count = count.min(RECENT_TIMINGS_CAPACITY as u64) as usize;
+        Ok(self
+            .block_timings
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .recent(count))
+    }
+
+    async fn replay_block(&self, block: BlockId) -> jsonrpsee::core::RpcResult<StateDiff> {
+        let source = self
+            .execution
+            .clone()
+            .ok_or_else(|| internal_error("chain history not available"))?;
+        // Re-execution is CPU-bound; keep it off the RPC worker threads.
+        tokio::task::spawn_blocking(move || source.replay_block(block))
+            .await
+            .map_err(|e| internal_error(e.to_string()))?
+            .map_err(|e| internal_error(e.to_string()))?
+            .ok_or_else(|| internal_error(format!("block {block} not found")))
+    }
+
+    async fn get_state_diff_range(
+        &self,
+        from: u64,
+        to: u64,
+        max_blocks: u64,
+    ) -> jsonrpsee::core::RpcResult<Vec<StateDiff>> {
+        if from > to {
+            return Err(invalid_params(format!("from {from} is after to {to}")));
+        }
+        let cap = max_blocks.min(MAX_STATE_DIFF_RANGE_BLOCKS);
+        let count = (to - from).saturating_add(1);
+        if count > cap {
+            return Err(invalid_params(format!(
+                "range of {count} blocks exceeds the limit of {cap}; request fewer blocks"
+            )));
+        }
+        let source = self
+            .execution
+            .clone()
+            .ok_or_else(|| internal_error("chain history not available"))?;
+        // Re-execution is CPU-bound; keep it off the RPC worker threads.
+        tokio::task::spawn_blocking(move || {
+            (from..=to)
+                .map(|number| {
+                    source
+                        .replay_block(BlockId::number(number))
+                        .map_err(|e| internal_error(e.to_string()))?
+                        .ok_or_else(|| internal_error(format!("block {number} not found")))
+                })
+                .collect()
+        })
+        .await
+        .map_err(|e| internal_error(e.to_string()))?
+    }
+
+    async fn trace_block(
+        &self,
+        block: BlockId,
+        config: Option<GethCallConfig>,
+    ) -> jsonrpsee::core::RpcResult<Vec<TraceResult>> {
+        let source = self
+            .execution
+            .clone()
+            .ok_or_else(|| internal_error("chain history not available"))?;
+        // Re-execution is CPU-bound; keep it off the RPC worker threads.
+        tokio::task::spawn_blocking(move || source.trace_block(block, config.unwrap_or_default()))
+            .await
+            .map_err(|e| internal_error(e.to_string()))??
+            .ok_or_else(|| internal_error(format!("block {block} not found")))
+    }
+
+    async fn get_chain_config(&self) -> jsonrpsee::core::RpcResult<Option<DynamicChainConfig>> {
+        let source = self
+            .state
+            .as_ref()
+            .ok_or_else(|| internal_error("chain state not available"))?;
+        source
+            .live_chain_config()
+            .map_err(|e| internal_error(e.to_string()))
+    }
+
+    async fn get_signer_registry(&self) -> jsonrpsee::core::RpcResult<Option<DynamicSignerList>> {
+        let source = self
+            .state
+            .as_ref()
+            .ok_or_else(|| internal_error("chain state not available"))?;
+        source
+            .live_signer_list()
+            .map_err(|e| internal_error(e.to_string()))
+    }
+
+    async fn get_miner_proxy_admin(&self) -> jsonrpsee::core::RpcResult<Option<Address>> {
+        let source = self
+            .state
+            .as_ref()
+            .ok_or_else(|| internal_error("chain state not available"))?;
+        source
+            .miner_proxy_admin()
+            .map_err(|e| internal_error(e.to_string()))
+    }
+
+    async fn get_timelock_operations(&self) -> jsonrpsee::core::RpcResult<Vec<TimelockOperation>> {
+        let source = self
+            .state
+            .as_ref()
+            .ok_or_else(|| internal_error("chain state not available"))?;
+        let scheduled = self
+            .timelock_schedule
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .to_vec();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        source
+            .timelock_operations(&scheduled, now)
+            .map_err(|e| internal_error(e.to_string()))
+    }
+
+    async fn get_governance_state(&self) -> jsonrpsee::core::RpcResult<GovernanceState> {
+        let source = self
+            .state
+            .as_ref()
+            .ok_or_else(|| internal_error("chain state not available"))?;
+        source
+            .governance_state()
+            .map_err(|e| internal_error(e.to_string()))
+    }
+
+    async fn estimate_gas(
+        &self,
+        tx: TransactionRequest,
+    ) -> jsonrpsee::core::RpcResult<GasEstimate> {
+        let source = self
+            .execution
+            .clone()
+            .ok_or_else(|| internal_error("chain state not available"))?;
+        // Each probe re-executes the transaction; keep it off the RPC worker threads.
+        Ok(tokio::task::spawn_blocking(move || source.estimate_gas(tx))
+            .await
+            .map_err(|e| internal_error(e.to_string()))??)
+    }
+
+    async fn check_tx(&self, raw_tx: Bytes) -> jsonrpsee::core::RpcResult<TxCheck> {
+        let source = self
+            .state
+            .as_ref()
+            .ok_or_else(|| internal_error("chain state not available"))?;
+        let tx = match reth_ethereum::TransactionSigned::decode_2718(&mut &raw_tx[..]) {
+            Ok(tx) => tx,
+            Err(e) => return Ok(TxCheck::rejected(format!("invalid transaction: {e}"))),
+        };
+        let Ok(sender) = tx.recover_signer() else {
+            return Ok(TxCheck::rejected("invalid transaction signature"));
+        };
+
+        let state = source
+            .check_state(sender)
+            .map_err(|e| internal_error(e.to_string()))?;
+        let pending_base_fee = state
+            .head
+            .as_ref()
+            .and_then(|head| {
+                head.next_block_base_fee(
+                    self.chain_spec.base_fee_params_at_timestamp(head.timestamp),
+                )
+            })
+            .unwrap_or_default();
+        Ok(check::check_transaction(
+            &tx,
+            self.chain_spec.inner().chain.id(),
+            self.min_gas_price,
+            pending_base_fee,
+            self.max_contract_size,
+            &state,
+        )
+        .into())
+    }
+
+    async fn get_safe_info(
+        &self,
+        safe: Option<Address>,
+    ) -> jsonrpsee::core::RpcResult<Option<SafeInfo>> {
+        let source = self
+            .state
+            .as_ref()
+            .ok_or_else(|| internal_error("chain state not available"))?;
+        source
+            .safe_info(safe.unwrap_or(GOVERNANCE_SAFE_ADDRESS))
+            .map_err(|e| internal_error(e.to_string()))
+    }
+
+    async fn decode_extra_data(
+        &self,
+        extra_data: Bytes,
+    ) -> jsonrpsee::core::RpcResult<DecodedExtraData> {
+        DecodedExtraData::decode(&extra_data).map_err(|e| invalid_params(e.to_string()))
+    }
+
+    async fn export_state(
+        &self,
+        block_number: u64,
+        file_name: String,
+    ) -> jsonrpsee::core::RpcResult<StateExport> {
+        self.require_dev_mode("exportState")?;
+        let (source, dir) = self
+            .export
+            .clone()
+            .ok_or_else(|| internal_error("chain state not available"))?;
+        let path =
+            export::export_path(&dir, &file_name).map_err(|e| invalid_params(e.to_string()))?;
+        // Walking the whole state takes a while; keep it off the RPC worker threads.
+        let export = tokio::task::spawn_blocking(move || {
+            std::fs::create_dir_all(&dir).map_err(export::ExportError::Io)?;
+            source.export_state(block_number, &path)
+        })
+        .await
+        .map_err(|e| internal_error(e.to_string()))?
+        .map_err(|e| internal_error(e.to_string()))?
+        .ok_or_else(|| internal_error(format!("block {block_number} not found")))?;
+        output::print_state_exported(&export.path, export.block_number, export.accounts);
+        Ok(export)
+    }
+
+    async fn sign_hash(&self, address: Address, hash: B256) -> jsonrpsee::core::RpcResult<Bytes> {
+        self.require_dev_mode("signHash")?;
+        if !self.signer_manager.has_signer(&address).await {
+            return Err(invalid_params(format!(
+                "address {address} is not controlled by this node"
+            )));
+        }
+        let signature = self
+            .signer_manager
+            .sign_hash(&address, hash)
+            .await
+            .map_err(|e| internal_error(e.to_string()))?;
+        Ok(Bytes::copy_from_slice(&signature_to_bytes(&signature)))
+    }
+
+    async fn recover_signer(&self, header_rlp: Bytes) -> jsonrpsee::core::RpcResult<Address> {
+        self.require_dev_mode("recoverSigner")?;
+        let header =
+            <alloy_consensus::Header as alloy_rlp::Decodable>::decode(&mut &header_rlp[..])
+                .map_err(|e| invalid_params(format!("invalid header RLP: {e}")))?;
+        PoaConsensus::new(self.chain_spec.clone())
+            .recover_signer(&header)
+            .map_err(|e| invalid_params(e.to_string()))
+    }
+
+    async fn simulate_reorg(
+        &self,
+        side_chain_headers: Vec<Bytes>,
+    ) -> jsonrpsee::core::RpcResult<ReorgSimulation> {
+        self.require_dev_mode("simulateReorg")?;
+        if side_chain_headers.is_empty() {
+            return Err(invalid_params("no side chain headers given"));
+        }
+        if side_chain_headers.len() > MAX_SIMULATED_REORG_BLOCKS {
+            return Err(invalid_params(format!(
+                "at most {MAX_SIMULATED_REORG_BLOCKS} side chain headers are accepted"
+            )));
+        }
+
+        let side = side_chain_headers
+            .iter()
+            .enumerate()
+            .map(|(index, rlp)| {
+                <alloy_consensus::Header as alloy_rlp::Decodable>::decode(&mut &rlp[..])
+                    .map_err(|e| invalid_params(format!("header {index}: invalid header RLP: {e}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for pair in side.windows(2) {
+            if pair[1].number != pair[0].number + 1 || pair[1].parent_hash != pair[0].hash_slow() {
+                return Err(invalid_params(format!(
+                    "side chain block {} is not a child of block {}",
+                    pair[1].number, pair[0].number
+                )));
+            }
+        }
+
+        let first = &side[0];
+        if first.number <= self.chain_spec.genesis_header().number {
+            return Err(invalid_params("the side chain cannot replace genesis"));
+        }
+        let fork_block = first.number - 1;
+        let source = self
+            .history
+            .as_ref()
+            .ok_or_else(|| internal_error("chain history not available"))?;
+        let attaches = source
+            .header_by_id(BlockId::number(fork_block))
+            .map_err(|e| internal_error(e.to_string()))?
+            .is_some_and(|parent| parent.hash_slow() == first.parent_hash);
+        if !attaches {
+            return Err(invalid_params(format!(
+                "side chain block {} does not attach to canonical block {fork_block}",
+                first.number
+            )));
+        }
+
+        let head = source
+            .recent_headers(1)
+            .map_err(|e| internal_error(e.to_string()))?
+            .last()
+            .map_or(fork_block, |header| header.number);
+        if head - fork_block > MAX_SIMULATED_REORG_BLOCKS as u64 {
+            return Err(invalid_params(format!(
+                "fork at block {fork_block} is more than {MAX_SIMULATED_REORG_BLOCKS} blocks deep"
+            )));
+        }
+        let mut canonical = Vec::new();
+        for number in fork_block + 1..=head {
+            if let Some(header) = source
+                .header_by_id(BlockId::number(number))
+                .map_err(|e| internal_error(e.to_string()))?
+            {
+                canonical.push(header);
+            }
+        }
+
+        let consensus = PoaConsensus::new(self.chain_spec.clone())
+            .with_min_distinct_signers(self.min_distinct_signers)
+            .with_strict_diversity(self.strict_diversity)
+            .with_in_turn_bonus(self.in_turn_bonus);
+        let ordering = ChainOrdering::from(consensus.compare_chains(&side, &canonical));
+        Ok(ReorgSimulation {
+            fork_block,
+            canonical_score: consensus.score_chain(&canonical),
+            canonical_length: canonical.len() as u64,
+            side_score: consensus.score_chain(&side),
+            side_length: side.len() as u64,
+            ordering,
+            reorg: ordering == ChainOrdering::Greater,
+        })
+    }
+
+    async fn subscribe_state_diff(&self, pending: PendingSubscriptionSink) -> SubscriptionResult {
+        // Subscribe before accepting so no diff committed in between is missed.
+        let diffs = self.state_diffs.subscribe();
+        let sink = pending.accept().await?;
+        tokio::spawn(forward_broadcast(
+            sink,
+            diffs,
+            "meow_subscribeStateDiff",
+            "diffs",
+        ));
+        Ok(())
+    }
+
+    async fn subscribe_signed_blocks(
+        &self,
+        pending: PendingSubscriptionSink,
+    ) -> SubscriptionResult {
+        let blocks = self.signed_blocks.subscribe();
+        let sink = pending.accept().await?;
+        tokio::spawn(forward_broadcast(
+            sink,
+            blocks,
+            "meow_subscribeSignedBlocks",
+            "blocks",
+        ));
+        Ok(())
+    }
+}
+
+/// Forward every item from a broadcast channel to a subscription until either
+/// side closes. A subscriber that lags behind loses the oldest items, with a
+/// warning naming the `method` and how many `items` were dropped.
+async fn forward_broadcast<T: Serialize>(
+    sink: SubscriptionSink,
+    mut receiver: broadcast::Receiver<Arc<T>>,
+    method: &'static str,
+    items: &'static str,
+) {
+    loop {
+        tokio::select! {
+            _ = sink.closed() => break,
+            received = receiver.recv() => match received {
+                Ok(item) => {
+                    let Ok(msg) =
+                        SubscriptionMessage::new(sink.method_name(), sink.subscription_id(), &*item)
+                    else {
+                        continue;
+                    };
+                    if sink.send(msg).await.is_err() {
+                        break;
+                    }
+                }
+                // Slow consumer: the channel dropped its oldest items
+                Err(RecvError::Lagged(skipped)) => output::print_warning(&format!(
+                    "{method} subscriber lagging, dropped {skipped} {items}"
+                )),
+                Err(RecvError::Closed) => break,
+            },
+        }
+    }
 }
 
 #[cfg(test)]
@@ -85,6 +991,7 @@ mod tests {
     use super::*;
     use crate::chainspec::{PoaChainSpec, PoaConfig};
     use crate::genesis;
+    use alloy_primitives::U256;
 
     fn test_chain_spec() -> Arc<PoaChainSpec> {
         let config = genesis::GenesisConfig::dev();
@@ -93,6 +1000,7 @@ mod tests {
             period: 2,
             epoch: 30000,
             signers: genesis::dev_signers(),
+            ..PoaConfig::default()
         };
         Arc::new(PoaChainSpec::new(genesis, poa_config))
     }
@@ -104,6 +1012,7 @@ mod tests {
             period: 12,
             epoch: 30000,
             signers: genesis::dev_accounts().into_iter().take(5).collect(),
+            ..PoaConfig::default()
         };
         Arc::new(PoaChainSpec::new(genesis, poa_config))
     }
@@ -115,6 +1024,7 @@ mod tests {
             period: 2,
             epoch: 30000,
             signers: vec![],
+            ..PoaConfig::default()
         };
         Arc::new(PoaChainSpec::new(genesis, poa_config))
     }
@@ -233,36 +1143,1508 @@ mod tests {
         assert_eq!(config.treasury_contract, TREASURY_ADDRESS);
     }
 
-    #[test]
-    fn test_chain_config_response_json_serialization() {
-        let config = ChainConfigResponse {
-            chain_id: 9323310,
-            gas_limit: 30_000_000,
-            block_time: 2,
-            epoch: 30000,
-            signer_count: 3,
-            governance_safe: GOVERNANCE_SAFE_ADDRESS,
-            chain_config_contract: CHAIN_CONFIG_ADDRESS,
-            signer_registry_contract: SIGNER_REGISTRY_ADDRESS,
-            treasury_contract: TREASURY_ADDRESS,
-        };
+    #[tokio::test]
+    async fn test_meow_get_epoch_info() {
+        let chain = Arc::new(PoaChainSpec::dev_chain());
+        let head = Arc::new(AtomicU64::new(45_123));
+        let rpc = MeowRpc::new(chain.clone(), Arc::new(SignerManager::new()), false)
+            .with_chain_head(head.clone());
 
-        let json = serde_json::to_string(&config).unwrap();
-        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let info = rpc.get_epoch_info().await.unwrap();
+        assert_eq!(info.epoch_length, 30_000);
+        assert_eq!(info.current_epoch, 1);
+        assert_eq!(info.epoch_start_block, 30_000);
+        assert_eq!(info.next_epoch_block, 60_000);
+        assert_eq!(info.blocks_until_next_epoch, 14_877);
+        assert!(!info.live_signers);
+        assert_eq!(info.effective_signer_count, 3);
 
-        // Verify camelCase field names
-        assert!(parsed.get("chainId").is_some());
-        assert!(parsed.get("gasLimit").is_some());
-        assert!(parsed.get("blockTime").is_some());
-        assert!(parsed.get("signerCount").is_some());
-        assert!(parsed.get("governanceSafe").is_some());
-        assert!(parsed.get("chainConfigContract").is_some());
-        assert!(parsed.get("signerRegistryContract").is_some());
-        assert!(parsed.get("treasuryContract").is_some());
+        // An epoch block starts a new epoch; the next one is a full epoch away
+        head.store(60_000, Ordering::Relaxed);
+        chain.update_live_signers(vec![Address::with_last_byte(1)]);
+        let info = rpc.get_epoch_info().await.unwrap();
+        assert_eq!(info.current_epoch, 2);
+        assert_eq!(info.epoch_start_block, 60_000);
+        assert_eq!(info.next_epoch_block, 90_000);
+        assert_eq!(info.blocks_until_next_epoch, 30_000);
+        assert!(info.live_signers);
+        assert_eq!(info.effective_signer_count, 1);
 
-        // Verify values
-        assert_eq!(parsed["chainId"], 9323310);
-        assert_eq!(parsed["gasLimit"], 30_000_000);
-        assert_eq!(parsed["blockTime"], 2);
+        let genesis = EpochInfo::at(0, 30_000, false, 3);
+        assert_eq!(genesis.current_epoch, 0);
+        assert_eq!(genesis.next_epoch_block, 30_000);
+
+        // Epoch length changed to 20_000 at block 60_000
+        let changed = EpochInfo::from_anchor(95_000, 60_000, 20_000, true, 3);
+        assert_eq!(changed.current_epoch, 1);
+        assert_eq!(changed.epoch_start_block, 80_000);
+        assert_eq!(changed.next_epoch_block, 100_000);
+    }
+
+    #[tokio::test]
+    async fn test_meow_in_turn_schedule_marks_our_blocks() {
+        let chain = test_chain_spec();
+        let manager = Arc::new(SignerManager::new());
+        // Hold only signer 1 of the 3 dev signers
+        manager
+            .add_signer_from_hex(crate::signer::dev::DEV_PRIVATE_KEYS[1])
+            .await
+            .unwrap();
+
+        let head = Arc::new(AtomicU64::new(9));
+        let rpc = MeowRpc::new(chain.clone(), manager, false).with_chain_head(head);
+        let schedule = rpc.get_in_turn_schedule(6).await.unwrap();
+
+        assert_eq!(schedule.len(), 6);
+        assert_eq!(schedule[0].block_number, 10);
+        for entry in &schedule {
+            let index = entry.block_number as usize % 3;
+            assert_eq!(entry.signer, chain.signers()[index]);
+            assert_eq!(entry.ours, index == 1, "block {}", entry.block_number);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_meow_in_turn_schedule_capped_and_empty() {
+        let manager = Arc::new(SignerManager::new());
+        let rpc = MeowRpc::new(test_chain_spec(), manager.clone(), false);
+        let schedule = rpc.get_in_turn_schedule(u64::MAX).await.unwrap();
+        assert_eq!(schedule.len() as u64, MAX_SCHEDULE_BLOCKS);
+        assert!(schedule.iter().all(|e| !e.ours));
+
+        let rpc = MeowRpc::new(empty_signer_chain_spec(), manager, false);
+        assert!(rpc.get_in_turn_schedule(10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_meow_decentralization_health_detects_dominant_signer() {
+        let chain = test_chain_spec();
+        let recent = RecentSigners::shared();
+        let rpc = MeowRpc::new(chain.clone(), Arc::new(SignerManager::new()), false)
+            .with_recent_signers(recent.clone(), 2);
+
+        // Not enough blocks yet to judge
+        assert!(rpc.get_decentralization_health().await.unwrap().healthy);
+
+        {
+            let mut recent = recent.lock().unwrap();
+            recent.record(chain.signers()[1]);
+            for _ in 0..3 {
+                recent.record(chain.signers()[0]);
+            }
+        }
+        let health = rpc.get_decentralization_health().await.unwrap();
+        assert_eq!(health.window, 3);
+        assert_eq!(health.distinct_signers, 1);
+        assert_eq!(health.min_distinct_signers, 2);
+        assert!(!health.healthy);
+    }
+
+    #[tokio::test]
+    async fn test_meow_signer_status() {
+        let chain = test_chain_spec();
+        let signers = chain.signers().to_vec();
+        let manager = Arc::new(SignerManager::new());
+        manager
+            .add_signer_from_hex(crate::signer::dev::DEV_PRIVATE_KEYS[1])
+            .await
+            .unwrap();
+        let recent = RecentSigners::shared();
+        recent.lock().unwrap().record_sealed(signers[0], 6);
+        recent.lock().unwrap().record_sealed(signers[1], 7);
+
+        // Head 7 → block 8 is in turn for signer 8 % 3 = 2
+        let rpc = MeowRpc::new(chain, manager, false)
+            .with_chain_head(Arc::new(AtomicU64::new(7)))
+            .with_recent_signers(recent, 0);
+        let status = rpc.get_signer_status().await.unwrap();
+
+        assert_eq!(status.len(), 3);
+        assert_eq!(
+            status[1],
+            SignerStatus {
+                address: signers[1],
+                controlled: true,
+                last_block: Some(7),
+                in_turn: false,
+            }
+        );
+        assert_eq!(status[0].last_block, Some(6));
+        assert!(!status[0].controlled);
+        // Signer 2 hasn't sealed anything yet but is next in line
+        assert_eq!(status[2].last_block, None);
+        assert!(status[2].in_turn);
+    }
+
+    #[tokio::test]
+    async fn test_meow_signer_performance() {
+        let chain = test_chain_spec();
+        let signers = chain.signers().to_vec();
+        let index = SignerIndex::shared(100);
+        {
+            let mut index = index.lock().unwrap();
+            // Signer 2 is in turn at 2 and 5 (3 signers) but signer 0 seals both
+            for number in 1..=6u64 {
+                let in_turn = signers[(number % 3) as usize];
+                let sealer = if in_turn == signers[2] {
+                    signers[0]
+                } else {
+                    in_turn
+                };
+                index.record(number, sealer, sealer == in_turn);
+            }
+        }
+
+        let rpc =
+            MeowRpc::new(chain, Arc::new(SignerManager::new()), false).with_signer_index(index);
+        let err = rpc.get_signer_performance(0).await.unwrap_err();
+        assert_eq!(err.code(), INVALID_PARAMS_CODE);
+
+        // More blocks than indexed: the window is what the index holds
+        let report = rpc.get_signer_performance(1000).await.unwrap();
+        assert_eq!((report.from_block, report.to_block), (Some(1), Some(6)));
+        let missed: Vec<_> = report.signers.iter().map(|s| s.missed_in_turn).collect();
+        assert_eq!(missed, vec![0, 0, 2]);
+        assert_eq!(report.signers[0].out_of_turn, 2);
+    }
+
+    #[tokio::test]
+    async fn test_meow_signer_status_follows_reorgs_in_signer_index() {
+        let chain = test_chain_spec();
+        let signers = chain.signers().to_vec();
+        let recent = RecentSigners::shared();
+        let index = SignerIndex::shared(100);
+        {
+            let mut recent = recent.lock().unwrap();
+            let mut index = index.lock().unwrap();
+            for (number, signer) in [
+                (6, signers[0]),
+                (7, signers[1]),
+                (8, signers[2]),
+                (9, signers[0]),
+                (10, signers[1]),
+            ] {
+                recent.record_sealed(signer, number);
+                index.record(number, signer, true);
+            }
+            // Blocks 9 and 10 are reorged out; signer 2 seals 9 out of turn
+            index.record(9, signers[2], false);
+        }
+
+        let rpc = MeowRpc::new(chain, Arc::new(SignerManager::new()), false)
+            .with_chain_head(Arc::new(AtomicU64::new(9)))
+            .with_recent_signers(recent, 0)
+            .with_signer_index(index);
+        let status = rpc.get_signer_status().await.unwrap();
+        let last_blocks: Vec<_> = status.iter().map(|s| s.last_block).collect();
+        assert_eq!(last_blocks, vec![Some(6), Some(7), Some(9)]);
+    }
+
+    #[tokio::test]
+    async fn test_meow_storage_proof_for_missing_account() {
+        let manager = Arc::new(SignerManager::new());
+        let rpc = MeowRpc::new(test_chain_spec(), manager.clone(), false);
+        let address = Address::with_last_byte(0x99);
+        let slots = vec![B256::ZERO, B256::with_last_byte(7)];
+
+        // No state wired in yet
+        assert!(rpc
+            .get_storage_proof(address, slots.clone(), None)
+            .await
+            .is_err());
+
+        let rpc =
+            rpc.with_state_source(Arc::new(GenesisState(crate::genesis::create_dev_genesis())));
+        let response = rpc
+            .get_storage_proof(address, slots.clone(), None)
+            .await
+            .unwrap();
+        assert_eq!(response.address, address);
+        assert_eq!(response.storage_proof.len(), 2);
+        for (proof, slot) in response.storage_proof.iter().zip(&slots) {
+            assert_eq!(proof.key.as_b256(), *slot);
+            assert!(proof.value.is_zero(), "absent slot proves zero");
+        }
+    }
+
+    /// History of a fixed chain segment. Each block holds one 21k-gas transaction
+    /// tipping the block number; no fee breakdowns are known.
+    struct FixedHeaders(Vec<alloy_consensus::Header>);
+
+    impl ChainHistorySource for FixedHeaders {
+        fn best_block_number(&self) -> reth_ethereum::provider::ProviderResult<u64> {
+            Ok(self.0.last().map_or(0, |header| header.number))
+        }
+
+        fn earliest_block_number(&self) -> reth_ethereum::provider::ProviderResult<u64> {
+            Ok(self.0.first().map_or(0, |header| header.number))
+        }
+
+        fn recent_headers(
+            &self,
+            count: u64,
+        ) -> reth_ethereum::provider::ProviderResult<Vec<alloy_consensus::Header>> {
+            let skip = self.0.len().saturating_sub(count as usize);
+            Ok(self.0[skip..].to_vec())
+        }
+
+        fn header_by_id(
+            &self,
+            block: BlockId,
+        ) -> reth_ethereum::provider::ProviderResult<Option<alloy_consensus::Header>> {
+            Ok(match block {
+                BlockId::Hash(hash) => self
+                    .0
+                    .iter()
+                    .find(|header| header.hash_slow() == hash.block_hash),
+                BlockId::Number(BlockNumberOrTag::Number(n)) => {
+                    self.0.iter().find(|header| header.number == n)
+                }
+                BlockId::Number(_) => self.0.last(),
+            }
+            .cloned())
+        }
+
+        fn block_fees(
+            &self,
+            _number: u64,
+        ) -> reth_ethereum::provider::ProviderResult<Option<BlockFees>> {
+            Ok(None)
+        }
+
+        fn fee_history_block(
+            &self,
+            number: u64,
+        ) -> reth_ethereum::provider::ProviderResult<Option<fee_history::FeeHistoryBlock>> {
+            Ok(self
+                .0
+                .iter()
+                .find(|header| header.number == number)
+                .map(|header| fee_history::FeeHistoryBlock {
+                    header: header.clone(),
+                    tx_tips: vec![(21_000, u128::from(number))],
+                }))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_meow_signer_signature_stats() {
+        let manager = crate::signer::dev::setup_dev_signers().await;
+        let sealer = crate::signer::BlockSealer::new(manager.clone());
+        let seal_len = crate::consensus::EXTRA_VANITY_LENGTH + crate::consensus::EXTRA_SEAL_LENGTH;
+        let signer = manager.signer_addresses().await[0];
+
+        let mut headers = Vec::new();
+        for number in 1..=3 {
+            let header = alloy_consensus::Header {
+                number,
+                extra_data: vec![0u8; seal_len].into(),
+                ..Default::default()
+            };
+            headers.push(sealer.seal_header(header, &signer).await.unwrap());
+        }
+        headers.push(alloy_consensus::Header {
+            number: 4,
+            extra_data: vec![0u8; 8].into(),
+            ..Default::default()
+        });
+
+        let rpc = MeowRpc::new(test_chain_spec(), manager, false);
+        assert!(rpc.get_signer_signature_stats(10).await.is_err());
+
+        let rpc = rpc.with_history_source(Arc::new(FixedHeaders(headers)));
+        let stats = rpc.get_signer_signature_stats(10).await.unwrap();
+        assert_eq!(stats.blocks, 4);
+        assert_eq!(stats.recovered, 3);
+        assert_eq!(stats.unauthorized, 0);
+        assert_eq!(stats.malformed_extra_data, 1);
+
+        // Window covers only the malformed head
+        let stats = rpc.get_signer_signature_stats(1).await.unwrap();
+        assert_eq!((stats.blocks, stats.malformed_extra_data), (1, 1));
+    }
+
+    #[tokio::test]
+    async fn test_meow_get_block_signer() {
+        let chain = test_chain_spec();
+        let signers = chain.signers().to_vec();
+        let manager = crate::signer::dev::setup_dev_signers().await;
+        let sealer = crate::signer::BlockSealer::new(manager.clone());
+        let seal_len = crate::consensus::EXTRA_VANITY_LENGTH + crate::consensus::EXTRA_SEAL_LENGTH;
+
+        // Genesis, blocks 1-2 in turn, block 3 sealed out of turn by signer 1
+        let mut headers = vec![alloy_consensus::Header::default()];
+        for (number, signer) in [(1, signers[1]), (2, signers[2]), (3, signers[1])] {
+            let header = alloy_consensus::Header {
+                number,
+                parent_hash: headers.last().unwrap().hash_slow(),
+                extra_data: vec![0u8; seal_len].into(),
+                ..Default::default()
+            };
+            headers.push(sealer.seal_header(header, &signer).await.unwrap());
+        }
+
+        let rpc = MeowRpc::new(chain, manager, false);
+        assert!(rpc.get_block_signer(BlockId::number(1)).await.is_err());
+        let rpc = rpc.with_history_source(Arc::new(FixedHeaders(headers.clone())));
+
+        let genesis = rpc.get_block_signer(BlockId::number(0)).await.unwrap();
+        assert!(genesis.unsigned_genesis);
+        assert_eq!(genesis.signer, None);
+        assert_eq!(genesis.block_hash, headers[0].hash_slow());
+
+        for number in 1..=2 {
+            let info = rpc.get_block_signer(BlockId::number(number)).await.unwrap();
+            assert_eq!(info.signer, Some(signers[number as usize]));
+            assert!(info.in_turn);
+            assert!(!info.unsigned_genesis);
+        }
+        let out_of_turn = rpc.get_block_signer(BlockId::latest()).await.unwrap();
+        assert_eq!(
+            out_of_turn,
+            BlockSigner {
+                block_number: 3,
+                block_hash: headers[3].hash_slow(),
+                signer: Some(signers[1]),
+                expected_signer: Some(signers[0]),
+                in_turn: false,
+                unsigned_genesis: false,
+            }
+        );
+        let by_hash = rpc
+            .get_block_signer(BlockId::from(headers[2].hash_slow()))
+            .await
+            .unwrap();
+        assert_eq!(by_hash.block_number, 2);
+
+        assert!(rpc.get_block_signer(BlockId::number(9)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_meow_get_block_signer_non_zero_genesis() {
+        let genesis =
+            genesis::create_genesis(genesis::GenesisConfig::dev().with_genesis_number(1_000));
+        let poa = PoaConfig::from_genesis(&genesis).unwrap();
+        let chain = Arc::new(PoaChainSpec::new(genesis, poa));
+        let genesis_header = chain.genesis_header().clone();
+        let manager = crate::signer::dev::setup_dev_signers().await;
+
+        let rpc = MeowRpc::new(chain, manager, false)
+            .with_history_source(Arc::new(FixedHeaders(vec![genesis_header.clone()])));
+        let info = rpc.get_block_signer(BlockId::number(1_000)).await.unwrap();
+        assert!(info.unsigned_genesis);
+        assert_eq!(info.block_number, 1_000);
+        assert_eq!(info.block_hash, genesis_header.hash_slow());
+    }
+
+    #[tokio::test]
+    async fn test_meow_status() {
+        let chain = test_chain_spec();
+        let manager = Arc::new(SignerManager::new());
+        manager
+            .add_signer_from_hex(crate::signer::dev::DEV_PRIVATE_KEYS[0])
+            .await
+            .unwrap();
+        let started = Instant::now() - std::time::Duration::from_secs(90);
+
+        // Without chain history: head number from the monitor, no hash
+        let rpc = MeowRpc::new(chain.clone(), manager.clone(), false)
+            .with_start_time(started)
+            .with_chain_head(Arc::new(AtomicU64::new(41)));
+        let status = rpc.status().await.unwrap();
+        assert_eq!(status.chain_id, 9323310);
+        assert_eq!(status.block_number, 41);
+        assert_eq!(status.block_hash, None);
+        assert_eq!(status.signer_count, 3);
+        assert!(status.is_authority);
+        assert!(status.uptime_seconds >= 90);
+        assert!(!status.dev_mode);
+        assert_eq!(status.block_period, 2);
+        assert!(!status.degraded);
+
+        let head = alloy_consensus::Header {
+            number: 42,
+            ..Default::default()
+        };
+        let rpc = rpc.with_history_source(Arc::new(FixedHeaders(vec![head.clone()])));
+        let status = rpc.status().await.unwrap();
+        assert_eq!(status.block_number, 42);
+        assert_eq!(status.block_hash, Some(head.hash_slow()));
+
+        let json = serde_json::to_value(&status).unwrap();
+        for field in [
+            "chainId",
+            "blockNumber",
+            "blockHash",
+            "signerCount",
+            "isAuthority",
+            "uptimeSeconds",
+            "devMode",
+            "blockPeriod",
+            "degraded",
+        ] {
+            assert!(json.get(field).is_some(), "missing {field}");
+        }
+
+        let liveness = SignerLiveness::shared();
+        liveness.update(1, 2);
+        let rpc = rpc.with_signer_liveness(liveness);
+        assert!(rpc.status().await.unwrap().degraded);
+
+        // A key that isn't an authorized signer doesn't make the node an authority
+        let outsider = Arc::new(SignerManager::new());
+        outsider
+            .add_signer_from_hex(crate::signer::dev::DEV_PRIVATE_KEYS[5])
+            .await
+            .unwrap();
+        let rpc = MeowRpc::new(chain, outsider, false);
+        assert!(!rpc.status().await.unwrap().is_authority);
+    }
+
+    #[tokio::test]
+    async fn test_meow_get_fee_history() {
+        let manager = crate::signer::dev::setup_dev_signers().await;
+        let sealer = crate::signer::BlockSealer::new(manager.clone());
+        let seal_len = crate::consensus::EXTRA_VANITY_LENGTH + crate::consensus::EXTRA_SEAL_LENGTH;
+
+        // Block 1 and 2 in turn, block 3 sealed out of turn by block 1's signer
+        let chain_spec = test_chain_spec();
+        let mut headers = Vec::new();
+        for number in 1..=3u64 {
+            let signer = chain_spec
+                .expected_signer(if number == 3 { 1 } else { number })
+                .unwrap();
+            let header = alloy_consensus::Header {
+                number,
+                gas_limit: 42_000,
+                gas_used: 21_000,
+                base_fee_per_gas: Some(1_000),
+                extra_data: vec![0u8; seal_len].into(),
+                ..Default::default()
+            };
+            headers.push(sealer.seal_header(header, &signer).await.unwrap());
+        }
+
+        let rpc = MeowRpc::new(chain_spec.clone(), manager, false).with_gas_price_oracle(2, 50);
+        assert!(rpc
+            .get_fee_history(3, BlockNumberOrTag::Latest, None)
+            .await
+            .is_err());
+
+        let rpc = rpc.with_history_source(Arc::new(FixedHeaders(headers)));
+        let history = rpc
+            .get_fee_history(3, BlockNumberOrTag::Latest, Some(vec![50.0]))
+            .await
+            .unwrap();
+        assert_eq!(history.history.oldest_block, 1);
+        // One extra entry for the next block; half-full blocks keep the base fee
+        assert_eq!(history.history.base_fee_per_gas, vec![1_000; 4]);
+        assert_eq!(history.history.gas_used_ratio, vec![0.5; 3]);
+        assert_eq!(
+            history.history.reward,
+            Some(vec![vec![1], vec![2], vec![3]])
+        );
+        assert_eq!(
+            history.signers,
+            vec![
+                chain_spec.expected_signer(1),
+                chain_spec.expected_signer(2),
+                chain_spec.expected_signer(1)
+            ]
+        );
+        assert_eq!(history.in_turn, vec![Some(true), Some(true), Some(false)]);
+
+        // Defaults come from the gas price oracle settings: 2 blocks at the 50th percentile
+        let history = rpc
+            .get_fee_history(0, BlockNumberOrTag::Number(2), None)
+            .await
+            .unwrap();
+        assert_eq!(history.history.oldest_block, 1);
+        assert_eq!(history.history.reward, Some(vec![vec![1], vec![2]]));
+
+        // Earliest is the oldest stored block, not genesis
+        let history = rpc
+            .get_fee_history(1, BlockNumberOrTag::Earliest, None)
+            .await
+            .unwrap();
+        assert_eq!(history.history.oldest_block, 1);
+
+        let err = rpc
+            .get_fee_history(1, BlockNumberOrTag::Latest, Some(vec![90.0, 10.0]))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), INVALID_PARAMS_CODE);
+        assert!(rpc
+            .get_fee_history(1, BlockNumberOrTag::Number(9), None)
+            .await
+            .is_err());
+    }
+
+    /// Knows fees for a single block, and nothing else.
+    struct OneBlockFees(BlockFees);
+
+    impl ChainHistorySource for OneBlockFees {
+        fn best_block_number(&self) -> reth_ethereum::provider::ProviderResult<u64> {
+            Ok(self.0.block_number)
+        }
+
+        fn earliest_block_number(&self) -> reth_ethereum::provider::ProviderResult<u64> {
+            Ok(self.0.block_number)
+        }
+
+        fn recent_headers(
+            &self,
+            _count: u64,
+        ) -> reth_ethereum::provider::ProviderResult<Vec<alloy_consensus::Header>> {
+            Ok(Vec::new())
+        }
+
+        fn header_by_id(
+            &self,
+            _block: BlockId,
+        ) -> reth_ethereum::provider::ProviderResult<Option<alloy_consensus::Header>> {
+            Ok(None)
+        }
+
+        fn block_fees(
+            &self,
+            number: u64,
+        ) -> reth_ethereum::provider::ProviderResult<Option<BlockFees>> {
+            Ok((number == self.0.block_number).then(|| self.0.clone()))
+        }
+
+        fn fee_history_block(
+            &self,
+            _number: u64,
+        ) -> reth_ethereum::provider::ProviderResult<Option<fee_history::FeeHistoryBlock>> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_meow_get_block_reward() {
+        let rpc = MeowRpc::new(test_chain_spec(), Arc::new(SignerManager::new()), false);
+        assert!(rpc.get_block_reward(5).await.is_err());
+
+        let coinbase = Address::with_last_byte(0xcb);
+        let fees = BlockFees {
+            block_number: 5,
+            base_fees: U256::from(100),
+            priority_fees: U256::from(7),
+            fee_recipient: coinbase,
+            ..Default::default()
+        };
+        let rpc = rpc.with_history_source(Arc::new(OneBlockFees(fees)));
+        let reward = rpc.get_block_reward(5).await.unwrap();
+        assert_eq!(reward.block_number, 5);
+        assert_eq!(reward.fees, U256::from(7));
+        assert_eq!(reward.burned, U256::from(100));
+        assert_eq!(reward.coinbase, coinbase);
+
+        let json = serde_json::to_value(reward).unwrap();
+        for field in ["fees", "burned", "coinbase"] {
+            assert!(json.get(field).is_some(), "missing {field}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_meow_get_block_fees() {
+        let rpc = MeowRpc::new(test_chain_spec(), Arc::new(SignerManager::new()), false);
+        assert!(rpc.get_block_fees(5).await.is_err());
+
+        let fees = BlockFees {
+            block_number: 5,
+            tx_count: 1,
+            ..Default::default()
+        };
+        let rpc = rpc.with_history_source(Arc::new(OneBlockFees(fees.clone())));
+        assert_eq!(rpc.get_block_fees(5).await.unwrap(), fees);
+        let err = rpc.get_block_fees(6).await.unwrap_err();
+        assert!(err.message().contains("block 6 not found"));
+    }
+
+    /// Replays only block 1, which touched a single account.
+    struct OneBlockReplay;
+
+    impl ExecutionSource for OneBlockReplay {
+        fn replay_block(
+            &self,
+            block: BlockId,
+        ) -> Result<Option<crate::statediff::StateDiff>, replay::ReplayError> {
+            if block != BlockId::number(1) {
+                return Ok(None);
+            }
+            let mut builder = crate::statediff::StateDiffBuilder::new(1, B256::with_last_byte(1));
+            builder.record_nonce_change(Address::with_last_byte(0xaa), 0, 1);
+            Ok(Some(builder.build()))
+        }
+
+        fn trace_block(
+            &self,
+            _block: BlockId,
+            _call_config: GethCallConfig,
+        ) -> Result<Option<Vec<TraceResult>>, debug::TraceError> {
+            Ok(None)
+        }
+
+        fn estimate_gas(
+            &self,
+            _request: TransactionRequest,
+        ) -> Result<GasEstimate, estimate::EstimateError> {
+            Err(estimate::EstimateError::HeadNotFound)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_meow_replay_block() {
+        let rpc = MeowRpc::new(test_chain_spec(), Arc::new(SignerManager::new()), false);
+        assert!(rpc.replay_block(BlockId::number(1)).await.is_err());
+
+        let rpc = rpc.with_execution_source(Arc::new(OneBlockReplay));
+        let diff = rpc.replay_block(BlockId::number(1)).await.unwrap();
+        assert_eq!(diff.block_number, 1);
+        assert_eq!(diff.touched_account_count(), 1);
+        let err = rpc.replay_block(BlockId::number(2)).await.unwrap_err();
+        assert!(err.message().contains("not found"));
+    }
+
+    /// Traces block 1 as two transactions. Each frame's gas is 1 if `onlyTopCall`
+    /// reached the source.
+    struct TwoTxTrace;
+
+    impl ExecutionSource for TwoTxTrace {
+        fn trace_block(
+            &self,
+            block: BlockId,
+            call_config: GethCallConfig,
+        ) -> Result<Option<Vec<TraceResult>>, debug::TraceError> {
+            if block != BlockId::number(1) {
+                return Ok(None);
+            }
+            let only_top_call = call_config.only_top_call.unwrap_or_default();
+            let traces = (1..=2)
+                .map(|n| TraceResult::Success {
+                    result: alloy_rpc_types_trace::geth::CallFrame {
+                        gas: U256::from(only_top_call as u8),
+                        ..Default::default()
+                    }
+                    .into(),
+                    tx_hash: Some(B256::with_last_byte(n)),
+                })
+                .collect();
+            Ok(Some(traces))
+        }
+
+        fn replay_block(
+            &self,
+            _block: BlockId,
+        ) -> Result<Option<crate::statediff::StateDiff>, replay::ReplayError> {
+            Ok(None)
+        }
+
+        fn estimate_gas(
+            &self,
+            _request: TransactionRequest,
+        ) -> Result<GasEstimate, estimate::EstimateError> {
+            Err(estimate::EstimateError::HeadNotFound)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_meow_trace_block() {
+        let rpc = MeowRpc::new(test_chain_spec(), Arc::new(SignerManager::new()), false);
+        assert!(rpc.trace_block(BlockId::number(1), None).await.is_err());
+
+        let rpc = rpc.with_execution_source(Arc::new(TwoTxTrace));
+        let config = GethCallConfig {
+            only_top_call: Some(true),
+            ..Default::default()
+        };
+        let traces = rpc
+            .trace_block(BlockId::number(1), Some(config))
+            .await
+            .unwrap();
+        let hashes: Vec<_> = traces
+            .iter()
+            .map(|trace| match trace {
+                TraceResult::Success {
+                    result: alloy_rpc_types_trace::geth::GethTrace::CallTracer(frame),
+                    tx_hash,
+                } => {
+                    assert_eq!(frame.gas, U256::from(1));
+                    tx_hash.unwrap()
+                }
+                other => panic!("unexpected trace {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            hashes,
+            vec![B256::with_last_byte(1), B256::with_last_byte(2)]
+        );
+
+        let err = rpc.trace_block(BlockId::number(2), None).await.unwrap_err();
+        assert!(err.message().contains("not found"));
+    }
+
+    /// Replays blocks 0..=10, each touching the account numbered after it.
+    struct TenBlockReplay;
+
+    impl ExecutionSource for TenBlockReplay {
+        fn replay_block(
+            &self,
+            block: BlockId,
+        ) -> Result<Option<crate::statediff::StateDiff>, replay::ReplayError> {
+            let Some(number) = block.as_u64().filter(|&n| n <= 10) else {
+                return Ok(None);
+            };
+            let mut builder =
+                crate::statediff::StateDiffBuilder::new(number, B256::with_last_byte(number as u8));
+            builder.record_nonce_change(Address::with_last_byte(number as u8), 0, 1);
+            Ok(Some(builder.build()))
+        }
+
+        fn trace_block(
+            &self,
+            _block: BlockId,
+            _call_config: GethCallConfig,
+        ) -> Result<Option<Vec<TraceResult>>, debug::TraceError> {
+            Ok(None)
+        }
+
+        fn estimate_gas(
+            &self,
+            _request: TransactionRequest,
+        ) -> Result<GasEstimate, estimate::EstimateError> {
+            Err(estimate::EstimateError::HeadNotFound)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_meow_state_diff_range() {
+        let rpc = MeowRpc::new(test_chain_spec(), Arc::new(SignerManager::new()), false)
+            .with_execution_source(Arc::new(TenBlockReplay));
+
+        let diffs = rpc.get_state_diff_range(3, 6, 10).await.unwrap();
+        let numbers: Vec<_> = diffs.iter().map(|diff| diff.block_number).collect();
+        assert_eq!(numbers, vec![3, 4, 5, 6]);
+        assert!(diffs.iter().all(|diff| diff.touched_account_count() == 1));
+
+        // Over the caller's cap, or backwards: the client must paginate
+        let err = rpc.get_state_diff_range(3, 6, 3).await.unwrap_err();
+        assert_eq!(err.code(), INVALID_PARAMS_CODE);
+        assert!(err.message().contains("exceeds"), "{}", err.message());
+        let err = rpc.get_state_diff_range(6, 3, 10).await.unwrap_err();
+        assert_eq!(err.code(), INVALID_PARAMS_CODE);
+        // The server cap applies whatever the caller asks for
+        let err = rpc
+            .get_state_diff_range(0, MAX_STATE_DIFF_RANGE_BLOCKS, u64::MAX)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), INVALID_PARAMS_CODE);
+
+        // A block past the head fails the whole range
+        let err = rpc.get_state_diff_range(9, 11, 10).await.unwrap_err();
+        assert!(
+            err.message().contains("block 11 not found"),
+            "{}",
+            err.message()
+        );
+    }
+
+    /// Serves state straight from a genesis alloc, with no head block. Proofs are
+    /// exclusion proofs, as over an empty trie.
+    struct GenesisState(alloy_genesis::Genesis);
+
+    impl GenesisState {
+        fn reader(&self) -> crate::onchain::GenesisStorageReader {
+            crate::onchain::GenesisStorageReader::from_genesis(&self.0)
+        }
+    }
+
+    impl ChainStateSource for GenesisState {
+        fn account_proof(
+            &self,
+            address: Address,
+            slots: &[B256],
+            _block: BlockId,
+        ) -> reth_ethereum::provider::ProviderResult<reth_trie_common::AccountProof> {
+            let mut proof = reth_trie_common::AccountProof::new(address);
+            proof.storage_proofs = slots
+                .iter()
+                .map(|slot| reth_trie_common::StorageProof::new(*slot))
+                .collect();
+            Ok(proof)
+        }
+
+        fn check_state(
+            &self,
+            sender: Address,
+        ) -> reth_ethereum::provider::ProviderResult<TxCheckState> {
+            Ok(TxCheckState {
+                head: None,
+                chain_config: crate::onchain::read_chain_config(&self.reader()),
+                sender: self
+                    .0
+                    .alloc
+                    .get(&sender)
+                    .map(|account| reth_primitives_traits::Account {
+                        nonce: account.nonce.unwrap_or_default(),
+                        balance: account.balance,
+                        bytecode_hash: None,
+                    }),
+            })
+        }
+
+        fn live_chain_config(
+            &self,
+        ) -> reth_ethereum::provider::ProviderResult<Option<DynamicChainConfig>> {
+            Ok(crate::onchain::read_chain_config(&self.reader()))
+        }
+
+        fn live_signer_list(
+            &self,
+        ) -> reth_ethereum::provider::ProviderResult<Option<DynamicSignerList>> {
+            Ok(crate::onchain::read_signer_list(&self.reader()))
+        }
+
+        fn safe_info(
+            &self,
+            safe: Address,
+        ) -> reth_ethereum::provider::ProviderResult<Option<SafeInfo>> {
+            Ok(crate::onchain::read_safe_info(&self.reader(), safe))
+        }
+
+        fn governance_state(&self) -> reth_ethereum::provider::ProviderResult<GovernanceState> {
+            Ok(read_governance_state(&self.reader()))
+        }
+
+        fn miner_proxy_admin(&self) -> reth_ethereum::provider::ProviderResult<Option<Address>> {
+            Ok(crate::onchain::read_miner_proxy_admin(&self.reader()))
+        }
+
+        fn timelock_operations(
+            &self,
+            scheduled: &[(B256, Address)],
+            now: u64,
+        ) -> reth_ethereum::provider::ProviderResult<Vec<TimelockOperation>> {
+            Ok(read_timelock_operations(&self.reader(), scheduled, now))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_meow_get_timelock_operations() {
+        let mut genesis = crate::genesis::create_dev_genesis();
+        let schedule = TimelockSchedule::shared();
+        let rpc = MeowRpc::new(test_chain_spec(), Arc::new(SignerManager::new()), false)
+            .with_timelock_schedule(schedule.clone())
+            .with_state_source(Arc::new(GenesisState(genesis.clone())));
+        // Nothing queued
+        assert!(rpc.get_timelock_operations().await.unwrap().is_empty());
+
+        // Queue one operation: timestamps[id] = eta, as schedule() would
+        let id = B256::repeat_byte(0x42);
+        let target = Address::with_last_byte(0xcc);
+        let eta = 4_102_444_800; // 2100-01-01, not ready yet
+        let slot = crate::onchain::mapping_b256_slot(
+            id,
+            crate::onchain::timelock_slots::TIMESTAMPS_MAPPING,
+        );
+        genesis
+            .alloc
+            .get_mut(&crate::genesis::TIMELOCK_ADDRESS)
+            .and_then(|account| account.storage.as_mut())
+            .unwrap()
+            .insert(slot, B256::from(U256::from(eta)));
+        schedule.lock().unwrap().record(1, id, target);
+        let rpc = rpc.with_state_source(Arc::new(GenesisState(genesis)));
+        assert_eq!(
+            rpc.get_timelock_operations().await.unwrap(),
+            vec![TimelockOperation {
+                id,
+                target,
+                eta: Some(eta),
+                ready: false,
+                executed: false,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_meow_get_miner_proxy_admin() {
+        let rpc = MeowRpc::new(test_chain_spec(), Arc::new(SignerManager::new()), false);
+        assert!(rpc.get_miner_proxy_admin().await.is_err());
+
+        let genesis = crate::genesis::create_dev_genesis();
+        let rpc = rpc.with_state_source(Arc::new(GenesisState(genesis)));
+        assert_eq!(
+            rpc.get_miner_proxy_admin().await.unwrap(),
+            Some(GOVERNANCE_SAFE_ADDRESS)
+        );
+
+        let rpc = rpc.with_state_source(Arc::new(GenesisState(Default::default())));
+        assert_eq!(rpc.get_miner_proxy_admin().await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_meow_get_governance_state() {
+        let rpc = MeowRpc::new(test_chain_spec(), Arc::new(SignerManager::new()), false);
+        assert!(rpc.get_governance_state().await.is_err());
+
+        let genesis = crate::genesis::create_dev_genesis();
+        let rpc = rpc.with_state_source(Arc::new(GenesisState(genesis)));
+        let state = rpc.get_governance_state().await.unwrap();
+        assert_eq!(state.chain_config.unwrap().gas_limit, 300_000_000);
+        assert_eq!(
+            state.signer_registry.unwrap().signers,
+            crate::genesis::dev_signers()
+        );
+        assert_eq!(state.timelock_delay, Some(86400));
+        assert_eq!(state.timelock_proposer, Some(GOVERNANCE_SAFE_ADDRESS));
+        assert_eq!(state.timelock_paused, Some(false));
+
+        // Custom genesis without governance contracts: every field null, no error
+        let rpc = rpc.with_state_source(Arc::new(GenesisState(Default::default())));
+        let state = rpc.get_governance_state().await.unwrap();
+        let json = serde_json::to_value(&state).unwrap();
+        for field in [
+            "chainConfig",
+            "signerRegistry",
+            "timelockDelay",
+            "timelockProposer",
+            "timelockPaused",
+        ] {
+            assert!(json[field].is_null(), "{field} should be null");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_meow_get_chain_config() {
+        let rpc = MeowRpc::new(test_chain_spec(), Arc::new(SignerManager::new()), false);
+        assert!(rpc.get_chain_config().await.is_err());
+
+        let genesis = crate::genesis::create_dev_genesis();
+        let rpc = rpc.with_state_source(Arc::new(GenesisState(genesis)));
+        let config = rpc.get_chain_config().await.unwrap().unwrap();
+        assert_eq!(config.gas_limit, 300_000_000);
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["gasLimit"], 300_000_000);
+        assert_eq!(json["eagerMining"], false);
+
+        // Custom genesis without the governance contracts
+        let rpc = rpc.with_state_source(Arc::new(GenesisState(Default::default())));
+        assert_eq!(rpc.get_chain_config().await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_meow_get_signer_registry() {
+        let rpc = MeowRpc::new(test_chain_spec(), Arc::new(SignerManager::new()), false);
+        assert!(rpc.get_signer_registry().await.is_err());
+
+        let genesis = crate::genesis::create_dev_genesis();
+        let rpc = rpc.with_state_source(Arc::new(GenesisState(genesis)));
+        let registry = rpc.get_signer_registry().await.unwrap().unwrap();
+        assert_eq!(registry.signers, crate::genesis::dev_signers());
+        assert_eq!(registry.threshold, 2);
+        assert_eq!(registry.governance, GOVERNANCE_SAFE_ADDRESS);
+        let json = serde_json::to_value(&registry).unwrap();
+        assert_eq!(json["signers"].as_array().unwrap().len(), 3);
+        assert_eq!(json["threshold"], 2);
+
+        let rpc = rpc.with_state_source(Arc::new(GenesisState(Default::default())));
+        assert_eq!(rpc.get_signer_registry().await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_meow_get_safe_info() {
+        use crate::onchain::{encode_address, encode_u64, mapping_address_bool_slot, safe_slots};
+        use alloy_primitives::U256;
+
+        let rpc = MeowRpc::new(test_chain_spec(), Arc::new(SignerManager::new()), false);
+        assert!(rpc.get_safe_info(None).await.is_err());
+
+        // A 1-of-1 Safe at the governance address, owned by the first dev account
+        let owner = crate::genesis::dev_accounts()[0];
+        let slot = |value: U256| B256::from(value.to_be_bytes());
+        let owner_slot = |key| {
+            slot(U256::from_be_bytes(
+                mapping_address_bool_slot(key, safe_slots::OWNERS).0,
+            ))
+        };
+        let storage = [
+            (
+                owner_slot(safe_slots::SENTINEL_OWNERS),
+                encode_address(owner),
+            ),
+            (
+                owner_slot(owner),
+                encode_address(safe_slots::SENTINEL_OWNERS),
+            ),
+            (slot(safe_slots::OWNER_COUNT), encode_u64(1)),
+            (slot(safe_slots::THRESHOLD), encode_u64(1)),
+        ];
+        let mut genesis = crate::genesis::create_dev_genesis();
+        genesis.alloc.insert(
+            GOVERNANCE_SAFE_ADDRESS,
+            alloy_genesis::GenesisAccount {
+                storage: Some(storage.into_iter().collect()),
+                ..Default::default()
+            },
+        );
+        let rpc = rpc.with_state_source(Arc::new(GenesisState(genesis)));
+
+        let info = rpc.get_safe_info(None).await.unwrap().unwrap();
+        assert_eq!(info.address, GOVERNANCE_SAFE_ADDRESS);
+        assert_eq!((info.owners, info.threshold), (vec![owner], 1));
+        let explicit = rpc
+            .get_safe_info(Some(GOVERNANCE_SAFE_ADDRESS))
+            .await
+            .unwrap();
+        assert_eq!(explicit.map(|info| info.threshold), Some(1));
+
+        // Addresses without a Safe read as null
+        assert_eq!(rpc.get_safe_info(Some(owner)).await.unwrap(), None);
+        assert_eq!(
+            rpc.get_safe_info(Some(CHAIN_CONFIG_ADDRESS)).await.unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_meow_decode_extra_data() {
+        let rpc = MeowRpc::new(test_chain_spec(), Arc::new(SignerManager::new()), false);
+        let vanity = [0x11u8; crate::constants::EXTRA_VANITY_LENGTH];
+        let seal = [0x22u8; crate::constants::EXTRA_SEAL_LENGTH];
+        let signers = [Address::with_last_byte(1), Address::with_last_byte(2)];
+
+        // Epoch block: vanity || signers || seal
+        let mut epoch = vanity.to_vec();
+        for signer in &signers {
+            epoch.extend_from_slice(signer.as_slice());
+        }
+        epoch.extend_from_slice(&seal);
+        let decoded = rpc.decode_extra_data(epoch.into()).await.unwrap();
+        assert_eq!(decoded.vanity, B256::from(vanity));
+        assert_eq!(decoded.signers, signers);
+        assert_eq!(decoded.seal, Bytes::from(seal));
+        assert!(decoded.is_epoch_format);
+
+        // Regular block: vanity || seal
+        let regular = [vanity.as_slice(), seal.as_slice()].concat();
+        let decoded = rpc.decode_extra_data(regular.into()).await.unwrap();
+        assert!(decoded.signers.is_empty());
+        assert_eq!(decoded.seal, Bytes::from(seal));
+        assert!(!decoded.is_epoch_format);
+
+        let json = serde_json::to_value(&decoded).unwrap();
+        assert!(json.get("isEpochFormat").is_some());
+
+        let err = rpc
+            .decode_extra_data(vec![0u8; 96].into())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), INVALID_PARAMS_CODE);
+        assert!(err.message().contains("too short"), "{}", err.message());
+        let err = rpc
+            .decode_extra_data(vec![0u8; 32 + 19 + 65].into())
+            .await
+            .unwrap_err();
+        assert!(err.message().contains("signer list"), "{}", err.message());
+    }
+
+    #[tokio::test]
+    async fn test_meow_check_tx() {
+        use alloy_eips::eip2718::Encodable2718;
+        use alloy_signer::SignerSync;
+
+        let chain = test_chain_spec();
+        let key: alloy_signer_local::PrivateKeySigner =
+            crate::signer::dev::DEV_PRIVATE_KEYS[0].parse().unwrap();
+        let raw = |gas_limit: u64| {
+            let tx = alloy_consensus::TxEip1559 {
+                chain_id: chain.inner().chain.id(),
+                gas_limit,
+                max_fee_per_gas: 1_000_000_000,
+                to: alloy_primitives::TxKind::Call(Address::with_last_byte(1)),
+                ..Default::default()
+            };
+            let signature = key
+                .sign_hash_sync(&alloy_consensus::SignableTransaction::signature_hash(&tx))
+                .unwrap();
+            let signed: reth_ethereum::TransactionSigned =
+                alloy_consensus::Signed::new_unhashed(tx, signature).into();
+            Bytes::from(signed.encoded_2718())
+        };
+
+        let rpc = MeowRpc::new(chain.clone(), Arc::new(SignerManager::new()), false);
+        assert!(rpc.check_tx(raw(21_000)).await.is_err());
+
+        // A funded dev sender under the dev ChainConfig, with maxTxGas lowered to 1M
+        let mut genesis = crate::genesis::create_dev_genesis();
+        genesis
+            .alloc
+            .get_mut(&CHAIN_CONFIG_ADDRESS)
+            .and_then(|account| account.storage.as_mut())
+            .unwrap()
+            .insert(
+                B256::from(crate::onchain::chain_config_slots::MAX_TX_GAS),
+                B256::from(U256::from(1_000_000)),
+            );
+        let rpc = rpc.with_state_source(Arc::new(GenesisState(genesis)));
+        assert_eq!(
+            rpc.check_tx(raw(21_000)).await.unwrap(),
+            TxCheck::accepted()
+        );
+        assert_eq!(
+            rpc.check_tx(raw(2_000_000)).await.unwrap(),
+            TxCheck::rejected("gas limit 2000000 exceeds ChainConfig maxTxGas 1000000")
+        );
+
+        // The pool's gas price floor applies too
+        let rpc = rpc.with_min_gas_price(2_000_000_000);
+        let check = rpc.check_tx(raw(21_000)).await.unwrap();
+        assert!(check.reason.unwrap().contains("underpriced"));
+
+        let check = rpc
+            .check_tx(Bytes::from_static(&[0x02, 0x01]))
+            .await
+            .unwrap();
+        assert!(!check.valid);
+    }
+
+    /// Exports a genesis alloc as the state of block 0, the only known block.
+    struct GenesisExport(alloy_genesis::Genesis);
+
+    impl StateExportSource for GenesisExport {
+        fn export_state(
+            &self,
+            block_number: u64,
+            path: &std::path::Path,
+        ) -> Result<Option<StateExport>, export::ExportError> {
+            if block_number != 0 {
+                return Ok(None);
+            }
+            if path.exists() {
+                return Err(export::ExportError::FileExists(path.to_path_buf()));
+            }
+            let file = std::fs::File::create(path)?;
+            let mut writer = crate::genesis::GenesisWriter::new(file, &self.0)?;
+            for (address, account) in &self.0.alloc {
+                writer.write_account(*address, account)?;
+            }
+            let (accounts, storage_slots) = (writer.accounts(), writer.storage_slots());
+            writer.finish()?;
+            Ok(Some(StateExport {
+                block_number,
+                block_hash: B256::ZERO,
+                accounts,
+                storage_slots,
+                path: path.to_path_buf(),
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_meow_export_state_round_trips_dev_genesis() {
+        let dir = std::env::temp_dir().join(format!(
+            "meowchain-export-test-{}",
+            hex::encode(&B256::random()[..8])
+        ));
+        let name = "state.json".to_string();
+        let genesis = crate::genesis::create_dev_genesis();
+        let source = Arc::new(GenesisExport(genesis.clone()));
+
+        // Production nodes never write files on behalf of RPC callers
+        let rpc = MeowRpc::new(test_chain_spec(), Arc::new(SignerManager::new()), false)
+            .with_export_source(source.clone(), dir.clone());
+        let err = rpc.export_state(0, name.clone()).await.unwrap_err();
+        assert_eq!(err.code(), METHOD_NOT_FOUND_CODE);
+
+        let rpc = MeowRpc::new(test_chain_spec(), Arc::new(SignerManager::new()), true);
+        assert!(rpc.export_state(0, name.clone()).await.is_err());
+        let rpc = rpc.with_export_source(source, dir.clone());
+        let export = rpc.export_state(0, name.clone()).await.unwrap();
+        assert_eq!(export.accounts, genesis.alloc.len() as u64);
+        assert_eq!(export.path, dir.join(&name));
+
+        // The export loads back as the same genesis
+        let loaded = crate::genesis::read_genesis_file(&export.path).unwrap();
+        assert_eq!(loaded, genesis);
+
+        let err = rpc.export_state(0, name).await.unwrap_err();
+        assert!(err.message().contains("already exists"));
+        let err = rpc
+            .export_state(1, "other.json".to_string())
+            .await
+            .unwrap_err();
+        assert!(err.message().contains("block 1 not found"));
+        // Only file names inside the export directory
+        for escape in ["../escape.json", "/tmp/escape.json", "sub/escape.json"] {
+            let err = rpc.export_state(0, escape.to_string()).await.unwrap_err();
+            assert_eq!(err.code(), INVALID_PARAMS_CODE);
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_meow_subscribe_state_diff_receives_committed_diffs() {
+        use crate::statediff::{StateDiff, StateDiffBuilder};
+        use alloy_primitives::B256;
+        use jsonrpsee::core::EmptyServerParams;
+
+        let sender = state_diff_channel();
+        let rpc = MeowRpc::new(test_chain_spec(), Arc::new(SignerManager::new()), false)
+            .with_state_diffs(sender.clone());
+        let module = rpc.into_rpc();
+        let mut sub = module
+            .subscribe_unbounded("meow_subscribeStateDiff", EmptyServerParams::new())
+            .await
+            .unwrap();
+
+        let mut builder = StateDiffBuilder::new(7, B256::with_last_byte(7)).with_tx_count(1);
+        builder.record_nonce_change(alloy_primitives::Address::with_last_byte(1), 0, 1);
+        let diff = builder.build();
+        sender.send(Arc::new(diff.clone())).unwrap();
+
+        let (received, _) = sub.next::<StateDiff>().await.unwrap().unwrap();
+        assert_eq!(received, diff);
+    }
+
+    #[tokio::test]
+    async fn test_meow_subscribe_signed_blocks() {
+        use crate::monitor::SignedBlock;
+        use jsonrpsee::core::EmptyServerParams;
+
+        let sender = signed_block_channel();
+        let rpc = MeowRpc::new(test_chain_spec(), Arc::new(SignerManager::new()), false)
+            .with_signed_blocks(sender.clone());
+        let module = rpc.into_rpc();
+        let mut sub = module
+            .subscribe_unbounded("meow_subscribeSignedBlocks", EmptyServerParams::new())
+            .await
+            .unwrap();
+
+        let unsigned = SignedBlock {
+            number: 0,
+            hash: B256::with_last_byte(0),
+            signer: None,
+            in_turn: false,
+            tx_count: 0,
+            gas_used: 0,
+        };
+        let signed = SignedBlock {
+            number: 1,
+            hash: B256::with_last_byte(1),
+            signer: Some(Address::with_last_byte(0xaa)),
+            in_turn: true,
+            tx_count: 2,
+            gas_used: 42_000,
+        };
+        sender.send(Arc::new(unsigned.clone())).unwrap();
+        sender.send(Arc::new(signed.clone())).unwrap();
+
+        let (received, _) = sub.next::<SignedBlock>().await.unwrap().unwrap();
+        assert_eq!(received, unsigned);
+        let (received, _) = sub.next::<SignedBlock>().await.unwrap().unwrap();
+        assert_eq!(received, signed);
+    }
+
+    #[test]
+    fn test_chain_config_response_json_serialization() {
+        let config = ChainConfigResponse {
+            chain_id: 9323310,
+            gas_limit: 30_000_000,
+            block_time: 2,
+            epoch: 30000,
+            signer_count: 3,
+            governance_safe: GOVERNANCE_SAFE_ADDRESS,
+            chain_config_contract: CHAIN_CONFIG_ADDRESS,
+            signer_registry_contract: SIGNER_REGISTRY_ADDRESS,
+            treasury_contract: TREASURY_ADDRESS,
+        };
+
+        let json = serde_json::to_string(&config).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        // Verify camelCase field names
+        assert!(parsed.get("chainId").is_some());
+        assert!(parsed.get("gasLimit").is_some());
+        assert!(parsed.get("blockTime").is_some());
+        assert!(parsed.get("signerCount").is_some());
+        assert!(parsed.get("governanceSafe").is_some());
+        assert!(parsed.get("chainConfigContract").is_some());
+        assert!(parsed.get("signerRegistryContract").is_some());
+        assert!(parsed.get("treasuryContract").is_some());
+
+        // Verify values
+        assert_eq!(parsed["chainId"], 9323310);
+        assert_eq!(parsed["gasLimit"], 30_000_000);
+        assert_eq!(parsed["blockTime"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_meow_sign_hash_and_recover_signer() {
+        let chain = test_chain_spec();
+        let manager = Arc::new(SignerManager::new());
+        let signer = manager
+            .add_signer_from_hex(crate::signer::dev::DEV_PRIVATE_KEYS[0])
+            .await
+            .unwrap();
+        let rpc = MeowRpc::new(chain.clone(), manager.clone(), true);
+
+        // The raw signature recovers to the signer
+        let hash = B256::repeat_byte(0x42);
+        let sig = rpc.sign_hash(signer, hash).await.unwrap();
+        assert_eq!(sig.len(), 65);
+        let recovered = crate::signer::bytes_to_signature(&sig)
+            .unwrap()
+            .recover_address_from_prehash(&hash)
+            .unwrap();
+        assert_eq!(recovered, signer);
+
+        // A header sealed by BlockSealer recovers through the consensus path
+        let header = alloy_consensus::Header {
+            number: 1,
+            extra_data: vec![0u8; 32].into(),
+            ..Default::default()
+        };
+        let sealed = crate::signer::BlockSealer::new(manager)
+            .seal_header(header, &signer)
+            .await
+            .unwrap();
+        let rlp = Bytes::from(alloy_rlp::encode(&sealed));
+        assert_eq!(rpc.recover_signer(rlp).await.unwrap(), signer);
+
+        // Uncontrolled addresses and malformed headers are rejected
+        let err = rpc.sign_hash(Address::ZERO, hash).await.unwrap_err();
+        assert_eq!(err.code(), INVALID_PARAMS_CODE);
+        let err = rpc
+            .recover_signer(Bytes::from_static(&[0x01, 0x02]))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), INVALID_PARAMS_CODE);
+
+        // Never served outside dev mode
+        let production = MeowRpc::new(chain, Arc::new(SignerManager::new()), false);
+        let err = production.sign_hash(signer, hash).await.unwrap_err();
+        assert_eq!(err.code(), METHOD_NOT_FOUND_CODE);
+        assert!(production.recover_signer(Bytes::new()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_meow_simulate_reorg() {
+        let chain = test_chain_spec();
+        let signers = chain.signers().to_vec();
+        let manager = crate::signer::dev::setup_dev_signers().await;
+        let sealer = crate::signer::BlockSealer::new(manager.clone());
+        let seal_len = crate::consensus::EXTRA_VANITY_LENGTH + crate::consensus::EXTRA_SEAL_LENGTH;
+        let sealer = &sealer;
+        let seal = move |parent: &alloy_consensus::Header, signer: Address, timestamp: u64| {
+            let header = alloy_consensus::Header {
+                number: parent.number + 1,
+                parent_hash: parent.hash_slow(),
+                timestamp,
+                extra_data: vec![0u8; seal_len].into(),
+                ..Default::default()
+            };
+            async move { sealer.seal_header(header, &signer).await.unwrap() }
+        };
+        let in_turn = |number: u64| signers[number as usize % signers.len()];
+        let out_of_turn = |number: u64| signers[(number as usize + 1) % signers.len()];
+
+        // Canonical: genesis, then blocks 1-3 all in turn
+        let mut canonical = vec![alloy_consensus::Header::default()];
+        for number in 1..=3 {
+            let header = seal(canonical.last().unwrap(), in_turn(number), number).await;
+            canonical.push(header);
+        }
+        let rlp = |headers: &[alloy_consensus::Header]| {
+            headers
+                .iter()
+                .map(|h| Bytes::from(alloy_rlp::encode(h)))
+                .collect::<Vec<_>>()
+        };
+
+        // Forks off block 1 with blocks 2-4: a longer but mostly out-of-turn chain loses
+        let mut weak = vec![seal(&canonical[1], in_turn(2), 20).await];
+        for number in 3..=4 {
+            weak.push(seal(weak.last().unwrap(), out_of_turn(number), number * 10).await);
+        }
+        // ...while an all in-turn one wins
+        let mut strong = vec![seal(&canonical[1], in_turn(2), 21).await];
+        for number in 3..=4 {
+            strong.push(seal(strong.last().unwrap(), in_turn(number), number * 11).await);
+        }
+
+        let rpc = MeowRpc::new(chain.clone(), manager.clone(), true)
+            .with_history_source(Arc::new(FixedHeaders(canonical.clone())));
+        let result = rpc.simulate_reorg(rlp(&weak)).await.unwrap();
+        assert_eq!(
+            result,
+            ReorgSimulation {
+                fork_block: 1,
+                canonical_score: 2,
+                canonical_length: 2,
+                side_score: 1,
+                side_length: 3,
+                ordering: ChainOrdering::Less,
+                reorg: false,
+            }
+        );
+        let result = rpc.simulate_reorg(rlp(&strong)).await.unwrap();
+        assert_eq!(
+            (result.side_score, result.ordering),
+            (3, ChainOrdering::Greater)
+        );
+        assert!(result.reorg);
+
+        // Malformed, badly linked and detached segments are rejected
+        let invalid = |result: jsonrpsee::core::RpcResult<ReorgSimulation>| {
+            assert_eq!(result.unwrap_err().code(), INVALID_PARAMS_CODE);
+        };
+        invalid(rpc.simulate_reorg(vec![]).await);
+        invalid(rpc.simulate_reorg(vec![Bytes::from_static(&[0x01])]).await);
+        invalid(
+            rpc.simulate_reorg(rlp(&[strong[0].clone(), strong[2].clone()]))
+                .await,
+        );
+        invalid(rpc.simulate_reorg(rlp(&strong[1..])).await);
+        invalid(rpc.simulate_reorg(rlp(&canonical[..1])).await);
+
+        // Never served outside dev mode
+        let production = MeowRpc::new(chain, manager, false)
+            .with_history_source(Arc::new(FixedHeaders(canonical)));
+        let err = production.simulate_reorg(rlp(&strong)).await.unwrap_err();
+        assert_eq!(err.code(), METHOD_NOT_FOUND_CODE);
+    }
+
+    #[tokio::test]
+    async fn test_meow_get_block_timings() {
+        let timings = BlockTimings::shared();
+        let rpc = MeowRpc::new(test_chain_spec(), Arc::new(SignerManager::new()), false)
+            .with_block_timings(timings.clone());
+        assert!(rpc.get_block_timings(10).await.unwrap().is_empty());
+
+        {
+            let mut timings = timings.lock().unwrap();
+            for n in 1..=3u8 {
+                timings.record_sealed(BlockTiming {
+                    block_number: n.into(),
+                    block_hash: B256::repeat_byte(n),
+                    build_ms: 10 * u64::from(n),
+                    sign_ms: 2,
+                });
+                timings.commit(B256::repeat_byte(n));
+            }
+        }
+        let recent = rpc.get_block_timings(2).await.unwrap();
+        assert_eq!(
+            recent.iter().map(|t| t.block_number).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        assert_eq!(recent[1].build_ms, 30);
+
+        let json = serde_json::to_value(recent[0]).unwrap();
+        assert_eq!(json["buildMs"], 20);
+        assert_eq!(json["signMs"], 2);
     }
 }
@@ -0,0 +1,40 @@
+//! Merkle proofs for `meow_getStorageProof`.
+//!
+//! Proofs come from [`ChainStateSource::account_proof`](super::ChainStateSource::account_proof).
+//! Responses use the standard EIP-1186 (`eth_getProof`) shape.
+
+use alloy_primitives::B256;
+use alloy_rpc_types_eth::EIP1186AccountProofResponse;
+use reth_trie_common::AccountProof;
+
+/// Convert a proof to the EIP-1186 response, echoing the requested slot keys.
+pub fn to_eip1186_response(proof: AccountProof, slots: &[B256]) -> EIP1186AccountProofResponse {
+    proof.into_eip1186_response(slots.iter().copied().map(Into::into).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::EMPTY_ROOT_HASH;
+    use alloy_primitives::{Address, U256};
+    use reth_trie_common::StorageProof;
+
+    #[test]
+    fn test_missing_account_is_valid_exclusion_proof() {
+        let address = Address::with_last_byte(0x42);
+        let slot = B256::with_last_byte(1);
+
+        // What a state provider returns for an account absent from an empty trie
+        let mut proof = AccountProof::new(address);
+        proof.storage_proofs = vec![StorageProof::new(slot)];
+        assert!(proof.verify(EMPTY_ROOT_HASH).is_ok());
+
+        let response = to_eip1186_response(proof, &[slot]);
+        assert_eq!(response.address, address);
+        assert_eq!(response.balance, U256::ZERO);
+        assert_eq!(response.nonce, 0);
+        assert_eq!(response.storage_hash, EMPTY_ROOT_HASH);
+        assert_eq!(response.storage_proof.len(), 1);
+        assert_eq!(response.storage_proof[0].value, U256::ZERO);
+    }
+}
@@ -0,0 +1,70 @@
+//! Block replay for `meow_replayBlock`.
+//!
+//! Re-executes a stored block on top of its parent state with the node's EVM
+//! config and turns the resulting bundle into a [`StateDiff`], the same shape
+//! `meow_subscribeStateDiff` publishes. Lets a replica rebuild a diff it missed
+//! without the node keeping a diff log. Genesis has no parent, so block 0
+//! returns its alloc as account creations.
+
+use alloy_consensus::BlockHeader;
+use alloy_eips::BlockId;
+use reth_chainspec::{ChainSpecProvider, EthChainSpec};
+use reth_ethereum::provider::ProviderError;
+use reth_ethereum::storage::StateProviderFactory;
+use reth_ethereum::{Block, EthPrimitives};
+use reth_evm::execute::Executor;
+use reth_evm::ConfigureEvm;
+use reth_revm::database::StateProviderDatabase;
+use reth_storage_api::{BlockIdReader, BlockReader, TransactionVariant};
+
+use crate::statediff::{StateDiff, StateDiffBuilder};
+
+/// Errors from replaying a block.
+#[derive(Debug, thiserror::Error)]
+pub enum ReplayError {
+    /// Reading the block or its parent state failed (e.g. pruned).
+    #[error(transparent)]
+    Provider(#[from] ProviderError),
+
+    /// Re-executing the block failed.
+    #[error("execution failed: {0}")]
+    Execution(String),
+}
+
+/// State diff of `block`, re-executed with `evm_config`, or `None` if the block
+/// is unknown.
+pub fn replay_block<Provider, EvmConfig>(
+    provider: &Provider,
+    evm_config: &EvmConfig,
+    block: BlockId,
+) -> Result<Option<StateDiff>, ReplayError>
+where
+    Provider: BlockReader<Block = Block>
+        + BlockIdReader
+        + StateProviderFactory
+        + ChainSpecProvider<ChainSpec: EthChainSpec>,
+    EvmConfig: ConfigureEvm<Primitives = EthPrimitives>,
+{
+    let Some(number) = provider.block_number_for_id(block)? else {
+        return Ok(None);
+    };
+    let Some(block) = provider.recovered_block(number.into(), TransactionVariant::WithHash)? else {
+        return Ok(None);
+    };
+
+    let mut builder = StateDiffBuilder::new(number, block.hash())
+        .with_gas_used(block.header().gas_used())
+        .with_tx_count(block.body().transactions.len());
+    if number == provider.chain_spec().genesis_header().number {
+        builder.record_genesis_alloc(&provider.chain_spec().genesis().alloc);
+        return Ok(Some(builder.build()));
+    }
+
+    let state = provider.history_by_block_hash(block.header().parent_hash())?;
+    let output = evm_config
+        .executor(StateProviderDatabase::new(state))
+        .execute(&block)
+        .map_err(|e| ReplayError::Execution(e.to_string()))?;
+    builder.record_bundle(&output.state);
+    Ok(Some(builder.build()))
+}
@@ -0,0 +1,139 @@
+//! Node state for the `meow_*` methods that read accounts and contracts.
+//!
+//! `MeowRpc` is not generic over the node's provider, so state is read through
+//! the object-safe [`ChainStateSource`], implemented for every Reth
+//! [`StateProviderFactory`]: storage proofs, the `meow_checkTx` inputs and the
+//! live governance contracts.
+
+use super::check::TxCheckState;
+use super::governance::{
+    read_governance_state, read_timelock_operations, GovernanceState, TimelockOperation,
+};
+use crate::onchain::{
+    read_chain_config, read_miner_proxy_admin, read_safe_info, read_signer_list,
+    DynamicChainConfig, DynamicSignerList, SafeInfo, StateProviderStorageReader,
+};
+use alloy_consensus::Header;
+use alloy_eips::BlockId;
+use alloy_primitives::{Address, B256};
+use reth_ethereum::provider::ProviderResult;
+use reth_ethereum::storage::StateProviderFactory;
+use reth_storage_api::{AccountReader, BlockNumReader, HeaderProvider};
+use reth_trie_common::AccountProof;
+
+/// Reads account and contract state.
+///
+/// The governance methods return `None` if the contract isn't deployed (e.g. a
+/// custom genesis without the governance contracts).
+pub trait ChainStateSource: Send + Sync {
+    /// Proof of `address` and each of `slots` against the state root at `block`.
+    ///
+    /// A missing account or slot yields an exclusion proof, not an error.
+    fn account_proof(
+        &self,
+        address: Address,
+        slots: &[B256],
+        block: BlockId,
+    ) -> ProviderResult<AccountProof>;
+
+    /// The chain head, live `ChainConfig` and `sender`'s account at the latest
+    /// state, which `meow_checkTx` validates against.
+    fn check_state(&self, sender: Address) -> ProviderResult<TxCheckState>;
+
+    /// The live `ChainConfig` parameters.
+    fn live_chain_config(&self) -> ProviderResult<Option<DynamicChainConfig>>;
+
+    /// The live `SignerRegistry` signer list and threshold.
+    fn live_signer_list(&self) -> ProviderResult<Option<DynamicSignerList>>;
+
+    /// The owners and threshold of the Gnosis Safe at `safe`.
+    fn safe_info(&self, safe: Address) -> ProviderResult<Option<SafeInfo>>;
+
+    /// `ChainConfig`, `SignerRegistry` and Timelock state, read together.
+    fn governance_state(&self) -> ProviderResult<GovernanceState>;
+
+    /// The Miner Proxy's EIP-1967 admin.
+    fn miner_proxy_admin(&self) -> ProviderResult<Option<Address>>;
+
+    /// The Timelock state of the `scheduled` operations at `now`, see
+    /// [`read_timelock_operations`].
+    fn timelock_operations(
+        &self,
+        scheduled: &[(B256, Address)],
+        now: u64,
+    ) -> ProviderResult<Vec<TimelockOperation>>;
+}
+
+impl<P> ChainStateSource for P
+where
+    P: StateProviderFactory + HeaderProvider<Header = Header> + BlockNumReader + Send + Sync,
+{
+    fn account_proof(
+        &self,
+        address: Address,
+        slots: &[B256],
+        block: BlockId,
+    ) -> ProviderResult<AccountProof> {
+        self.state_by_block_id(block)?
+            .proof(Default::default(), address, slots)
+    }
+
+    fn check_state(&self, sender: Address) -> ProviderResult<TxCheckState> {
+        let head = self.header_by_number(self.best_block_number()?)?;
+        let state = self.latest()?;
+        Ok(TxCheckState {
+            head,
+            chain_config: read_chain_config(&StateProviderStorageReader::new(state.as_ref())),
+            sender: state.basic_account(&sender)?,
+        })
+    }
+
+    fn live_chain_config(&self) -> ProviderResult<Option<DynamicChainConfig>> {
+        let state = self.latest()?;
+        Ok(read_chain_config(&StateProviderStorageReader::new(
+            state.as_ref(),
+        )))
+    }
+
+    fn live_signer_list(&self) -> ProviderResult<Option<DynamicSignerList>> {
+        let state = self.latest()?;
+        Ok(read_signer_list(&StateProviderStorageReader::new(
+            state.as_ref(),
+        )))
+    }
+
+    fn safe_info(&self, safe: Address) -> ProviderResult<Option<SafeInfo>> {
+        let state = self.latest()?;
+        Ok(read_safe_info(
+            &StateProviderStorageReader::new(state.as_ref()),
+            safe,
+        ))
+    }
+
+    fn governance_state(&self) -> ProviderResult<GovernanceState> {
+        let state = self.latest()?;
+        Ok(read_governance_state(&StateProviderStorageReader::new(
+            state.as_ref(),
+        )))
+    }
+
+    fn miner_proxy_admin(&self) -> ProviderResult<Option<Address>> {
+        let state = self.latest()?;
+        Ok(read_miner_proxy_admin(&StateProviderStorageReader::new(
+            state.as_ref(),
+        )))
+    }
+
+    fn timelock_operations(
+        &self,
+        scheduled: &[(B256, Address)],
+        now: u64,
+    ) -> ProviderResult<Vec<TimelockOperation>> {
+        let state = self.latest()?;
+        Ok(read_timelock_operations(
+            &StateProviderStorageReader::new(state.as_ref()),
+            scheduled,
+            now,
+        ))
+    }
+}
@@ -0,0 +1,124 @@
+//! Txpool RPC Namespace
+//!
+//! `txpool_status` and `txpool_content` backed by the node's transaction pool,
+//! in geth's response shape. Useful for finding out why a sealed block came out
+//! empty despite submitted transactions (e.g. everything is stuck in `queued`).
+
+use alloy_primitives::Address;
+use alloy_rpc_types_eth::Transaction;
+use alloy_rpc_types_txpool::{TxpoolContent, TxpoolStatus};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_ethereum::TransactionSigned;
+use reth_transaction_pool::{PoolTransaction, TransactionPool, ValidPoolTransaction};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// A pool transaction in `eth_getTransactionByHash` form (not yet in a block).
+pub type TxpoolTransaction = Transaction<TransactionSigned>;
+
+/// The `txpool_*` RPC namespace definition.
+#[rpc(server, namespace = "txpool")]
+pub trait TxpoolApi {
+    /// Returns the number of pending (executable) and queued (nonce-gapped) transactions.
+    #[method(name = "status")]
+    async fn status(&self) -> RpcResult<TxpoolStatus>;
+
+    /// Returns every pool transaction, grouped by sender and keyed by nonce.
+    #[method(name = "content")]
+    async fn content(&self) -> RpcResult<TxpoolContent<TxpoolTransaction>>;
+}
+
+/// Implementation of the `txpool_*` RPC namespace.
+#[derive(Debug)]
+pub struct TxpoolRpc<Pool> {
+    pool: Pool,
+}
+
+impl<Pool> TxpoolRpc<Pool> {
+    /// Create a new TxpoolRpc instance.
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Group transactions by sender, then by nonce (as a decimal string, like geth).
+fn group_by_sender<T>(
+    txs: Vec<Arc<ValidPoolTransaction<T>>>,
+) -> BTreeMap<Address, BTreeMap<String, TxpoolTransaction>>
+where
+    T: PoolTransaction<Consensus = TransactionSigned>,
+{
+    let mut grouped: BTreeMap<Address, BTreeMap<String, TxpoolTransaction>> = BTreeMap::new();
+    for tx in txs {
+        let entry = Transaction {
+            inner: tx.transaction.clone_into_consensus(),
+            block_hash: None,
+            block_number: None,
+            transaction_index: None,
+            effective_gas_price: None,
+        };
+        grouped
+            .entry(tx.sender())
+            .or_default()
+            .insert(tx.nonce().to_string(), entry);
+    }
+    grouped
+}
+
+#[async_trait::async_trait]
+impl<Pool> TxpoolApiServer for TxpoolRpc<Pool>
+where
+    Pool: TransactionPool<Transaction: PoolTransaction<Consensus = TransactionSigned>> + 'static,
+{
+    async fn status(&self) -> RpcResult<TxpoolStatus> {
+        let size = self.pool.pool_size();
+        Ok(TxpoolStatus {
+            pending: size.pending as u64,
+            queued: size.queued as u64,
+        })
+    }
+
+    async fn content(&self) -> RpcResult<TxpoolContent<TxpoolTransaction>> {
+        let all = self.pool.all_transactions();
+        Ok(TxpoolContent {
+            pending: group_by_sender(all.pending),
+            queued: group_by_sender(all.queued),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_transaction_pool::test_utils::{testing_pool, MockTransaction};
+    use reth_transaction_pool::TransactionOrigin;
+
+    #[tokio::test]
+    async fn test_txpool_status_and_content() {
+        let pool = testing_pool();
+        let executable = MockTransaction::eip1559();
+        let sender = executable.sender();
+        // Nonce gap: stays queued until nonces 1..=4 arrive
+        let gapped = executable.clone().rng_hash().with_nonce(5);
+        pool.add_transaction(TransactionOrigin::External, executable)
+            .await
+            .unwrap();
+        pool.add_transaction(TransactionOrigin::External, gapped)
+            .await
+            .unwrap();
+
+        let rpc = TxpoolRpc::new(pool);
+        let status = rpc.status().await.unwrap();
+        assert_eq!((status.pending, status.queued), (1, 1));
+
+        let content = rpc.content().await.unwrap();
+        assert!(content.pending[&sender].contains_key("0"));
+        assert!(content.queued[&sender].contains_key("5"));
+
+        // geth shape: hex quantities in status, sender → nonce → tx in content
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(json["pending"], "0x1");
+        let json = serde_json::to_value(&content).unwrap();
+        assert!(json["queued"][sender.to_checksum(None)]["5"]["hash"].is_string());
+    }
+}
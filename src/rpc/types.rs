@@ -1,6 +1,9 @@
-use alloy_primitives::Address;
+use alloy_primitives::{Address, Bytes, B256};
 use serde::Serialize;
 
+use crate::consensus::PoaConsensusError;
+use crate::constants::{ADDRESS_LENGTH, EXTRA_SEAL_LENGTH, EXTRA_VANITY_LENGTH};
+
 /// Response for `meow_chainConfig`
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -27,3 +30,243 @@ pub struct NodeInfoResponse {
     pub local_signers: Vec<Address>,
     pub authorized_signers: Vec<Address>,
 }
+
+/// Response for `meow_status`: a flat summary for dashboards
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeStatus {
+    pub chain_id: u64,
+    /// Latest canonical block number
+    pub block_number: u64,
+    /// Hash of the latest canonical block (`null` if chain history is unavailable)
+    pub block_hash: Option<B256>,
+    /// Number of signers currently in effect
+    pub signer_count: usize,
+    /// Whether this node holds the key of at least one signer in effect
+    pub is_authority: bool,
+    pub uptime_seconds: u64,
+    pub dev_mode: bool,
+    /// Block period in seconds
+    pub block_period: u64,
+    /// `--safe-mode`: fewer signers sealed the last epoch than the `SignerRegistry`
+    /// threshold, so this node only seals in-turn blocks
+    pub degraded: bool,
+}
+
+/// Response for `meow_getBlockSigner`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockSigner {
+    pub block_number: u64,
+    pub block_hash: B256,
+    /// Recovered signer (`null` for the unsigned genesis block)
+    pub signer: Option<Address>,
+    /// Signer whose turn it was (`null` for genesis or with no signers)
+    pub expected_signer: Option<Address>,
+    /// Whether the block was sealed by its in-turn signer
+    pub in_turn: bool,
+    /// The genesis block carries no seal, so it has no signer
+    pub unsigned_genesis: bool,
+}
+
+impl BlockSigner {
+    /// Authorship of a sealed block.
+    pub fn sealed(
+        block_number: u64,
+        block_hash: B256,
+        signer: Address,
+        expected_signer: Option<Address>,
+    ) -> Self {
+        Self {
+            block_number,
+            block_hash,
+            signer: Some(signer),
+            expected_signer,
+            in_turn: expected_signer == Some(signer),
+            unsigned_genesis: false,
+        }
+    }
+
+    /// The genesis block, which nobody signed.
+    pub fn genesis(block_number: u64, block_hash: B256) -> Self {
+        Self {
+            block_number,
+            block_hash,
+            signer: None,
+            expected_signer: None,
+            in_turn: false,
+            unsigned_genesis: true,
+        }
+    }
+}
+
+/// Result of comparing two chain segments for fork choice
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChainOrdering {
+    Less,
+    Equal,
+    Greater,
+}
+
+impl From<std::cmp::Ordering> for ChainOrdering {
+    fn from(ordering: std::cmp::Ordering) -> Self {
+        match ordering {
+            std::cmp::Ordering::Less => Self::Less,
+            std::cmp::Ordering::Equal => Self::Equal,
+            std::cmp::Ordering::Greater => Self::Greater,
+        }
+    }
+}
+
+/// Response for `meow_simulateReorg`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReorgSimulation {
+    /// Last block both chains share
+    pub fork_block: u64,
+    /// In-turn blocks on the canonical chain above `fork_block`
+    pub canonical_score: u64,
+    /// Canonical blocks above `fork_block`
+    pub canonical_length: u64,
+    /// In-turn blocks in the side chain
+    pub side_score: u64,
+    /// Blocks in the side chain
+    pub side_length: u64,
+    /// The side chain compared to the canonical one (`greater` = side chain preferred)
+    pub ordering: ChainOrdering,
+    /// Whether fork choice would switch to the side chain (ties keep the canonical one)
+    pub reorg: bool,
+}
+
+/// One upcoming block in the `meow_getInTurnSchedule` response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InTurnScheduleEntry {
+    pub block_number: u64,
+    /// The in-turn signer for this block
+    pub signer: Address,
+    /// Whether this node holds the in-turn signer's key
+    pub ours: bool,
+}
+
+/// One authority in the `meow_getSignerStatus` response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignerStatus {
+    pub address: Address,
+    /// Whether this node holds the signer's key
+    pub controlled: bool,
+    /// Most recent block sealed by this signer since the node started
+    pub last_block: Option<u64>,
+    /// Whether the signer is in turn for the next block
+    pub in_turn: bool,
+}
+
+/// Response for `meow_getEpochInfo`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochInfo {
+    /// Blocks per epoch
+    pub epoch_length: u64,
+    /// Latest canonical block number
+    pub current_block: u64,
+    /// Epoch the current block belongs to, counted from the block the current epoch
+    /// length took effect (genesis unless governance changed it)
+    pub current_epoch: u64,
+    /// First block of the current epoch
+    pub epoch_start_block: u64,
+    /// Next epoch block, where signer list changes take effect
+    pub next_epoch_block: u64,
+    /// Blocks left until `next_epoch_block`
+    pub blocks_until_next_epoch: u64,
+    /// Whether the signer list has been refreshed from `SignerRegistry` (otherwise
+    /// the genesis signers are in effect)
+    pub live_signers: bool,
+    /// Number of signers currently in effect
+    pub effective_signer_count: usize,
+}
+
+impl EpochInfo {
+    /// Epoch position of `current_block` for epochs of `epoch_length` blocks.
+    pub fn at(
+        current_block: u64,
+        epoch_length: u64,
+        live_signers: bool,
+        effective_signer_count: usize,
+    ) -> Self {
+        Self::from_anchor(
+            current_block,
+            0,
+            epoch_length,
+            live_signers,
+            effective_signer_count,
+        )
+    }
+
+    /// Epoch position of `current_block` for epochs of `epoch_length` blocks counted
+    /// from block `anchor` (at or before `current_block`).
+    pub fn from_anchor(
+        current_block: u64,
+        anchor: u64,
+        epoch_length: u64,
+        live_signers: bool,
+        effective_signer_count: usize,
+    ) -> Self {
+        let epoch_length = epoch_length.max(1);
+        let current_epoch = current_block.saturating_sub(anchor) / epoch_length;
+        let epoch_start_block = anchor + current_epoch * epoch_length;
+        let next_epoch_block = epoch_start_block + epoch_length;
+        Self {
+            epoch_length,
+            current_block,
+            current_epoch,
+            epoch_start_block,
+            next_epoch_block,
+            blocks_until_next_epoch: next_epoch_block - current_block,
+            live_signers,
+            effective_signer_count,
+        }
+    }
+}
+
+/// Response for `meow_decodeExtraData`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedExtraData {
+    /// Leading 32 vanity bytes
+    pub vanity: B256,
+    /// Signer list between vanity and seal (empty outside epoch blocks)
+    pub signers: Vec<Address>,
+    /// Trailing 65-byte `r || s || v` seal
+    pub seal: Bytes,
+    /// Whether a signer list is present, as in epoch blocks
+    pub is_epoch_format: bool,
+}
+
+impl DecodedExtraData {
+    /// Split POA `extra_data` into vanity, signer list and seal.
+    pub fn decode(extra_data: &[u8]) -> Result<Self, PoaConsensusError> {
+        let min_length = EXTRA_VANITY_LENGTH + EXTRA_SEAL_LENGTH;
+        if extra_data.len() < min_length {
+            return Err(PoaConsensusError::ExtraDataTooShort {
+                expected: min_length,
+                got: extra_data.len(),
+            });
+        }
+        let seal_start = extra_data.len() - EXTRA_SEAL_LENGTH;
+        let signers_data = &extra_data[EXTRA_VANITY_LENGTH..seal_start];
+        if !signers_data.len().is_multiple_of(ADDRESS_LENGTH) {
+            return Err(PoaConsensusError::InvalidSignerList);
+        }
+        Ok(Self {
+            vanity: B256::from_slice(&extra_data[..EXTRA_VANITY_LENGTH]),
+            signers: signers_data
+                .chunks_exact(ADDRESS_LENGTH)
+                .map(Address::from_slice)
+                .collect(),
+            seal: Bytes::copy_from_slice(&extra_data[seal_start..]),
+            is_epoch_format: !signers_data.is_empty(),
+        })
+    }
+}
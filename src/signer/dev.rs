@@ -1,6 +1,7 @@
 use alloy_signer_local::PrivateKeySigner;
 use std::sync::Arc;
 
+use super::errors::SignerError;
 use super::manager::SignerManager;
 
 /// Private keys for the dev accounts (from "test test..." mnemonic)
@@ -17,18 +18,34 @@ pub const DEV_PRIVATE_KEYS: &[&str] = &[
     "2a871d0798f97d79848a013d4936a73bf4cc922c825d33c1cf7073dff6d409c6",
 ];
 
-/// Set up the signer manager with dev keys
-pub async fn setup_dev_signers() -> Arc<SignerManager> {
-    let manager = Arc::new(SignerManager::new());
+/// Dev keys a dev node holds by default: the three genesis signers.
+pub const DEFAULT_DEV_SIGNER_INDICES: &[usize] = &[0, 1, 2];
 
-    for key in DEV_PRIVATE_KEYS.iter().take(3) {
-        // Use first 3 as default signers
-        manager
-            .add_signer_from_hex(key)
-            .await
-            .expect("Dev keys should be valid");
+/// Load the [`DEV_PRIVATE_KEYS`] at `indices` into `manager`.
+///
+/// Every index is checked before any key is loaded.
+pub async fn load_dev_signers(
+    manager: &SignerManager,
+    indices: &[usize],
+) -> Result<(), SignerError> {
+    if let Some(&index) = indices.iter().find(|&&i| i >= DEV_PRIVATE_KEYS.len()) {
+        return Err(SignerError::DevKeyIndexOutOfRange {
+            index,
+            available: DEV_PRIVATE_KEYS.len(),
+        });
+    }
+    for &index in indices {
+        manager.add_signer_from_hex(DEV_PRIVATE_KEYS[index]).await?;
     }
+    Ok(())
+}
 
+/// Set up the signer manager with dev keys
+pub async fn setup_dev_signers() -> Arc<SignerManager> {
+    let manager = Arc::new(SignerManager::new());
+    load_dev_signers(&manager, DEFAULT_DEV_SIGNER_INDICES)
+        .await
+        .expect("Dev keys should be valid");
     manager
 }
 
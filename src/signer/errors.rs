@@ -15,4 +15,25 @@ pub enum SignerError {
     /// Invalid private key format
     #[error("Invalid private key")]
     InvalidPrivateKey,
+
+    /// The remote signer could not be reached or did not answer in time
+    #[error("Remote signer unavailable: {0}")]
+    RemoteUnavailable(String),
+
+    /// The remote signer refused the request or returned an unusable signature
+    #[error("Remote signer rejected request: {0}")]
+    RemoteRejected(String),
+
+    /// Vanity identifier does not fit in the extra_data vanity region
+    #[error("Vanity too long: {0} bytes (max 32)")]
+    VanityTooLong(usize),
+
+    /// A dev key index past the end of the dev key list
+    #[error("Dev signer index {index} out of range (valid: 0..{available})")]
+    DevKeyIndexOutOfRange {
+        /// The requested index
+        index: usize,
+        /// Number of dev keys available
+        available: usize,
+    },
 }
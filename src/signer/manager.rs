@@ -1,16 +1,41 @@
+use alloy_consensus::Header;
 use alloy_primitives::{Address, Signature, B256};
 use alloy_signer::Signer;
 use alloy_signer_local::PrivateKeySigner;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
 use tokio::sync::RwLock;
 
 use super::errors::SignerError;
+use super::remote::RemoteSigner;
+use super::sealer::BlockSealer;
+use crate::constants::EXTRA_VANITY_LENGTH;
+use crate::output;
+
+/// Where a signer's key lives.
+#[derive(Debug, Clone)]
+pub enum SignerBackend {
+    /// Key held in this process.
+    Local(PrivateKeySigner),
+    /// Key held by an external signer reached over HTTP.
+    Remote(RemoteSigner),
+}
 
 /// Manages signing keys for POA block production
 #[derive(Debug)]
 pub struct SignerManager {
-    /// Map of address to signer
-    signers: RwLock<HashMap<Address, PrivateKeySigner>>,
+    /// Map of address to signing backend
+    signers: RwLock<HashMap<Address, SignerBackend>>,
+    /// Per-signer vanity written into the first 32 bytes of extra_data when that signer seals
+    vanities: RwLock<HashMap<Address, [u8; EXTRA_VANITY_LENGTH]>>,
+    /// Pending signer votes cast by this node: candidate -> authorize (true=add, false=remove).
+    /// Uses a std lock so the payload builder can read it synchronously.
+    proposals: std::sync::RwLock<HashMap<Address, bool>>,
+    /// Monotonic deadline after which a signer's key is treated as absent
+    expiries: RwLock<HashMap<Address, Instant>>,
+    /// Whether the "every key expired" warning has been printed
+    all_expired_warned: AtomicBool,
 }
 
 impl SignerManager {
@@ -18,6 +43,10 @@ impl SignerManager {
     pub fn new() -> Self {
         Self {
             signers: RwLock::new(HashMap::new()),
+            vanities: RwLock::new(HashMap::new()),
+            proposals: std::sync::RwLock::new(HashMap::new()),
+            expiries: RwLock::new(HashMap::new()),
+            all_expired_warned: AtomicBool::new(false),
         }
     }
 
@@ -27,46 +56,266 @@ impl SignerManager {
             .parse::<PrivateKeySigner>()
             .map_err(|_| SignerError::InvalidPrivateKey)?;
 
-        let address = signer.address();
-        self.signers.write().await.insert(address, signer);
+        Ok(self.add_signer(signer).await)
+    }
 
+    /// Add a signer from a private key hex string that stops being used at `expires_at`.
+    ///
+    /// Once expired the key is treated as absent: `has_signer` is false, it is left out
+    /// of `signer_addresses` and `sign_hash` refuses it, so the payload builder stops
+    /// sealing with it and the key has to be rotated.
+    pub async fn add_signer_from_hex_with_expiry(
+        &self,
+        private_key_hex: &str,
+        expires_at: Instant,
+    ) -> Result<Address, SignerError> {
+        let address = self.add_signer_from_hex(private_key_hex).await?;
+        self.expiries.write().await.insert(address, expires_at);
         Ok(address)
     }
 
-    /// Add a signer directly
+    /// Add a signer directly (replacing any expiry set for its address)
     pub async fn add_signer(&self, signer: PrivateKeySigner) -> Address {
         let address = signer.address();
-        self.signers.write().await.insert(address, signer);
+        self.expiries.write().await.remove(&address);
+        self.signers
+            .write()
+            .await
+            .insert(address, SignerBackend::Local(signer));
         address
     }
 
-    /// Check if we have a signer for the given address
+    /// Register `address` as signed for by a remote signer (no local key).
+    pub async fn add_remote_signer(&self, address: Address, remote: RemoteSigner) {
+        self.signers
+            .write()
+            .await
+            .insert(address, SignerBackend::Remote(remote));
+    }
+
+    /// Check if we have an unexpired signer for the given address
     pub async fn has_signer(&self, address: &Address) -> bool {
-        self.signers.read().await.contains_key(address)
+        self.signers.read().await.contains_key(address) && !self.is_expired(address).await
     }
 
-    /// Get all registered signer addresses
+    /// Get all registered signer addresses whose key hasn't expired
     pub async fn signer_addresses(&self) -> Vec<Address> {
-        self.signers.read().await.keys().copied().collect()
+        let addresses: Vec<Address> = self.signers.read().await.keys().copied().collect();
+        let mut live = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            if !self.is_expired(&address).await {
+                live.push(address);
+            }
+        }
+        live
     }
 
-    /// Sign a message hash with the specified signer
-    pub async fn sign_hash(&self, address: &Address, hash: B256) -> Result<Signature, SignerError> {
-        let signers = self.signers.read().await;
-        let signer = signers
-            .get(address)
-            .ok_or(SignerError::NoSignerForAddress(*address))?;
+    /// When the key for `address` expires, if it was added with an expiry
+    pub async fn expiry(&self, address: &Address) -> Option<Instant> {
+        self.expiries.read().await.get(address).copied()
+    }
+
+    /// Whether the key for `address` is past its expiry.
+    ///
+    /// The first time every loaded key is found expired, warns that the node is now
+    /// a passive validator.
+    async fn is_expired(&self, address: &Address) -> bool {
+        let now = Instant::now();
+        let expiries = self.expiries.read().await;
+        if !expiries.get(address).is_some_and(|at| *at <= now) {
+            return false;
+        }
+        let all_expired = self
+            .signers
+            .read()
+            .await
+            .keys()
+            .all(|address| expiries.get(address).is_some_and(|at| *at <= now));
+        if all_expired && !self.all_expired_warned.swap(true, Ordering::Relaxed) {
+            output::print_warning(
+                "Every signing key has expired; running as a passive validator until keys are rotated",
+            );
+        }
+        true
+    }
 
-        signer
-            .sign_hash(&hash)
+    /// The backend of an unexpired signer.
+    async fn backend(&self, address: &Address) -> Result<SignerBackend, SignerError> {
+        if self.is_expired(address).await {
+            return Err(SignerError::NoSignerForAddress(*address));
+        }
+        // Clone the backend so a slow remote signer doesn't hold the lock
+        self.signers
+            .read()
             .await
-            .map_err(|e| SignerError::SigningFailed(e.to_string()))
+            .get(address)
+            .cloned()
+            .ok_or(SignerError::NoSignerForAddress(*address))
+    }
+
+    /// Sign a message hash with the specified signer.
+    ///
+    /// Remote signers only seal headers (see [`sign_header`](Self::sign_header)), so
+    /// they refuse raw hashes.
+    pub async fn sign_hash(&self, address: &Address, hash: B256) -> Result<Signature, SignerError> {
+        match self.backend(address).await? {
+            SignerBackend::Local(signer) => signer
+                .sign_hash(&hash)
+                .await
+                .map_err(|e| SignerError::SigningFailed(e.to_string())),
+            SignerBackend::Remote(_) => Err(SignerError::SigningFailed(
+                "remote signers only sign block headers".into(),
+            )),
+        }
+    }
+
+    /// Sign `header`'s seal hash with the specified signer.
+    pub async fn sign_header(
+        &self,
+        address: &Address,
+        header: &Header,
+    ) -> Result<Signature, SignerError> {
+        match self.backend(address).await? {
+            SignerBackend::Local(signer) => signer
+                .sign_hash(&BlockSealer::seal_hash(header))
+                .await
+                .map_err(|e| SignerError::SigningFailed(e.to_string())),
+            SignerBackend::Remote(remote) => remote.sign_header(address, header).await,
+        }
     }
 
-    /// Remove a signer
+    /// Remove a signer (and any vanity or expiry set for it)
     pub async fn remove_signer(&self, address: &Address) -> bool {
+        self.vanities.write().await.remove(address);
+        self.expiries.write().await.remove(address);
         self.signers.write().await.remove(address).is_some()
     }
+
+    /// Replace the in-process keys with `fresh`, keeping any address in `retain`.
+    ///
+    /// Local keys in neither set are removed (with their vanity); remote signers are
+    /// never touched. Returns the `(added, removed)` addresses.
+    pub async fn sync_local_signers(
+        &self,
+        fresh: Vec<PrivateKeySigner>,
+        retain: &[Address],
+    ) -> (Vec<Address>, Vec<Address>) {
+        let mut signers = self.signers.write().await;
+        let fresh_addresses: Vec<Address> = fresh.iter().map(|s| s.address()).collect();
+        let removed: Vec<Address> = signers
+            .iter()
+            .filter(|(address, backend)| {
+                matches!(backend, SignerBackend::Local(_))
+                    && !fresh_addresses.contains(address)
+                    && !retain.contains(address)
+            })
+            .map(|(address, _)| *address)
+            .collect();
+        for address in &removed {
+            signers.remove(address);
+        }
+
+        let mut added = Vec::new();
+        for signer in fresh {
+            let address = signer.address();
+            if signers
+                .insert(address, SignerBackend::Local(signer))
+                .is_none()
+            {
+                added.push(address);
+            }
+        }
+        drop(signers);
+
+        let mut vanities = self.vanities.write().await;
+        let mut expiries = self.expiries.write().await;
+        for address in &removed {
+            vanities.remove(address);
+            expiries.remove(address);
+        }
+        (added, removed)
+    }
+
+    /// Set a vanity identifier (e.g. a node nickname) for a loaded signer.
+    ///
+    /// The bytes are right-padded with zeros to 32 bytes and written into the vanity
+    /// region of every block this signer seals. Consensus ignores vanity content, so
+    /// this is purely informational for operators.
+    pub async fn set_vanity(&self, address: Address, vanity: &[u8]) -> Result<(), SignerError> {
+        if vanity.len() > EXTRA_VANITY_LENGTH {
+            return Err(SignerError::VanityTooLong(vanity.len()));
+        }
+        if !self.has_signer(&address).await {
+            return Err(SignerError::NoSignerForAddress(address));
+        }
+
+        let mut padded = [0u8; EXTRA_VANITY_LENGTH];
+        padded[..vanity.len()].copy_from_slice(vanity);
+        self.vanities.write().await.insert(address, padded);
+        Ok(())
+    }
+
+    /// Stage a vote to authorize (`true`) or deauthorize (`false`) a candidate signer.
+    ///
+    /// Blocks sealed by this node carry the vote in `beneficiary` + `nonce` until the
+    /// proposal is discarded or the signer set already reflects it.
+    pub fn propose(&self, candidate: Address, authorize: bool) {
+        self.proposals
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(candidate, authorize);
+    }
+
+    /// Drop a pending vote. Returns `true` if a proposal existed.
+    pub fn discard(&self, candidate: &Address) -> bool {
+        self.proposals
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(candidate)
+            .is_some()
+    }
+
+    /// All pending votes (candidate -> authorize).
+    pub fn proposals(&self) -> HashMap<Address, bool> {
+        self.proposals
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Pick the vote to cast in the next block given the current signer set.
+    ///
+    /// Proposals that would not change the set (authorizing an existing signer or
+    /// removing a non-signer) are skipped. Among the rest, the lowest candidate address
+    /// is chosen so the choice is deterministic.
+    pub fn pending_vote(&self, signers: &[Address]) -> Option<(Address, bool)> {
+        self.proposals
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .filter(|(candidate, authorize)| signers.contains(candidate) != **authorize)
+            .map(|(candidate, authorize)| (*candidate, *authorize))
+            .min_by_key(|(candidate, _)| *candidate)
+    }
+
+    /// Get the vanity for a signer, or 32 zero bytes if none was set
+    pub async fn vanity(&self, address: &Address) -> [u8; EXTRA_VANITY_LENGTH] {
+        self.vanity_or(address, [0u8; EXTRA_VANITY_LENGTH]).await
+    }
+
+    /// Get the vanity for a signer, or `default` if none was set for it
+    pub async fn vanity_or(
+        &self,
+        address: &Address,
+        default: [u8; EXTRA_VANITY_LENGTH],
+    ) -> [u8; EXTRA_VANITY_LENGTH] {
+        self.vanities
+            .read()
+            .await
+            .get(address)
+            .copied()
+            .unwrap_or(default)
+    }
 }
 
 impl Default for SignerManager {
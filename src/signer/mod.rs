@@ -4,14 +4,17 @@
 //! - Key management for authorized signers
 //! - Block sealing (signing)
 //! - Signature verification
+//! - Remote (out-of-process) signing
 
 pub mod dev;
 pub mod errors;
 pub mod manager;
+pub mod remote;
 pub mod sealer;
 
 pub use errors::SignerError;
-pub use manager::SignerManager;
+pub use manager::{SignerBackend, SignerManager};
+pub use remote::RemoteSigner;
 pub use sealer::{bytes_to_signature, signature_to_bytes, BlockSealer};
 
 #[cfg(test)]
@@ -189,6 +192,30 @@ mod tests {
         assert_eq!(bytes[64], recovered_bytes[64]);
     }
 
+    #[tokio::test]
+    async fn test_load_dev_signer_subset() {
+        let manager = SignerManager::new();
+        dev::load_dev_signers(&manager, &[0, 2]).await.unwrap();
+        let accounts = crate::genesis::dev_accounts();
+        let mut loaded = manager.signer_addresses().await;
+        loaded.sort();
+        let mut expected = vec![accounts[0], accounts[2]];
+        expected.sort();
+        assert_eq!(loaded, expected);
+
+        // An out-of-range index is rejected before anything is loaded
+        let manager = SignerManager::new();
+        let err = dev::load_dev_signers(&manager, &[1, 10]).await.unwrap_err();
+        assert!(matches!(
+            err,
+            SignerError::DevKeyIndexOutOfRange {
+                index: 10,
+                available: 10
+            }
+        ));
+        assert!(manager.signer_addresses().await.is_empty());
+    }
+
     #[test]
     fn test_first_dev_signer() {
         let signer = dev::first_dev_signer();
@@ -378,4 +405,116 @@ mod tests {
         let addresses = manager.signer_addresses().await;
         assert!(addresses.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_set_vanity_pads_to_32_bytes() {
+        let manager = SignerManager::new();
+        let address = manager
+            .add_signer_from_hex(dev::DEV_PRIVATE_KEYS[0])
+            .await
+            .unwrap();
+
+        assert_eq!(manager.vanity(&address).await, [0u8; 32]);
+
+        manager.set_vanity(address, b"node-a").await.unwrap();
+        let vanity = manager.vanity(&address).await;
+        assert_eq!(&vanity[..6], b"node-a");
+        assert!(vanity[6..].iter().all(|b| *b == 0));
+    }
+
+    #[tokio::test]
+    async fn test_set_vanity_rejects_unknown_signer_and_long_vanity() {
+        let manager = SignerManager::new();
+        let unknown = Address::repeat_byte(0x42);
+        assert!(matches!(
+            manager.set_vanity(unknown, b"x").await,
+            Err(SignerError::NoSignerForAddress(a)) if a == unknown
+        ));
+
+        let address = manager
+            .add_signer_from_hex(dev::DEV_PRIVATE_KEYS[0])
+            .await
+            .unwrap();
+        assert!(matches!(
+            manager.set_vanity(address, &[1u8; 33]).await,
+            Err(SignerError::VanityTooLong(33))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_remove_signer_clears_vanity() {
+        let manager = SignerManager::new();
+        let address = manager
+            .add_signer_from_hex(dev::DEV_PRIVATE_KEYS[0])
+            .await
+            .unwrap();
+        manager.set_vanity(address, b"node-a").await.unwrap();
+        manager.remove_signer(&address).await;
+        manager
+            .add_signer_from_hex(dev::DEV_PRIVATE_KEYS[0])
+            .await
+            .unwrap();
+        assert_eq!(manager.vanity(&address).await, [0u8; 32]);
+    }
+
+    #[tokio::test]
+    async fn test_sync_local_signers_applies_diff() {
+        let manager = SignerManager::new();
+        let key = |i: usize| {
+            dev::DEV_PRIVATE_KEYS[i]
+                .parse::<alloy_signer_local::PrivateKeySigner>()
+                .unwrap()
+        };
+        let a = manager.add_signer(key(0)).await;
+        let b = manager.add_signer(key(1)).await;
+        let c = manager.add_signer(key(2)).await;
+        let remote_address = Address::with_last_byte(0x42);
+        let remote =
+            RemoteSigner::new("http://127.0.0.1:1", remote::DEFAULT_REMOTE_SIGNER_TIMEOUT).unwrap();
+        manager.add_remote_signer(remote_address, remote).await;
+
+        // `a` stays, `b` is retained (e.g. its keystore failed to decrypt), `c` is
+        // gone from the fresh set, `d` is new
+        let (added, removed) = manager.sync_local_signers(vec![key(0), key(3)], &[b]).await;
+        let d = key(3).address();
+        assert_eq!(added, vec![d]);
+        assert_eq!(removed, vec![c]);
+        for address in [a, b, d, remote_address] {
+            assert!(manager.has_signer(&address).await, "{address}");
+        }
+        assert!(!manager.has_signer(&c).await);
+    }
+
+    #[tokio::test]
+    async fn test_expired_signer_is_treated_as_absent() {
+        let manager = SignerManager::new();
+        let past = std::time::Instant::now();
+        let expired = manager
+            .add_signer_from_hex_with_expiry(dev::DEV_PRIVATE_KEYS[0], past)
+            .await
+            .unwrap();
+        let future = std::time::Instant::now() + std::time::Duration::from_secs(3600);
+        let live = manager
+            .add_signer_from_hex_with_expiry(dev::DEV_PRIVATE_KEYS[1], future)
+            .await
+            .unwrap();
+
+        assert!(!manager.has_signer(&expired).await);
+        assert!(manager.has_signer(&live).await);
+        assert_eq!(manager.signer_addresses().await, vec![live]);
+        assert_eq!(manager.expiry(&live).await, Some(future));
+        assert!(matches!(
+            manager.sign_hash(&expired, B256::ZERO).await,
+            Err(SignerError::NoSignerForAddress(addr)) if addr == expired
+        ));
+        assert!(manager.sign_hash(&live, B256::ZERO).await.is_ok());
+
+        // Re-adding the key without an expiry rotates it back in
+        manager
+            .add_signer_from_hex(dev::DEV_PRIVATE_KEYS[0])
+            .await
+            .unwrap();
+        assert!(manager.has_signer(&expired).await);
+        assert_eq!(manager.expiry(&expired).await, None);
+    }
 }
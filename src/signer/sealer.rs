@@ -19,6 +19,12 @@ impl BlockSealer {
 
     /// Calculate the seal hash for a header (hash without signature)
     pub fn seal_hash(header: &Header) -> B256 {
+        keccak256(Self::seal_rlp(header))
+    }
+
+    /// RLP encoding of a header with the signature stripped from its extra data:
+    /// what the seal hash is computed over (geth's `CliqueRLP`).
+    pub fn seal_rlp(header: &Header) -> Vec<u8> {
         // Create a copy with signature stripped from extra data
         let mut header_for_hash = header.clone();
 
@@ -29,7 +35,7 @@ impl BlockSealer {
             header_for_hash.extra_data = without_seal.to_vec().into();
         }
 
-        keccak256(alloy_rlp::encode(&header_for_hash))
+        alloy_rlp::encode(&header_for_hash)
     }
 
     /// Seal a block header with a signature
@@ -38,13 +44,10 @@ impl BlockSealer {
         mut header: Header,
         signer_address: &Address,
     ) -> Result<Header, SignerError> {
-        // Calculate seal hash
-        let seal_hash = Self::seal_hash(&header);
-
-        // Sign the hash
+        // Sign the seal hash
         let signature = self
             .signer_manager
-            .sign_hash(signer_address, seal_hash)
+            .sign_header(signer_address, &header)
             .await?;
 
         // Encode signature as bytes (r, s, v)
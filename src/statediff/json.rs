@@ -0,0 +1,236 @@
+//! Stable JSON schema for [`StateDiff`] and a one-file-per-block directory sink.
+//!
+//! The in-memory diff is keyed by `HashMap`s; on the wire it is flattened into
+//! sorted arrays so the output is deterministic and easy to consume from other
+//! languages:
+//!
+//! ```json
+//! {
+//!   "blockNumber": 42,
+//!   "blockHash": "0x…",
+//!   "gasUsed": 21000,
+//!   "txCount": 1,
+//!   "balanceChanges": [{ "address": "0x…", "from": "0x64", "to": "0x5a" }],
+//!   "nonceChanges":   [{ "address": "0x…", "from": 0, "to": 1 }],
+//!   "codeChanges":    ["0x…"],
+//!   "storageChanges": [{ "address": "0x…", "slot": "0x1", "from": "0x…", "to": "0x…" }],
+//!   "reverted": false
+//! }
+//! ```
+//!
+//! This is the format an external replica reads from `--statediff-dir`.
+
+use super::{AccountDiff, StateDiff, StorageSlotDiff};
+use alloy_primitives::{Address, B256, U256};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Wire form of a [`StateDiff`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateDiffJson {
+    pub block_number: u64,
+    pub block_hash: B256,
+    pub gas_used: u64,
+    pub tx_count: usize,
+    pub balance_changes: Vec<BalanceChange>,
+    pub nonce_changes: Vec<NonceChange>,
+    /// Accounts whose code was created or destroyed.
+    pub code_changes: Vec<Address>,
+    pub storage_changes: Vec<StorageChange>,
+    /// `true` when this diff undoes `blockNumber` (reorg); absent in older files.
+    #[serde(default)]
+    pub reverted: bool,
+}
+
+/// A balance change for one account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceChange {
+    pub address: Address,
+    pub from: U256,
+    pub to: U256,
+}
+
+/// A nonce change for one account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NonceChange {
+    pub address: Address,
+    pub from: u64,
+    pub to: u64,
+}
+
+/// A single storage slot change.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageChange {
+    pub address: Address,
+    pub slot: U256,
+    pub from: B256,
+    pub to: B256,
+}
+
+impl From<StateDiff> for StateDiffJson {
+    fn from(diff: StateDiff) -> Self {
+        let mut accounts: Vec<_> = diff.changes.into_iter().collect();
+        accounts.sort_by_key(|(addr, _)| *addr);
+
+        let mut json = Self {
+            block_number: diff.block_number,
+            block_hash: diff.block_hash,
+            gas_used: diff.gas_used,
+            tx_count: diff.tx_count,
+            balance_changes: Vec::new(),
+            nonce_changes: Vec::new(),
+            code_changes: Vec::new(),
+            storage_changes: Vec::new(),
+            reverted: diff.reverted,
+        };
+        for (address, account) in accounts {
+            if let Some((from, to)) = account.balance {
+                json.balance_changes
+                    .push(BalanceChange { address, from, to });
+            }
+            if let Some((from, to)) = account.nonce {
+                json.nonce_changes.push(NonceChange { address, from, to });
+            }
+            if account.code_changed {
+                json.code_changes.push(address);
+            }
+            let mut slots: Vec<_> = account.storage.into_iter().collect();
+            slots.sort_by_key(|(slot, _)| *slot);
+            json.storage_changes
+                .extend(slots.into_iter().map(|(slot, d)| StorageChange {
+                    address,
+                    slot,
+                    from: d.old_value,
+                    to: d.new_value,
+                }));
+        }
+        json
+    }
+}
+
+impl From<StateDiffJson> for StateDiff {
+    fn from(json: StateDiffJson) -> Self {
+        let mut changes: HashMap<Address, AccountDiff> = HashMap::new();
+        for c in json.balance_changes {
+            changes.entry(c.address).or_default().balance = Some((c.from, c.to));
+        }
+        for c in json.nonce_changes {
+            changes.entry(c.address).or_default().nonce = Some((c.from, c.to));
+        }
+        for address in json.code_changes {
+            changes.entry(address).or_default().code_changed = true;
+        }
+        for c in json.storage_changes {
+            changes
+                .entry(c.address)
+                .or_default()
+                .storage
+                .insert(c.slot, StorageSlotDiff::new(c.from, c.to));
+        }
+        Self {
+            block_number: json.block_number,
+            block_hash: json.block_hash,
+            changes,
+            gas_used: json.gas_used,
+            tx_count: json.tx_count,
+            reverted: json.reverted,
+        }
+    }
+}
+
+impl StateDiff {
+    /// Serialize this diff to its stable JSON form.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Write this diff to `<dir>/<block_number>.json`, creating `dir` if needed.
+    ///
+    /// Writes to a temporary file and renames it, so readers polling the
+    /// directory never see a partially written diff. Returns the file path.
+    pub fn write_to_dir(&self, dir: impl AsRef<Path>) -> io::Result<PathBuf> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(format!("{}.json", self.block_number));
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, self.to_json().map_err(io::Error::other)?)?;
+        std::fs::rename(&tmp, &path)?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statediff::StateDiffBuilder;
+
+    fn sample_diff() -> StateDiff {
+        let mut b = StateDiffBuilder::new(42, B256::with_last_byte(0x42))
+            .with_gas_used(63_000)
+            .with_tx_count(3);
+        b.record_balance_change(Address::with_last_byte(1), U256::from(100), U256::from(90));
+        b.record_nonce_change(Address::with_last_byte(1), 4, 5);
+        b.record_code_change(Address::with_last_byte(2));
+        b.record_storage_change(
+            Address::with_last_byte(2),
+            U256::from(7),
+            B256::ZERO,
+            B256::with_last_byte(1),
+        );
+        b.record_storage_change(
+            Address::with_last_byte(3),
+            U256::from(1),
+            B256::with_last_byte(9),
+            B256::ZERO,
+        );
+        b.build()
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let diff = sample_diff();
+        let json = diff.to_json().unwrap();
+        let decoded: StateDiff = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, diff);
+    }
+
+    #[test]
+    fn test_json_schema_fields() {
+        let value = serde_json::to_value(sample_diff()).unwrap();
+        assert_eq!(value["blockNumber"], 42);
+        assert_eq!(value["gasUsed"], 63_000);
+        assert_eq!(value["txCount"], 3);
+        assert_eq!(value["balanceChanges"].as_array().unwrap().len(), 1);
+        assert_eq!(value["nonceChanges"][0]["from"], 4);
+        assert_eq!(value["codeChanges"].as_array().unwrap().len(), 1);
+        assert_eq!(value["storageChanges"].as_array().unwrap().len(), 2);
+        // Sorted by address: 0x..02 before 0x..03
+        assert_eq!(
+            value["storageChanges"][0]["address"],
+            serde_json::to_value(Address::with_last_byte(2)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_write_to_dir_one_file_per_block() {
+        let dir = std::env::temp_dir().join(format!("meowchain-diffdir-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let diff = sample_diff();
+        let path = diff.write_to_dir(&dir).unwrap();
+        assert_eq!(path, dir.join("42.json"));
+
+        let decoded: StateDiff = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(decoded, diff);
+        assert_eq!(
+            std::fs::read_dir(&dir).unwrap().count(),
+            1,
+            "no temp file left"
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,307 @@
+//! Append-only on-disk log of [`StateDiff`]s with batched flushing.
+//!
+//! Diffs are buffered in memory and written + fsynced at most once per
+//! `flush_interval`, trading a bounded window of data loss on crash for far
+//! fewer fsyncs. A graceful shutdown calls [`StateDiffLog::flush`] (and `Drop`
+//! flushes as a last resort) so nothing buffered is lost on a clean exit.
+//!
+//! Each diff is one line:
+//! ```text
+//! block=<n> hash=<h> gas_used=<g> txs=<t> | <addr> balance=<old>-><new> nonce=<old>-><new> code storage=<slot>:<old>-><new>,... | ...
+//! ```
+//! Accounts and slots are sorted so the output is deterministic.
+
+use super::{AccountDiff, StateDiff};
+use crate::output;
+use std::fmt::Write as _;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// A [`StateDiffLog`] shared between the block monitor, the flush ticker, and shutdown.
+pub type SharedStateDiffLog = Arc<Mutex<StateDiffLog>>;
+
+/// Lock a [`SharedStateDiffLog`], recovering the guard if a holder panicked.
+///
+/// A panic mid-append leaves at most a partial line in the buffer, which is
+/// preferable to losing every later diff (and the shutdown flush) to a panic.
+pub fn lock_shared(log: &SharedStateDiffLog) -> MutexGuard<'_, StateDiffLog> {
+    log.lock().unwrap_or_else(|e| {
+        output::print_warning("State diff log lock was poisoned, recovering");
+        e.into_inner()
+    })
+}
+
+/// Buffered, append-only state diff log.
+#[derive(Debug)]
+pub struct StateDiffLog {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    flush_interval: Duration,
+    last_flush: Instant,
+    /// Diffs appended since the last flush.
+    pending: usize,
+}
+
+impl StateDiffLog {
+    /// Open (or create) the log at `path`, appending to any existing content.
+    ///
+    /// A zero `flush_interval` flushes after every appended diff.
+    pub fn open(path: impl AsRef<Path>, flush_interval: Duration) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        Ok(Self {
+            path,
+            writer: BufWriter::new(file),
+            flush_interval,
+            last_flush: Instant::now(),
+            pending: 0,
+        })
+    }
+
+    /// Wrap this log for sharing across tasks.
+    pub fn shared(self) -> SharedStateDiffLog {
+        Arc::new(Mutex::new(self))
+    }
+
+    /// Buffer a diff, flushing if the interval has elapsed.
+    pub fn append(&mut self, diff: &StateDiff) -> io::Result<()> {
+        writeln!(self.writer, "{}", encode_line(diff))?;
+        self.pending += 1;
+        self.flush_if_due()
+    }
+
+    /// Flush if there is buffered data and the interval has elapsed.
+    ///
+    /// Called from a periodic ticker so data reaches disk even when no new blocks arrive.
+    pub fn flush_if_due(&mut self) -> io::Result<()> {
+        if self.pending > 0 && self.last_flush.elapsed() >= self.flush_interval {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Write all buffered diffs and fsync the file.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()?;
+        self.writer.get_ref().sync_data()?;
+        self.pending = 0;
+        self.last_flush = Instant::now();
+        Ok(())
+    }
+
+    /// Number of diffs buffered since the last flush.
+    pub fn pending(&self) -> usize {
+        self.pending
+    }
+
+    /// Configured flush interval.
+    pub fn flush_interval(&self) -> Duration {
+        self.flush_interval
+    }
+
+    /// Path of the log file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for StateDiffLog {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
+
+/// Encode a diff as a single log line (see module docs for the format).
+pub fn encode_line(diff: &StateDiff) -> String {
+    let mut line = format!(
+        "block={} hash={} gas_used={} txs={}",
+        diff.block_number, diff.block_hash, diff.gas_used, diff.tx_count
+    );
+    if diff.reverted {
+        line.push_str(" reverted");
+    }
+
+    let mut accounts: Vec<_> = diff.changes.iter().collect();
+    accounts.sort_by_key(|(addr, _)| **addr);
+    for (addr, account) in accounts {
+        let _ = write!(line, " | {addr}");
+        encode_account(&mut line, account);
+    }
+    line
+}
+
+fn encode_account(line: &mut String, account: &AccountDiff) {
+    if let Some((old, new)) = account.balance {
+        let _ = write!(line, " balance={old}->{new}");
+    }
+    if let Some((old, new)) = account.nonce {
+        let _ = write!(line, " nonce={old}->{new}");
+    }
+    if account.code_changed {
+        line.push_str(" code");
+    }
+    if !account.storage.is_empty() {
+        let mut slots: Vec<_> = account.storage.iter().collect();
+        slots.sort_by_key(|(slot, _)| **slot);
+        let encoded: Vec<String> = slots
+            .into_iter()
+            .map(|(slot, d)| format!("{slot}:{}->{}", d.old_value, d.new_value))
+            .collect();
+        let _ = write!(line, " storage={}", encoded.join(","));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statediff::StateDiffBuilder;
+    use alloy_primitives::{Address, B256, U256};
+
+    /// Minimal temp dir that cleans up on drop.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir()
+                .join(format!("meowchain-statediff-{name}-{}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn sample_diff(block: u64) -> StateDiff {
+        let mut b = StateDiffBuilder::new(block, B256::from([block as u8; 32]))
+            .with_gas_used(21_000)
+            .with_tx_count(1);
+        b.record_balance_change(Address::with_last_byte(1), U256::from(100), U256::from(90));
+        b.record_nonce_change(Address::with_last_byte(1), 0, 1);
+        b.record_storage_change(
+            Address::with_last_byte(2),
+            U256::from(1),
+            B256::ZERO,
+            B256::with_last_byte(7),
+        );
+        b.build()
+    }
+
+    fn read_lines(path: &Path) -> Vec<String> {
+        std::fs::read_to_string(path)
+            .unwrap_or_default()
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_encode_line_is_deterministic() {
+        let line = encode_line(&sample_diff(5));
+        assert!(line.starts_with("block=5 "));
+        assert!(line.contains("gas_used=21000 txs=1"));
+        assert!(line.contains("balance=100->90 nonce=0->1"));
+        // Account 0x..01 sorts before 0x..02
+        let a1 = line
+            .find(&format!("{}", Address::with_last_byte(1)))
+            .unwrap();
+        let a2 = line
+            .find(&format!("{}", Address::with_last_byte(2)))
+            .unwrap();
+        assert!(a1 < a2);
+        assert_eq!(line, encode_line(&sample_diff(5)));
+    }
+
+    #[test]
+    fn test_diffs_buffered_until_interval_elapses() {
+        let dir = TempDir::new("interval");
+        let path = dir.0.join("statediff.log");
+        let mut log = StateDiffLog::open(&path, Duration::from_millis(50)).unwrap();
+
+        log.append(&sample_diff(1)).unwrap();
+        log.append(&sample_diff(2)).unwrap();
+        assert_eq!(log.pending(), 2);
+        assert!(read_lines(&path).is_empty(), "nothing flushed yet");
+
+        std::thread::sleep(Duration::from_millis(60));
+        log.flush_if_due().unwrap();
+        assert_eq!(log.pending(), 0);
+        let lines = read_lines(&path);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("block=1 "));
+        assert!(lines[1].starts_with("block=2 "));
+    }
+
+    #[test]
+    fn test_graceful_shutdown_flushes_pending() {
+        let dir = TempDir::new("shutdown");
+        let path = dir.0.join("statediff.log");
+        let log = StateDiffLog::open(&path, Duration::from_secs(3600))
+            .unwrap()
+            .shared();
+
+        log.lock().unwrap().append(&sample_diff(3)).unwrap();
+        assert!(read_lines(&path).is_empty());
+
+        // Simulated shutdown handler
+        log.lock().unwrap().flush().unwrap();
+        assert_eq!(read_lines(&path).len(), 1);
+
+        // Anything appended later is flushed when the log is dropped
+        log.lock().unwrap().append(&sample_diff(4)).unwrap();
+        drop(log);
+        assert_eq!(read_lines(&path).len(), 2);
+    }
+
+    #[test]
+    fn test_zero_interval_flushes_every_append() {
+        let dir = TempDir::new("zero");
+        let path = dir.0.join("statediff.log");
+        let mut log = StateDiffLog::open(&path, Duration::ZERO).unwrap();
+        log.append(&sample_diff(1)).unwrap();
+        assert_eq!(log.pending(), 0);
+        assert_eq!(read_lines(&path).len(), 1);
+    }
+
+    #[test]
+    fn test_open_appends_to_existing_log() {
+        let dir = TempDir::new("append");
+        let path = dir.0.join("nested/statediff.log");
+        {
+            let mut log = StateDiffLog::open(&path, Duration::ZERO).unwrap();
+            log.append(&sample_diff(1)).unwrap();
+        }
+        let mut log = StateDiffLog::open(&path, Duration::ZERO).unwrap();
+        log.append(&sample_diff(2)).unwrap();
+        assert_eq!(read_lines(&path).len(), 2);
+        assert_eq!(log.path(), path.as_path());
+    }
+
+    #[test]
+    fn test_lock_shared_recovers_poisoned_lock() {
+        let dir = TempDir::new("poison");
+        let path = dir.0.join("statediff.log");
+        let log = StateDiffLog::open(&path, Duration::ZERO).unwrap().shared();
+
+        let poisoner = log.clone();
+        let _ = std::thread::spawn(move || {
+            let _guard = poisoner.lock().unwrap();
+            panic!("poison the lock");
+        })
+        .join();
+        assert!(log.is_poisoned());
+
+        lock_shared(&log).append(&sample_diff(1)).unwrap();
+        assert_eq!(read_lines(&path).len(), 1);
+    }
+}
@@ -20,8 +20,33 @@
 //! println!("{}", diff.summary());
 //! ```
 
+pub mod json;
+pub mod log;
+
+pub use json::StateDiffJson;
+pub use log::{lock_shared, SharedStateDiffLog, StateDiffLog};
+
+use alloy_genesis::GenesisAccount;
 use alloy_primitives::{Address, B256, U256};
-use std::collections::HashMap;
+use reth_revm::db::BundleState;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+/// Diffs buffered per subscriber before a slow subscriber starts dropping them.
+pub const STATE_DIFF_CHANNEL_CAPACITY: usize = 256;
+
+/// Broadcast sender for committed state diffs (block monitor → `meow_subscribeStateDiff`).
+///
+/// Sending never blocks: subscribers that fall more than
+/// [`STATE_DIFF_CHANNEL_CAPACITY`] diffs behind skip the oldest ones.
+pub type StateDiffSender = broadcast::Sender<Arc<StateDiff>>;
+
+/// Create a state diff broadcast channel with no subscribers yet.
+pub fn state_diff_channel() -> StateDiffSender {
+    broadcast::channel(STATE_DIFF_CHANNEL_CAPACITY).0
+}
 
 // ── Per-account diff ──────────────────────────────────────────────────────────
 
@@ -46,6 +71,11 @@ impl StorageSlotDiff {
     pub fn is_noop(&self) -> bool {
         self.old_value == self.new_value
     }
+
+    /// The same change in reverse.
+    pub fn invert(&self) -> Self {
+        Self::new(self.new_value, self.old_value)
+    }
 }
 
 /// All changes to a single account during one block.
@@ -79,6 +109,21 @@ impl AccountDiff {
     pub fn is_storage_only(&self) -> bool {
         self.balance.is_none() && self.nonce.is_none() && !self.code_changed
     }
+
+    /// The same changes in reverse. A code change stays a code change: undoing a
+    /// creation is a destruction and vice versa.
+    pub fn invert(&self) -> Self {
+        Self {
+            balance: self.balance.map(|(old, new)| (new, old)),
+            nonce: self.nonce.map(|(old, new)| (new, old)),
+            code_changed: self.code_changed,
+            storage: self
+                .storage
+                .iter()
+                .map(|(slot, diff)| (*slot, diff.invert()))
+                .collect(),
+        }
+    }
 }
 
 // ── Block-level diff ─────────────────────────────────────────────────────────
@@ -87,7 +132,11 @@ impl AccountDiff {
 ///
 /// A diff captures *exactly* what changed; nothing that stayed the same is included.
 /// Applying the diff to state at `block_number - 1` yields state at `block_number`.
-#[derive(Debug, Clone, Default)]
+/// A `reverted` diff (see [`invert`](Self::invert)) undoes `block_number` instead.
+///
+/// Serializes through [`StateDiffJson`], a stable schema of sorted change arrays.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "StateDiffJson", from = "StateDiffJson")]
 pub struct StateDiff {
     /// The block that produced this diff.
     pub block_number: u64,
@@ -99,9 +148,30 @@ pub struct StateDiff {
     pub gas_used: u64,
     /// Number of transactions in the block.
     pub tx_count: usize,
+    /// Whether this diff rolls `block_number` back (emitted for reorged-out blocks).
+    pub reverted: bool,
 }
 
 impl StateDiff {
+    /// The diff that undoes this one: every old/new pair is swapped and
+    /// `reverted` is flipped. Inverting twice yields the original diff.
+    ///
+    /// Replicas apply the inverse of a reorged-out block to roll back to its parent.
+    pub fn invert(&self) -> Self {
+        Self {
+            block_number: self.block_number,
+            block_hash: self.block_hash,
+            changes: self
+                .changes
+                .iter()
+                .map(|(addr, diff)| (*addr, diff.invert()))
+                .collect(),
+            gas_used: self.gas_used,
+            tx_count: self.tx_count,
+            reverted: !self.reverted,
+        }
+    }
+
     /// Number of accounts touched by this block.
     pub fn touched_account_count(&self) -> usize {
         self.changes.len()
@@ -230,6 +300,49 @@ impl StateDiffBuilder {
         }
     }
 
+    /// Record every account and storage change in an executed block's bundle.
+    pub fn record_bundle(&mut self, bundle: &BundleState) {
+        for (addr, account) in &bundle.state {
+            let addr = *addr;
+            // Account-level changes: balance, nonce, code
+            match (&account.original_info, &account.info) {
+                (Some(old), Some(new)) => {
+                    self.record_balance_change(addr, old.balance, new.balance);
+                    self.record_nonce_change(addr, old.nonce, new.nonce);
+                    if old.code_hash != new.code_hash {
+                        self.record_code_change(addr);
+                    }
+                }
+                (None, Some(_)) => self.record_code_change(addr), // created
+                (Some(_), None) => self.record_code_change(addr), // destroyed
+                (None, None) => {}
+            }
+            // Storage-slot changes
+            for (slot_key, slot) in &account.storage {
+                if slot.is_changed() {
+                    let old = B256::from(slot.previous_or_original_value.to_be_bytes::<32>());
+                    let new = B256::from(slot.present_value.to_be_bytes::<32>());
+                    self.record_storage_change(addr, *slot_key, old, new);
+                }
+            }
+        }
+    }
+
+    /// Record a genesis alloc as account creations from empty state.
+    ///
+    /// Genesis has no parent to execute against, so this stands in for a block diff.
+    pub fn record_genesis_alloc(&mut self, alloc: &BTreeMap<Address, GenesisAccount>) {
+        for (addr, account) in alloc {
+            let addr = *addr;
+            self.record_code_change(addr);
+            self.record_balance_change(addr, U256::ZERO, account.balance);
+            self.record_nonce_change(addr, 0, account.nonce.unwrap_or_default());
+            for (slot, value) in account.storage.iter().flatten() {
+                self.record_storage_change(addr, U256::from_be_bytes(slot.0), B256::ZERO, *value);
+            }
+        }
+    }
+
     /// Set gas used after building incrementally.
     pub fn set_gas_used(&mut self, gas: u64) {
         self.gas_used = gas;
@@ -248,6 +361,7 @@ impl StateDiffBuilder {
             changes: self.changes,
             gas_used: self.gas_used,
             tx_count: self.tx_count,
+            reverted: false,
         }
     }
 
@@ -390,6 +504,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_builder_records_genesis_alloc_as_creations() {
+        let alloc = BTreeMap::from([
+            (
+                addr(1),
+                GenesisAccount {
+                    balance: U256::from(1_000u64),
+                    ..Default::default()
+                },
+            ),
+            (
+                addr(2),
+                GenesisAccount {
+                    nonce: Some(1),
+                    code: Some(vec![0x60, 0x00].into()),
+                    storage: Some(BTreeMap::from([(B256::with_last_byte(3), val(7))])),
+                    ..Default::default()
+                },
+            ),
+        ]);
+        let mut b = StateDiffBuilder::new(0, hash(0));
+        b.record_genesis_alloc(&alloc);
+        let diff = b.build();
+
+        assert_eq!(diff.touched_account_count(), 2);
+        let funded = diff.account_diff(&addr(1)).unwrap();
+        assert!(funded.code_changed);
+        assert_eq!(funded.balance, Some((U256::ZERO, U256::from(1_000u64))));
+        let contract = diff.account_diff(&addr(2)).unwrap();
+        assert_eq!(contract.nonce, Some((0, 1)));
+        assert_eq!(diff.storage_after(&addr(2), &slot(3)), Some(val(7)));
+    }
+
     #[test]
     fn test_builder_ignores_noop_balance_change() {
         let mut b = StateDiffBuilder::new(1, hash(1));
@@ -569,6 +716,78 @@ mod tests {
         assert_eq!(state[&addr(1)][&slot(0)], val(5));
     }
 
+    // ── invert ────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_invert_swaps_every_change() {
+        let mut b = StateDiffBuilder::new(5, hash(5));
+        b.record_balance_change(addr(1), U256::from(100), U256::from(40));
+        b.record_nonce_change(addr(1), 2, 3);
+        b.record_code_change(addr(2));
+        b.record_storage_change(addr(2), slot(1), B256::ZERO, val(7));
+        let diff = b.build();
+
+        let inverse = diff.invert();
+        assert!(inverse.reverted);
+        assert_eq!(inverse.block_number, 5);
+        let a1 = inverse.account_diff(&addr(1)).unwrap();
+        assert_eq!(a1.balance, Some((U256::from(40), U256::from(100))));
+        assert_eq!(a1.nonce, Some((3, 2)));
+        let a2 = inverse.account_diff(&addr(2)).unwrap();
+        assert!(a2.code_changed);
+        assert_eq!(
+            a2.storage[&slot(1)],
+            StorageSlotDiff::new(val(7), B256::ZERO)
+        );
+
+        assert_eq!(inverse.invert(), diff, "double inversion is identity");
+    }
+
+    #[test]
+    fn test_apply_then_invert_restores_state() {
+        // Property: for any pre-state and any diff consistent with it,
+        // applying the diff and then its inverse returns the original state.
+        let mut seed: u64 = 0x5eed;
+        let mut next = |bound: u64| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed % bound
+        };
+
+        for case in 0..200u64 {
+            let mut state: HashMap<Address, HashMap<U256, B256>> = HashMap::new();
+            for _ in 0..next(8) {
+                let v = next(4) as u8;
+                if v != 0 {
+                    state
+                        .entry(addr(next(4) as u8))
+                        .or_default()
+                        .insert(slot(next(6)), val(v));
+                }
+            }
+            let original = state.clone();
+
+            let mut b = StateDiffBuilder::new(case, hash(case as u8));
+            for _ in 0..next(10) {
+                let (a, s) = (addr(next(4) as u8), slot(next(6)));
+                let old = original
+                    .get(&a)
+                    .and_then(|m| m.get(&s))
+                    .copied()
+                    .unwrap_or(B256::ZERO);
+                b.record_storage_change(a, s, old, val(next(4) as u8));
+            }
+            let diff = b.build();
+            assert!(verify_diff_against_pre_state(&original, &diff));
+
+            apply_diff(&mut state, &diff);
+            apply_diff(&mut state, &diff.invert());
+            state.retain(|_, slots| !slots.is_empty());
+            assert_eq!(state, original, "case {case}");
+        }
+    }
+
     // ── verify_diff_against_pre_state ────────────────────────────────────────
 
     #[test]